@@ -89,12 +89,14 @@ where
     heuristic: Option<Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>>,
     context: Option<C>,
     termination: Option<Box<dyn Termination<Context = C, Objective = O>>>,
+    additional_terminations: Vec<Box<dyn Termination<Context = C, Objective = O>>>,
     strategy: Option<Box<dyn EvolutionStrategy<Context = C, Objective = O, Solution = S>>>,
 
     search_operators: Option<HeuristicSearchOperators<C, O, S>>,
     diversify_operators: Option<HeuristicDiversifyOperators<C, O, S>>,
 
     objective: Option<Arc<dyn HeuristicObjective<Solution = S>>>,
+    observer: Option<TelemetryObserver<S>>,
 
     initial: InitialConfig<C, O, S>,
     processing: ProcessingConfig<C, O, S>,
@@ -116,10 +118,12 @@ where
             heuristic: None,
             context: None,
             termination: None,
+            additional_terminations: vec![],
             strategy: None,
             search_operators: None,
             diversify_operators: None,
             objective: None,
+            observer: None,
             initial: InitialConfig { operators: vec![], max_size: 4, quota: 0.05, individuals: vec![] },
             processing: ProcessingConfig { context: vec![], solution: vec![] },
         }
@@ -188,18 +192,33 @@ where
         self
     }
 
+    /// Sets an observer which is called on each new best solution found during evolution.
+    pub fn with_observer(mut self, observer: TelemetryObserver<S>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
     /// Sets heuristic context.
     pub fn with_context(mut self, context: C) -> Self {
         self.context = Some(context);
         self
     }
 
-    /// Sets termination.
+    /// Sets termination which replaces the configured termination criteria.
     pub fn with_termination(mut self, termination: Box<dyn Termination<Context = C, Objective = O>>) -> Self {
         self.termination = Some(termination);
         self
     }
 
+    /// Adds a termination which is used in addition to the configured termination criteria.
+    pub fn with_additional_termination(
+        mut self,
+        termination: Box<dyn Termination<Context = C, Objective = O>>,
+    ) -> Self {
+        self.additional_terminations.push(termination);
+        self
+    }
+
     /// Sets a different heuristic replacing initial.
     pub fn with_heuristic(
         mut self,
@@ -302,10 +321,29 @@ where
 
     /// Builds the evolution config.
     pub fn build(self) -> Result<EvolutionConfig<C, O, S>, GenericError> {
-        let context = self.context.ok_or_else(|| "missing heuristic context".to_string())?;
+        let mut context = self.context.ok_or_else(|| "missing heuristic context".to_string())?;
+        if let Some(observer) = self.observer {
+            context.set_observer(observer);
+        }
+
         let logger = context.environment().logger.clone();
-        let termination =
-            Self::get_termination(&logger, self.max_generations, self.max_time, self.min_cv, self.target_proximity)?;
+        let termination = match self.termination {
+            Some(termination) => {
+                (logger)("configured to use a custom termination");
+                termination
+            }
+            None => {
+                Self::get_termination(&logger, self.max_generations, self.max_time, self.min_cv, self.target_proximity)?
+            }
+        };
+        let termination: Box<dyn Termination<Context = C, Objective = O>> = if self.additional_terminations.is_empty() {
+            termination
+        } else {
+            // NOTE additional terminations go first to be evaluated on each generation
+            let mut terminations = self.additional_terminations;
+            terminations.push(termination);
+            Box::new(CompositeTermination::new(terminations))
+        };
 
         Ok(EvolutionConfig {
             initial: self.initial,
@@ -58,7 +58,10 @@ where
         let _ = (init_size..config.initial.max_size).try_for_each(|idx| {
             let item_time = Timer::start();
 
-            let is_overall_termination = config.termination.is_termination(&mut heuristic_ctx);
+            // NOTE at least one initial solution is built to have a result when quota is reached early
+            let is_quota_reached =
+                idx > 0 && heuristic_ctx.environment().quota.as_ref().is_some_and(|quota| quota.is_reached());
+            let is_overall_termination = config.termination.is_termination(&mut heuristic_ctx) || is_quota_reached;
             let is_initial_quota_reached = config.termination.estimate(&heuristic_ctx) > config.initial.quota;
 
             if is_initial_quota_reached || is_overall_termination {
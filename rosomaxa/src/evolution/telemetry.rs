@@ -9,6 +9,7 @@ use crate::prelude::*;
 use crate::utils::Timer;
 use crate::{DynHeuristicPopulation, RemedianUsize};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// Encapsulates different measurements regarding algorithm evaluation.
 pub struct TelemetryMetrics {
@@ -84,6 +85,10 @@ pub enum TelemetryMode {
     },
 }
 
+/// Specifies a callback which is called on each new best individual found during the search.
+/// It accepts generation number, best individual's fitness values and the individual itself.
+pub type TelemetryObserver<S> = Arc<dyn Fn(usize, &[Float], &S) + Send + Sync>;
+
 /// Provides way to collect metrics and write information into log.
 pub struct Telemetry<O, S>
 where
@@ -97,6 +102,8 @@ where
     improvement_tracker: ImprovementTracker,
     speed_tracker: SpeedTracker,
     next_generation: Option<usize>,
+    observer: Option<TelemetryObserver<S>>,
+    best_fitness: Option<Vec<Float>>,
     _marker: (PhantomData<O>, PhantomData<S>),
 }
 
@@ -115,10 +122,29 @@ where
             improvement_tracker: ImprovementTracker::new(1000),
             speed_tracker: SpeedTracker::default(),
             next_generation: None,
+            observer: None,
+            best_fitness: None,
             _marker: Default::default(),
         }
     }
 
+    /// Sets an observer which is notified about each new best individual.
+    pub fn set_observer(&mut self, observer: TelemetryObserver<S>) {
+        self.observer = Some(observer);
+    }
+
+    /// Notifies observer if the best individual in population has changed since last call.
+    pub fn on_best(&mut self, population: &DynHeuristicPopulation<O, S>) {
+        let Some(observer) = self.observer.as_ref() else { return };
+        let Some(best_individual) = population.ranked().next() else { return };
+
+        let fitness = best_individual.fitness().collect::<Vec<_>>();
+        if self.best_fitness.as_ref() != Some(&fitness) {
+            (observer)(self.statistics.generation, fitness.as_slice(), best_individual);
+            self.best_fitness = Some(fitness);
+        }
+    }
+
     /// Reports initial solution statistics.
     pub fn on_initial(&mut self, solution: &S, name: &str, item_time: Timer) {
         match &self.mode {
@@ -161,6 +187,8 @@ where
             termination_estimate,
         };
 
+        self.on_best(population);
+
         let (log_best, log_population, track_population) = match &self.mode {
             TelemetryMode::None => return,
             TelemetryMode::OnlyLogging { log_best, log_population, .. } => (Some(log_best), Some(log_population), None),
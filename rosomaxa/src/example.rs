@@ -93,6 +93,10 @@ impl HeuristicContext for VectorContext {
         self.inner_context.environment()
     }
 
+    fn set_observer(&mut self, observer: TelemetryObserver<Self::Solution>) {
+        self.inner_context.set_observer(observer)
+    }
+
     fn on_initial(&mut self, solution: Self::Solution, name: &str, item_time: Timer) {
         self.inner_context.on_initial(solution, name, item_time)
     }
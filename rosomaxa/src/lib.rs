@@ -58,7 +58,7 @@ pub mod termination;
 pub mod utils;
 
 use crate::algorithms::math::RemedianUsize;
-use crate::evolution::{Telemetry, TelemetryMetrics, TelemetryMode};
+use crate::evolution::{Telemetry, TelemetryMetrics, TelemetryMode, TelemetryObserver};
 use crate::population::*;
 use crate::prelude::*;
 use crate::utils::Timer;
@@ -103,6 +103,9 @@ pub trait HeuristicContext: Send + Sync {
     /// Returns environment.
     fn environment(&self) -> &Environment;
 
+    /// Sets an observer which is notified about each new best solution. Does nothing by default.
+    fn set_observer(&mut self, _observer: TelemetryObserver<Self::Solution>) {}
+
     /// Updates population with initial solution.
     fn on_initial(&mut self, solution: Self::Solution, name: &str, item_time: Timer);
 
@@ -219,9 +222,14 @@ where
         self.environment.as_ref()
     }
 
+    fn set_observer(&mut self, observer: TelemetryObserver<Self::Solution>) {
+        self.telemetry.set_observer(observer);
+    }
+
     fn on_initial(&mut self, solution: Self::Solution, name: &str, item_time: Timer) {
         self.telemetry.on_initial(&solution, name, item_time);
         self.population.add(solution);
+        self.telemetry.on_best(self.population.as_ref());
     }
 
     fn on_generation(&mut self, offspring: Vec<Self::Solution>, termination_estimate: Float, generation_time: Timer) {
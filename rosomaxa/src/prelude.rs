@@ -25,6 +25,7 @@ pub use crate::hyper::HyperHeuristic;
 
 pub use crate::termination::Termination;
 
+pub use crate::utils::CancellationToken;
 pub use crate::utils::DefaultRandom;
 pub use crate::utils::Environment;
 pub use crate::utils::Float;
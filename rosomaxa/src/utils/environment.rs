@@ -1,7 +1,12 @@
 //! Contains environment specific logic.
 
+#[cfg(test)]
+#[path = "../../tests/unit/utils/environment_test.rs"]
+mod environment_test;
+
 use crate::utils::{DefaultRandom, Float, Random, ThreadPool, Timer};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// A logger type which is called with various information.
 pub type InfoLogger = Arc<dyn Fn(&str) + Send + Sync>;
@@ -51,6 +56,16 @@ impl Environment {
     ) -> Self {
         Self { random, quota, parallelism, logger, is_experimental }
     }
+
+    /// Returns a copy of the environment which quota is also reached when the given token is cancelled.
+    pub fn with_cancellation(self, token: CancellationToken) -> Self {
+        let quota: Arc<dyn Quota> = match self.quota.clone() {
+            Some(inner) => Arc::new(CancellableQuota { inner, token }),
+            None => Arc::new(token),
+        };
+
+        Self { quota: Some(quota), ..self }
+    }
 }
 
 impl Default for Environment {
@@ -84,6 +99,42 @@ impl Quota for TimeQuota {
     }
 }
 
+/// A cancellation token which allows to stop computation from outside, e.g. from another thread.
+/// All clones of the token share the same state.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Requests cancellation.
+    pub fn cancel(&self) {
+        self.is_cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if cancellation was requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.is_cancelled.load(Ordering::Relaxed)
+    }
+}
+
+impl Quota for CancellationToken {
+    fn is_reached(&self) -> bool {
+        self.is_cancelled()
+    }
+}
+
+struct CancellableQuota {
+    inner: Arc<dyn Quota>,
+    token: CancellationToken,
+}
+
+impl Quota for CancellableQuota {
+    fn is_reached(&self) -> bool {
+        self.token.is_cancelled() || self.inner.is_reached()
+    }
+}
+
 /// Specifies data parallelism settings.
 #[derive(Clone)]
 pub struct Parallelism {
@@ -38,3 +38,27 @@ fn can_update_statistic() {
     telemetry.on_generation(population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
 }
+
+#[test]
+fn can_notify_observer_about_new_best() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let mut population = get_default_population(objective.clone(), VectorRosomaxaContext, environment, selection_size);
+    let notifications = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut telemetry = Telemetry::new(TelemetryMode::None);
+    telemetry.set_observer(Arc::new({
+        let notifications = notifications.clone();
+        move |generation, fitness: &[Float], _: &VectorSolution| {
+            notifications.lock().unwrap().push((generation, fitness.to_vec()))
+        }
+    }));
+
+    population.add(VectorSolution::new_with_objective(vec![2., 2.], objective.as_ref()));
+    telemetry.on_generation(population.as_ref(), 0., Timer::start(), true);
+    telemetry.on_generation(population.as_ref(), 0., Timer::start(), false);
+    population.add(VectorSolution::new_with_objective(vec![1., 1.], objective.as_ref()));
+    telemetry.on_generation(population.as_ref(), 0., Timer::start(), true);
+
+    assert_eq!(notifications.lock().unwrap().as_slice(), &[(0, vec![401.]), (2, vec![0.])]);
+}
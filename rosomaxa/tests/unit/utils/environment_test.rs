@@ -0,0 +1,38 @@
+use super::*;
+
+#[test]
+fn can_cancel_using_token_clone() {
+    let token = CancellationToken::default();
+    let environment = Environment::default().with_cancellation(token.clone());
+    let quota = environment.quota.expect("quota should be set");
+
+    assert!(!quota.is_reached());
+    token.clone().cancel();
+
+    assert!(token.is_cancelled());
+    assert!(quota.is_reached());
+}
+
+parameterized_test! {can_combine_cancellation_with_existing_quota, (max_time, is_cancelled, expected), {
+    can_combine_cancellation_with_existing_quota_impl(max_time, is_cancelled, expected);
+}}
+
+can_combine_cancellation_with_existing_quota! {
+    case01_not_reached: (Some(100), false, false),
+    case02_cancelled: (Some(100), true, true),
+    case03_time_reached: (Some(0), false, true),
+}
+
+fn can_combine_cancellation_with_existing_quota_impl(max_time: Option<usize>, is_cancelled: bool, expected: bool) {
+    let token = CancellationToken::default();
+    let environment = Environment::new_with_time_quota(max_time).with_cancellation(token.clone());
+    if is_cancelled {
+        token.cancel();
+    }
+    // NOTE ensure that zero time quota is exceeded
+    std::thread::sleep(std::time::Duration::from_millis(1));
+
+    let result = environment.quota.expect("quota should be set").is_reached();
+
+    assert_eq!(result, expected);
+}
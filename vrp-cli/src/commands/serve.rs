@@ -0,0 +1,47 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/serve_test.rs"]
+mod serve_test;
+
+use super::*;
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::time::Duration;
+use vrp_cli::extensions::serve::{DEFAULT_JOB_TTL, SolverServer, run_server};
+use vrp_core::prelude::GenericError;
+use vrp_core::utils::InfoLogger;
+
+const PORT_ARG_NAME: &str = "port";
+const DEFAULT_PORT: u16 = 8080;
+const JOB_TTL_ARG_NAME: &str = "job-ttl";
+
+pub fn get_serve_app() -> Command {
+    Command::new("serve")
+        .about("Runs a local HTTP server which solves problems in pragmatic format asynchronously")
+        .arg(
+            Arg::new(PORT_ARG_NAME)
+                .help("Specifies a port to listen on localhost. Default is 8080")
+                .short('p')
+                .long(PORT_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(JOB_TTL_ARG_NAME)
+                .help("Specifies time in seconds to keep finished jobs in memory. Default is 3600")
+                .long(JOB_TTL_ARG_NAME)
+                .required(false),
+        )
+}
+
+pub fn run_serve(matches: &ArgMatches) -> Result<(), GenericError> {
+    let port = parse_int_value::<u16>(matches, PORT_ARG_NAME, "port")?.unwrap_or(DEFAULT_PORT);
+    let job_ttl =
+        parse_int_value::<u64>(matches, JOB_TTL_ARG_NAME, "job ttl")?.map_or(DEFAULT_JOB_TTL, Duration::from_secs);
+    let logger: InfoLogger = Arc::new(|msg: &str| println!("{msg}"));
+
+    let listener =
+        TcpListener::bind(("127.0.0.1", port)).map_err(|err| format!("cannot listen on port '{port}': '{err}'"))?;
+
+    (logger)(format!("listening on http://{}", listener.local_addr()?).as_str());
+
+    run_server(listener, Arc::new(SolverServer::new(job_ttl, logger)))
+}
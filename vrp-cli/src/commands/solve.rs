@@ -6,10 +6,11 @@ use super::*;
 
 use clap::ArgAction;
 use std::fs::File;
-use std::io::{BufReader, BufWriter, Write};
+use std::io::{BufReader, BufWriter, Cursor, Read, Write};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use vrp_cli::core::solver::TargetHeuristic;
+use vrp_cli::extensions::check::check_pragmatic_solution;
 use vrp_cli::extensions::solve::config::create_builder_from_config_file;
 use vrp_cli::extensions::solve::formats::*;
 use vrp_core::construction::heuristics::InsertionContext;
@@ -18,6 +19,11 @@ use vrp_core::prelude::*;
 use vrp_core::rosomaxa::{evolution::*, get_default_population, get_default_selection_size};
 use vrp_core::solver::*;
 use vrp_core::utils::*;
+use vrp_pragmatic::format::problem::{
+    Problem as ApiProblem, deserialize_matrix, deserialize_problem, serialize_problem,
+};
+use vrp_pragmatic::format::replanning::{create_replanning_problem, deserialize_execution_state};
+use vrp_pragmatic::format::solution::{deserialize_solution, read_init_solution, serialize_solution};
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -29,6 +35,7 @@ const GEO_JSON_ARG_NAME: &str = "geo-json";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const INIT_SIZE_ARG_NAME: &str = "init-size";
+const EXECUTION_STATE_ARG_NAME: &str = "execution-state";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 const CONFIG_ARG_NAME: &str = "config";
@@ -87,6 +94,15 @@ pub fn get_solve_app() -> Command {
                 .long(INIT_SIZE_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(EXECUTION_STATE_ARG_NAME)
+                .help(
+                    "Specifies path to file with execution state of initial solution to re-plan it. Applicable only for pragmatic format",
+                )
+                .long(EXECUTION_STATE_ARG_NAME)
+                .requires(INIT_SOLUTION_ARG_NAME)
+                .required(false)
+        )
         .arg(
             Arg::new(MATRIX_ARG_NAME)
                 .help("Specifies path to file with routing matrix")
@@ -198,6 +214,8 @@ pub fn run_solve(
     let problem_file = open_file(problem_path, "problem");
 
     let init_solution = matches.get_one::<String>(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
+    let execution_state =
+        matches.get_one::<String>(EXECUTION_STATE_ARG_NAME).map(|path| open_file(path, "execution state"));
     let config = matches.get_one::<String>(CONFIG_ARG_NAME).map(|path| open_file(path, "config"));
     let matrix_files = get_matrix_files(matches);
     let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
@@ -219,8 +237,25 @@ pub fn run_solve(
             if is_get_locations_set {
                 locations_writer(problem_file, out_buffer).map_err(|err| format!("cannot get locations '{err}'").into())
             } else {
-                match problem_reader(problem_file, matrix_files) {
-                    Ok(problem) => {
+                let (problem, init_solutions, replanning_problem) = match execution_state {
+                    Some(execution_state) => {
+                        let (api_problem, problem, init_solution) = read_replanning_problem(
+                            problem_format,
+                            problem_file,
+                            init_solution,
+                            execution_state,
+                            matrix_files,
+                            environment.clone(),
+                        )?;
+
+                        (problem, vec![init_solution], Some(api_problem))
+                    }
+                    None => {
+                        let problem = problem_reader(problem_file, matrix_files).map_err(|error| {
+                            GenericError::from(format!(
+                                "cannot read {problem_format} problem from '{problem_path}': '{error}'"
+                            ))
+                        })?;
                         let problem = Arc::new(problem);
 
                         let init_solutions = read_init_solutions_if_necessary(
@@ -230,26 +265,28 @@ pub fn run_solve(
                             init_reader,
                         )?;
 
-                        let solver = match config {
-                            Some(config) => from_config_parameters(problem.clone(), init_solutions, config)?,
-                            _ => from_cli_parameters(problem.clone(), environment, init_solutions, matches)?,
-                        };
+                        (problem, init_solutions, None)
+                    }
+                };
 
-                        let solution = solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?;
+                let solver = match config {
+                    Some(config) => from_config_parameters(problem.clone(), init_solutions, config)?,
+                    _ => from_cli_parameters(problem.clone(), environment, init_solutions, matches)?,
+                };
 
-                        solution_writer(&problem, solution, out_buffer, geo_buffer)?;
+                let solution = solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?;
 
-                        if is_check_requested {
-                            check_pragmatic_solution_with_args(matches)?;
-                            println!("solution feasibility check is completed successfully");
-                        }
+                solution_writer(&problem, solution, out_buffer, geo_buffer)?;
 
-                        Ok(())
-                    }
-                    Err(error) => {
-                        Err(format!("cannot read {problem_format} problem from '{problem_path}': '{error}'").into())
+                if is_check_requested {
+                    match replanning_problem {
+                        Some(api_problem) => check_replanning_solution_with_args(matches, &api_problem)?,
+                        None => check_pragmatic_solution_with_args(matches)?,
                     }
+                    println!("solution feasibility check is completed successfully");
                 }
+
+                Ok(())
             }
         }
         None => Err(format!("unknown format: '{problem_format}'").into()),
@@ -284,6 +321,53 @@ fn read_init_solutions_if_necessary(
     })
 }
 
+/// Applies execution state to pragmatic problem and its initial solution. Returns the problem with the rest of
+/// the work in both formats and initial solution for it.
+fn read_replanning_problem(
+    problem_format: &str,
+    problem_file: File,
+    init_solution_file: Option<File>,
+    execution_state_file: File,
+    matrix_files: Option<Vec<File>>,
+    environment: Arc<Environment>,
+) -> GenericResult<(ApiProblem, Arc<Problem>, InsertionContext)> {
+    if problem_format != "pragmatic" {
+        return Err(format!("execution state is not supported for {problem_format} format").into());
+    }
+
+    let init_solution_file =
+        init_solution_file.ok_or_else(|| GenericError::from("execution state requires initial solution"))?;
+
+    let api_problem = deserialize_problem(BufReader::new(problem_file))
+        .map_err(|err| format!("cannot read pragmatic problem: '{err}'"))?;
+    let api_solution = deserialize_solution(BufReader::new(init_solution_file))
+        .map_err(|err| format!("cannot read initial solution: '{err}'"))?;
+    let state = deserialize_execution_state(BufReader::new(execution_state_file))?;
+    let matrices = matrix_files
+        .map(|files| {
+            files.into_iter().map(|file| deserialize_matrix(BufReader::new(file))).collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|err| format!("cannot read routing matrix: '{err}'"))?;
+
+    let (api_problem, api_solution) = create_replanning_problem(&api_problem, &api_solution, &state)?;
+
+    let problem = Arc::new(
+        (api_problem.clone(), matrices)
+            .read_pragmatic()
+            .map_err(|err| format!("cannot read re-planning problem: '{err}'"))?,
+    );
+
+    let mut buffer = BufWriter::new(Vec::new());
+    serialize_solution(&api_solution, &mut buffer).map_err(|err| format!("cannot write initial solution: '{err}'"))?;
+    let buffer = buffer.into_inner().map_err(|err| format!("cannot write initial solution: '{err}'"))?;
+
+    let solution = read_init_solution(BufReader::new(buffer.as_slice()), problem.clone(), environment.random.clone())
+        .map_err(|err| format!("cannot read initial solution: '{err}'"))?;
+
+    Ok((api_problem, problem.clone(), InsertionContext::new_from_solution(problem, (solution, None), environment)))
+}
+
 fn from_config_parameters(
     problem: Arc<Problem>,
     init_solutions: Vec<InsertionContext>,
@@ -434,6 +518,28 @@ fn check_pragmatic_solution_with_args(matches: &ArgMatches) -> GenericResult<()>
     check_solution(matches, "pragmatic", PROBLEM_ARG_NAME, OUT_RESULT_ARG_NAME, MATRIX_ARG_NAME)
 }
 
+fn check_replanning_solution_with_args(matches: &ArgMatches, problem: &ApiProblem) -> GenericResult<()> {
+    let mut buffer = BufWriter::new(Vec::new());
+    serialize_problem(problem, &mut buffer).map_err(|err| format!("cannot write re-planning problem: '{err}'"))?;
+    let buffer = buffer.into_inner().map_err(|err| format!("cannot write re-planning problem: '{err}'"))?;
+
+    let into_reader = |reader: Box<dyn Read>| BufReader::new(reader);
+    let solution_file = matches
+        .get_one::<String>(OUT_RESULT_ARG_NAME)
+        .map(|path| open_file(path, "solution"))
+        .ok_or_else(|| GenericError::from("checker requires solution file"))?;
+    let matrix_files = matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .map(|paths| paths.map(|path| into_reader(Box::new(open_file(path, "routing matrix")))).collect());
+
+    check_pragmatic_solution(
+        into_reader(Box::new(Cursor::new(buffer))),
+        into_reader(Box::new(solution_file)),
+        matrix_files,
+    )
+    .map_err(|errs| format!("checker found {} errors:\n{}", errs.len(), GenericError::join_many(&errs, "\n")).into())
+}
+
 /// Creates interruption quota.
 pub fn create_interruption_quota(max_time: Option<usize>) -> Arc<dyn Quota> {
     struct InterruptionQuota {
@@ -33,11 +33,16 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
                 capacity: get_random_item(capacities.as_slice(), &rnd).expect("cannot find any capacity").clone(),
                 skills: get_random_item(skills.as_slice(), &rnd).expect("cannot find any skills").clone(),
                 limits: get_random_item(limits.as_slice(), &rnd).expect("cannot find any limits").clone(),
+                compartments: None,
+                hours_of_service: None,
+                consumption: None,
+                loading_policy: None,
+                cargo_space: None,
             }
         })
         .collect();
 
-    Fleet { vehicles, profiles, resources: None }
+    Fleet { vehicles, drivers: None, profiles, resources: None }
 }
 
 fn get_from_vehicle<F, T>(problem_proto: &Problem, func: F) -> Vec<T>
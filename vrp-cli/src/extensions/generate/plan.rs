@@ -46,6 +46,8 @@ pub(crate) fn generate_plan(
                         get_random_item(demands.as_slice(), &rnd).cloned()
                     },
                     order: task.order,
+                    product: None,
+                    items: None,
                 })
                 .collect::<Vec<_>>()
         })
@@ -69,11 +71,18 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                visits: job_proto.visits.clone(),
+                satellite: None,
+                soft_time_windows: None,
+                split: None,
+                max_ride_time: None,
+                sync: None,
+                mandatory: None,
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, clustering: None })
+    Ok(Plan { jobs, relations: None, precedences: None, alternatives: None, sites: None, clustering: None })
 }
 
 type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
@@ -74,6 +74,8 @@ mod actual {
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
+            product: None,
+            items: None,
         };
 
         let get_tasks = |jobs: &Vec<&CsvJob>, filter: Box<dyn Fn(&CsvJob) -> bool>| {
@@ -98,6 +100,13 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                visits: None,
+                satellite: None,
+                soft_time_windows: None,
+                split: None,
+                max_ride_time: None,
+                sync: None,
+                mandatory: None,
             })
             .collect();
 
@@ -120,15 +129,22 @@ mod actual {
                             earliest: vehicle.tw_start,
                             latest: None,
                             location: depot_location.clone(),
+                            satellite: None,
                         },
                         end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
                         breaks: None,
                         reloads: None,
                         recharges: None,
+                        overtime: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    hours_of_service: None,
+                    consumption: None,
+                    loading_policy: None,
+                    cargo_space: None,
                 }
             })
             .collect();
@@ -155,10 +171,14 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, clustering: None },
+            plan: Plan { jobs, relations: None, precedences: None, alternatives: None, sites: None, clustering: None },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                drivers: None,
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile { name, speed: None, speed_profiles: None })
+                    .collect(),
                 resources: None,
             },
             objectives: None,
@@ -0,0 +1,354 @@
+//! A helper module which contains functionality to run solver as a local HTTP service.
+//!
+//! The service accepts problems in `pragmatic` format and solves them asynchronously as jobs
+//! identified by id. The following endpoints are available:
+//!
+//! - `POST /solve`: submits a new job, a request body has `problem`, optional `matrices` and `config`
+//! - `GET /solve/{id}`: returns job status
+//! - `GET /solve/{id}/best`: returns the best known solution found so far
+//! - `GET /solve/{id}/result`: returns the final solution
+//! - `DELETE /solve/{id}`: cancels the job, the best known solution becomes the final one
+//!
+//! Finished jobs are evicted after a configured time to live.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/serve_test.rs"]
+mod serve_test;
+
+use crate::extensions::solve::config::Config;
+use crate::{get_solution_serialized_with_cancellation, serialize_solution};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::{GoalContext, Problem as CoreProblem};
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::TelemetryObserver;
+use vrp_core::rosomaxa::prelude::*;
+use vrp_core::solver::RefinementContext;
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem};
+
+/// A request to solve a problem.
+#[derive(Deserialize)]
+pub struct SolveRequest {
+    /// A problem definition in pragmatic format.
+    pub problem: Problem,
+    /// Routing matrices.
+    pub matrices: Option<Vec<Matrix>>,
+    /// A solver configuration.
+    pub config: Option<Config>,
+}
+
+/// Specifies a status of the solve job.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JobStatus {
+    /// The job is running.
+    Running,
+    /// The job is completed.
+    Completed,
+    /// The job is cancelled, the best known solution is used as the final one.
+    Cancelled,
+    /// The job is failed.
+    Failed,
+}
+
+/// A default time to live of finished jobs.
+pub const DEFAULT_JOB_TTL: Duration = Duration::from_secs(3600);
+
+/// A max size of request body in bytes.
+const MAX_BODY_SIZE: usize = 256 * 1024 * 1024;
+
+/// A simplified http response.
+pub struct HttpResponse {
+    /// A status code.
+    pub status: u16,
+    /// A response body in json.
+    pub body: String,
+}
+
+/// Keeps track of solve jobs submitted to the server. Jobs are kept in memory while they are running
+/// and evicted when their time to live is elapsed after they are finished.
+pub struct SolverServer {
+    jobs: Mutex<HashMap<String, Arc<SolveJob>>>,
+    counter: AtomicUsize,
+    job_ttl: Duration,
+    logger: InfoLogger,
+}
+
+impl Default for SolverServer {
+    fn default() -> Self {
+        Self::new(DEFAULT_JOB_TTL, Environment::default().logger)
+    }
+}
+
+impl SolverServer {
+    /// Creates a new instance of `SolverServer`.
+    pub fn new(job_ttl: Duration, logger: InfoLogger) -> Self {
+        Self { jobs: Default::default(), counter: Default::default(), job_ttl, logger }
+    }
+
+    /// Submits a new solve job and returns its id.
+    pub fn submit(&self, request: SolveRequest) -> Result<String, GenericError> {
+        self.evict_finished_jobs();
+
+        let problem = match request.matrices {
+            Some(matrices) => (request.problem, matrices).read_pragmatic(),
+            None => request.problem.read_pragmatic(),
+        }
+        .map_err(|errs| errs.to_json())?;
+
+        let id = (self.counter.fetch_add(1, Ordering::Relaxed) + 1).to_string();
+        let job = Arc::new(SolveJob {
+            problem: Arc::new(problem),
+            config: request.config.unwrap_or_default(),
+            cancellation: CancellationToken::default(),
+            state: Arc::new(Mutex::new(JobState::default())),
+        });
+
+        self.jobs.lock().unwrap().insert(id.clone(), job.clone());
+
+        thread::spawn(move || {
+            let termination = JobTermination { state: job.state.clone() };
+            let observer: TelemetryObserver<InsertionContext> = Arc::new({
+                let state = job.state.clone();
+                move |_, fitness, insertion_ctx| {
+                    let mut state = state.lock().unwrap();
+                    state.best = Some(insertion_ctx.deep_copy().into());
+                    state.fitness = Some(fitness.to_vec());
+                }
+            });
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                let cancellation = job.cancellation.clone();
+                get_solution_serialized_with_cancellation(
+                    job.problem.clone(),
+                    job.config.clone(),
+                    cancellation,
+                    |builder| builder.with_additional_termination(Box::new(termination)).with_observer(observer),
+                )
+            }))
+            .unwrap_or_else(|_| Err("solver panicked".into()));
+
+            let mut state = job.state.lock().unwrap();
+            state.status = match (&result, job.cancellation.is_cancelled()) {
+                (Err(_), _) => JobStatus::Failed,
+                (Ok(_), true) => JobStatus::Cancelled,
+                (Ok(_), false) => JobStatus::Completed,
+            };
+            state.result = Some(result.map_err(|err| err.to_string()));
+            state.finished = Some(Instant::now());
+        });
+
+        Ok(id)
+    }
+
+    /// Returns status of the job with given id.
+    pub fn status(&self, id: &str) -> Option<JobStatus> {
+        self.get_job(id).map(|job| job.state.lock().unwrap().status)
+    }
+
+    /// Cancels the job with given id. Returns false if there is no such job.
+    pub fn cancel(&self, id: &str) -> bool {
+        self.get_job(id).map(|job| job.cancellation.cancel()).is_some()
+    }
+
+    /// Handles http request and returns a response.
+    pub fn handle(&self, method: &str, path: &str, body: &str) -> HttpResponse {
+        self.evict_finished_jobs();
+
+        let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+        match (method, segments.as_slice()) {
+            ("POST", ["solve"]) => match serde_json::from_str::<SolveRequest>(body) {
+                Ok(request) => match self.submit(request) {
+                    Ok(id) => HttpResponse { status: 202, body: serde_json::json!({ "id": id }).to_string() },
+                    Err(err) => HttpResponse { status: 400, body: err.to_string() },
+                },
+                Err(err) => create_error_response(400, &format!("cannot read solve request: '{err}'")),
+            },
+            ("GET", ["solve", id]) => self.with_job(id, |id, job| create_info_response(200, id, job)),
+            ("DELETE", ["solve", id]) => self.with_job(id, |id, job| {
+                job.cancellation.cancel();
+                create_info_response(202, id, job)
+            }),
+            ("GET", ["solve", id, "best"]) => self.with_job(id, |_, job| {
+                let state = job.state.lock().unwrap();
+                match state.best.as_ref() {
+                    Some(solution) => match serialize_solution(job.problem.as_ref(), solution, &job.config) {
+                        Ok(body) => HttpResponse { status: 200, body },
+                        Err(err) => create_error_response(500, &err.to_string()),
+                    },
+                    None => HttpResponse { status: 204, body: String::default() },
+                }
+            }),
+            ("GET", ["solve", id, "result"]) => self.with_job(id, |_, job| match &job.state.lock().unwrap().result {
+                Some(Ok(solution)) => HttpResponse { status: 200, body: solution.clone() },
+                Some(Err(err)) => create_error_response(500, err),
+                None => create_error_response(409, "job is still running"),
+            }),
+            _ => create_error_response(404, &format!("unknown endpoint: '{method} {path}'")),
+        }
+    }
+
+    fn evict_finished_jobs(&self) {
+        self.jobs.lock().unwrap().retain(|_, job| {
+            job.state.lock().unwrap().finished.is_none_or(|finished| finished.elapsed() < self.job_ttl)
+        });
+    }
+
+    fn get_job(&self, id: &str) -> Option<Arc<SolveJob>> {
+        self.jobs.lock().unwrap().get(id).cloned()
+    }
+
+    fn with_job<F>(&self, id: &str, handle_fn: F) -> HttpResponse
+    where
+        F: FnOnce(&str, &SolveJob) -> HttpResponse,
+    {
+        match self.get_job(id) {
+            Some(job) => handle_fn(id, job.as_ref()),
+            None => create_error_response(404, &format!("unknown job id: '{id}'")),
+        }
+    }
+}
+
+/// Runs http server which handles connections accepted by given listener.
+pub fn run_server(listener: TcpListener, server: Arc<SolverServer>) -> Result<(), GenericError> {
+    for stream in listener.incoming() {
+        let stream = stream.map_err(|err| format!("cannot accept connection: '{err}'"))?;
+        let server = server.clone();
+
+        thread::spawn(move || {
+            if let Err(err) = handle_connection(stream, server.as_ref()) {
+                (server.logger)(format!("cannot handle connection: '{err}'").as_str());
+            }
+        });
+    }
+
+    Ok(())
+}
+
+struct SolveJob {
+    problem: Arc<CoreProblem>,
+    config: Config,
+    cancellation: CancellationToken,
+    state: Arc<Mutex<JobState>>,
+}
+
+struct JobState {
+    status: JobStatus,
+    generation: usize,
+    fitness: Option<Vec<Float>>,
+    best: Option<Solution>,
+    result: Option<Result<String, String>>,
+    finished: Option<Instant>,
+}
+
+impl Default for JobState {
+    fn default() -> Self {
+        Self { status: JobStatus::Running, generation: 0, fitness: None, best: None, result: None, finished: None }
+    }
+}
+
+/// A termination which never stops the search, but tracks generations.
+struct JobTermination {
+    state: Arc<Mutex<JobState>>,
+}
+
+impl Termination for JobTermination {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        self.state.lock().unwrap().generation = heuristic_ctx.statistics().generation;
+
+        false
+    }
+
+    fn estimate(&self, _: &Self::Context) -> Float {
+        0.
+    }
+}
+
+fn create_info_response(status: u16, id: &str, job: &SolveJob) -> HttpResponse {
+    let state = job.state.lock().unwrap();
+    let error = state.result.as_ref().and_then(|result| result.as_ref().err());
+
+    let body = serde_json::json!({
+        "id": id,
+        "status": state.status,
+        "generation": state.generation,
+        "fitness": state.fitness,
+        "error": error,
+    });
+
+    HttpResponse { status, body: body.to_string() }
+}
+
+fn create_error_response(status: u16, message: &str) -> HttpResponse {
+    HttpResponse { status, body: serde_json::json!({ "error": message }).to_string() }
+}
+
+fn handle_connection(stream: TcpStream, server: &SolverServer) -> Result<(), GenericError> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or_default(), parts.next().unwrap_or_default());
+
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse::<usize>().map_err(|err| format!("invalid content length: '{err}'"))?;
+        }
+    }
+
+    if content_length > MAX_BODY_SIZE {
+        let message = format!("request body exceeds the limit of {MAX_BODY_SIZE} bytes");
+        return write_response(stream, create_error_response(413, message.as_str()));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    let body = String::from_utf8(body).map_err(|err| format!("invalid request body: '{err}'"))?;
+
+    let response = server.handle(method, path, body.as_str());
+
+    write_response(stream, response)
+}
+
+fn write_response(mut stream: TcpStream, response: HttpResponse) -> Result<(), GenericError> {
+    let reason = match response.status {
+        200 => "OK",
+        202 => "Accepted",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        _ => "Internal Server Error",
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body
+    )?;
+
+    stream.flush().map_err(From::from)
+}
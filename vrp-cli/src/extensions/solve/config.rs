@@ -804,15 +804,38 @@ pub fn create_builder_from_config(
     problem: Arc<Problem>,
     solutions: Vec<InsertionContext>,
     config: &Config,
+) -> GenericResult<ProblemConfigBuilder> {
+    create_builder(problem, solutions, config, None)
+}
+
+/// Creates a solver `Builder` from config using cancellation token which allows to stop the search
+/// from another thread.
+pub fn create_builder_from_config_with_cancellation(
+    problem: Arc<Problem>,
+    solutions: Vec<InsertionContext>,
+    config: &Config,
+    token: CancellationToken,
+) -> GenericResult<ProblemConfigBuilder> {
+    create_builder(problem, solutions, config, Some(token))
+}
+
+fn create_builder(
+    problem: Arc<Problem>,
+    solutions: Vec<InsertionContext>,
+    config: &Config,
+    cancellation: Option<CancellationToken>,
 ) -> GenericResult<ProblemConfigBuilder> {
     let environment =
         configure_from_environment(&config.environment, config.termination.as_ref().and_then(|t| t.max_time));
     let telemetry_mode = get_telemetry_mode(environment.clone(), &config.telemetry);
-    let mut builder = VrpConfigBuilder::new(problem.clone())
+    let vrp_builder = VrpConfigBuilder::new(problem.clone())
         .set_environment(environment.clone())
-        .set_telemetry_mode(telemetry_mode.clone())
-        .prebuild()?
-        .with_init_solutions(solutions, None);
+        .set_telemetry_mode(telemetry_mode.clone());
+    let vrp_builder = match cancellation {
+        Some(token) => vrp_builder.set_cancellation(token),
+        None => vrp_builder,
+    };
+    let mut builder = vrp_builder.prebuild()?.with_init_solutions(solutions, None);
 
     builder =
         configure_from_evolution(builder, problem.clone(), environment.clone(), telemetry_mode, &config.evolution)?;
@@ -7,7 +7,7 @@ use std::io::{BufReader, BufWriter, Write};
 use std::sync::Arc;
 use vrp_core::models::{Problem, Solution};
 use vrp_core::prelude::{GenericError, Random};
-use vrp_pragmatic::format::solution::{PragmaticOutputType, write_pragmatic};
+use vrp_pragmatic::format::solution::{PragmaticOutputType, check_mandatory_jobs, write_pragmatic};
 use vrp_scientific::tsplib::{TsplibProblem, TsplibSolution};
 
 /// A reader for problem.
@@ -118,9 +118,12 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random>) {
                 read_init_pragmatic(BufReader::new(file), problem, random.clone())
             })),
             SolutionWriter(Box::new(|problem, solution, mut default_writer, geojson_writer| {
-                geojson_writer
-                    .map_or(Ok(()), |mut geojson_writer| {
-                        write_pragmatic(problem, &solution, PragmaticOutputType::OnlyGeoJson, &mut geojson_writer)
+                check_mandatory_jobs(problem, &solution)
+                    .map_err(|err| GenericError::from(err.to_json()))
+                    .and_then(|_| {
+                        geojson_writer.map_or(Ok(()), |mut geojson_writer| {
+                            write_pragmatic(problem, &solution, PragmaticOutputType::OnlyGeoJson, &mut geojson_writer)
+                        })
                     })
                     .and_then(|_| write_pragmatic(problem, &solution, Default::default(), &mut default_writer))
             })),
@@ -35,14 +35,20 @@ pub use vrp_scientific as scientific;
 pub mod extensions;
 
 use crate::extensions::import::import_problem;
-use crate::extensions::solve::config::{Config, create_builder_from_config};
+use crate::extensions::solve::config::{
+    Config, create_builder_from_config, create_builder_from_config_with_cancellation,
+};
 use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::Problem as CoreProblem;
-use vrp_core::prelude::{GenericError, Solver};
+use vrp_core::prelude::{Float, GenericError, Solution, Solver};
+use vrp_core::rosomaxa::evolution::TelemetryObserver;
+use vrp_core::rosomaxa::prelude::{CancellationToken, HeuristicSolution};
+use vrp_core::solver::ProblemConfigBuilder;
 use vrp_pragmatic::format::FormatError;
 use vrp_pragmatic::format::problem::{PragmaticProblem, Problem, serialize_problem};
-use vrp_pragmatic::format::solution::{PragmaticOutputType, write_pragmatic};
+use vrp_pragmatic::format::solution::{PragmaticOutputType, check_mandatory_jobs, write_pragmatic};
 use vrp_pragmatic::get_unique_locations;
 use vrp_pragmatic::validation::ValidationContext;
 
@@ -189,6 +195,80 @@ mod c_interop {
         success: Callback,
         failure: Callback,
     ) {
+        solve(problem, matrices, matrices_len, config, get_solution_serialized, success, failure)
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Each new best known solution
+    /// is reported via `progress` callback while the search is running.
+    #[unsafe(no_mangle)]
+    extern "C" fn solve_pragmatic_with_progress(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        config: *const c_char,
+        progress: Callback,
+        success: Callback,
+        failure: Callback,
+    ) {
+        let solve_fn = |problem, config| solve_with_options(problem, config, None, Some(progress));
+        solve(problem, matrices, matrices_len, config, solve_fn, success, failure)
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. The search can be stopped from
+    /// another thread using `cancel_solve` with the given `token`: in this case, the best known
+    /// solution is returned. An optional `progress` callback (can be null) reports each new best
+    /// known solution while the search is running.
+    #[unsafe(no_mangle)]
+    #[allow(clippy::too_many_arguments)]
+    extern "C" fn solve_pragmatic_with_cancellation(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        config: *const c_char,
+        token: *const CancellationToken,
+        progress: Option<Callback>,
+        success: Callback,
+        failure: Callback,
+    ) {
+        let token = unsafe { token.as_ref() }.cloned();
+        let solve_fn = move |problem, config| solve_with_options(problem, config, token, progress);
+        solve(problem, matrices, matrices_len, config, solve_fn, success, failure)
+    }
+
+    /// Creates a cancellation token to be used with `solve_pragmatic_with_cancellation`.
+    /// The token has to be released using `release_cancellation_token`.
+    #[unsafe(no_mangle)]
+    extern "C" fn create_cancellation_token() -> *mut CancellationToken {
+        Box::into_raw(Box::default())
+    }
+
+    /// Requests cancellation of the search which uses the given `token`.
+    #[unsafe(no_mangle)]
+    extern "C" fn cancel_solve(token: *const CancellationToken) {
+        if let Some(token) = unsafe { token.as_ref() } {
+            token.cancel();
+        }
+    }
+
+    /// Releases the cancellation token created by `create_cancellation_token`.
+    #[unsafe(no_mangle)]
+    extern "C" fn release_cancellation_token(token: *mut CancellationToken) {
+        if !token.is_null() {
+            drop(unsafe { Box::from_raw(token) });
+        }
+    }
+
+    fn solve<F>(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        config: *const c_char,
+        solve_fn: F,
+        success: Callback,
+        failure: Callback,
+    ) where
+        F: FnOnce(Arc<CoreProblem>, Config) -> Result<String, GenericError> + UnwindSafe,
+    {
         catch_panic(failure, || {
             let problem = to_string(problem);
             let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len).to_vec() };
@@ -202,12 +282,31 @@ mod c_interop {
                             .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
                             .map(|config| (problem, config))
                     })
-                    .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config));
+                    .and_then(|(problem, config)| solve_fn(Arc::new(problem), config));
 
             call_back(result, success, failure);
         });
     }
 
+    fn solve_with_options(
+        problem: Arc<CoreProblem>,
+        config: Config,
+        cancellation: Option<CancellationToken>,
+        progress: Option<Callback>,
+    ) -> Result<String, GenericError> {
+        let observer = progress.map(|progress| {
+            create_progress_observer(problem.clone(), config.clone(), move |json| {
+                let json = CString::new(json.as_bytes()).unwrap();
+                progress(json.as_ptr());
+            })
+        });
+
+        solve_serialized(problem, config, cancellation, |builder| match observer {
+            Some(observer) => builder.with_observer(observer),
+            None => builder,
+        })
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -331,6 +430,65 @@ mod c_interop {
                 failure,
             );
         }
+
+        #[test]
+        fn can_solve_problem_with_progress() {
+            extern "C" fn progress(progress: *const c_char) {
+                let progress = to_string(progress);
+                assert!(progress.contains("\"generation\""));
+                assert!(progress.contains("\"solution\""));
+            }
+            extern "C" fn success(solution: *const c_char) {
+                assert!(to_string(solution).starts_with('{'));
+            }
+            extern "C" fn failure(err: *const c_char) {
+                unreachable!("{}", to_string(err))
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrices = CString::new("[]").unwrap();
+            let config = CString::new("{\"termination\": {\"max-generations\": 1}}").unwrap();
+
+            solve_pragmatic_with_progress(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr() as *const *const c_char,
+                0,
+                config.as_ptr() as *const c_char,
+                progress,
+                success,
+                failure,
+            );
+        }
+
+        #[test]
+        fn can_solve_problem_with_cancelled_token() {
+            extern "C" fn success(solution: *const c_char) {
+                assert!(to_string(solution).starts_with('{'));
+            }
+            extern "C" fn failure(err: *const c_char) {
+                unreachable!("{}", to_string(err))
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrices = CString::new("[]").unwrap();
+            let config = CString::new("{\"termination\": {\"max-generations\": 100000}}").unwrap();
+            let token = create_cancellation_token();
+            cancel_solve(token);
+
+            solve_pragmatic_with_cancellation(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr() as *const *const c_char,
+                0,
+                config.as_ptr() as *const c_char,
+                token,
+                None,
+                success,
+                failure,
+            );
+
+            assert!(unsafe { token.as_ref() }.is_some_and(|token| token.is_cancelled()));
+            release_cancellation_token(token);
+        }
     }
 }
 
@@ -373,9 +531,78 @@ mod py_interop {
             .map_err(|err| PyOSError::new_err(err.to_string()))
     }
 
-    /// Validates and solves Vehicle Routing Problem.
+    /// A cancellation token which allows to stop the search from another thread. When cancelled,
+    /// the best known solution is returned.
+    #[pyclass(name = "CancellationToken")]
+    #[derive(Clone, Default)]
+    struct PyCancellationToken(CancellationToken);
+
+    #[pymethods]
+    impl PyCancellationToken {
+        #[new]
+        fn new() -> Self {
+            Self::default()
+        }
+
+        /// Requests cancellation.
+        fn cancel(&self) {
+            self.0.cancel()
+        }
+
+        /// Returns true if cancellation was requested.
+        fn is_cancelled(&self) -> bool {
+            self.0.is_cancelled()
+        }
+    }
+
+    /// Validates and solves Vehicle Routing Problem. An optional `cancellation` token can be used
+    /// to stop the search from another thread.
+    #[pyfunction]
+    #[pyo3(signature = (problem, matrices, config, cancellation=None))]
+    fn solve_pragmatic(
+        py: Python<'_>,
+        problem: String,
+        matrices: Vec<String>,
+        config: String,
+        cancellation: Option<PyCancellationToken>,
+    ) -> PyResult<String> {
+        solve(py, problem, matrices, config, |problem, config| {
+            solve_serialized(problem, config, cancellation.map(|token| token.0), |builder| builder)
+        })
+    }
+
+    /// Validates and solves Vehicle Routing Problem. Each new best known solution is reported
+    /// via `progress` callable while the search is running. An optional `cancellation` token
+    /// can be used to stop the search from another thread.
     #[pyfunction]
-    fn solve_pragmatic(problem: String, matrices: Vec<String>, config: String) -> PyResult<String> {
+    #[pyo3(signature = (problem, matrices, config, progress, cancellation=None))]
+    fn solve_pragmatic_with_progress(
+        py: Python<'_>,
+        problem: String,
+        matrices: Vec<String>,
+        config: String,
+        progress: Py<PyAny>,
+        cancellation: Option<PyCancellationToken>,
+    ) -> PyResult<String> {
+        solve(py, problem, matrices, config, |problem, config| {
+            let observer = create_progress_observer(problem.clone(), config.clone(), move |json| {
+                Python::with_gil(|py| {
+                    if let Err(err) = progress.call1(py, (json,)) {
+                        err.print(py);
+                    }
+                })
+            });
+
+            solve_serialized(problem, config, cancellation.map(|token| token.0), |builder| {
+                builder.with_observer(observer)
+            })
+        })
+    }
+
+    fn solve<F>(py: Python<'_>, problem: String, matrices: Vec<String>, config: String, solve_fn: F) -> PyResult<String>
+    where
+        F: FnOnce(Arc<CoreProblem>, Config) -> Result<String, GenericError> + Send,
+    {
         // validate first
         deserialize_problem(BufReader::new(problem.as_bytes()))
             .and_then(|problem| {
@@ -401,7 +628,8 @@ mod py_interop {
                     .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
                     .map(|config| (problem, config))
             })
-            .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config))
+            // NOTE release GIL to allow cancellation from another python thread
+            .and_then(|(problem, config)| py.allow_threads(|| solve_fn(Arc::new(problem), config)))
             .map_err(|err| PyOSError::new_err(err.to_string()))
     }
 
@@ -410,6 +638,8 @@ mod py_interop {
         m.add_function(wrap_pyfunction!(convert_to_pragmatic, m)?)?;
         m.add_function(wrap_pyfunction!(get_routing_locations, m)?)?;
         m.add_function(wrap_pyfunction!(solve_pragmatic, m)?)?;
+        m.add_function(wrap_pyfunction!(solve_pragmatic_with_progress, m)?)?;
+        m.add_class::<PyCancellationToken>()?;
         Ok(())
     }
 }
@@ -420,6 +650,7 @@ mod wasm {
     extern crate wasm_bindgen;
 
     use super::*;
+    use std::cell::RefCell;
     use vrp_pragmatic::format::CoordIndex;
     use vrp_pragmatic::format::problem::Matrix;
     use wasm_bindgen::prelude::*;
@@ -477,6 +708,45 @@ mod wasm {
     /// Solves Vehicle Routing Problem passed in `pragmatic` format.
     #[wasm_bindgen]
     pub fn solve_pragmatic(problem: JsValue, matrices: JsValue, config: JsValue) -> Result<JsValue, JsValue> {
+        solve(problem, matrices, config, get_solution_serialized)
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Each new best known solution
+    /// is reported via `progress` function while the search is running.
+    #[wasm_bindgen]
+    pub fn solve_pragmatic_with_progress(
+        problem: JsValue,
+        matrices: JsValue,
+        config: JsValue,
+        progress: js_sys::Function,
+    ) -> Result<JsValue, JsValue> {
+        // NOTE js function cannot be shared between threads, so it is kept in thread local storage
+        // which is fine as the solver runs in a single thread in wasm environment.
+        PROGRESS_FN.with(|progress_fn| *progress_fn.borrow_mut() = Some(progress));
+
+        let result = solve(problem, matrices, config, |problem, config| {
+            get_solution_serialized_with_progress(problem, config, |json| {
+                PROGRESS_FN.with(|progress_fn| {
+                    if let Some(progress_fn) = progress_fn.borrow().as_ref() {
+                        let _ = progress_fn.call1(&JsValue::NULL, &JsValue::from_str(json.as_str()));
+                    }
+                })
+            })
+        });
+
+        PROGRESS_FN.with(|progress_fn| *progress_fn.borrow_mut() = None);
+
+        result
+    }
+
+    thread_local! {
+        static PROGRESS_FN: RefCell<Option<js_sys::Function>> = const { RefCell::new(None) };
+    }
+
+    fn solve<F>(problem: JsValue, matrices: JsValue, config: JsValue, solve_fn: F) -> Result<JsValue, JsValue>
+    where
+        F: FnOnce(Arc<CoreProblem>, Config) -> Result<String, GenericError>,
+    {
         let problem: Problem =
             serde_wasm_bindgen::from_value(problem).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
 
@@ -492,7 +762,7 @@ mod wasm {
             .map_err(|err| serialize_as_config_error(&err.to_string()))
             .map_err(|err| JsValue::from_str(err.as_str()))?;
 
-        get_solution_serialized(problem, config)
+        solve_fn(problem, config)
             .map(|problem| JsValue::from_str(problem.as_str()))
             .map_err(|err| JsValue::from_str(&err.to_string()))
     }
@@ -508,27 +778,113 @@ pub fn get_locations_serialized(problem: &Problem) -> Result<String, GenericErro
 
 /// Gets solution serialized in json.
 pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Result<String, GenericError> {
-    let solution = create_builder_from_config(problem.clone(), Default::default(), &config)
-        .and_then(|builder| builder.build())
-        .map(|config| Solver::new(problem.clone(), config))
-        .and_then(|solver| solver.solve())
-        .map_err(|err| {
-            FormatError::new(
-                "E0003".to_string(),
-                "cannot find any solution".to_string(),
-                format!("please submit a bug and share original problem and routing matrix. Error: '{err}'"),
-            )
-            .to_json()
-        })?;
-
-    let output_type = if config.output.and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false) {
+    get_solution_serialized_with(problem, config, |builder| builder)
+}
+
+/// Gets solution serialized in json using a function to customize solver configuration before it is built.
+pub fn get_solution_serialized_with<F>(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    configure_fn: F,
+) -> Result<String, GenericError>
+where
+    F: FnOnce(ProblemConfigBuilder) -> ProblemConfigBuilder,
+{
+    solve_serialized(problem, config, None, configure_fn)
+}
+
+/// Gets solution serialized in json using a cancellation token to stop the search from another thread
+/// and a function to customize solver configuration before it is built. When cancelled, the best known
+/// solution is returned.
+pub fn get_solution_serialized_with_cancellation<F>(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    cancellation: CancellationToken,
+    configure_fn: F,
+) -> Result<String, GenericError>
+where
+    F: FnOnce(ProblemConfigBuilder) -> ProblemConfigBuilder,
+{
+    solve_serialized(problem, config, Some(cancellation), configure_fn)
+}
+
+fn solve_serialized<F>(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    cancellation: Option<CancellationToken>,
+    configure_fn: F,
+) -> Result<String, GenericError>
+where
+    F: FnOnce(ProblemConfigBuilder) -> ProblemConfigBuilder,
+{
+    let solution = match cancellation {
+        Some(token) => {
+            create_builder_from_config_with_cancellation(problem.clone(), Default::default(), &config, token)
+        }
+        None => create_builder_from_config(problem.clone(), Default::default(), &config),
+    }
+    .map(configure_fn)
+    .and_then(|builder| builder.build())
+    .map(|config| Solver::new(problem.clone(), config))
+    .and_then(|solver| solver.solve())
+    .map_err(|err| {
+        FormatError::new(
+            "E0003".to_string(),
+            "cannot find any solution".to_string(),
+            format!("please submit a bug and share original problem and routing matrix. Error: '{err}'"),
+        )
+        .to_json()
+    })?;
+
+    check_mandatory_jobs(problem.as_ref(), &solution).map_err(|err| err.to_json())?;
+
+    serialize_solution(problem.as_ref(), &solution, &config)
+}
+
+/// Gets solution serialized in json reporting each new best known solution found during the search
+/// using `progress_fn`. See [create_progress_observer] for details.
+pub fn get_solution_serialized_with_progress<F>(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    progress_fn: F,
+) -> Result<String, GenericError>
+where
+    F: Fn(String) + Send + Sync + 'static,
+{
+    let observer = create_progress_observer(problem.clone(), config.clone(), progress_fn);
+
+    get_solution_serialized_with(problem, config, |builder| builder.with_observer(observer))
+}
+
+/// Creates an observer which reports each new best known solution using `progress_fn`. A progress
+/// is reported in json with generation number, fitness values and the solution serialized in the
+/// same way as the final one.
+pub fn create_progress_observer<F>(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    progress_fn: F,
+) -> TelemetryObserver<InsertionContext>
+where
+    F: Fn(String) + Send + Sync + 'static,
+{
+    Arc::new(move |generation, fitness, insertion_ctx| {
+        // NOTE progress reporting is best effort: a failure should not stop the search
+        if let Ok(progress) = serialize_progress(problem.as_ref(), &config, generation, fitness, insertion_ctx) {
+            progress_fn(progress)
+        }
+    })
+}
+
+/// Serializes solution in json using output settings from the config.
+pub fn serialize_solution(problem: &CoreProblem, solution: &Solution, config: &Config) -> Result<String, GenericError> {
+    let output_type = if config.output.as_ref().and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false) {
         PragmaticOutputType::Combined
     } else {
         Default::default()
     };
 
     let mut writer = BufWriter::new(Vec::new());
-    write_pragmatic(problem.as_ref(), &solution, output_type, &mut writer)?;
+    write_pragmatic(problem, solution, output_type, &mut writer)?;
 
     let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
     let result = String::from_utf8(bytes).map_err(|err| format!("{err}"))?;
@@ -536,6 +892,19 @@ pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Res
     Ok(result)
 }
 
+fn serialize_progress(
+    problem: &CoreProblem,
+    config: &Config,
+    generation: usize,
+    fitness: &[Float],
+    insertion_ctx: &InsertionContext,
+) -> Result<String, GenericError> {
+    let solution = serialize_solution(problem, &insertion_ctx.deep_copy().into(), config)?;
+    let solution = serde_json::from_str::<serde_json::Value>(solution.as_str()).map_err(|err| format!("{err}"))?;
+
+    Ok(serde_json::json!({ "generation": generation, "fitness": fitness, "solution": solution }).to_string())
+}
+
 fn serialize_as_config_error(err: &str) -> String {
     FormatError::new(
         "E0004".to_string(),
@@ -20,6 +20,7 @@ mod cli {
     use crate::commands::check::{get_check_app, run_check};
     use crate::commands::create_write_buffer;
     use crate::commands::generate::{get_generate_app, run_generate};
+    use crate::commands::serve::{get_serve_app, run_serve};
     use clap::{ArgMatches, Command};
     use std::process;
 
@@ -37,6 +38,7 @@ mod cli {
             .subcommand(get_import_app())
             .subcommand(get_check_app())
             .subcommand(get_generate_app())
+            .subcommand(get_serve_app())
     }
 
     pub fn run_subcommand(arg_matches: ArgMatches) {
@@ -46,6 +48,7 @@ mod cli {
             Some(("import", import_matches)) => run_import(import_matches),
             Some(("check", check_matches)) => run_check(check_matches),
             Some(("generate", generate_matches)) => run_generate(generate_matches),
+            Some(("serve", serve_matches)) => run_serve(serve_matches),
             _ => {
                 eprintln!("no subcommand was used. Use -h to print help information.");
                 process::exit(1);
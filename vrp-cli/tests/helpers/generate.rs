@@ -12,11 +12,18 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        visits: None,
+        satellite: None,
+        soft_time_windows: None,
+        split: None,
+        max_ride_time: None,
+        sync: None,
+        mandatory: None,
     }
 }
 
 pub fn create_empty_job_task() -> JobTask {
-    JobTask { places: vec![], demand: None, order: None }
+    JobTask { places: vec![], demand: None, order: None, product: None, items: None }
 }
 
 pub fn create_empty_job_place() -> JobPlace {
@@ -24,7 +31,7 @@ pub fn create_empty_job_place() -> JobPlace {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, precedences: None, alternatives: None, sites: None, clustering: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -38,20 +45,27 @@ pub fn create_test_vehicle_type() -> VehicleType {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
                 latest: None,
                 location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                satellite: None,
             },
             end: None,
             breaks: None,
             reloads: None,
             recharges: None,
+            overtime: None,
         }],
         capacity: vec![10],
         skills: None,
         limits: None,
+        compartments: None,
+        hours_of_service: None,
+        consumption: None,
+        loading_policy: None,
+        cargo_space: None,
     }
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile { name: "car".to_string(), speed: None, speed_profiles: None }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
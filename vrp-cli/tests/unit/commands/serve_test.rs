@@ -0,0 +1,13 @@
+use super::*;
+use crate::cli::get_app;
+
+#[test]
+fn can_detect_invalid_port() {
+    let args = vec!["vrp-cli", "serve", "--port", "not_a_port"];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+    let (_, serve_matches) = matches.subcommand().unwrap();
+
+    let result = run_serve(serve_matches);
+
+    assert!(result.is_err());
+}
@@ -3,6 +3,8 @@ use crate::cli::{get_app, run_subcommand};
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
 const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+const PRAGMATIC_EXECUTION_STATE_PATH: &str = "../examples/data/pragmatic/basics/replanning.basic.state.json";
 const SOLOMON_PROBLEM_PATH: &str = "../examples/data/scientific/solomon/C101.25.txt";
 const LILIM_PROBLEM_PATH: &str = "../examples/data/scientific/lilim/LC101.txt";
 
@@ -178,3 +180,64 @@ fn can_specify_cv() {
         assert_eq!(min_cv, result);
     }
 }
+
+#[test]
+fn can_solve_pragmatic_problem_with_execution_state() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--init-solution",
+        PRAGMATIC_SOLUTION_PATH,
+        "--execution-state",
+        PRAGMATIC_EXECUTION_STATE_PATH,
+        "--max-generations",
+        "1",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+        "--check",
+    ];
+
+    run_subcommand(get_app().try_get_matches_from(args).unwrap());
+
+    let solution = deserialize_solution(BufReader::new(tmpfile.reopen().unwrap())).unwrap();
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].type_id, "vehicle_vehicle_1");
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(|activity| activity.job_id.as_str()))
+        .collect::<Vec<_>>();
+    assert!(job_ids.contains(&"job3"));
+    assert!(job_ids.contains(&"job4"));
+    assert!(!job_ids.contains(&"job1"));
+    assert!(!job_ids.contains(&"job2"));
+}
+
+#[test]
+fn can_require_init_solution_with_execution_state() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--execution-state", PRAGMATIC_EXECUTION_STATE_PATH];
+
+    assert!(get_solve_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn can_reject_execution_state_for_non_pragmatic_format() {
+    let args = vec![
+        "solve",
+        "solomon",
+        SOLOMON_PROBLEM_PATH,
+        "--init-solution",
+        PRAGMATIC_SOLUTION_PATH,
+        "--execution-state",
+        PRAGMATIC_EXECUTION_STATE_PATH,
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert_eq!(result, Err("execution state is not supported for solomon format".into()));
+}
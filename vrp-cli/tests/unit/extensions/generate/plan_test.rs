@@ -6,6 +6,9 @@ fn can_generate_bounding_box() {
     let plan = Plan {
         jobs: vec![create_test_job(-1., 1.), create_test_job(1., 0.), create_test_job(3., 1.), create_test_job(1., 2.)],
         relations: None,
+        precedences: None,
+        alternatives: None,
+        sites: None,
         clustering: None,
     };
 
@@ -22,6 +25,9 @@ fn can_get_bounding_box_from_size() {
     let plan = Plan {
         jobs: vec![create_test_job(0., 1.), create_test_job(1., 0.), create_test_job(0., 0.), create_test_job(1., 1.)],
         relations: None,
+        precedences: None,
+        alternatives: None,
+        sites: None,
         clustering: None,
     };
 
@@ -0,0 +1,131 @@
+use super::*;
+use std::time::{Duration, Instant};
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+
+fn create_solve_request_body(config: &str) -> String {
+    let problem = std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap();
+    let matrix = std::fs::read_to_string(PRAGMATIC_MATRIX_PATH).unwrap();
+
+    format!(r#"{{ "problem": {problem}, "matrices": [{matrix}], "config": {config} }}"#)
+}
+
+fn submit(server: &SolverServer, config: &str) -> String {
+    let response = server.handle("POST", "/solve", create_solve_request_body(config).as_str());
+    assert_eq!(response.status, 202);
+
+    let body: serde_json::Value = serde_json::from_str(response.body.as_str()).unwrap();
+    body["id"].as_str().unwrap().to_string()
+}
+
+fn wait_for<F: Fn() -> bool>(condition: F) {
+    let start = Instant::now();
+    while !condition() {
+        assert!(start.elapsed() < Duration::from_secs(60), "timeout while waiting for condition");
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn can_solve_problem_and_get_result() {
+    let server = SolverServer::default();
+    let id = submit(&server, r#"{ "termination": { "maxGenerations": 10 } }"#);
+
+    wait_for(|| server.status(id.as_str()) != Some(JobStatus::Running));
+
+    assert_eq!(server.status(id.as_str()), Some(JobStatus::Completed));
+    let response = server.handle("GET", format!("/solve/{id}/result").as_str(), "");
+    assert_eq!(response.status, 200);
+    let solution = deserialize_solution(BufReader::new(response.body.as_bytes())).unwrap();
+    assert!(!solution.tours.is_empty());
+    assert_eq!(server.handle("GET", format!("/solve/{id}/best").as_str(), "").status, 200);
+    let response = server.handle("GET", format!("/solve/{id}").as_str(), "");
+    assert_eq!(response.status, 200);
+    assert!(response.body.contains(r#""status":"completed""#));
+}
+
+#[test]
+fn can_cancel_running_job() {
+    let server = SolverServer::default();
+    let id = submit(&server, r#"{ "termination": { "maxTime": 300, "maxGenerations": 1000000 } }"#);
+    wait_for(|| server.handle("GET", format!("/solve/{id}/best").as_str(), "").status == 200);
+    assert_eq!(server.handle("GET", format!("/solve/{id}/result").as_str(), "").status, 409);
+
+    let response = server.handle("DELETE", format!("/solve/{id}").as_str(), "");
+    assert_eq!(response.status, 202);
+    wait_for(|| server.status(id.as_str()) != Some(JobStatus::Running));
+
+    assert_eq!(server.status(id.as_str()), Some(JobStatus::Cancelled));
+    assert_eq!(server.handle("GET", format!("/solve/{id}/result").as_str(), "").status, 200);
+}
+
+#[test]
+fn can_evict_finished_jobs() {
+    let server = SolverServer::new(Duration::ZERO, Arc::new(|_: &str| {}));
+    let id = submit(&server, r#"{ "termination": { "maxGenerations": 1 } }"#);
+
+    wait_for(|| server.handle("GET", format!("/solve/{id}").as_str(), "").status == 404);
+
+    assert_eq!(server.status(id.as_str()), None);
+}
+
+parameterized_test! {can_handle_invalid_requests, (method, path, body, expected), {
+    can_handle_invalid_requests_impl(method, path, body, expected);
+}}
+
+can_handle_invalid_requests! {
+    case01_unknown_job: ("GET", "/solve/42", "", 404),
+    case02_unknown_job_cancel: ("DELETE", "/solve/42", "", 404),
+    case03_unknown_endpoint: ("GET", "/unknown", "", 404),
+    case04_invalid_body: ("POST", "/solve", "{}", 400),
+}
+
+fn can_handle_invalid_requests_impl(method: &str, path: &str, body: &str, expected: u16) {
+    let server = SolverServer::default();
+
+    let response = server.handle(method, path, body);
+
+    assert_eq!(response.status, expected);
+}
+
+#[test]
+fn can_reject_invalid_problem() {
+    let server = SolverServer::default();
+    let problem = std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap();
+    let matrix = r#"{ "profile": "normal_car", "travelTimes": [0], "distances": [0] }"#;
+    let body = format!(r#"{{ "problem": {problem}, "matrices": [{matrix}] }}"#);
+
+    let response = server.handle("POST", "/solve", body.as_str());
+
+    assert_eq!(response.status, 400);
+    assert!(response.body.contains("E15"));
+}
+
+#[test]
+fn can_serve_requests_on_localhost() {
+    let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || run_server(listener, Arc::new(SolverServer::default())));
+    let send = |request: String| {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+
+    let body = create_solve_request_body(r#"{ "termination": { "maxGenerations": 1 } }"#);
+    let response =
+        send(format!("POST /solve HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}", body.len()));
+    assert!(response.starts_with("HTTP/1.1 202 Accepted"));
+    assert!(response.ends_with(r#"{"id":"1"}"#));
+
+    let response = send("GET /solve/42 HTTP/1.1\r\nHost: localhost\r\n\r\n".to_string());
+    assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+
+    let response = send(format!("POST /solve HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n", usize::MAX));
+    assert!(response.starts_with("HTTP/1.1 413 Payload Too Large"));
+    assert!(response.contains(r#"{"error":"#));
+}
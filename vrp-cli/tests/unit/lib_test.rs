@@ -1,13 +1,14 @@
 use super::*;
+use crate::extensions::solve::config::TerminationConfig;
 use crate::helpers::generate::{create_empty_plan, create_test_job, create_test_vehicle_type};
 use vrp_pragmatic::format::MultiFormatError;
-use vrp_pragmatic::format::problem::{Fleet, MatrixProfile, Plan};
+use vrp_pragmatic::format::problem::{Fleet, Job, JobSkills, MatrixProfile, Plan};
 
 #[test]
 fn can_get_locations_serialized() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], ..create_empty_plan() },
-        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], drivers: None, profiles: vec![], resources: None },
         objectives: None,
     };
 
@@ -22,7 +23,8 @@ fn can_get_solution_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, speed_profiles: None }],
             resources: None,
         },
         objectives: None,
@@ -38,6 +40,112 @@ fn can_get_solution_serialized() {
     assert!(solution.contains("stops"));
 }
 
+#[test]
+fn can_get_solution_serialized_with_progress() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, speed_profiles: None }],
+            resources: None,
+        },
+        objectives: None,
+    };
+    let problem = Arc::new(problem.read_pragmatic().unwrap());
+    let config = Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        ..Config::default()
+    };
+    let reports = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let result = get_solution_serialized_with_progress(problem, config, {
+        let reports = reports.clone();
+        move |progress| reports.lock().unwrap().push(progress)
+    });
+
+    assert!(result.is_ok());
+    let reports = reports.lock().unwrap();
+    assert!(!reports.is_empty());
+    reports.iter().for_each(|progress| {
+        let progress = serde_json::from_str::<serde_json::Value>(progress).unwrap();
+        assert!(progress["generation"].is_u64());
+        assert!(progress["fitness"].is_array());
+        assert!(progress["solution"]["tours"].is_array());
+    });
+}
+
+#[test]
+fn can_get_solution_serialized_with_cancellation() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, speed_profiles: None }],
+            resources: None,
+        },
+        objectives: None,
+    };
+    let problem = Arc::new(problem.read_pragmatic().unwrap());
+    let config = Config {
+        termination: Some(TerminationConfig {
+            max_time: Some(300),
+            max_generations: Some(usize::MAX),
+            variation: None,
+        }),
+        ..Config::default()
+    };
+    let token = CancellationToken::default();
+    let timer = std::thread::spawn({
+        let token = token.clone();
+        move || {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            token.cancel();
+        }
+    });
+
+    let solution = get_solution_serialized_with_cancellation(problem, config, token, |builder| builder).unwrap();
+    timer.join().unwrap();
+
+    let solution = serde_json::from_str::<serde_json::Value>(&solution).unwrap();
+    assert_eq!(solution["tours"].as_array().map(|tours| tours.len()), Some(1));
+}
+
+#[test]
+fn can_get_mandatory_jobs_diagnosis() {
+    let job = Job {
+        id: "job1".to_string(),
+        mandatory: Some(true),
+        skills: Some(JobSkills { all_of: Some(vec!["unknown".to_string()]), one_of: None, none_of: None }),
+        ..create_test_job(1., 0.)
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, speed_profiles: None }],
+            resources: None,
+        },
+        objectives: None,
+    };
+    let problem = Arc::new(problem.read_pragmatic().unwrap());
+    let config = Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        ..Config::default()
+    };
+
+    let result = get_solution_serialized(problem, config).unwrap_err().to_string();
+
+    let error = serde_json::from_str::<serde_json::Value>(&result).unwrap();
+    assert_eq!(error["code"], "E0005");
+    let diagnosis = serde_json::from_str::<serde_json::Value>(error["details"].as_str().unwrap()).unwrap();
+    assert_eq!(diagnosis[0]["jobId"], "job1");
+    assert_eq!(diagnosis[0]["reasons"][0]["code"], "SKILL_CONSTRAINT");
+    assert_eq!(diagnosis[0]["relaxations"][0]["type"], "addSkills");
+}
+
 #[test]
 fn can_get_errors_serialized() {
     let errors = vec![
@@ -92,7 +92,6 @@ fn combine_features(
     let state: Option<Arc<dyn FeatureState>> = match states.len() {
         0 => None,
         1 => states.first().cloned(),
-        // TODO: combined feature state seems behave differently: check reload with one state wrapped in CombinedFeatureState
         _ => Some(Arc::new(CombinedFeatureState::new(states))),
     };
 
@@ -112,12 +111,18 @@ impl CombinedFeatureState {
 }
 
 impl FeatureState for CombinedFeatureState {
+    fn notify_failure(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], jobs: &[Job]) -> bool {
+        notify_failure_with_states(&self.states, solution_ctx, route_indices, jobs)
+    }
+
     fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
         accept_insertion_with_states(&self.states, solution_ctx, route_index, job)
     }
 
     fn accept_route_state(&self, route_ctx: &mut RouteContext) {
-        accept_route_state_with_states(&self.states, route_ctx)
+        // NOTE route state is cleared and stale flag is reset by the caller, so it should not be done here
+        // as it would discard states of other features which are already accepted
+        self.states.iter().for_each(|state| state.accept_route_state(route_ctx));
     }
 
     fn accept_solution_state(&self, ctx: &mut SolutionContext) {
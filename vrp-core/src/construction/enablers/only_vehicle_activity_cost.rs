@@ -1,3 +1,4 @@
+use crate::construction::features::get_service_start;
 use crate::models::common::{Cost, Timestamp};
 use crate::models::problem::{ActivityCost, SimpleActivityCost};
 use crate::models::solution::Activity;
@@ -14,7 +15,7 @@ impl ActivityCost for OnlyVehicleActivityCost {
     fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
         let actor = route.actor.as_ref();
 
-        let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0.0 };
+        let waiting = get_service_start(route, activity, arrival) - arrival;
         let service = activity.place.duration;
 
         waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
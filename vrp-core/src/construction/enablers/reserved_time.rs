@@ -2,6 +2,7 @@
 #[path = "../../../tests/unit/construction/enablers/reserved_time_test.rs"]
 mod reserved_time_test;
 
+use crate::construction::features::{get_latest_arrival, get_service_start};
 use crate::models::common::*;
 use crate::models::problem::{ActivityCost, Actor, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
@@ -61,7 +62,7 @@ impl ActivityCost for DynamicActivityCost {
         activity: &Activity,
         arrival: Timestamp,
     ) -> ControlFlow<Timestamp, Timestamp> {
-        let activity_start = arrival.max(activity.place.time.start);
+        let activity_start = get_service_start(route, activity, arrival);
         let departure = activity_start + activity.place.duration;
         let schedule = TimeWindow::new(arrival, departure);
 
@@ -72,10 +73,8 @@ impl ActivityCost for DynamicActivityCost {
 
             assert!(reserved_tw.intersects(&schedule));
 
-            let activity_tw = &activity.place.time;
-
-            let extra_duration = if reserved_tw.start < activity_tw.start {
-                let waiting_time = TimeWindow::new(arrival, activity_tw.start);
+            let extra_duration = if reserved_tw.start < activity_start {
+                let waiting_time = TimeWindow::new(arrival, activity_start);
                 let overlapping = waiting_time.overlapping(&reserved_tw).map(|tw| tw.duration()).unwrap_or(0.);
 
                 reserved_time.duration - overlapping
@@ -100,7 +99,7 @@ impl ActivityCost for DynamicActivityCost {
         activity: &Activity,
         departure: Timestamp,
     ) -> ControlFlow<Timestamp, Timestamp> {
-        let arrival = activity.place.time.end.min(departure - activity.place.duration);
+        let arrival = get_latest_arrival(route, activity, departure)?;
         let schedule = TimeWindow::new(arrival, departure);
 
         let value = (self.reserved_times_fn)(route, &schedule)
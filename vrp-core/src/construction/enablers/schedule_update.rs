@@ -1,9 +1,12 @@
-use crate::construction::heuristics::{RouteContext, RouteState};
-use crate::models::OP_START_MSG;
+use crate::construction::heuristics::{RouteContext, RouteState, SolutionContext, UnassignmentInfo};
 use crate::models::common::{Distance, Duration, Schedule, Timestamp};
-use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::problem::{ActivityCost, Job, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use crate::models::{OP_START_MSG, ViolationCode};
 use rosomaxa::prelude::Float;
 use rosomaxa::utils::UnwrapValue;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 
 custom_activity_state!(pub(crate) LatestArrival typeof Timestamp);
 custom_activity_state!(pub(crate) WaitingTime typeof Timestamp);
@@ -31,6 +34,232 @@ pub fn update_route_departure(
     update_route_schedule(route_ctx, activity, transport);
 }
 
+/// Aligns service start of activities which belong to the same group across different routes: all of them
+/// should start within group's tolerance. An activity which starts too early waits for the latest one of its
+/// group, waiting is modeled by moving the start of activity's time window. As waiting in one route might
+/// shift other synchronized activities of the same route, the process is repeated until all groups are aligned.
+/// Please note, that activities can become late after alignment, so their feasibility should be checked separately.
+pub fn update_synchronized_schedules<K>(
+    solution_ctx: &mut SolutionContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+    sync_fn: &(dyn Fn(&Activity) -> Option<(K, Duration)> + Send + Sync),
+) where
+    K: Hash + Eq,
+{
+    let mut groups = HashMap::<K, Vec<(usize, usize, Duration)>>::default();
+    solution_ctx.routes.iter().enumerate().for_each(|(route_idx, route_ctx)| {
+        route_ctx.route().tour.all_activities().enumerate().for_each(|(activity_idx, activity)| {
+            if let Some((key, tolerance)) = sync_fn(activity) {
+                groups.entry(key).or_default().push((route_idx, activity_idx, tolerance));
+            }
+        })
+    });
+
+    // reset waiting scheduled previously
+    let indices = groups.values().flatten().map(|&(route_idx, activity_idx, _)| (route_idx, activity_idx));
+    reset_time_starts(solution_ctx, indices.collect(), activity, transport);
+
+    let total_activities = groups.values().map(|group| group.len()).sum::<usize>();
+    for _ in 0..total_activities {
+        let changed = groups
+            .values()
+            .filter(|group| group.len() > 1)
+            .flat_map(|group| {
+                let routes = solution_ctx.routes.as_slice();
+                let get_start = |route_idx: usize, activity_idx: usize| {
+                    let activity = &routes[route_idx].route().tour[activity_idx];
+                    activity.schedule.arrival.max(activity.place.time.start)
+                };
+
+                let latest = group
+                    .iter()
+                    .map(|&(route_idx, activity_idx, _)| get_start(route_idx, activity_idx))
+                    .fold(Timestamp::MIN, Timestamp::max);
+
+                group.iter().filter_map(move |&(route_idx, activity_idx, tolerance)| {
+                    let earliest = latest - tolerance;
+                    (get_start(route_idx, activity_idx) < earliest).then_some((route_idx, activity_idx, earliest))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if changed.is_empty() {
+            break;
+        }
+
+        set_time_starts(solution_ctx, changed, activity, transport);
+    }
+}
+
+/// Shifts service start of activities which should start not earlier than the given time lag after
+/// the end of their predecessors served by the same or different routes. Waiting is modeled by moving
+/// the start of activity's time window. As shifting in one route might delay predecessors of other
+/// activities, the process is repeated until all precedences are respected or iteration limit is reached
+/// (e.g. when precedences form a cycle across routes). Please note, that activities can become late after
+/// shifting, so their feasibility should be checked separately.
+pub fn update_precedence_schedules<K, F>(
+    solution_ctx: &mut SolutionContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+    precedence_fn: F,
+) where
+    K: Hash + Eq,
+    F: Fn(&Activity) -> Option<(K, Vec<(K, Duration)>)>,
+{
+    let mut positions = HashMap::<K, (usize, usize)>::default();
+    let mut successors = Vec::<(usize, usize, Vec<(K, Duration)>)>::default();
+    solution_ctx.routes.iter().enumerate().for_each(|(route_idx, route_ctx)| {
+        route_ctx.route().tour.all_activities().enumerate().for_each(|(activity_idx, activity)| {
+            if let Some((key, predecessors)) = precedence_fn(activity) {
+                positions.insert(key, (route_idx, activity_idx));
+                if !predecessors.is_empty() {
+                    successors.push((route_idx, activity_idx, predecessors));
+                }
+            }
+        })
+    });
+
+    // reset waiting scheduled previously
+    let indices = successors.iter().map(|&(route_idx, activity_idx, _)| (route_idx, activity_idx));
+    reset_time_starts(solution_ctx, indices.collect(), activity, transport);
+
+    for _ in 0..=successors.len() {
+        let changed = successors
+            .iter()
+            .filter_map(|(route_idx, activity_idx, predecessors)| {
+                let routes = solution_ctx.routes.as_slice();
+                let activity = &routes[*route_idx].route().tour[*activity_idx];
+
+                let earliest = predecessors
+                    .iter()
+                    .filter_map(|(key, lag)| {
+                        positions.get(key).map(|&(pred_route_idx, pred_activity_idx)| {
+                            routes[pred_route_idx].route().tour[pred_activity_idx].schedule.departure + *lag
+                        })
+                    })
+                    .fold(Timestamp::MIN, Timestamp::max);
+
+                (activity.schedule.arrival.max(activity.place.time.start) < earliest).then_some((
+                    *route_idx,
+                    *activity_idx,
+                    earliest,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        if changed.is_empty() {
+            break;
+        }
+
+        set_time_starts(solution_ctx, changed, activity, transport);
+    }
+}
+
+/// Returns the start of activity's time window as it was defined before it was moved to model waiting.
+pub(crate) fn get_original_start(route: &Route, activity: &Activity) -> Option<Timestamp> {
+    let departure = route.tour.start().map_or(Timestamp::default(), |start| start.schedule.departure);
+
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.places.get(activity.place.idx))
+        .and_then(|place| {
+            place
+                .times
+                .iter()
+                .map(|span| span.to_time_window(departure))
+                .find(|time| time.end == activity.place.time.end)
+        })
+        .map(|time| time.start)
+}
+
+/// Returns jobs which cannot be served in time anymore because of waiting modeled by moving the start
+/// of activity's time window. Only routes with such waiting activities are considered. When only tour
+/// end is late, the last job of the tour is returned to make the tour shorter. Locked jobs are ignored.
+pub(crate) fn get_late_jobs_after_waiting(
+    solution_ctx: &SolutionContext,
+    is_waiting_fn: &dyn Fn(&Activity) -> bool,
+) -> HashSet<Job> {
+    let is_late =
+        |activity: &Activity| activity.schedule.arrival.max(activity.place.time.start) > activity.place.time.end;
+
+    solution_ctx
+        .routes
+        .iter()
+        .map(|route_ctx| route_ctx.route())
+        .filter(|route| {
+            route.tour.all_activities().any(|activity| {
+                is_waiting_fn(activity)
+                    && get_original_start(route, activity).is_some_and(|start| activity.place.time.start > start)
+            })
+        })
+        .flat_map(|route| {
+            let late_jobs =
+                route.tour.all_activities().filter(|activity| is_late(activity)).filter_map(|a| a.retrieve_job());
+
+            let is_end_late = route.tour.end().is_some_and(is_late);
+            let last_job = route.tour.jobs().filter(|_| is_end_late).last().cloned();
+
+            late_jobs.chain(last_job).collect::<Vec<_>>()
+        })
+        .filter(|job| !solution_ctx.locked.contains(job))
+        .collect()
+}
+
+/// Removes given jobs from their routes and marks them as unassigned with the given code.
+pub(crate) fn unassign_jobs<I>(solution_ctx: &mut SolutionContext, jobs: I, code: ViolationCode)
+where
+    I: IntoIterator<Item = Job>,
+{
+    jobs.into_iter().for_each(|job| {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.route().tour.contains(&job)).for_each(
+            |route_ctx| {
+                assert!(route_ctx.route_mut().tour.remove(&job), "cannot remove job from the tour");
+            },
+        );
+
+        solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(code));
+    });
+}
+
+fn reset_time_starts(
+    solution_ctx: &mut SolutionContext,
+    indices: Vec<(usize, usize)>,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) {
+    let changed = indices
+        .into_iter()
+        .filter_map(|(route_idx, activity_idx)| {
+            let route = solution_ctx.routes[route_idx].route();
+            let activity = &route.tour[activity_idx];
+
+            get_original_start(route, activity)
+                .filter(|&start| start != activity.place.time.start)
+                .map(|start| (route_idx, activity_idx, start))
+        })
+        .collect();
+
+    set_time_starts(solution_ctx, changed, activity, transport);
+}
+
+fn set_time_starts(
+    solution_ctx: &mut SolutionContext,
+    changed: Vec<(usize, usize, Timestamp)>,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) {
+    changed.iter().for_each(|&(route_idx, activity_idx, start)| {
+        let route = solution_ctx.routes[route_idx].route_mut();
+        route.tour.get_mut(activity_idx).expect("invalid activity index").place.time.start = start;
+    });
+
+    changed.into_iter().map(|(route_idx, ..)| route_idx).collect::<HashSet<_>>().into_iter().for_each(|route_idx| {
+        update_route_schedule(&mut solution_ctx.routes[route_idx], activity, transport);
+    });
+}
+
 fn update_schedules(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, transport: &dyn TransportCost) {
     let init = {
         let start = route_ctx.route().tour.start().unwrap();
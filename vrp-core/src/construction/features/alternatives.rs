@@ -0,0 +1,125 @@
+//! A feature to model sets of alternative jobs: at most one job of the set can be assigned.
+
+use super::*;
+use crate::construction::enablers::*;
+use std::collections::HashSet;
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/alternatives_test.rs"]
+mod alternatives_test;
+
+custom_dimension!(pub JobAlternative typeof JobAlternative);
+custom_tour_state!(CurrentAlternatives typeof HashSet<String>);
+
+/// Specifies a job which is a member of the alternative set.
+pub struct JobAlternative {
+    /// A key which is shared by all jobs of the same set.
+    pub key: String,
+    /// If true, the whole set can be left unassigned without penalty, otherwise exactly one job is expected
+    /// to be assigned.
+    pub is_optional: bool,
+}
+
+/// Creates a feature which allows to assign at most one job of the set having the same [`JobAlternativeDimension`].
+/// Once a job of the set is assigned, the rest of the set is moved to ignored jobs. This is a hard constraint.
+pub fn create_alternative_feature(name: &str, code: ViolationCode) -> GenericResult<Feature> {
+    let context_transition = ConcreteJobContextTransition {
+        remove_required: |solution_ctx, _, job| is_alternative_assigned(solution_ctx, job),
+        promote_required: |solution_ctx, _, job| {
+            job.dimens().get_job_alternative().is_some() && !is_alternative_assigned(solution_ctx, job)
+        },
+        remove_locked: |_, _, _| false,
+        promote_locked: |_, _, _| false,
+    };
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(AlternativeConstraint { code })
+        .with_state(AlternativeState { context_transition: Box::new(context_transition) })
+        .build()
+}
+
+struct AlternativeConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for AlternativeConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, job, .. } => {
+                if is_alternative_assigned(solution_ctx, job) {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_alternative(), candidate.dimens().get_job_alternative()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct AlternativeState {
+    context_transition: Box<dyn JobContextTransition>,
+}
+
+impl FeatureState for AlternativeState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if job.dimens().get_job_alternative().is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+            process_conditional_jobs(solution_ctx, Some(route_index), self.context_transition.as_ref());
+            ignore_unassigned_alternatives(solution_ctx);
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let alternatives = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter_map(|job| job.dimens().get_job_alternative())
+            .map(|alternative| alternative.key.clone())
+            .collect();
+
+        route_ctx.state_mut().set_current_alternatives(alternatives);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+
+        process_conditional_jobs(solution_ctx, None, self.context_transition.as_ref());
+        ignore_unassigned_alternatives(solution_ctx);
+    }
+}
+
+/// Checks whether any job of the same alternative set is already assigned.
+fn is_alternative_assigned(solution_ctx: &SolutionContext, job: &Job) -> bool {
+    job.dimens().get_job_alternative().is_some_and(|alternative| {
+        solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| route_ctx.state().get_current_alternatives())
+            .any(|alternatives| alternatives.contains(&alternative.key))
+    })
+}
+
+/// Moves unassigned jobs to ignored when their alternative set is already assigned.
+fn ignore_unassigned_alternatives(solution_ctx: &mut SolutionContext) {
+    let ignored = solution_ctx
+        .unassigned
+        .keys()
+        .filter(|job| is_alternative_assigned(solution_ctx, job))
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    if !ignored.is_empty() {
+        solution_ctx.unassigned.retain(|job, _| !ignored.contains(job));
+        solution_ctx.ignored.extend(ignored);
+    }
+}
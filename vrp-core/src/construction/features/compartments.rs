@@ -0,0 +1,383 @@
+//! A feature to model vehicles with multiple compartments. Each compartment has its own capacity,
+//! can be restricted to specific products and can forbid some products to share it.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/compartments_test.rs"]
+mod compartments_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+use std::collections::{HashMap, HashSet};
+
+custom_dimension!(pub JobProduct typeof String);
+
+/// Specifies a vehicle compartment.
+#[derive(Clone, Debug)]
+pub struct Compartment<T: LoadOps> {
+    /// A compartment id.
+    pub id: String,
+    /// A compartment capacity.
+    pub capacity: T,
+    /// Products allowed to be loaded into compartment. If not set, any product is allowed.
+    pub products: Option<HashSet<String>>,
+    /// Groups of products which cannot share the compartment.
+    pub incompatible: Vec<HashSet<String>>,
+}
+
+impl<T: LoadOps> Compartment<T> {
+    /// Returns true if the product can be loaded into the compartment which already has given products.
+    pub fn can_load(&self, product: Option<&String>, loaded: &HashSet<&String>) -> bool {
+        let is_allowed = match (self.products.as_ref(), product) {
+            (None, _) => true,
+            (Some(products), Some(product)) => products.contains(product),
+            (Some(_), None) => false,
+        };
+
+        is_allowed
+            && product.is_none_or(|product| {
+                loaded.iter().filter(|&&other| other != product).all(|other| {
+                    !self.incompatible.iter().any(|group| group.contains(product) && group.contains(*other))
+                })
+            })
+    }
+}
+
+/// A trait to get or set vehicle compartments.
+pub trait VehicleCompartmentsDimension {
+    /// Sets vehicle compartments.
+    fn set_vehicle_compartments<T: LoadOps>(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self;
+
+    /// Gets vehicle compartments.
+    fn get_vehicle_compartments<T: LoadOps>(&self) -> Option<&Vec<Compartment<T>>>;
+}
+
+/// Creates a compartments feature as a hard constraint. Job demands are assigned to the first
+/// compartment which can fit them, following the tour order. Activities of the same job are
+/// assigned to the same compartment.
+pub fn create_compartments_feature<T: LoadOps>(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CompartmentsConstraint::<T> { code, phantom: Default::default() })
+        .with_state(CompartmentsState::<T> { phantom: Default::default() })
+        .build()
+}
+
+/// Returns a compartment index for each activity in the route's tour. Returns `None` if vehicle has
+/// no compartments or jobs cannot be assigned to them.
+pub fn get_compartment_assignment<T: LoadOps>(route: &Route) -> Option<Vec<Option<usize>>> {
+    let compartments = route.actor.vehicle.dimens.get_vehicle_compartments::<T>()?;
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let plan = create_compartment_plan(compartments, activities.as_slice())?;
+
+    let compartment_indices =
+        plan.jobs.iter().map(|(job, _, compartment_idx)| (job, *compartment_idx)).collect::<HashMap<_, _>>();
+
+    Some(
+        activities
+            .iter()
+            .map(|activity| {
+                activity
+                    .job
+                    .as_ref()
+                    .filter(|single| has_demand::<T>(single))
+                    .and_then(|_| activity.retrieve_job())
+                    .and_then(|job| compartment_indices.get(&job).copied())
+            })
+            .collect(),
+    )
+}
+
+struct CompartmentsConstraint<T: LoadOps> {
+    code: ViolationCode,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureConstraint for CompartmentsConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let compartments = route_ctx.route().actor.vehicle.dimens.get_vehicle_compartments::<T>()?;
+                let can_load = |single: &Single| {
+                    !has_demand::<T>(single)
+                        || compartments
+                            .iter()
+                            .any(|compartment| compartment.can_load(single.dimens.get_job_product(), &HashSet::new()))
+                };
+
+                let is_feasible = match job {
+                    Job::Single(single) => can_load(single),
+                    Job::Multi(multi) => multi.jobs.iter().all(|single| can_load(single)),
+                };
+
+                if is_feasible { None } else { ConstraintViolation::fail(self.code) }
+            }
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let compartments = route_ctx.route().actor.vehicle.dimens.get_vehicle_compartments::<T>()?;
+                if !activity_ctx.target.job.as_ref().is_some_and(|single| has_demand::<T>(single)) {
+                    return None;
+                }
+
+                let is_feasible = match route_ctx.state().get_compartment_plan::<T>() {
+                    Some(plan) => can_insert(compartments, plan, route_ctx.route(), activity_ctx),
+                    None => {
+                        let mut activities = route_ctx.route().tour.all_activities().collect::<Vec<_>>();
+                        activities.insert(activity_ctx.index + 1, activity_ctx.target);
+
+                        create_compartment_plan(compartments, activities.as_slice()).is_some()
+                    }
+                };
+
+                if is_feasible { None } else { ConstraintViolation::skip(self.code) }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_product(), candidate.dimens().get_job_product()) {
+            (_, None) => Ok(source),
+            (Some(s_product), Some(c_product)) if s_product == c_product => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct CompartmentsState<T: LoadOps> {
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureState for CompartmentsState<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let plan = route_ctx.route().actor.vehicle.dimens.get_vehicle_compartments::<T>().and_then(|compartments| {
+            let activities = route_ctx.route().tour.all_activities().collect::<Vec<_>>();
+            create_compartment_plan(compartments, activities.as_slice())
+        });
+
+        match plan {
+            Some(plan) => route_ctx.state_mut().set_compartment_plan(plan),
+            None => {
+                route_ctx.state_mut().remove_compartment_plan::<T>();
+            }
+        }
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+/// Keeps compartment assignment of the route's tour.
+struct CompartmentPlan<T: LoadOps> {
+    /// Jobs with demand in the order of their first activity, together with the index of their first
+    /// activity and assigned compartment.
+    jobs: Vec<(Job, usize, usize)>,
+    /// Load of each compartment at each activity of the tour.
+    loads: Vec<Vec<T>>,
+}
+
+/// Assigns jobs to compartments greedily in the order of their first activity in the tour.
+fn create_compartment_plan<T: LoadOps>(
+    compartments: &[Compartment<T>],
+    activities: &[&Activity],
+) -> Option<CompartmentPlan<T>> {
+    let jobs = get_jobs::<T>(activities, |_| true);
+    let mut loads = vec![vec![T::default(); activities.len()]; compartments.len()];
+    let mut products = vec![HashSet::<&String>::new(); compartments.len()];
+
+    let compartment_indices = assign_jobs(compartments, activities, jobs.as_slice(), &mut loads, &mut products)?;
+
+    let jobs = jobs
+        .into_iter()
+        .zip(compartment_indices)
+        .map(|((job, indices), compartment_idx)| (job, indices[0], compartment_idx))
+        .collect();
+
+    Some(CompartmentPlan { jobs, loads })
+}
+
+/// Checks whether the target activity can be inserted using the cached plan: jobs which first activity
+/// is before the insertion point keep their compartments, only the rest of jobs are assigned again.
+fn can_insert<T: LoadOps>(
+    compartments: &[Compartment<T>],
+    plan: &CompartmentPlan<T>,
+    route: &Route,
+    activity_ctx: &ActivityContext,
+) -> bool {
+    let index = activity_ctx.index + 1;
+    let target_job = activity_ctx.target.retrieve_job();
+    let mut activities = route.tour.all_activities().collect::<Vec<_>>();
+
+    if plan.loads.iter().any(|loads| loads.len() != activities.len()) {
+        activities.insert(index, activity_ctx.target);
+        return create_compartment_plan(compartments, activities.as_slice()).is_some();
+    }
+
+    let split = plan
+        .jobs
+        .iter()
+        .position(|(job, first_idx, _)| *first_idx >= index || target_job.as_ref() == Some(job))
+        .unwrap_or(plan.jobs.len());
+    let (kept, affected) = plan.jobs.split_at(split);
+
+    // NOTE remove contribution of affected jobs, the rest of loads stays the same
+    let mut loads = plan.loads.clone();
+    let affected_jobs = affected.iter().map(|(job, _, _)| job.clone()).collect::<HashSet<_>>();
+    let old_jobs = get_jobs::<T>(activities.as_slice(), |job| affected_jobs.contains(job));
+    old_jobs.iter().zip(affected.iter()).for_each(|((_, indices), (_, _, compartment_idx))| {
+        let changes = get_changes(activities.as_slice(), indices.as_slice());
+        loads[*compartment_idx].iter_mut().zip(changes).for_each(|(load, change)| *load = *load - change);
+    });
+
+    // NOTE inserted activity has the same load from kept jobs as its predecessor
+    loads.iter_mut().for_each(|loads| loads.insert(index, loads[index - 1]));
+    activities.insert(index, activity_ctx.target);
+
+    let mut products = vec![HashSet::<&String>::new(); compartments.len()];
+    kept.iter().for_each(|(job, first_idx, compartment_idx)| {
+        let first_idx = if *first_idx >= index { first_idx + 1 } else { *first_idx };
+        debug_assert!(activities[first_idx].retrieve_job().as_ref() == Some(job));
+        if let Some(product) = get_product(activities.as_slice(), &[first_idx]) {
+            products[*compartment_idx].insert(product);
+        }
+    });
+
+    let new_jobs =
+        get_jobs::<T>(activities.as_slice(), |job| affected_jobs.contains(job) || target_job.as_ref() == Some(job));
+
+    assign_jobs(compartments, activities.as_slice(), new_jobs.as_slice(), &mut loads, &mut products).is_some()
+}
+
+/// Returns jobs with demand in the order of their first activity together with their activity indices.
+fn get_jobs<T: LoadOps>(activities: &[&Activity], filter: impl Fn(&Job) -> bool) -> Vec<(Job, Vec<usize>)> {
+    let mut positions = HashMap::<Job, usize>::new();
+
+    activities.iter().enumerate().fold(Vec::new(), |mut acc, (idx, activity)| {
+        let job = activity
+            .job
+            .as_ref()
+            .filter(|single| has_demand::<T>(single))
+            .and_then(|_| activity.retrieve_job())
+            .filter(|job| filter(job));
+
+        if let Some(job) = job {
+            match positions.get(&job) {
+                Some(&position) => acc[position].1.push(idx),
+                None => {
+                    positions.insert(job.clone(), acc.len());
+                    acc.push((job, vec![idx]));
+                }
+            }
+        }
+
+        acc
+    })
+}
+
+/// Assigns jobs, one by one, to the first compartment which can fit them. Returns compartment index per job.
+fn assign_jobs<'a, T: LoadOps>(
+    compartments: &[Compartment<T>],
+    activities: &[&'a Activity],
+    jobs: &[(Job, Vec<usize>)],
+    loads: &mut [Vec<T>],
+    products: &mut [HashSet<&'a String>],
+) -> Option<Vec<usize>> {
+    jobs.iter()
+        .map(|(_, indices)| {
+            let changes = get_changes(activities, indices.as_slice());
+            let product = get_product(activities, indices.as_slice());
+
+            let compartment_idx = compartments.iter().zip(loads.iter().zip(products.iter())).position(
+                |(compartment, (loads, loaded))| {
+                    compartment.can_load(product, loaded)
+                        && loads
+                            .iter()
+                            .zip(changes.iter())
+                            .all(|(&load, &change)| compartment.capacity.can_fit(&(load + change)))
+                },
+            )?;
+
+            loads[compartment_idx].iter_mut().zip(changes.iter()).for_each(|(load, &change)| *load = *load + change);
+            if let Some(product) = product {
+                products[compartment_idx].insert(product);
+            }
+
+            Some(compartment_idx)
+        })
+        .collect()
+}
+
+/// Returns load change at each activity of the tour caused by the job's activities.
+fn get_changes<T: LoadOps>(activities: &[&Activity], indices: &[usize]) -> Vec<T> {
+    let mut changes = vec![T::default(); activities.len()];
+
+    indices.iter().filter_map(|&idx| activities[idx].job.as_ref().map(|single| (idx, single))).for_each(
+        |(idx, single)| {
+            let demand = single.dimens.get_job_demand::<T>().cloned().unwrap_or_default();
+            changes.iter_mut().enumerate().for_each(|(position, change)| {
+                if position < idx {
+                    *change = *change + demand.delivery.0;
+                } else {
+                    *change = *change + demand.pickup.0 + demand.pickup.1 - demand.delivery.1;
+                }
+            });
+        },
+    );
+
+    changes
+}
+
+fn get_product<'a>(activities: &[&'a Activity], indices: &[usize]) -> Option<&'a String> {
+    indices.iter().find_map(|&idx| activities[idx].job.as_ref()?.dimens.get_job_product())
+}
+
+fn has_demand<T: LoadOps>(single: &Single) -> bool {
+    single.dimens.get_job_demand::<T>().is_some_and(|demand| {
+        [demand.pickup.0, demand.pickup.1, demand.delivery.0, demand.delivery.1]
+            .iter()
+            .any(|load| *load != T::default())
+    })
+}
+
+// TODO extend macros to support generic state and dimension.
+struct CompartmentPlanTourStateKey;
+
+trait CompartmentPlanTourState {
+    fn get_compartment_plan<T: LoadOps>(&self) -> Option<&CompartmentPlan<T>>;
+
+    fn set_compartment_plan<T: LoadOps>(&mut self, plan: CompartmentPlan<T>);
+
+    fn remove_compartment_plan<T: LoadOps>(&mut self) -> bool;
+}
+
+impl CompartmentPlanTourState for RouteState {
+    fn get_compartment_plan<T: LoadOps>(&self) -> Option<&CompartmentPlan<T>> {
+        self.get_tour_state::<CompartmentPlanTourStateKey, _>()
+    }
+
+    fn set_compartment_plan<T: LoadOps>(&mut self, plan: CompartmentPlan<T>) {
+        self.set_tour_state::<CompartmentPlanTourStateKey, _>(plan);
+    }
+
+    fn remove_compartment_plan<T: LoadOps>(&mut self) -> bool {
+        self.remove_tour_state::<CompartmentPlanTourStateKey>()
+    }
+}
+
+struct VehicleCompartmentsDimenKey;
+impl VehicleCompartmentsDimension for Dimensions {
+    fn set_vehicle_compartments<T: LoadOps>(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self {
+        self.set_value::<VehicleCompartmentsDimenKey, _>(compartments);
+        self
+    }
+
+    fn get_vehicle_compartments<T: LoadOps>(&self) -> Option<&Vec<Compartment<T>>> {
+        self.get_value::<VehicleCompartmentsDimenKey, _>()
+    }
+}
@@ -0,0 +1,225 @@
+//! A feature to minimize fuel consumption and CO2 emissions. Consumption of each leg depends on
+//! vehicle's consumption model, travelled distance, speed and the load carried on the leg.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/emissions_test.rs"]
+mod emissions_test;
+
+use super::*;
+use crate::construction::features::capacity::CurrentCapacityActivityState;
+use crate::models::solution::{Activity, Route};
+use std::marker::PhantomData;
+
+custom_dimension!(pub VehicleFuelConsumption typeof FuelConsumption);
+custom_activity_state!(TravelledDistance typeof Distance);
+
+/// Specifies vehicle's fuel consumption model.
+#[derive(Clone, Debug)]
+pub struct FuelConsumption {
+    /// Fuel consumed per distance unit by empty vehicle.
+    pub empty: Float,
+    /// Fuel consumed per distance unit by fully loaded vehicle.
+    pub full: Float,
+    /// Extra fuel consumed per distance unit and squared speed (aerodynamic drag).
+    pub drag: Float,
+    /// CO2 emitted per fuel unit.
+    pub co2: Float,
+}
+
+impl FuelConsumption {
+    /// Returns fuel consumed on the leg. Load ratio is a share of vehicle's capacity in `[0, 1]`.
+    pub fn get_fuel(&self, distance: Distance, duration: Duration, load_ratio: Float) -> Float {
+        let speed = if duration > 0. { distance / duration } else { 0. };
+
+        distance * (self.get_load_rate(load_ratio) + self.drag * speed * speed)
+    }
+
+    /// Returns fuel consumed per distance unit because of carried load.
+    fn get_load_rate(&self, load_ratio: Float) -> Float {
+        let load_ratio = if load_ratio.is_finite() { load_ratio.clamp(0., 1.) } else { 0. };
+
+        self.empty + (self.full - self.empty) * load_ratio
+    }
+}
+
+/// Creates a feature which minimizes CO2 emissions of vehicles with fuel consumption model.
+/// Loads carried on legs are taken from the states maintained by capacity feature.
+pub fn create_emissions_feature<T: LoadOps>(name: &str, transport: Arc<dyn TransportCost>) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(EmissionsObjective::<T> { transport: transport.clone(), phantom: Default::default() })
+        .with_state(EmissionsState { transport })
+        .build()
+}
+
+/// Returns fuel consumed on the route. A load ratio function returns a share of vehicle's capacity
+/// occupied after departure from the activity with given index.
+pub fn get_route_fuel_consumption(
+    route: &Route,
+    transport: &dyn TransportCost,
+    load_ratio_fn: impl Fn(usize) -> Float,
+) -> Option<Float> {
+    let consumption = route.actor.vehicle.dimens.get_vehicle_fuel_consumption()?;
+
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let fuel = activities
+        .windows(2)
+        .enumerate()
+        .map(|(idx, leg)| {
+            let (distance, duration) = get_leg(route, transport, leg[0], leg[1]);
+            consumption.get_fuel(distance, duration, load_ratio_fn(idx))
+        })
+        .sum();
+
+    Some(fuel)
+}
+
+struct EmissionsObjective<T: LoadOps> {
+    transport: Arc<dyn TransportCost>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureObjective for EmissionsObjective<T> {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .filter_map(|route_ctx| {
+                let consumption = route_ctx.route().actor.vehicle.dimens.get_vehicle_fuel_consumption()?;
+                let fuel = get_route_fuel_consumption(route_ctx.route(), self.transport.as_ref(), |idx| {
+                    get_load_ratio::<T>(route_ctx, idx)
+                })?;
+
+                Some(fuel * consumption.co2)
+            })
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                self.estimate_activity(route_ctx, activity_ctx).unwrap_or_default()
+            }
+        }
+    }
+}
+
+impl<T: LoadOps> EmissionsObjective<T> {
+    /// Estimates emissions change caused by the detour to the target activity and by its demand
+    /// which is carried on the legs before (static delivery) or after (static pickup) it.
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Option<Cost> {
+        let route = route_ctx.route();
+        let consumption = route.actor.vehicle.dimens.get_vehicle_fuel_consumption()?;
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let prev_idx = activity_ctx.index;
+
+        let demand = target.job.as_ref().and_then(|single| single.dimens.get_job_demand::<T>());
+        let get_ratio = |load: T| get_capacity_ratio(route, &load);
+
+        let prev_load = route_ctx.state().get_current_capacity_at::<T>(prev_idx).copied().unwrap_or_default();
+        let (before_load, after_load) = demand.map_or((prev_load, prev_load), |demand| {
+            let before = prev_load + demand.delivery.0;
+            (before, before + demand.change())
+        });
+
+        let get_fuel = |from: &Activity, to: &Activity, load_ratio: Float| {
+            let (distance, duration) = get_leg(route, self.transport.as_ref(), from, to);
+            consumption.get_fuel(distance, duration, load_ratio)
+        };
+
+        let detour = get_fuel(prev, target, get_ratio(before_load))
+            + next.map_or(0., |next| {
+                get_fuel(target, next, get_ratio(after_load)) - get_fuel(prev, next, get_ratio(prev_load))
+            });
+
+        let (start_idx, end_idx) = get_interval(route_ctx, prev_idx);
+        let get_distance = |idx: usize| route_ctx.state().get_travelled_distance_at(idx).copied().unwrap_or_default();
+        let load_rate = |load: Option<T>| {
+            load.filter(|load| load.is_not_empty())
+                .map_or(0., |load| consumption.get_load_rate(get_ratio(load)) - consumption.empty)
+        };
+
+        let delivery =
+            load_rate(demand.map(|demand| demand.delivery.0)) * (get_distance(prev_idx) - get_distance(start_idx));
+        let pickup = next.map_or(0., |_| {
+            load_rate(demand.map(|demand| demand.pickup.0)) * (get_distance(end_idx) - get_distance(prev_idx + 1))
+        });
+
+        Some((detour + delivery + pickup) * consumption.co2)
+    }
+}
+
+struct EmissionsState {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureState for EmissionsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(&mut solution_ctx.routes[route_index]);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        if route.actor.vehicle.dimens.get_vehicle_fuel_consumption().is_none() {
+            return;
+        }
+
+        let distances = route
+            .tour
+            .all_activities()
+            .scan((None::<&Activity>, Distance::default()), |(prev, total), activity| {
+                if let Some(prev) = prev {
+                    *total += get_leg(route, self.transport.as_ref(), prev, activity).0;
+                }
+                *prev = Some(activity);
+
+                Some(*total)
+            })
+            .collect();
+
+        route_ctx.state_mut().set_travelled_distance_states(distances);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+fn get_leg(route: &Route, transport: &dyn TransportCost, from: &Activity, to: &Activity) -> (Distance, Duration) {
+    let (from, to, departure) = (from.place.location, to.place.location, from.schedule.departure);
+
+    (
+        transport.distance(route, from, to, TravelTime::Departure(departure)),
+        transport.duration(route, from, to, TravelTime::Departure(departure)),
+    )
+}
+
+fn get_load_ratio<T: LoadOps>(route_ctx: &RouteContext, activity_idx: usize) -> Float {
+    route_ctx
+        .state()
+        .get_current_capacity_at::<T>(activity_idx)
+        .map_or(0., |load| get_capacity_ratio(route_ctx.route(), load))
+}
+
+fn get_capacity_ratio<T: LoadOps>(route: &Route, load: &T) -> Float {
+    route.actor.vehicle.dimens.get_vehicle_capacity::<T>().map_or(0., |capacity| load.ratio(capacity))
+}
+
+/// Returns indices of the first and the last activities of the route interval (e.g. between
+/// reloads) which contains the activity with given index.
+fn get_interval(route_ctx: &RouteContext, activity_idx: usize) -> (usize, usize) {
+    let last_idx = route_ctx.route().tour.total().saturating_sub(1);
+
+    route_ctx
+        .state()
+        .get_reload_intervals()
+        .and_then(|intervals| intervals.iter().find(|(start, end)| *start <= activity_idx && activity_idx <= *end))
+        .copied()
+        .unwrap_or((0, last_idx))
+}
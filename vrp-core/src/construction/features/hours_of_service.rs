@@ -0,0 +1,469 @@
+//! A feature to model hours-of-service regulations (e.g. EU 561/2006 or US FMCSA). It tracks
+//! cumulative driving time along the route, inserts driving breaks and daily rests where they are
+//! required and limits driving time within a tour, a week and all tours of the same vehicle.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/hours_of_service_test.rs"]
+mod hours_of_service_test;
+
+use super::*;
+use crate::construction::enablers::TotalDurationTourState;
+use crate::models::solution::{Activity, Route};
+use rosomaxa::utils::UnwrapValue;
+
+custom_dimension!(pub VehicleHoursOfService typeof HoursOfService);
+custom_tour_state!(HoursOfServiceDriving typeof Duration);
+custom_activity_state!(HoursOfServiceProgress typeof DrivingProgress);
+
+/// A week duration used to group tours by weekly driving limit.
+const WEEK_DURATION: Duration = 7. * 24. * 3600.;
+
+/// Specifies hours-of-service rules of the vehicle's driver.
+#[derive(Clone, Debug, Default)]
+pub struct HoursOfService {
+    /// A break which has to be taken after some continuous driving time.
+    pub driving_break: Option<DrivingBreak>,
+    /// A rest which has to be taken after some daily driving time.
+    pub daily_rest: Option<DailyRest>,
+    /// A maximum driving time within a tour.
+    pub max_tour_driving: Option<Duration>,
+    /// A maximum driving time within all tours of the same vehicle.
+    pub max_vehicle_driving: Option<Duration>,
+    /// A maximum driving time within all tours of the same vehicle started in the same week.
+    pub weekly_driving: Option<WeeklyDriving>,
+}
+
+/// Specifies a break which has to be taken after some continuous driving time.
+#[derive(Clone, Debug)]
+pub struct DrivingBreak {
+    /// A maximum continuous driving time.
+    pub max_driving: Duration,
+    /// Break parts in the order they have to be taken. Waiting time at a stop is counted as the next
+    /// part if it is long enough. A break inserted into the route always covers all remaining parts.
+    pub parts: Vec<Duration>,
+}
+
+/// Specifies a rest which has to be taken after some driving time: it limits daily driving time.
+/// The rest resets continuous driving time too, so it also counts as a driving break.
+#[derive(Clone, Debug)]
+pub struct DailyRest {
+    /// A maximum driving time between daily rests.
+    pub max_driving: Duration,
+    /// A rest duration. Waiting time at a stop is counted as the rest if it is long enough.
+    pub duration: Duration,
+}
+
+/// Specifies a maximum driving time within a week.
+#[derive(Clone, Debug)]
+pub struct WeeklyDriving {
+    /// A start of the first week: a tour belongs to the week in which it starts.
+    pub start: Timestamp,
+    /// A maximum driving time within all tours of the same vehicle started in the same week.
+    pub max_driving: Duration,
+}
+
+/// Keeps hours-of-service details of the route.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HoursOfServiceInfo {
+    /// Driving breaks and daily rests inserted into the route as pairs of activity index and
+    /// duration. A break is taken right after the activity.
+    pub breaks: Vec<(usize, Duration)>,
+    /// Amount of daily rests within inserted breaks.
+    pub daily_rests: usize,
+    /// Total driving time.
+    pub driving: Duration,
+    /// The longest continuous driving time between breaks.
+    pub continuous_driving: Duration,
+}
+
+/// Keeps driving progress at the end of activity's service, before a break which follows it.
+#[derive(Clone, Debug, Default)]
+struct DrivingProgress {
+    /// An end of activity's service.
+    departure: Timestamp,
+    /// Total driving time since the route start.
+    driving: Duration,
+    /// Driving time since the last daily rest.
+    daily: Duration,
+    /// Driving time since the last driving break.
+    continuous: Duration,
+    /// An index of the next driving break part.
+    part_idx: usize,
+}
+
+impl DrivingProgress {
+    /// Returns true if the rest of the route is scheduled the same way starting from both progresses.
+    fn is_same(&self, other: &DrivingProgress) -> bool {
+        self.departure == other.departure
+            && self.daily == other.daily
+            && self.continuous == other.continuous
+            && self.part_idx == other.part_idx
+    }
+
+    /// Applies pause: it is counted as a daily rest, a driving break or a part of it when long enough.
+    fn pause(&mut self, hos: &HoursOfService, pause: Duration) {
+        if pause <= 0. {
+            return;
+        }
+
+        if hos.daily_rest.as_ref().is_some_and(|daily_rest| pause >= daily_rest.duration) {
+            self.take_daily_rest();
+        } else if let Some(driving_break) = hos.driving_break.as_ref() {
+            if pause >= get_remaining_break(driving_break, self.part_idx) {
+                self.take_break();
+            } else if driving_break.parts.get(self.part_idx).is_some_and(|&part| pause >= part) {
+                self.part_idx += 1;
+            }
+        }
+    }
+
+    fn take_break(&mut self) {
+        self.continuous = 0.;
+        self.part_idx = 0;
+    }
+
+    fn take_daily_rest(&mut self) {
+        self.daily = 0.;
+        self.take_break();
+    }
+}
+
+/// Keeps details of the leg scheduled according to hours-of-service rules.
+struct ScheduledLeg {
+    progress: DrivingProgress,
+    schedule: Schedule,
+    /// A break taken before the leg and a flag whether it is a daily rest.
+    rest: Option<(Duration, bool)>,
+}
+
+/// Keeps details of the route scheduled according to hours-of-service rules.
+struct ScheduledRoute {
+    schedules: Vec<Schedule>,
+    progresses: Vec<DrivingProgress>,
+    info: HoursOfServiceInfo,
+}
+
+/// Creates a feature to follow hours-of-service rules. Driving breaks and daily rests are inserted into the route
+/// schedule automatically, so the feature should be used after the one which updates schedules.
+pub fn create_hours_of_service_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(HoursOfServiceConstraint { code, transport: transport.clone(), activity: activity.clone() })
+        .with_state(HoursOfServiceState { code, transport, activity })
+        .build()
+}
+
+/// Returns hours-of-service details of the route or `None` if the vehicle has no rules or the
+/// route cannot be scheduled according to them.
+pub fn get_hours_of_service_info(
+    route: &Route,
+    transport: &dyn TransportCost,
+    activity: &dyn ActivityCost,
+) -> Option<HoursOfServiceInfo> {
+    let hos = route.actor.vehicle.dimens.get_vehicle_hours_of_service()?;
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+    schedule_route(route, hos, activities.as_slice(), transport, activity).ok().map(|scheduled| scheduled.info)
+}
+
+struct HoursOfServiceConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureConstraint for HoursOfServiceConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        let MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } = move_ctx else {
+            return None;
+        };
+
+        let route = route_ctx.route();
+        let hos = route.actor.vehicle.dimens.get_vehicle_hours_of_service()?;
+
+        let is_feasible = self
+            .estimate_driving(route_ctx, hos, activity_ctx)
+            .is_some_and(|driving| is_within_driving_limits(solution_ctx.routes.as_slice(), route, hos, driving));
+
+        if is_feasible { None } else { ConstraintViolation::skip(self.code) }
+    }
+}
+
+impl HoursOfServiceConstraint {
+    /// Estimates total driving time of the route with the target activity inserted. Driving progress
+    /// is kept per activity, so only the legs which are affected by insertion are rescheduled.
+    /// Returns `None` if the route cannot be scheduled according to hours-of-service rules.
+    fn estimate_driving(
+        &self,
+        route_ctx: &RouteContext,
+        hos: &HoursOfService,
+        activity_ctx: &ActivityContext,
+    ) -> Option<Duration> {
+        let (route, state) = (route_ctx.route(), route_ctx.state());
+        let (transport, activity) = (self.transport.as_ref(), self.activity.as_ref());
+
+        let (Some(progress), Some(&total_driving)) =
+            (state.get_hours_of_service_progress_at(activity_ctx.index), state.get_hours_of_service_driving())
+        else {
+            // NOTE route state is not accepted yet, so the whole route has to be scheduled
+            let activities = route
+                .tour
+                .all_activities()
+                .take(activity_ctx.index + 1)
+                .chain(std::iter::once(activity_ctx.target))
+                .chain(route.tour.all_activities().skip(activity_ctx.index + 1))
+                .collect::<Vec<_>>();
+
+            return schedule_route(route, hos, activities.as_slice(), transport, activity)
+                .ok()
+                .map(|scheduled| scheduled.info.driving);
+        };
+
+        let target = activity_ctx.target;
+        let leg = schedule_leg(route, hos, activity_ctx.prev.place.location, progress, target, transport, activity)?;
+        let (mut location, mut progress) = (target.place.location, leg.progress);
+
+        for (activity_idx, next) in route.tour.all_activities().enumerate().skip(activity_ctx.index + 1) {
+            let leg = schedule_leg(route, hos, location, &progress, next, transport, activity)?;
+            let old_progress = state.get_hours_of_service_progress_at(activity_idx)?;
+
+            if leg.progress.is_same(old_progress) {
+                return Some(total_driving - old_progress.driving + leg.progress.driving);
+            }
+
+            (location, progress) = (next.place.location, leg.progress);
+        }
+
+        Some(progress.driving)
+    }
+}
+
+struct HoursOfServiceState {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureState for HoursOfServiceState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(&mut solution_ctx.routes[route_index]);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let Some(hos) = route_ctx.route().actor.vehicle.dimens.get_vehicle_hours_of_service().cloned() else {
+            return;
+        };
+
+        let route = route_ctx.route();
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+        let result =
+            schedule_route(route, &hos, activities.as_slice(), self.transport.as_ref(), self.activity.as_ref());
+
+        // NOTE infeasible routes are fixed by removing jobs when solution state is accepted
+        let Ok(ScheduledRoute { schedules, progresses, info }) = result else {
+            route_ctx.state_mut().set_hours_of_service_progress_states(vec![]);
+            return;
+        };
+
+        let (route, state) = route_ctx.as_mut();
+        route.tour.all_activities_mut().zip(schedules).for_each(|(activity, schedule)| activity.schedule = schedule);
+
+        let total_duration = route.tour.end().zip(route.tour.start()).map(|(end, start)| {
+            // NOTE breaks extend schedule, so total duration needs to be corrected
+            end.schedule.departure - start.schedule.departure
+        });
+
+        if let Some(total_duration) = total_duration {
+            state.set_total_duration(total_duration);
+        }
+        state.set_hours_of_service_driving(info.driving);
+        state.set_hours_of_service_progress_states(progresses);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_infeasible_jobs(solution_ctx);
+
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+impl HoursOfServiceState {
+    /// Removes jobs which cannot be served anymore as the route's schedule has changed, e.g. job's
+    /// waiting time used as a break part is not available after ruin.
+    fn remove_infeasible_jobs(&self, solution_ctx: &mut SolutionContext) {
+        let mut removed = Vec::new();
+
+        for route_ctx in solution_ctx.routes.iter_mut() {
+            let Some(hos) = route_ctx.route().actor.vehicle.dimens.get_vehicle_hours_of_service().cloned() else {
+                continue;
+            };
+
+            loop {
+                let route = route_ctx.route();
+                let activities = route.tour.all_activities().collect::<Vec<_>>();
+                let Err(activity_idx) =
+                    schedule_route(route, &hos, activities.as_slice(), self.transport.as_ref(), self.activity.as_ref())
+                else {
+                    break;
+                };
+
+                let job = activities[..=activity_idx]
+                    .iter()
+                    .rev()
+                    .filter_map(|activity| activity.retrieve_job())
+                    .find(|job| !solution_ctx.locked.contains(job));
+
+                // NOTE keep the route as is when there is no job which can be removed
+                let Some(job) = job.filter(|job| route_ctx.route_mut().tour.remove(job)) else {
+                    break;
+                };
+
+                removed.push(job);
+            }
+        }
+
+        solution_ctx.unassigned.extend(removed.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
+}
+
+/// Schedules the route following hours-of-service rules. Returns new activity schedules with
+/// inserted breaks or an index of the first activity which cannot be served in time.
+fn schedule_route(
+    route: &Route,
+    hos: &HoursOfService,
+    activities: &[&Activity],
+    transport: &dyn TransportCost,
+    activity: &dyn ActivityCost,
+) -> Result<ScheduledRoute, usize> {
+    let Some(start) = activities.first() else {
+        return Ok(ScheduledRoute { schedules: vec![], progresses: vec![], info: HoursOfServiceInfo::default() });
+    };
+
+    let progress = DrivingProgress { departure: start.schedule.departure, ..DrivingProgress::default() };
+    let mut scheduled = ScheduledRoute {
+        schedules: vec![start.schedule.clone()],
+        progresses: vec![progress],
+        info: Default::default(),
+    };
+
+    for (activity_idx, target) in activities.iter().enumerate().skip(1) {
+        let (prev, progress) = (activities[activity_idx - 1], &scheduled.progresses[activity_idx - 1]);
+        let leg =
+            schedule_leg(route, hos, prev.place.location, progress, target, transport, activity).ok_or(activity_idx)?;
+
+        if let Some((duration, is_daily_rest)) = leg.rest {
+            scheduled.schedules[activity_idx - 1].departure += duration;
+            scheduled.info.breaks.push((activity_idx - 1, duration));
+            scheduled.info.daily_rests += usize::from(is_daily_rest);
+        }
+
+        scheduled.info.continuous_driving = scheduled.info.continuous_driving.max(leg.progress.continuous);
+        scheduled.info.driving = leg.progress.driving;
+        scheduled.schedules.push(leg.schedule);
+        scheduled.progresses.push(leg.progress);
+    }
+
+    Ok(scheduled)
+}
+
+/// Schedules a leg from the location to the target activity taking a daily rest or a driving break
+/// before it if required. Returns `None` if the target cannot be served in time.
+fn schedule_leg(
+    route: &Route,
+    hos: &HoursOfService,
+    location: Location,
+    progress: &DrivingProgress,
+    target: &Activity,
+    transport: &dyn TransportCost,
+    activity: &dyn ActivityCost,
+) -> Option<ScheduledLeg> {
+    let get_driving =
+        |departure| transport.duration(route, location, target.place.location, TravelTime::Departure(departure));
+
+    let mut progress = progress.clone();
+    let mut departure = progress.departure;
+    let mut driving = get_driving(departure);
+
+    let is_too_long = hos.driving_break.as_ref().is_some_and(|driving_break| driving > driving_break.max_driving)
+        || hos.daily_rest.as_ref().is_some_and(|daily_rest| driving > daily_rest.max_driving);
+    if is_too_long {
+        return None;
+    }
+
+    let rest = match (hos.daily_rest.as_ref(), hos.driving_break.as_ref()) {
+        (Some(daily_rest), _) if progress.daily + driving > daily_rest.max_driving => {
+            progress.take_daily_rest();
+            Some((daily_rest.duration, true))
+        }
+        (_, Some(driving_break)) if progress.continuous + driving > driving_break.max_driving => {
+            let duration = get_remaining_break(driving_break, progress.part_idx);
+            progress.take_break();
+            Some((duration, false))
+        }
+        _ => None,
+    };
+
+    if let Some((duration, _)) = rest {
+        departure += duration;
+        driving = get_driving(departure);
+    }
+
+    progress.driving += driving;
+    progress.daily += driving;
+    progress.continuous += driving;
+
+    let arrival = departure + driving;
+    if arrival > target.place.time.end {
+        return None;
+    }
+
+    progress.pause(hos, (target.place.time.start - arrival).max(0.));
+    progress.departure = activity.estimate_departure(route, target, arrival).unwrap_value();
+
+    Some(ScheduledLeg { schedule: Schedule::new(arrival, progress.departure), progress, rest })
+}
+
+/// Returns duration of break parts which are not taken yet.
+fn get_remaining_break(driving_break: &DrivingBreak, part_idx: usize) -> Duration {
+    driving_break.parts.iter().skip(part_idx).sum()
+}
+
+/// Checks that total driving time of the route is within tour, weekly and vehicle limits.
+fn is_within_driving_limits(routes: &[RouteContext], route: &Route, hos: &HoursOfService, driving: Duration) -> bool {
+    let week = hos.weekly_driving.as_ref().and_then(|weekly| get_week(route, weekly));
+
+    hos.max_tour_driving.is_none_or(|max| driving <= max)
+        && hos.max_vehicle_driving.is_none_or(|max| get_other_tours_driving(routes, route, |_| true) + driving <= max)
+        && hos.weekly_driving.as_ref().zip(week).is_none_or(|(weekly, week)| {
+            get_other_tours_driving(routes, route, |other| get_week(other, weekly) == Some(week)) + driving
+                <= weekly.max_driving
+        })
+}
+
+/// Returns an index of the week in which the route starts.
+fn get_week(route: &Route, weekly: &WeeklyDriving) -> Option<i64> {
+    route.tour.start().map(|start| ((start.schedule.departure - weekly.start) / WEEK_DURATION).floor() as i64)
+}
+
+fn get_other_tours_driving(routes: &[RouteContext], route: &Route, filter: impl Fn(&Route) -> bool) -> Duration {
+    let Some(vehicle_id) = route.actor.vehicle.dimens.get_vehicle_id() else {
+        return Duration::default();
+    };
+
+    routes
+        .iter()
+        .filter(|route_ctx| !Arc::ptr_eq(&route_ctx.route().actor, &route.actor))
+        .filter(|route_ctx| route_ctx.route().actor.vehicle.dimens.get_vehicle_id() == Some(vehicle_id))
+        .filter(|route_ctx| filter(route_ctx.route()))
+        .filter_map(|route_ctx| route_ctx.state().get_hours_of_service_driving())
+        .sum()
+}
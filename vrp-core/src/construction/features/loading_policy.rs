@@ -0,0 +1,114 @@
+//! A feature to enforce loading order of multi jobs (e.g. pickup and delivery) for vehicles with
+//! restricted access to cargo, such as rear-loaded trucks.
+//!
+//! A multi job is considered to be on board from its first activity till its last one in the tour.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/loading_policy_test.rs"]
+mod loading_policy_test;
+
+use super::*;
+use crate::models::problem::Multi;
+use crate::models::solution::Activity;
+use std::collections::{HashMap, VecDeque};
+use std::iter::once;
+
+custom_dimension!(pub VehicleLoadingPolicy typeof LoadingPolicy);
+
+/// Specifies a loading policy of a vehicle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoadingPolicy {
+    /// Last in, first out: a job can be unloaded only if it is the most recent one loaded.
+    Lifo,
+    /// First in, first out: a job can be unloaded only if it is the earliest one loaded.
+    Fifo,
+}
+
+/// Creates a feature to enforce loading policy of vehicles with [`VehicleLoadingPolicyDimension`]
+/// for multi jobs. This is a hard constraint.
+pub fn create_loading_policy_feature(name: &str, code: ViolationCode) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_constraint(LoadingPolicyConstraint { code }).build()
+}
+
+struct LoadingPolicyConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for LoadingPolicyConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let route = route_ctx.route();
+                let &policy = route.actor.vehicle.dimens.get_vehicle_loading_policy()?;
+
+                // NOTE activities of multi job are inserted in order, so loading order can be violated only when
+                //      the last one is inserted. Other insertions do not change relative order of activities.
+                let multi = activity_ctx.target.job.as_ref().and_then(|single| Multi::roots(single))?;
+                let job = Job::Multi(multi.clone());
+                let inserted = route.tour.all_activities().filter(|activity| activity.has_same_job(&job)).count();
+                if inserted + 1 != multi.jobs.len() {
+                    return None;
+                }
+
+                let tour = &route.tour;
+                let activities = tour
+                    .all_activities()
+                    .take(activity_ctx.index + 1)
+                    .chain(once(activity_ctx.target))
+                    .chain(tour.all_activities().skip(activity_ctx.index + 1))
+                    .collect::<Vec<_>>();
+
+                if is_loading_order_valid(policy, activities.as_slice()) {
+                    None
+                } else {
+                    ConstraintViolation::skip(self.code)
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+/// Simulates loading and unloading of multi jobs fully present in the activity sequence.
+fn is_loading_order_valid(policy: LoadingPolicy, activities: &[&Activity]) -> bool {
+    let jobs = activities
+        .iter()
+        .map(|activity| activity.job.as_ref().and_then(|single| Multi::roots(single)).map(Job::Multi))
+        .collect::<Vec<_>>();
+
+    let bounds = jobs.iter().enumerate().filter_map(|(idx, job)| job.as_ref().map(|job| (idx, job))).fold(
+        HashMap::<&Job, (usize, usize, usize)>::default(),
+        |mut acc, (idx, job)| {
+            acc.entry(job).and_modify(|(_, last, count)| (*last, *count) = (idx, *count + 1)).or_insert((idx, idx, 1));
+            acc
+        },
+    );
+
+    let mut on_board = VecDeque::<&Job>::default();
+    jobs.iter().enumerate().filter_map(|(idx, job)| job.as_ref().map(|job| (idx, job))).all(|(idx, job)| {
+        let Some(&(first, last, count)) = bounds.get(job) else { return true };
+        if count != job.as_multi().map_or(0, |multi| multi.jobs.len()) {
+            return true;
+        }
+
+        if idx == first {
+            on_board.push_back(job);
+            return true;
+        }
+
+        if idx != last {
+            return true;
+        }
+
+        let unloaded = match policy {
+            LoadingPolicy::Lifo => on_board.pop_back(),
+            LoadingPolicy::Fifo => on_board.pop_front(),
+        };
+
+        unloaded == Some(job)
+    })
+}
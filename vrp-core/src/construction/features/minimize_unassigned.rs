@@ -6,6 +6,7 @@ mod minimize_unassigned_test;
 
 use super::*;
 use crate::utils::Either;
+use std::collections::HashMap;
 use std::iter::empty;
 
 /// Provides a way to build a feature to minimize amount of unassigned jobs.
@@ -50,7 +51,7 @@ struct MinimizeUnassignedObjective {
 
 impl FeatureObjective for MinimizeUnassignedObjective {
     fn fitness(&self, solution: &InsertionContext) -> Cost {
-        if solution.solution.routes.is_empty() {
+        let (total, alternatives) = if solution.solution.routes.is_empty() {
             // NOTE: when some solution is empty, then it might look better by the number of unassigned jobs
             //       if we do not estimate ignored jobs.
             Either::Left(solution.solution.ignored.iter())
@@ -58,14 +59,35 @@ impl FeatureObjective for MinimizeUnassignedObjective {
             Either::Right(empty())
         }
         .chain(solution.solution.unassigned.keys())
-        .map(|job| (self.unassigned_job_estimator)(&solution.solution, job))
-        .sum::<Float>()
+        .fold((Float::default(), HashMap::<&String, Float>::default()), |(total, mut alternatives), job| {
+            let estimate = (self.unassigned_job_estimator)(&solution.solution, job);
+
+            match job.dimens().get_job_alternative() {
+                // NOTE unassigned optional alternative set is not penalized
+                Some(alternative) if alternative.is_optional => (total, alternatives),
+                // NOTE unassigned alternative set is estimated once using its most expensive job
+                Some(alternative) => {
+                    let value = alternatives.entry(&alternative.key).or_default();
+                    *value = value.max(estimate);
+                    (total, alternatives)
+                }
+                None => (total + estimate, alternatives),
+            }
+        });
+
+        total + alternatives.values().sum::<Float>()
     }
 
     fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
         match move_ctx {
+            // NOTE optional alternative set is not penalized when unassigned, so its insertion gives nothing
+            MoveContext::Route { job, .. } if is_optional_alternative(job) => Cost::default(),
             MoveContext::Route { solution_ctx, job, .. } => -(self.unassigned_job_estimator)(solution_ctx, job),
             MoveContext::Activity { .. } => Cost::default(),
         }
     }
 }
+
+fn is_optional_alternative(job: &Job) -> bool {
+    job.dimens().get_job_alternative().is_some_and(|alternative| alternative.is_optional)
+}
@@ -7,6 +7,9 @@ use crate::models::*;
 use rosomaxa::prelude::*;
 use std::sync::Arc;
 
+mod alternatives;
+pub use self::alternatives::{JobAlternative, JobAlternativeDimension, create_alternative_feature};
+
 mod breaks;
 pub use self::breaks::*;
 
@@ -14,9 +17,20 @@ mod capacity;
 pub(crate) use self::capacity::MaxVehicleLoadTourState;
 pub use self::capacity::{CapacityFeatureBuilder, JobDemandDimension, VehicleCapacityDimension};
 
+mod compartments;
+pub use self::compartments::{
+    Compartment, JobProductDimension, VehicleCompartmentsDimension, create_compartments_feature,
+    get_compartment_assignment,
+};
+
 mod compatibility;
 pub use self::compatibility::{JobCompatibilityDimension, create_compatibility_feature};
 
+mod emissions;
+pub use self::emissions::{
+    FuelConsumption, VehicleFuelConsumptionDimension, create_emissions_feature, get_route_fuel_consumption,
+};
+
 mod fast_service;
 pub use self::fast_service::FastServiceFeatureBuilder;
 
@@ -29,26 +43,78 @@ pub use self::groups::{JobGroupDimension, create_group_feature};
 mod hierarchical_areas;
 pub use self::hierarchical_areas::*;
 
+mod hours_of_service;
+pub use self::hours_of_service::{
+    DailyRest, DrivingBreak, HoursOfService, HoursOfServiceInfo, VehicleHoursOfServiceDimension, WeeklyDriving,
+    create_hours_of_service_feature, get_hours_of_service_info,
+};
+
 mod known_edge;
 pub use self::known_edge::create_known_edge_feature;
 
+mod loading_policy;
+pub use self::loading_policy::{LoadingPolicy, VehicleLoadingPolicyDimension, create_loading_policy_feature};
+
 mod locked_jobs;
 pub use self::locked_jobs::*;
 
 mod minimize_unassigned;
 pub use self::minimize_unassigned::*;
 
+mod overtime;
+pub use self::overtime::{
+    Overtime, VehicleOvertimeDimension, create_overtime_feature, get_route_overtime, get_route_overtime_cost,
+};
+
+mod packing;
+pub use self::packing::{
+    CargoSpace, ItemPlacement, JobPackingItemsDimension, PackingItem, ReloadActivityFn, VehicleCargoSpaceDimension,
+    create_packing_feature, get_loading_plan,
+};
+
+mod periodic;
+pub use self::periodic::{JobVisit, JobVisitDimension, VehicleDayDimension, create_periodic_feature};
+
 mod reachable;
 pub use self::reachable::create_reachable_feature;
 
 mod recharge;
-pub use self::recharge::RechargeFeatureBuilder;
+pub use self::recharge::{
+    Battery, ChargingCurve, JobChargingCurveDimension, RechargeFeatureBuilder, VehicleBatteryDimension,
+    get_route_states_of_charge,
+};
 
 mod reloads;
 pub use self::reloads::{ReloadFeatureFactory, ReloadIntervalsTourState, SharedResource, SharedResourceId};
 
+mod ride_time;
+pub use self::ride_time::{JobMaxRideTimeDimension, MaxRideTime, create_max_ride_time_feature};
+
+mod sites;
+pub use self::sites::{Site, create_site_feature};
+
 mod skills;
-pub use self::skills::{JobSkills, JobSkillsDimension, VehicleSkillsDimension, create_skills_feature};
+pub use self::skills::{
+    DriverSkillsDimension, JobSkills, JobSkillsDimension, VehicleSkillsDimension, create_skills_feature,
+};
+
+mod soft_time_windows;
+pub use self::soft_time_windows::{
+    JobSoftTimeWindowsDimension, SoftTimeWindows, create_soft_time_windows_feature, get_activity_time_deviation,
+    get_latest_arrival, get_service_start,
+};
+
+mod solution_stability;
+pub use self::solution_stability::{JobReference, create_solution_stability_feature};
+
+mod precedence;
+pub use self::precedence::{JobPrecedence, JobPrecedenceDimension, create_precedence_feature};
+
+mod split;
+pub use self::split::{JobSplit, JobSplitDimension, create_split_feature};
+
+mod synchronization;
+pub use self::synchronization::{JobSync, JobSyncDimension, create_synchronization_feature};
 
 mod total_value;
 pub use self::total_value::*;
@@ -65,6 +131,9 @@ pub use self::tour_order::*;
 mod transport;
 pub use self::transport::*;
 
+mod two_echelon;
+pub use self::two_echelon::{JobSatelliteDimension, VehicleSatelliteDimension, create_two_echelon_feature};
+
 mod work_balance;
 pub use self::work_balance::{
     create_activity_balanced_feature, create_distance_balanced_feature, create_duration_balanced_feature,
@@ -0,0 +1,219 @@
+//! A feature to model overtime: work done after a regular end of vehicle's shift is charged by
+//! overtime rates instead of vehicle's regular time costs.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/overtime_test.rs"]
+mod overtime_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+use rosomaxa::utils::UnwrapValue;
+use std::ops::ControlFlow;
+
+custom_dimension!(pub VehicleOvertime typeof Overtime);
+custom_tour_state!(OvertimeCost typeof Cost);
+
+/// Specifies costs of work done after a regular end of the vehicle's shift.
+#[derive(Clone, Debug)]
+pub struct Overtime {
+    /// A regular end of the shift: overtime starts after it.
+    pub start: Timestamp,
+    /// Costs per time unit which replace vehicle's regular time costs. Specified as pairs of offset
+    /// from overtime start and a cost, sorted by offset.
+    pub rates: Vec<(Duration, Cost)>,
+}
+
+impl Overtime {
+    /// Returns overtime duration within given time interval.
+    pub fn duration(&self, start: Timestamp, end: Timestamp) -> Duration {
+        (end - start.max(self.start)).max(0.)
+    }
+
+    /// Returns extra cost of overtime within given time interval when regular time cost per time
+    /// unit is `regular`.
+    pub fn cost(&self, start: Timestamp, end: Timestamp, regular: Cost) -> Cost {
+        if end <= self.start {
+            return Cost::default();
+        }
+
+        self.rates
+            .iter()
+            .enumerate()
+            .map(|(idx, &(offset, rate))| {
+                let rate_start = self.start + offset;
+                let rate_end = self.rates.get(idx + 1).map_or(Timestamp::MAX, |&(next, _)| self.start + next);
+
+                (end.min(rate_end) - start.max(rate_start)).max(0.) * (rate - regular)
+            })
+            .sum()
+    }
+}
+
+/// Creates an overtime feature as an objective which charges driving, waiting and service done
+/// after a regular end of vehicle's shift by overtime rates. Regular time costs are still charged
+/// by transport feature, so the objective adds only the difference.
+pub fn create_overtime_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(OvertimeObjective { transport, activity })
+        .with_state(OvertimeState {})
+        .build()
+}
+
+/// Returns overtime duration of the route from its start till departure from the activity at
+/// given index. Returns `None` if the vehicle has no overtime.
+pub fn get_route_overtime(route: &Route, activity_idx: usize) -> Option<Duration> {
+    let overtime = get_overtime(route)?;
+    let start = route.tour.start()?;
+    let activity = route.tour.get(activity_idx)?;
+
+    Some(overtime.duration(start.schedule.departure, activity.schedule.departure))
+}
+
+/// Returns overtime cost of the route: a difference between overtime rates and vehicle's regular
+/// time costs for the work done after a regular shift end.
+pub fn get_route_overtime_cost(route: &Route) -> Cost {
+    let Some(overtime) = get_overtime(route) else { return Cost::default() };
+
+    route
+        .tour
+        .all_activities()
+        .zip(route.tour.all_activities().skip(1))
+        .map(|(prev, activity)| {
+            get_activity_cost(
+                overtime,
+                route,
+                activity,
+                prev.schedule.departure,
+                activity.schedule.arrival,
+                activity.schedule.departure,
+            )
+        })
+        .sum()
+}
+
+struct OvertimeObjective {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureObjective for OvertimeObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                route_ctx
+                    .state()
+                    .get_overtime_cost()
+                    .copied()
+                    .unwrap_or_else(|| get_route_overtime_cost(route_ctx.route()))
+            })
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+impl OvertimeObjective {
+    /// Estimates overtime cost of the target activity and cost change of the next activities caused
+    /// by a delay which the target activity introduces.
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let Some(overtime) = get_overtime(route) else { return Cost::default() };
+        let (target, prev) = (activity_ctx.target, activity_ctx.prev);
+
+        let arrival = self.get_arrival(route, prev.place.location, prev.schedule.departure, target);
+        let departure = self.activity.estimate_departure(route, target, arrival).unwrap_value();
+        let target_cost = get_activity_cost(overtime, route, target, prev.schedule.departure, arrival, departure);
+
+        let next_cost = route
+            .tour
+            .all_activities()
+            .skip(activity_ctx.index + 1)
+            .try_fold(
+                (target.place.location, departure, prev.schedule.departure, Cost::default()),
+                |(location, departure, old_departure, acc), next| {
+                    let arrival = self.get_arrival(route, location, departure, next);
+                    let next_departure = self.activity.estimate_departure(route, next, arrival).unwrap_value();
+
+                    let delta = get_activity_cost(overtime, route, next, departure, arrival, next_departure)
+                        - get_activity_cost(
+                            overtime,
+                            route,
+                            next,
+                            old_departure,
+                            next.schedule.arrival,
+                            next.schedule.departure,
+                        );
+                    let acc = acc + delta;
+
+                    if next_departure <= next.schedule.departure {
+                        return ControlFlow::Break((location, departure, old_departure, acc));
+                    }
+
+                    ControlFlow::Continue((next.place.location, next_departure, next.schedule.departure, acc))
+                },
+            )
+            .unwrap_value()
+            .3;
+
+        target_cost + next_cost
+    }
+
+    fn get_arrival(&self, route: &Route, from: Location, departure: Timestamp, activity: &Activity) -> Timestamp {
+        departure + self.transport.duration(route, from, activity.place.location, TravelTime::Departure(departure))
+    }
+}
+
+struct OvertimeState {}
+
+impl FeatureState for OvertimeState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(&mut solution_ctx.routes[route_index]);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let cost = get_route_overtime_cost(route_ctx.route());
+        route_ctx.state_mut().set_overtime_cost(cost);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx))
+    }
+}
+
+/// Returns overtime cost of driving to the activity, waiting and serving it.
+fn get_activity_cost(
+    overtime: &Overtime,
+    route: &Route,
+    activity: &Activity,
+    prev_departure: Timestamp,
+    arrival: Timestamp,
+    departure: Timestamp,
+) -> Cost {
+    let costs = &route.actor.vehicle.costs;
+    let service_start = arrival.max(departure - activity.place.duration);
+
+    overtime.cost(prev_departure, arrival, costs.per_driving_time)
+        + overtime.cost(arrival, service_start, costs.per_waiting_time)
+        + overtime.cost(service_start, departure, costs.per_service_time)
+}
+
+fn get_overtime(route: &Route) -> Option<&Overtime> {
+    route.actor.vehicle.dimens.get_vehicle_overtime()
+}
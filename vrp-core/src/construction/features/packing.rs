@@ -0,0 +1,438 @@
+//! A feature to check three-dimensional loading feasibility of job items in vehicle's cargo space.
+//!
+//! Each item is on board only during a part of the tour: a delivery item is loaded at the start of
+//! the tour or at the preceding reload, a pickup item stays till the end of the tour or the next reload,
+//! and items of a multi job are on board between its first and last activities. Items which are not
+//! on board at the same time can take the same space.
+//!
+//! Items are packed using a greedy heuristic: they are placed in the order of decreasing volume at the
+//! lowest, then the front-most possible position. A position is taken from extreme points of already
+//! placed items and an item should be fully supported by stackable items which stay on board while it
+//! is loaded.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/packing_test.rs"]
+mod packing_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::iter::once;
+
+custom_dimension!(pub JobPackingItems typeof Vec<PackingItem>);
+custom_dimension!(pub VehicleCargoSpace typeof CargoSpace);
+custom_tour_state!(PackingPlan typeof Vec<ItemPlacement>);
+
+const EPSILON: Float = 1E-6;
+
+/// Specifies an item to be loaded into vehicle's cargo space.
+#[derive(Clone, Debug)]
+pub struct PackingItem {
+    /// An item id.
+    pub id: String,
+    /// An item length.
+    pub length: Float,
+    /// An item width.
+    pub width: Float,
+    /// An item height.
+    pub height: Float,
+    /// An item weight.
+    pub weight: Float,
+    /// Specifies whether other items can be put on top of this one.
+    pub stackable: bool,
+    /// Specifies whether item can be rotated around vertical axis (length and width are swapped).
+    pub rotatable: bool,
+}
+
+/// Specifies vehicle's cargo space.
+#[derive(Clone, Debug)]
+pub struct CargoSpace {
+    /// A cargo space length.
+    pub length: Float,
+    /// A cargo space width.
+    pub width: Float,
+    /// A cargo space height.
+    pub height: Float,
+    /// A max total weight of items on board. If not set, weight is not limited.
+    pub max_weight: Option<Float>,
+}
+
+/// Specifies placement of job's item inside vehicle's cargo space.
+#[derive(Clone, Debug)]
+pub struct ItemPlacement {
+    /// A job which has the item.
+    pub job: Arc<Single>,
+    /// An index of the item in job's items.
+    pub item_idx: usize,
+    /// Activity indices of the tour between which the item is on board: it is loaded at the first one
+    /// and unloaded at the second one.
+    pub interval: (usize, usize),
+    /// A position of the item's corner closest to the origin of cargo space: length, width and height offsets.
+    pub position: (Float, Float, Float),
+    /// An item size after rotation: length, width and height.
+    pub size: (Float, Float, Float),
+}
+
+/// Specifies a function which returns true if the activity is a reload.
+pub type ReloadActivityFn = Arc<dyn Fn(&Activity) -> bool + Send + Sync>;
+
+/// Creates a feature to check that items of jobs having [`JobPackingItemsDimension`] which are on board
+/// at the same time can be loaded together into cargo space of vehicles with [`VehicleCargoSpaceDimension`].
+/// Job demand is used to decide whether single job's items are delivered or picked up.
+/// This is a hard constraint.
+pub fn create_packing_feature<T: LoadOps>(
+    name: &str,
+    code: ViolationCode,
+    is_reload_activity: ReloadActivityFn,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(PackingConstraint::<T> {
+            code,
+            is_reload_activity: is_reload_activity.clone(),
+            phantom: Default::default(),
+        })
+        .with_state(PackingState::<T> { is_reload_activity, phantom: Default::default() })
+        .build()
+}
+
+/// Returns a loading plan of the route's vehicle. Returns `None` if vehicle has no cargo space,
+/// tour has no items or they cannot be packed.
+pub fn get_loading_plan<T: LoadOps>(
+    route: &Route,
+    is_reload_activity: impl Fn(&Activity) -> bool,
+) -> Option<Vec<ItemPlacement>> {
+    let space = route.actor.vehicle.dimens.get_vehicle_cargo_space()?;
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let items = get_tour_items::<T>(activities.as_slice(), &is_reload_activity);
+
+    if items.is_empty() { None } else { pack_items(space, items.as_slice(), &[]) }
+}
+
+struct PackingConstraint<T: LoadOps> {
+    code: ViolationCode,
+    is_reload_activity: ReloadActivityFn,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureConstraint for PackingConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let space = route_ctx.route().actor.vehicle.dimens.get_vehicle_cargo_space()?;
+
+                // NOTE job's own items should fit into empty cargo space
+                let items = get_items(get_singles(job).into_iter(), (0, 1));
+
+                if items.is_empty() || pack_items(space, items.as_slice(), &[]).is_some() {
+                    None
+                } else {
+                    ConstraintViolation::fail(self.code)
+                }
+            }
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let space = route_ctx.route().actor.vehicle.dimens.get_vehicle_cargo_space()?;
+                let job = activity_ctx.target.retrieve_job()?;
+                let tour = &route_ctx.route().tour;
+
+                let has_items = get_singles(&job).into_iter().any(|single| has_items(single));
+                // NOTE multi job is checked when its last activity is inserted
+                let is_complete = match &job {
+                    Job::Single(_) => true,
+                    Job::Multi(multi) => tour.job_activities(&job).count() + 1 == multi.jobs.len(),
+                };
+                if !has_items || !is_complete {
+                    return None;
+                }
+
+                let index = activity_ctx.index + 1;
+                let mut activities = tour.all_activities().collect::<Vec<_>>();
+                activities.insert(index, activity_ctx.target);
+                let items = get_tour_items::<T>(activities.as_slice(), self.is_reload_activity.as_ref());
+
+                // NOTE items are packed in the same order as for the current tour, so its cached plan is
+                //      reused till the first new item or the item which on board interval is changed
+                let shift = |idx: usize| if idx >= index { idx + 1 } else { idx };
+                let placed = route_ctx
+                    .state()
+                    .get_packing_plan()
+                    .map(|plan| plan.as_slice())
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|placement| ItemPlacement {
+                        interval: (shift(placement.interval.0), shift(placement.interval.1)),
+                        ..placement.clone()
+                    })
+                    .zip(items.iter())
+                    .take_while(|(placement, (single, item_idx, interval))| {
+                        Arc::ptr_eq(single, &placement.job)
+                            && *item_idx == placement.item_idx
+                            && *interval == placement.interval
+                    })
+                    .map(|(placement, _)| placement)
+                    .collect::<Vec<_>>();
+
+                if pack_items(space, items.as_slice(), placed.as_slice()).is_some() {
+                    None
+                } else {
+                    ConstraintViolation::skip(self.code)
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let has_items = |job: &Job| job.dimens().get_job_packing_items().is_some_and(|items| !items.is_empty());
+
+        if has_items(&source) || has_items(&candidate) { Err(self.code) } else { Ok(source) }
+    }
+}
+
+struct PackingState<T: LoadOps> {
+    is_reload_activity: ReloadActivityFn,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureState for PackingState<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        match get_loading_plan::<T>(route_ctx.route(), self.is_reload_activity.as_ref()) {
+            Some(plan) => route_ctx.state_mut().set_packing_plan(plan),
+            None => {
+                route_ctx.state_mut().remove_packing_plan();
+            }
+        }
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+/// An item reference: a job, an index of the item in job's items and on board interval.
+type ItemRef = (Arc<Single>, usize, (usize, usize));
+
+fn get_item(single: &Single, item_idx: usize) -> &PackingItem {
+    &single.dimens.get_job_packing_items().expect("no packing items")[item_idx]
+}
+
+fn get_singles(job: &Job) -> Vec<&Arc<Single>> {
+    match job {
+        Job::Single(single) => vec![single],
+        Job::Multi(multi) => multi.jobs.iter().collect(),
+    }
+}
+
+fn has_items(single: &Single) -> bool {
+    single.dimens.get_job_packing_items().is_some_and(|items| !items.is_empty())
+}
+
+fn get_items<'a>(singles: impl Iterator<Item = &'a Arc<Single>>, interval: (usize, usize)) -> Vec<ItemRef> {
+    singles
+        .flat_map(|single| {
+            let size = single.dimens.get_job_packing_items().map_or(0, |items| items.len());
+            (0..size).map(move |item_idx| (single.clone(), item_idx, interval))
+        })
+        .collect()
+}
+
+/// Returns items of the tour's jobs with their on board intervals in the packing order.
+fn get_tour_items<T: LoadOps>(
+    activities: &[&Activity],
+    is_reload_activity: &dyn Fn(&Activity) -> bool,
+) -> Vec<ItemRef> {
+    // NOTE a reload activity starts a new segment of the tour
+    let mut segments = vec![(0, activities.len()); activities.len()];
+    let mut start = 0;
+    (1..activities.len()).filter(|&idx| is_reload_activity(activities[idx])).chain(once(activities.len())).for_each(
+        |end| {
+            segments[start..end].iter_mut().for_each(|segment| *segment = (start, end));
+            start = end;
+        },
+    );
+
+    let multi_intervals = activities
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, activity)| activity.job.as_ref().and_then(|single| Multi::roots(single)).map(|m| (idx, m)))
+        .fold(HashMap::<Job, (usize, usize)>::new(), |mut acc, (idx, multi)| {
+            acc.entry(Job::Multi(multi)).and_modify(|interval| interval.1 = idx).or_insert((idx, idx));
+            acc
+        });
+
+    let mut items = activities
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, activity)| activity.job.as_ref().filter(|single| has_items(single)).map(|s| (idx, s)))
+        .flat_map(|(idx, single)| {
+            let (start, end) = segments[idx];
+            let interval = match Multi::roots(single).and_then(|multi| multi_intervals.get(&Job::Multi(multi))) {
+                Some(&(first, last)) => (first, last.max(first + 1)),
+                None => {
+                    let demand = single.dimens.get_job_demand::<T>();
+                    let is_pickup = demand.is_some_and(|demand| demand.pickup.0 != T::default());
+                    let is_delivery = demand.is_some_and(|demand| demand.delivery.0 != T::default());
+
+                    match (is_pickup, is_delivery) {
+                        (true, false) => (idx, end),
+                        (false, true) => (start, idx),
+                        _ => (start, end),
+                    }
+                }
+            };
+
+            get_items(once(single), interval)
+        })
+        .collect::<Vec<_>>();
+
+    sort_items(items.as_mut_slice());
+
+    items
+}
+
+/// Sorts items in the packing order. The sort is stable and items which differ only by id or weight
+/// are interchangeable for packing.
+fn sort_items(items: &mut [ItemRef]) {
+    items.sort_by(|(a_single, a_idx, _), (b_single, b_idx, _)| {
+        compare_items(get_item(a_single, *a_idx), get_item(b_single, *b_idx))
+    });
+}
+
+fn compare_items(a: &PackingItem, b: &PackingItem) -> Ordering {
+    let volume = |item: &PackingItem| item.length * item.width * item.height;
+
+    volume(b)
+        .total_cmp(&volume(a))
+        .then_with(|| b.length.total_cmp(&a.length))
+        .then_with(|| b.width.total_cmp(&a.width))
+        .then_with(|| b.height.total_cmp(&a.height))
+        .then_with(|| b.stackable.cmp(&a.stackable))
+        .then_with(|| a.rotatable.cmp(&b.rotatable))
+}
+
+/// Packs items in the given order starting from already known placements of the first items.
+fn pack_items(space: &CargoSpace, items: &[ItemRef], placed: &[ItemPlacement]) -> Option<Vec<ItemPlacement>> {
+    if space.max_weight.is_some_and(|max_weight| get_max_weight(items) > max_weight) {
+        return None;
+    }
+
+    items.iter().skip(placed.len()).try_fold(placed.to_vec(), |mut placed, (single, item_idx, interval)| {
+        let item = get_item(single, *item_idx);
+        let orientations = once((item.length, item.width, item.height))
+            .chain((item.rotatable && item.length != item.width).then_some((item.width, item.length, item.height)));
+        let orientations = orientations.collect::<Vec<_>>();
+
+        let (position, size) = get_extreme_points(space, placed.as_slice()).into_iter().find_map(|position| {
+            orientations
+                .iter()
+                .find(|&&size| can_place(space, placed.as_slice(), *interval, position, size))
+                .map(|&size| (position, size))
+        })?;
+
+        placed.push(ItemPlacement { job: single.clone(), item_idx: *item_idx, interval: *interval, position, size });
+
+        Some(placed)
+    })
+}
+
+/// Returns max total weight of items which are on board at the same time.
+fn get_max_weight(items: &[ItemRef]) -> Float {
+    let mut events = items
+        .iter()
+        .flat_map(|(single, item_idx, (start, end))| {
+            let weight = get_item(single, *item_idx).weight;
+            [(*start, weight), (*end, -weight)]
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE item is unloaded before others are loaded at the same activity
+    events.sort_by(|(a_idx, a_weight), (b_idx, b_weight)| a_idx.cmp(b_idx).then_with(|| a_weight.total_cmp(b_weight)));
+
+    events
+        .into_iter()
+        .fold((0., 0.), |(total, max): (Float, Float), (_, weight)| (total + weight, max.max(total + weight)))
+        .1
+}
+
+fn is_on_board_together(a: (usize, usize), b: (usize, usize)) -> bool {
+    a.0 < b.1 && b.0 < a.1
+}
+
+/// Returns candidate positions sorted by height, then length and width offsets.
+fn get_extreme_points(space: &CargoSpace, placed: &[ItemPlacement]) -> Vec<(Float, Float, Float)> {
+    let mut points = once((0., 0., 0.))
+        .chain(placed.iter().flat_map(|placement| {
+            let ((x, y, z), (length, width, height)) = (placement.position, placement.size);
+            [(x + length, y, z), (x, y + width, z), (x, y, z + height)]
+        }))
+        .filter(|&(x, y, z)| x < space.length && y < space.width && z < space.height)
+        .collect::<Vec<_>>();
+
+    points.sort_by(|a, b| a.2.total_cmp(&b.2).then_with(|| a.0.total_cmp(&b.0)).then_with(|| a.1.total_cmp(&b.1)));
+    points.dedup();
+
+    points
+}
+
+fn can_place(
+    space: &CargoSpace,
+    placed: &[ItemPlacement],
+    interval: (usize, usize),
+    position: (Float, Float, Float),
+    size: (Float, Float, Float),
+) -> bool {
+    let ((x, y, z), (length, width, height)) = (position, size);
+
+    let is_inside = x + length <= space.length + EPSILON
+        && y + width <= space.width + EPSILON
+        && z + height <= space.height + EPSILON;
+    if !is_inside {
+        return false;
+    }
+
+    let has_overlap = placed.iter().filter(|other| is_on_board_together(interval, other.interval)).any(|other| {
+        let ((ox, oy, oz), (ol, ow, oh)) = (other.position, other.size);
+        x + EPSILON < ox + ol
+            && ox + EPSILON < x + length
+            && y + EPSILON < oy + ow
+            && oy + EPSILON < y + width
+            && z + EPSILON < oz + oh
+            && oz + EPSILON < z + height
+    });
+    if has_overlap {
+        return false;
+    }
+
+    if z < EPSILON {
+        return true;
+    }
+
+    // NOTE placed items which are on board together do not overlap, so sum of intersections with their
+    //      top faces is the supported area
+    let supports = placed
+        .iter()
+        .filter(|other| other.interval.0 <= interval.0 && interval.1 <= other.interval.1)
+        .filter(|other| (other.position.2 + other.size.2 - z).abs() < EPSILON)
+        .map(|other| {
+            let ((ox, oy, _), (ol, ow, _)) = (other.position, other.size);
+            let overlap_x = (x + length).min(ox + ol) - x.max(ox);
+            let overlap_y = (y + width).min(oy + ow) - y.max(oy);
+
+            (other, overlap_x.max(0.) * overlap_y.max(0.))
+        })
+        .filter(|(_, area)| *area > EPSILON)
+        .collect::<Vec<_>>();
+
+    let is_stackable = supports.iter().all(|(other, _)| get_item(&other.job, other.item_idx).stackable);
+    let supported_area = supports.iter().map(|(_, area)| *area).sum::<Float>();
+
+    is_stackable && supported_area + EPSILON >= length * width
+}
@@ -0,0 +1,186 @@
+//! A feature to model periodic jobs: a job which has to be visited multiple times within planning horizon
+//! on different days with respect to allowed day patterns and minimum spacing between visits.
+//! The job is either served by all its visits or unassigned.
+
+use super::*;
+use crate::construction::enablers::*;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/periodic_test.rs"]
+mod periodic_test;
+
+custom_dimension!(pub JobVisit typeof JobVisit);
+custom_dimension!(pub VehicleDay typeof usize);
+custom_tour_state!(CurrentVisits typeof HashSet<String>);
+
+/// Specifies a single visit of the periodic job.
+pub struct JobVisit {
+    /// A key which is shared by all visits of the same periodic job.
+    pub key: String,
+    /// Total amount of visits of the job.
+    pub size: usize,
+    /// Allowed day patterns: all visits of the job should happen on days of one of the patterns.
+    /// If not set, any days can be used.
+    pub patterns: Option<Vec<HashSet<usize>>>,
+    /// Minimum amount of days between two consecutive visits. Visits never share the same day.
+    pub min_spacing: usize,
+}
+
+impl JobVisit {
+    /// Creates a new instance of [`JobVisit`].
+    pub fn new(key: String, size: usize, patterns: Option<Vec<Vec<usize>>>, min_spacing: usize) -> Self {
+        let patterns = patterns.map(|patterns| patterns.into_iter().map(|days| days.into_iter().collect()).collect());
+
+        Self { key, size, patterns, min_spacing }
+    }
+
+    /// Checks whether given days are allowed for visits of the job.
+    pub fn is_allowed_days(&self, days: &[usize]) -> bool {
+        let mut sorted = days.to_vec();
+        sorted.sort_unstable();
+
+        let has_spacing = sorted.windows(2).all(|pair| pair[1] - pair[0] >= self.min_spacing.max(1));
+        let has_pattern = self
+            .patterns
+            .as_ref()
+            .is_none_or(|patterns| patterns.iter().any(|pattern| sorted.iter().all(|day| pattern.contains(day))));
+
+        has_spacing && has_pattern
+    }
+}
+
+/// Creates a periodic feature as a hard constraint. Each route should have a day specified on the
+/// vehicle level using [`VehicleDay`] dimension, visits of the same job cannot share the same route.
+/// Visits of a job which is not served completely are moved to unassigned.
+pub fn create_periodic_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(PeriodicConstraint { code })
+        .with_state(PeriodicState { code })
+        .build()
+}
+
+struct PeriodicConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for PeriodicConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => job.dimens().get_job_visit().and_then(|visit| {
+                let actor = &route_ctx.route().actor;
+                let Some(day) = actor.vehicle.dimens.get_vehicle_day().copied() else {
+                    return ConstraintViolation::fail(self.code);
+                };
+
+                let other_days = solution_ctx
+                    .routes
+                    .iter()
+                    .filter(|rc| rc.state().get_current_visits().is_some_and(|visits| visits.contains(&visit.key)))
+                    .map(|rc| {
+                        if rc.route().actor == *actor {
+                            None
+                        } else {
+                            rc.route().actor.vehicle.dimens.get_vehicle_day().copied()
+                        }
+                    })
+                    .collect::<Option<Vec<_>>>();
+
+                let Some(mut days) = other_days else {
+                    return ConstraintViolation::fail(self.code);
+                };
+                days.push(day);
+
+                if visit.is_allowed_days(days.as_slice()) { None } else { ConstraintViolation::fail(self.code) }
+            }),
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_visit(), candidate.dimens().get_job_visit()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct PeriodicState {
+    code: ViolationCode,
+}
+
+impl FeatureState for PeriodicState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if job.dimens().get_job_visit().is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        // NOTE route state is cleared when route is modified, so visits have to be restored here
+        let visits = get_visits(route_ctx);
+        route_ctx.state_mut().set_current_visits(visits);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+
+        self.remove_incomplete_jobs(solution_ctx);
+    }
+}
+
+impl PeriodicState {
+    /// Removes visits of periodic jobs which are not served completely.
+    fn remove_incomplete_jobs(&self, solution_ctx: &mut SolutionContext) {
+        let mut visits = HashMap::<String, (usize, Vec<Job>)>::default();
+        solution_ctx.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()).for_each(|job| {
+            if let Some(visit) = job.dimens().get_job_visit() {
+                visits.entry(visit.key.clone()).or_insert_with(|| (visit.size, Vec::default())).1.push(job.clone());
+            }
+        });
+
+        // NOTE missing visits might be still inserted when insertion process is not finished
+        let required = solution_ctx
+            .required
+            .iter()
+            .filter_map(|job| job.dimens().get_job_visit())
+            .map(|visit| visit.key.clone())
+            .collect::<HashSet<_>>();
+
+        let jobs = visits
+            .iter()
+            .filter(|(key, (size, jobs))| jobs.len() < *size && !required.contains(key.as_str()))
+            .flat_map(|(_, (_, jobs))| jobs.iter())
+            .filter(|job| !solution_ctx.locked.contains(*job))
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        unassign_jobs(solution_ctx, jobs, self.code);
+
+        // NOTE a visit might be left without a reason when insertion of its sibling fails
+        solution_ctx
+            .unassigned
+            .iter_mut()
+            .filter(|(job, info)| {
+                let is_unknown = match info {
+                    UnassignmentInfo::Unknown => true,
+                    UnassignmentInfo::Simple(code) => code.is_unknown(),
+                    UnassignmentInfo::Detailed(_) => false,
+                };
+
+                is_unknown && job.dimens().get_job_visit().is_some_and(|visit| !required.contains(visit.key.as_str()))
+            })
+            .for_each(|(_, info)| *info = UnassignmentInfo::Simple(self.code));
+    }
+}
+
+fn get_visits(route_ctx: &RouteContext) -> HashSet<String> {
+    route_ctx
+        .route()
+        .tour
+        .jobs()
+        .filter_map(|job| job.dimens().get_job_visit())
+        .map(|visit| visit.key.clone())
+        .collect()
+}
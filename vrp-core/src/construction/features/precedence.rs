@@ -0,0 +1,255 @@
+//! A feature to model precedence between jobs: a job should start not earlier than the given time lag
+//! after the end of another job, e.g. installation after delivery or concrete pour after formwork.
+//!
+//! Unlike relations, precedence does not bind jobs to a specific vehicle: jobs can be served by the same
+//! or different vehicles. A vehicle which arrives too early waits until the predecessor is served. A job
+//! is kept in the solution only if all its predecessors are served.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/precedence_test.rs"]
+mod precedence_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::common::{Duration, Timestamp};
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::Activity;
+use rosomaxa::utils::UnwrapValue;
+use std::collections::HashSet;
+
+custom_dimension!(pub JobPrecedence typeof JobPrecedence);
+custom_tour_state!(CurrentPrecedences typeof HashSet<String>);
+
+/// Specifies precedence relations of the job.
+pub struct JobPrecedence {
+    /// A unique key of the job.
+    pub key: String,
+    /// Keys of the jobs which should be served before with minimum time lag between the end of
+    /// the predecessor and the start of this job.
+    pub predecessors: Vec<(String, Duration)>,
+    /// Keys of the jobs which should be served after with minimum time lag between the end of
+    /// this job and the start of the successor.
+    pub successors: Vec<(String, Duration)>,
+}
+
+/// Creates a feature to keep precedence between jobs having [`JobPrecedenceDimension`] across different
+/// routes. This is a hard constraint.
+pub fn create_precedence_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(PrecedenceConstraint { code, transport: transport.clone(), activity: activity.clone() })
+        .with_state(PrecedenceState { code, transport, activity })
+        .build()
+}
+
+struct PrecedenceConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureConstraint for PrecedenceConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                self.evaluate_activity(solution_ctx, route_ctx, activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if source.dimens().get_job_precedence().is_some() || candidate.dimens().get_job_precedence().is_some() {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+impl PrecedenceConstraint {
+    fn evaluate_activity(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let precedence = get_precedence(target)?;
+        let route = route_ctx.route();
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        let mut start = arrival.max(target.place.time.start);
+        for (key, lag) in precedence.predecessors.iter() {
+            if let Some(visit) = find_visit(solution_ctx, key) {
+                // NOTE predecessor cannot be served later in the same route
+                if *visit.actor == route.actor && visit.activity_idx > activity_ctx.index {
+                    return ConstraintViolation::skip(self.code);
+                }
+
+                start = start.max(visit.departure + *lag);
+            }
+        }
+
+        if start > target.place.time.end {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let departure = self.activity.estimate_departure(route, target, start).unwrap_value();
+
+        if let Some(next) = next {
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+            let latest_arrival =
+                route_ctx.state().get_latest_arrival_at(activity_ctx.index + 1).copied().unwrap_or(next.place.time.end);
+
+            if arrival > latest_arrival {
+                return ConstraintViolation::skip(self.code);
+            }
+        }
+
+        let is_successor_violated = precedence.successors.iter().any(|(key, lag)| {
+            find_visit(solution_ctx, key).is_some_and(|visit| {
+                let is_served_earlier = *visit.actor == route.actor && visit.activity_idx <= activity_ctx.index;
+
+                is_served_earlier || departure + *lag > visit.latest_arrival
+            })
+        });
+
+        if is_successor_violated { ConstraintViolation::skip(self.code) } else { None }
+    }
+}
+
+struct PrecedenceState {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureState for PrecedenceState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+
+        // NOTE any insertion into the route with precedence jobs might shift their successors in other routes
+        if route_ctx.state().get_current_precedences().is_some_and(|keys| !keys.is_empty()) {
+            self.update_schedules(solution_ctx);
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let keys = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter_map(|job| job.dimens().get_job_precedence())
+            .map(|precedence| precedence.key.clone())
+            .collect::<HashSet<_>>();
+
+        route_ctx.state_mut().set_current_precedences(keys);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+
+        self.update_schedules(solution_ctx);
+
+        // NOTE schedules are outdated after removal, so violated jobs are checked on the next run
+        let jobs = get_late_jobs_after_waiting(solution_ctx, &|activity| get_precedence(activity).is_some());
+        let jobs = if jobs.is_empty() { self.get_violated_jobs(solution_ctx) } else { jobs };
+
+        unassign_jobs(solution_ctx, jobs, self.code);
+    }
+}
+
+impl PrecedenceState {
+    fn update_schedules(&self, solution_ctx: &mut SolutionContext) {
+        update_precedence_schedules(solution_ctx, self.activity.as_ref(), self.transport.as_ref(), |activity| {
+            get_precedence(activity).map(|precedence| {
+                let predecessors = precedence.predecessors.iter().map(|(key, lag)| (key.clone(), *lag)).collect();
+                (precedence.key.clone(), predecessors)
+            })
+        });
+    }
+
+    /// Returns jobs which predecessors are not served or served too late.
+    fn get_violated_jobs(&self, solution_ctx: &SolutionContext) -> HashSet<Job> {
+        // NOTE predecessors might be still inserted when insertion process is not finished
+        let required = solution_ctx
+            .required
+            .iter()
+            .filter_map(|job| job.dimens().get_job_precedence())
+            .map(|precedence| precedence.key.as_str())
+            .collect::<HashSet<_>>();
+
+        solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .filter_map(|activity| get_precedence(activity).map(|precedence| (activity, precedence)))
+            .filter(|(activity, precedence)| {
+                let start = activity.schedule.arrival.max(activity.place.time.start);
+
+                precedence.predecessors.iter().any(|(key, lag)| match find_visit(solution_ctx, key) {
+                    Some(visit) => start < visit.departure + *lag,
+                    None => !required.contains(key.as_str()),
+                })
+            })
+            .filter_map(|(activity, _)| activity.retrieve_job())
+            .filter(|job| !solution_ctx.locked.contains(job))
+            .collect()
+    }
+}
+
+/// Keeps information about already served job with precedence.
+struct PrecedenceVisit<'a> {
+    actor: &'a Arc<Actor>,
+    activity_idx: usize,
+    departure: Timestamp,
+    latest_arrival: Timestamp,
+}
+
+fn get_precedence(activity: &Activity) -> Option<&JobPrecedence> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_precedence())
+}
+
+fn find_visit<'a>(solution_ctx: &'a SolutionContext, key: &str) -> Option<PrecedenceVisit<'a>> {
+    solution_ctx
+        .routes
+        .iter()
+        .filter(|route_ctx| route_ctx.state().get_current_precedences().is_some_and(|keys| keys.contains(key)))
+        .find_map(|route_ctx| {
+            let route = route_ctx.route();
+
+            route.tour.all_activities().enumerate().find_map(|(activity_idx, activity)| {
+                get_precedence(activity).filter(|precedence| precedence.key == key).map(|_| PrecedenceVisit {
+                    actor: &route.actor,
+                    activity_idx,
+                    departure: activity.schedule.departure,
+                    latest_arrival: route_ctx
+                        .state()
+                        .get_latest_arrival_at(activity_idx)
+                        .copied()
+                        .unwrap_or(activity.place.time.end),
+                })
+            })
+        })
+}
@@ -1,5 +1,13 @@
 //! An experimental feature which provides a way to insert recharge stations in the tour to recharge
 //! (refuel) vehicle.
+//!
+//! A limit between two recharges is either a maximum travelled distance or a vehicle's battery. In the
+//! latter case, energy consumption depends on travelled distance and, optionally, on carried load.
+//! A vehicle charges just enough energy on the station to reach the next one (or the tour end)
+//! keeping the reserve, and charging duration is derived from the station's charging curve.
+//!
+//! Stations can be shared across the fleet and have a limited number of chargers. In this case,
+//! a vehicle waits at the station until one of its chargers becomes free.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/recharge_test.rs"]
@@ -7,19 +15,181 @@ mod recharge_test;
 
 use super::*;
 use crate::construction::enablers::*;
-use crate::models::solution::Route;
+use crate::construction::features::capacity::CurrentCapacityActivityState;
+use crate::construction::features::reloads::{
+    SharedResourceUsage, SharedResourceVisit, get_shared_resource_start, update_shared_resource_usage,
+};
+use crate::models::solution::{Activity, Route};
 use std::collections::HashSet;
+use std::iter::once;
 use std::sync::Arc;
 
+custom_dimension!(pub VehicleBattery typeof Battery);
+custom_dimension!(pub JobChargingCurve typeof ChargingCurve);
+
+/// Specifies vehicle's battery and its energy consumption model.
+#[derive(Clone, Debug)]
+pub struct Battery {
+    /// Battery capacity.
+    pub capacity: Float,
+    /// A state of charge at the start of the tour.
+    pub initial: Float,
+    /// A minimum state of charge which has to be kept all the time.
+    pub reserve: Float,
+    /// Energy consumed per distance unit by empty vehicle.
+    pub empty: Float,
+    /// Energy consumed per distance unit by fully loaded vehicle.
+    pub full: Float,
+}
+
+impl Battery {
+    /// Returns energy consumed on the leg. Load ratio is a share of vehicle's capacity in `[0, 1]`.
+    pub fn get_energy(&self, distance: Distance, load_ratio: Float) -> Float {
+        let load_ratio = if load_ratio.is_finite() { load_ratio.clamp(0., 1.) } else { 0. };
+
+        distance * (self.empty + (self.full - self.empty) * load_ratio)
+    }
+
+    /// Returns states of charge after departure from each activity of the tour given energy consumed
+    /// on the leg to each activity (the first value is ignored).
+    pub fn get_states_of_charge(&self, energies: &[Float], is_recharge: impl Fn(usize) -> bool) -> Vec<Float> {
+        // NOTE energy needed after departure from activity to reach the next recharge or tour end
+        let mut needed = vec![Float::default(); energies.len()];
+        (1..energies.len()).rev().for_each(|idx| {
+            needed[idx - 1] = energies[idx] + if is_recharge(idx) { Float::default() } else { needed[idx] };
+        });
+
+        energies
+            .iter()
+            .enumerate()
+            .scan(self.initial, |soc, (idx, &energy)| {
+                if idx > 0 {
+                    *soc -= energy;
+                    if is_recharge(idx) {
+                        *soc = soc.max(self.get_charge_target(needed[idx]));
+                    }
+                }
+
+                Some(*soc)
+            })
+            .collect()
+    }
+
+    /// Returns energy which can be consumed within route interval started at tour start or at recharge.
+    fn get_usable_energy(&self, is_tour_start: bool) -> Float {
+        (if is_tour_start { self.initial } else { self.capacity }) - self.reserve
+    }
+
+    fn get_charge_target(&self, needed: Float) -> Float {
+        (needed + self.reserve).min(self.capacity)
+    }
+
+    /// Returns states of charge on arrival to and departure from the start of each route interval
+    /// given energy consumed within intervals.
+    fn get_interval_charges(&self, consumptions: &[Float]) -> Vec<(Float, Float)> {
+        consumptions
+            .iter()
+            .scan(None, |prev: &mut Option<(Float, Float)>, &consumption| {
+                let charge = match *prev {
+                    Some((departure, prev_consumption)) => {
+                        let arrival = departure - prev_consumption;
+                        (arrival, arrival.max(self.get_charge_target(consumption)))
+                    }
+                    None => (self.initial, self.initial),
+                };
+                *prev = Some((charge.1, consumption));
+
+                Some(charge)
+            })
+            .collect()
+    }
+}
+
+/// Specifies a piecewise-linear charging curve: a charging rate (energy per time unit) as a function
+/// of battery's state of charge expressed as a share of its capacity.
+#[derive(Clone, Debug)]
+pub struct ChargingCurve {
+    points: Vec<(Float, Float)>,
+}
+
+impl ChargingCurve {
+    /// Creates a new instance of `ChargingCurve` from (state of charge share, charging rate) points.
+    pub fn new(mut points: Vec<(Float, Float)>) -> Self {
+        points.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        Self { points }
+    }
+
+    /// Returns time needed to charge a battery of given capacity from one state of charge to another.
+    pub fn get_duration(&self, capacity: Float, from: Float, to: Float) -> Duration {
+        let (Some(&first), Some(&last)) = (self.points.first(), self.points.last()) else {
+            return Duration::default();
+        };
+
+        if capacity <= 0. || to <= from {
+            return Duration::default();
+        }
+
+        let (from, to) = ((from / capacity).clamp(0., 1.), (to / capacity).clamp(0., 1.));
+        let points = once((first.0.min(0.), first.1))
+            .chain(self.points.iter().copied())
+            .chain(once((last.0.max(1.), last.1)))
+            .collect::<Vec<_>>();
+
+        points
+            .windows(2)
+            .map(|segment| {
+                let ((x0, r0), (x1, r1)) = (segment[0], segment[1]);
+                let (a, b) = (from.max(x0), to.min(x1));
+
+                if a >= b || r0 <= 0. || r1 <= 0. {
+                    return Duration::default();
+                }
+
+                // NOTE rate changes linearly within the segment, so time is an integral of 1 / rate
+                let slope = (r1 - r0) / (x1 - x0);
+                let rate = |x: Float| r0 + slope * (x - x0);
+
+                if slope.abs() < 1E-9 { capacity * (b - a) / r0 } else { capacity * (rate(b) / rate(a)).ln() / slope }
+            })
+            .sum()
+    }
+}
+
+/// Returns states of charge after departure from each activity of the route if vehicle has a battery.
+/// A load ratio function returns a share of vehicle's capacity occupied after departure from the
+/// activity with given index.
+pub fn get_route_states_of_charge(
+    route: &Route,
+    transport: &dyn TransportCost,
+    is_recharge_fn: impl Fn(&Activity) -> bool,
+    load_ratio_fn: impl Fn(usize) -> Float,
+) -> Option<Vec<Float>> {
+    let battery = route.actor.vehicle.dimens.get_vehicle_battery()?;
+
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let energies = once(Float::default())
+        .chain(activities.windows(2).enumerate().map(|(idx, leg)| {
+            battery.get_energy(get_leg_distance(route, transport, leg[0], leg[1]), load_ratio_fn(idx))
+        }))
+        .collect::<Vec<_>>();
+
+    Some(battery.get_states_of_charge(&energies, |idx| is_recharge_fn(activities[idx])))
+}
+
 /// Provides a way to build the recharge/refuel feature.
 #[allow(clippy::type_complexity)]
 pub struct RechargeFeatureBuilder {
     name: String,
     violation_code: Option<ViolationCode>,
     transport: Option<Arc<dyn TransportCost>>,
+    activity: Option<Arc<dyn ActivityCost>>,
     belongs_to_route_fn: Option<Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>>,
     is_recharge_single_fn: Option<RechargeSingleFn>,
     distance_limit_fn: Option<RechargeDistanceLimitFn>,
+    load: Option<RechargeLoad>,
+    chargers_code: Option<ViolationCode>,
+    chargers_fn: Option<RechargeChargersFn>,
 }
 
 impl RechargeFeatureBuilder {
@@ -32,6 +202,10 @@ impl RechargeFeatureBuilder {
             belongs_to_route_fn: None,
             distance_limit_fn: None,
             transport: None,
+            activity: None,
+            load: None,
+            chargers_code: None,
+            chargers_fn: None,
         }
     }
 
@@ -41,12 +215,25 @@ impl RechargeFeatureBuilder {
         self
     }
 
+    /// Sets constraint violation code which is used to report back the reason of job's unassignment
+    /// when shared station has no free chargers.
+    pub fn set_chargers_code(mut self, code: ViolationCode) -> Self {
+        self.chargers_code = Some(code);
+        self
+    }
+
     /// Sets transport costs to estimate distance.
     pub fn set_transport(mut self, transport: Arc<dyn TransportCost>) -> Self {
         self.transport = Some(transport);
         self
     }
 
+    /// Sets activity costs to update schedule when charging durations change.
+    pub fn set_activity(mut self, activity: Arc<dyn ActivityCost>) -> Self {
+        self.activity = Some(activity);
+        self
+    }
+
     /// Sets a function which specifies whether a given single job can be considered as a recharge job.
     pub fn set_is_recharge_single<F>(mut self, func: F) -> Self
     where
@@ -67,7 +254,7 @@ impl RechargeFeatureBuilder {
     }
 
     /// Specifies a distance limit function for recharge. It should return a fixed value for the same
-    /// actor all the time.
+    /// actor all the time. It is not used for vehicles with battery.
     pub fn set_distance_limit<F>(mut self, func: F) -> Self
     where
         F: Fn(&Actor) -> Option<Distance> + Send + Sync + 'static,
@@ -76,6 +263,46 @@ impl RechargeFeatureBuilder {
         self
     }
 
+    /// Makes energy consumption of vehicles with battery dependent on carried load. Loads are taken
+    /// from the states maintained by capacity feature.
+    pub fn set_load_type<T: LoadOps>(mut self) -> Self {
+        fn get_ratio<T: LoadOps>(route: &Route, load: &T) -> Float {
+            route
+                .actor
+                .vehicle
+                .dimens
+                .get_vehicle_capacity::<T>()
+                .map(|capacity| load.ratio(capacity))
+                .filter(|ratio| ratio.is_finite())
+                .unwrap_or_default()
+        }
+
+        self.load = Some(RechargeLoad {
+            ratio_fn: Arc::new(|route_ctx, activity_idx| {
+                route_ctx
+                    .state()
+                    .get_current_capacity_at::<T>(activity_idx)
+                    .map_or(0., |load| get_ratio(route_ctx.route(), load))
+            }),
+            demand_fn: Arc::new(|route, single| {
+                single.dimens.get_job_demand::<T>().map_or((0., 0.), |demand| {
+                    (get_ratio(route, &demand.delivery.0), get_ratio(route, &demand.pickup.0))
+                })
+            }),
+        });
+        self
+    }
+
+    /// Sets a function which returns a number of chargers and an id of the station shared across
+    /// the fleet for given recharge activity. When all chargers are busy, vehicle waits for a free one.
+    pub fn set_shared_chargers<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Activity) -> Option<(usize, SharedResourceId)> + Send + Sync + 'static,
+    {
+        self.chargers_fn = Some(Arc::new(func));
+        self
+    }
+
     /// Builds the recharge feature if all dependencies are set.
     pub fn build(&mut self) -> GenericResult<Feature> {
         let is_marker_single_fn =
@@ -84,95 +311,157 @@ impl RechargeFeatureBuilder {
             self.belongs_to_route_fn.take().ok_or_else(|| GenericError::from("belongs_to_route must be set"))?;
 
         let transport = self.transport.take().ok_or_else(|| GenericError::from("transport must be set"))?;
+        let activity = self.activity.take().ok_or_else(|| GenericError::from("activity must be set"))?;
         let distance_limit_fn =
             self.distance_limit_fn.take().ok_or_else(|| GenericError::from("distance_limit must be set"))?;
 
         let code = self.violation_code.unwrap_or_default();
+        let model = Arc::new(RechargeModel { transport, distance_limit_fn, load: self.load.take() });
+
+        let multi_trip = Arc::new(RechargeableMultiTrip {
+            route_intervals: RouteIntervals::Multiple {
+                is_marker_single_fn: is_marker_single_fn.clone(),
+                is_new_interval_needed_fn: Arc::new({
+                    let model = model.clone();
+                    move |route_ctx| {
+                        let start_idx = route_ctx
+                            .state()
+                            .get_recharge_intervals()
+                            .and_then(|intervals| intervals.last())
+                            .map_or(0, |(start_idx, _)| *start_idx);
+
+                        route_ctx
+                            .route()
+                            .tour
+                            .end_idx()
+                            .map(|end_idx| {
+                                let current = get_counter(route_ctx, end_idx);
+
+                                model
+                                    .get_limit(route_ctx.route().actor.as_ref(), start_idx)
+                                    .is_some_and(|threshold| current > threshold)
+                            })
+                            .unwrap_or(false)
+                    }
+                }),
+                is_obsolete_interval_fn: Arc::new({
+                    let model = model.clone();
+                    move |route_ctx, left, right| {
+                        let route = route_ctx.route();
+                        let end_idx = get_end_idx(route_ctx, right.end);
+
+                        let leg = route.tour.get(left.end).zip(route.tour.get(right.start + 1)).map_or(
+                            Float::default(),
+                            |(from, to)| {
+                                let distance = model.get_distance(route, from, to);
+                                model.get_consumption(route, distance, model.get_load_ratio(route_ctx, left.end))
+                            },
+                        );
+
+                        let new_consumption = get_counter(route_ctx, left.end) + get_counter(route_ctx, end_idx)
+                            - get_counter(route_ctx, right.start + 1)
+                            + leg;
 
-        create_multi_trip_feature(
-            self.name.as_str(),
+                        model
+                            .get_limit(route.actor.as_ref(), left.start)
+                            .is_some_and(|threshold| new_consumption <= threshold)
+                    }
+                }),
+                is_assignable_fn,
+                intervals_state: Arc::new(RechargeIntervalsState),
+            },
+            model: model.clone(),
+            activity: activity.clone(),
             code,
-            MarkerInsertionPolicy::Any,
-            Arc::new(RechargeableMultiTrip {
-                route_intervals: RouteIntervals::Multiple {
-                    is_marker_single_fn: is_marker_single_fn.clone(),
-                    is_new_interval_needed_fn: Arc::new({
-                        let distance_limit_fn = distance_limit_fn.clone();
-                        move |route_ctx| {
-                            route_ctx
-                                .route()
-                                .tour
-                                .end_idx()
-                                .map(|end_idx| {
-                                    let current: Distance = route_ctx
-                                        .state()
-                                        .get_recharge_distance_at(end_idx)
-                                        .copied()
-                                        .unwrap_or_default();
-
-                                    (distance_limit_fn)(route_ctx.route().actor.as_ref())
-                                        .is_some_and(|threshold| current > threshold)
-                                })
-                                .unwrap_or(false)
-                        }
-                    }),
-                    is_obsolete_interval_fn: Arc::new({
-                        let distance_limit_fn = distance_limit_fn.clone();
-                        let transport = transport.clone();
-                        let get_counter = move |route_ctx: &RouteContext, activity_idx: usize| {
-                            route_ctx
-                                .state()
-                                .get_recharge_distance_at(activity_idx)
-                                .copied()
-                                .unwrap_or(Distance::default())
-                        };
-                        let get_distance = move |route: &Route, from_idx: usize, to_idx: usize| {
-                            route.tour.get(from_idx).zip(route.tour.get(to_idx)).map_or(
-                                Distance::default(),
-                                |(from, to)| {
-                                    transport.distance(
-                                        route,
-                                        from.place.location,
-                                        to.place.location,
-                                        TravelTime::Departure(from.schedule.departure),
-                                    )
-                                },
-                            )
-                        };
-                        move |route_ctx, left, right| {
-                            let end_idx = get_end_idx(route_ctx, right.end);
-
-                            let new_distance = get_counter(route_ctx, left.end) + get_counter(route_ctx, end_idx)
-                                - get_counter(route_ctx, right.start + 1)
-                                + get_distance(route_ctx.route(), left.end, right.start + 1);
-
-                            (distance_limit_fn)(route_ctx.route().actor.as_ref())
-                                .is_some_and(|threshold| new_distance <= threshold)
-                        }
-                    }),
-                    is_assignable_fn,
-                    intervals_state: Arc::new(RechargeIntervalsState),
-                },
-                transport,
+            recharge_single_fn: is_marker_single_fn.clone(),
+        });
+
+        let recharge =
+            create_multi_trip_feature(self.name.as_str(), code, MarkerInsertionPolicy::Any, multi_trip.clone())?;
+
+        let Some(chargers_fn) = self.chargers_fn.take() else { return Ok(recharge) };
+
+        let code = self.chargers_code.unwrap_or_default();
+        let transport = model.transport.clone();
+
+        let shared_chargers = FeatureBuilder::default()
+            .with_name(self.name.as_str())
+            .with_constraint(SharedChargersConstraint {
                 code,
-                distance_limit_fn,
-                recharge_single_fn: is_marker_single_fn.clone(),
-            }),
-        )
+                transport: transport.clone(),
+                chargers_fn: chargers_fn.clone(),
+                multi_trip,
+            })
+            .with_state(SharedChargersState {
+                code,
+                transport,
+                activity,
+                chargers_fn,
+                recharge_single_fn: is_marker_single_fn,
+            })
+            .build()?;
+
+        FeatureCombinator::default().use_name(self.name.as_str()).add_features(&[recharge, shared_chargers]).combine()
     }
 }
 
 type RechargeDistanceLimitFn = Arc<dyn Fn(&Actor) -> Option<Distance> + Send + Sync>;
 type RechargeSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+type RechargeChargersFn = Arc<dyn Fn(&Activity) -> Option<(usize, SharedResourceId)> + Send + Sync>;
 
 custom_route_intervals_state!(RechargeIntervals);
-custom_activity_state!(RechargeDistance typeof Distance);
+custom_activity_state!(RechargeConsumption typeof Float);
+custom_activity_state!(TravelledDistance typeof Distance);
+
+/// Provides a way to get loads carried by vehicle.
+#[allow(clippy::type_complexity)]
+struct RechargeLoad {
+    /// Returns a share of vehicle's capacity occupied after departure from the activity.
+    ratio_fn: Arc<dyn Fn(&RouteContext, usize) -> Float + Send + Sync>,
+    /// Returns shares of vehicle's capacity taken by static delivery and pickup of the job.
+    demand_fn: Arc<dyn Fn(&Route, &Single) -> (Float, Float) + Send + Sync>,
+}
+
+/// Specifies what is consumed between recharges: either distance or battery energy.
+struct RechargeModel {
+    transport: Arc<dyn TransportCost>,
+    distance_limit_fn: RechargeDistanceLimitFn,
+    load: Option<RechargeLoad>,
+}
+
+impl RechargeModel {
+    /// Returns a consumption limit of the route interval which starts at the given activity.
+    fn get_limit(&self, actor: &Actor, start_idx: usize) -> Option<Float> {
+        match actor.vehicle.dimens.get_vehicle_battery() {
+            Some(battery) => Some(battery.get_usable_energy(start_idx == 0)),
+            None => (self.distance_limit_fn)(actor),
+        }
+    }
+
+    /// Returns consumption on the leg with given distance and load ratio.
+    fn get_consumption(&self, route: &Route, distance: Distance, load_ratio: Float) -> Float {
+        route
+            .actor
+            .vehicle
+            .dimens
+            .get_vehicle_battery()
+            .map_or(distance, |battery| battery.get_energy(distance, load_ratio))
+    }
+
+    fn get_load_ratio(&self, route_ctx: &RouteContext, activity_idx: usize) -> Float {
+        self.load.as_ref().map_or(0., |load| (load.ratio_fn)(route_ctx, activity_idx))
+    }
+
+    fn get_distance(&self, route: &Route, from: &Activity, to: &Activity) -> Distance {
+        get_leg_distance(route, self.transport.as_ref(), from, to)
+    }
+}
 
 struct RechargeableMultiTrip {
     route_intervals: RouteIntervals,
-    transport: Arc<dyn TransportCost>,
+    model: Arc<RechargeModel>,
+    activity: Arc<dyn ActivityCost>,
     code: ViolationCode,
-    distance_limit_fn: RechargeDistanceLimitFn,
     recharge_single_fn: RechargeSingleFn,
 }
 
@@ -186,45 +475,53 @@ impl MultiTrip for RechargeableMultiTrip {
     }
 
     fn recalculate_states(&self, route_ctx: &mut RouteContext) {
-        if (self.distance_limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
+        if self.model.get_limit(route_ctx.route().actor.as_ref(), 0).is_none() {
             return;
         }
 
-        let last_idx = route_ctx.route().tour.total() - 1;
-        let marker_intervals = self.route_intervals.resolve_marker_intervals(route_ctx).collect::<Vec<_>>();
-        let mut distance_counters = vec![Distance::default(); route_ctx.route().tour.total()];
+        let route = route_ctx.route();
+        let last_idx = route.tour.total() - 1;
 
-        marker_intervals.into_iter().for_each(|(start_idx, end_idx)| {
-            let route = route_ctx.route();
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+        let distances = once(Distance::default())
+            .chain(activities.windows(2).map(|leg| self.model.get_distance(route, leg[0], leg[1])))
+            .collect::<Vec<_>>();
+        let consumptions = distances
+            .iter()
+            .enumerate()
+            .map(|(idx, &distance)| {
+                if idx == 0 {
+                    Float::default()
+                } else {
+                    self.model.get_consumption(route, distance, self.model.get_load_ratio(route_ctx, idx - 1))
+                }
+            })
+            .collect::<Vec<_>>();
 
+        let mut counters = vec![Float::default(); route.tour.total()];
+        self.route_intervals.resolve_marker_intervals(route_ctx).for_each(|(start_idx, end_idx)| {
             let end_idx = if end_idx != last_idx { end_idx + 1 } else { end_idx };
 
-            let _ = route
-                .tour
-                .activities_slice(start_idx, end_idx)
-                .windows(2)
-                .enumerate()
-                .filter_map(|(leg_idx, leg)| match leg {
-                    [prev, next] => Some((start_idx + leg_idx, prev, next)),
-                    _ => None,
-                })
-                .fold(Distance::default(), |acc, (activity_idx, prev, next)| {
-                    let distance = self.transport.distance(
-                        route,
-                        prev.place.location,
-                        next.place.location,
-                        TravelTime::Departure(prev.schedule.departure),
-                    );
-                    let counter = acc + distance;
-                    let next_idx = activity_idx + 1;
-
-                    distance_counters[next_idx] = counter;
+            (start_idx + 1..=end_idx).fold(Float::default(), |acc, activity_idx| {
+                let counter = acc + consumptions[activity_idx];
+                counters[activity_idx] = counter;
 
-                    counter
-                });
+                counter
+            });
         });
 
-        route_ctx.state_mut().set_recharge_distance_states(distance_counters);
+        let travelled = distances
+            .iter()
+            .scan(Distance::default(), |total, distance| {
+                *total += distance;
+                Some(*total)
+            })
+            .collect();
+
+        route_ctx.state_mut().set_recharge_consumption_states(counters);
+        route_ctx.state_mut().set_travelled_distance_states(travelled);
+
+        self.update_charging_durations(route_ctx, &consumptions);
     }
 
     fn try_recover(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], _: &[Job]) -> bool {
@@ -287,55 +584,566 @@ impl RechargeableMultiTrip {
         route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ConstraintViolation> {
-        let threshold = (self.distance_limit_fn)(route_ctx.route().actor.as_ref())?;
+        let actor = route_ctx.route().actor.as_ref();
+        self.model.get_limit(actor, 0)?;
 
-        let interval_distance = self
-            .route_intervals
-            .resolve_marker_intervals(route_ctx)
-            .find(|(_, end_idx)| activity_ctx.index <= *end_idx)
-            .map(|(_, end_idx)| get_end_idx(route_ctx, end_idx))
-            .map(|end_idx| self.get_distance(route_ctx, end_idx))
-            .expect("invalid markers state");
+        let (intervals, interval_idx, consumptions) = self.get_interval_consumptions(route_ctx, activity_ctx);
 
         let is_new_recharge = activity_ctx.target.job.as_ref().is_some_and(|single| (self.recharge_single_fn)(single));
 
-        let is_violation = if is_new_recharge {
-            let ((prev_to_tar_distance, tar_to_next_distance), _) =
-                calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
-
-            // S ----- A ---- [X] ------ B ----- F
-
-            let current_distance = self.get_distance(route_ctx, activity_ctx.index);
-            // check S->X
-            let is_begin_violates = (current_distance + prev_to_tar_distance) > threshold;
-            // check X->F
-            let is_end_violates = if activity_ctx.next.is_some() {
-                let next_distance = self.get_distance(route_ctx, activity_ctx.index + 1);
-                let new_interval_distance = interval_distance - next_distance;
+        // S ----- A ---- [X] ------ B ----- F
+        let new_intervals = if is_new_recharge {
+            self.get_split_consumptions(route_ctx, activity_ctx, &intervals, &consumptions, interval_idx)
+        } else {
+            self.get_changed_consumptions(route_ctx, activity_ctx, &intervals, &consumptions, interval_idx)
+        };
 
-                (new_interval_distance + tar_to_next_distance) > threshold
+        // NOTE new recharge splits the interval, so the number of intervals differs by one
+        let is_violation = new_intervals.iter().enumerate().any(|(idx, &(start_idx, consumption))| {
+            let is_changed = if is_new_recharge {
+                idx == interval_idx || idx == interval_idx + 1
             } else {
-                false
+                idx == interval_idx || consumption > consumptions[idx]
             };
 
-            is_begin_violates || is_end_violates
+            is_changed
+                && self
+                    .model
+                    .get_limit(actor, start_idx.unwrap_or(activity_ctx.index + 1))
+                    .is_some_and(|threshold| consumption > threshold)
+        });
+
+        if is_violation {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let battery = actor.vehicle.dimens.get_vehicle_battery()?;
+
+        if self.is_schedule_violated(route_ctx, activity_ctx, battery, &new_intervals) {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+
+    /// Returns route intervals, an index of the interval which contains the insertion point and
+    /// consumptions of the intervals.
+    fn get_interval_consumptions(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> (Vec<(usize, usize)>, usize, Vec<Float>) {
+        let intervals = self.route_intervals.resolve_marker_intervals(route_ctx).collect::<Vec<_>>();
+        let interval_idx =
+            intervals.iter().position(|(_, end_idx)| activity_ctx.index <= *end_idx).expect("invalid markers state");
+        let consumptions = intervals
+            .iter()
+            .map(|&(_, end_idx)| get_counter(route_ctx, get_end_idx(route_ctx, end_idx)))
+            .collect::<Vec<_>>();
+
+        (intervals, interval_idx, consumptions)
+    }
+
+    /// Estimates charging duration of a new recharge station inserted as the target activity using
+    /// states of charge which the route would have after insertion.
+    fn estimate_charging_duration(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Duration {
+        let actor = route_ctx.route().actor.as_ref();
+        let Some(battery) = actor.vehicle.dimens.get_vehicle_battery() else { return Duration::default() };
+
+        let (intervals, interval_idx, consumptions) = self.get_interval_consumptions(route_ctx, activity_ctx);
+        let consumptions = self
+            .get_split_consumptions(route_ctx, activity_ctx, &intervals, &consumptions, interval_idx)
+            .into_iter()
+            .map(|(_, consumption)| consumption)
+            .collect::<Vec<_>>();
+
+        battery
+            .get_interval_charges(&consumptions)
+            .get(interval_idx + 1)
+            .map_or(Duration::default(), |&(arrival, departure)| {
+                get_charging_duration(activity_ctx.target, battery, arrival, departure)
+            })
+    }
+
+    /// Returns consumptions of route intervals (with their start activities) when a new recharge
+    /// station is inserted. The interval with the station is split and `None` marks the new one.
+    fn get_split_consumptions(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        intervals: &[(usize, usize)],
+        consumptions: &[Float],
+        interval_idx: usize,
+    ) -> Vec<(Option<usize>, Float)> {
+        let route = route_ctx.route();
+        let prev_idx = activity_ctx.index;
+        let load_ratio = self.model.get_load_ratio(route_ctx, prev_idx);
+        let ((prev_to_tar, tar_to_next), _) = calculate_travel(route_ctx, activity_ctx, self.model.transport.as_ref());
+
+        // check S->X
+        let left = get_counter(route_ctx, prev_idx) + self.model.get_consumption(route, prev_to_tar, load_ratio);
+        // check X->F
+        let right = if activity_ctx.next.is_some() {
+            consumptions[interval_idx] - get_counter(route_ctx, prev_idx + 1)
+                + self.model.get_consumption(route, tar_to_next, load_ratio)
         } else {
-            let (distance_delta, _) = calculate_travel_delta(route_ctx, activity_ctx, self.transport.as_ref());
+            Float::default()
+        };
+
+        intervals.iter().zip(consumptions.iter()).enumerate().fold(
+            Vec::with_capacity(intervals.len() + 1),
+            |mut acc, (idx, (&(start_idx, _), &consumption))| {
+                if idx == interval_idx {
+                    acc.push((Some(start_idx), left));
+                    acc.push((None, right));
+                } else {
+                    acc.push((Some(start_idx), consumption));
+                }
+
+                acc
+            },
+        )
+    }
+
+    /// Returns consumptions of route intervals (with their start activities) when a job activity is
+    /// inserted. Besides the detour, static demand of the job is carried on the legs before (delivery)
+    /// or after (pickup) the activity, so it can change consumption of other intervals too.
+    fn get_changed_consumptions(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        intervals: &[(usize, usize)],
+        consumptions: &[Float],
+        interval_idx: usize,
+    ) -> Vec<(Option<usize>, Float)> {
+        let route = route_ctx.route();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let prev_idx = activity_ctx.index;
+
+        let load_ratio = self.model.get_load_ratio(route_ctx, prev_idx);
+        let (delivery, pickup) = target
+            .job
+            .as_ref()
+            .zip(self.model.load.as_ref())
+            .map_or((0., 0.), |(single, load)| (load.demand_fn)(route, single));
 
-            (interval_distance + distance_delta) > threshold
+        let ((prev_to_tar, tar_to_next), _) = calculate_travel(route_ctx, activity_ctx, self.model.transport.as_ref());
+        let detour = self.model.get_consumption(route, prev_to_tar, load_ratio + delivery)
+            + next.map_or(Float::default(), |next| {
+                self.model.get_consumption(route, tar_to_next, load_ratio + pickup)
+                    - self.model.get_consumption(route, self.model.get_distance(route, prev, next), load_ratio)
+            });
+
+        let extra_rate =
+            route.actor.vehicle.dimens.get_vehicle_battery().map_or(0., |battery| battery.full - battery.empty);
+        let (load_start, load_end) = get_load_interval(route_ctx, prev_idx);
+        let load_end = get_end_idx(route_ctx, load_end);
+
+        // NOTE returns distance of legs to activities within (from, to] which belong to the interval
+        let get_overlap = |(from, to): (usize, usize), (start, end): (usize, usize)| {
+            let (from, to) = (from.max(start), to.min(end));
+            if from < to { get_travelled(route_ctx, to) - get_travelled(route_ctx, from) } else { Distance::default() }
         };
 
-        if is_violation { ConstraintViolation::skip(self.code) } else { None }
+        intervals
+            .iter()
+            .zip(consumptions.iter())
+            .enumerate()
+            .map(|(idx, (&(start_idx, end_idx), &consumption))| {
+                let interval = (start_idx, get_end_idx(route_ctx, end_idx));
+                let carried = delivery * get_overlap((load_start, prev_idx), interval)
+                    + next.map_or(0., |_| pickup * get_overlap((prev_idx + 1, load_end), interval));
+                let delta = extra_rate * carried + if idx == interval_idx { detour } else { Float::default() };
+
+                (Some(start_idx), consumption + delta)
+            })
+            .collect()
     }
-}
 
-impl RechargeableMultiTrip {
-    fn get_distance(&self, route_ctx: &RouteContext, activity_idx: usize) -> Distance {
-        route_ctx.state().get_recharge_distance_at(activity_idx).copied().unwrap_or(Distance::default())
+    /// Checks whether charging durations changed by insertion keep the tour schedule feasible.
+    fn is_schedule_violated(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        battery: &Battery,
+        intervals: &[(Option<usize>, Float)],
+    ) -> bool {
+        let route = route_ctx.route();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let prev_idx = activity_ctx.index;
+
+        let consumptions = intervals.iter().map(|(_, consumption)| *consumption).collect::<Vec<_>>();
+        let charges = battery.get_interval_charges(&consumptions);
+
+        // NOTE extra charging time on each station, existing ones keep their charging durations
+        let extras = intervals
+            .iter()
+            .zip(charges)
+            .skip(1)
+            .map(|(&(start_idx, _), (arrival, departure))| match start_idx {
+                Some(activity_idx) => {
+                    let activity = &route.tour[activity_idx];
+                    let current = activity.place.duration - get_base_duration(activity);
+                    let extra = get_charging_duration(activity, battery, arrival, departure) - current;
+
+                    (Some(activity_idx), extra.max(0.))
+                }
+                None => (None, get_charging_duration(target, battery, arrival, departure)),
+            })
+            .collect::<Vec<_>>();
+
+        if extras.iter().all(|(_, extra)| *extra == 0.) {
+            return false;
+        }
+
+        let is_station_violated = |activity_idx: usize, shift: Duration, extra: Duration| {
+            let activity = &route.tour[activity_idx];
+            let latest_arrival =
+                route_ctx.state().get_latest_arrival_at(activity_idx).copied().unwrap_or(activity.place.time.end);
+
+            shift + extra > 0.
+                && activity.schedule.arrival.max(activity.place.time.start) + shift + extra > latest_arrival
+        };
+
+        // check stations before the target
+        let mut shift = Duration::default();
+        for &(activity_idx, extra) in extras.iter() {
+            match activity_idx {
+                Some(activity_idx) if activity_idx <= prev_idx => {
+                    if is_station_violated(activity_idx, shift, extra) {
+                        return true;
+                    }
+                    shift += extra;
+                }
+                _ => break,
+            }
+        }
+
+        // check the target itself
+        let target_extra =
+            extras.iter().find(|(activity_idx, _)| activity_idx.is_none()).map_or(0., |(_, extra)| *extra);
+        if shift + target_extra > 0. {
+            let transport = self.model.transport.as_ref();
+            let departure = prev.schedule.departure + shift;
+            let arrival = departure
+                + transport.duration(
+                    route,
+                    prev.place.location,
+                    target.place.location,
+                    TravelTime::Departure(departure),
+                );
+            let start = arrival.max(target.place.time.start);
+
+            if start > target.place.time.end {
+                return true;
+            }
+
+            if let Some(next) = next {
+                let departure = start + target.place.duration + target_extra;
+                let arrival = departure
+                    + transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let latest_arrival =
+                    route_ctx.state().get_latest_arrival_at(prev_idx + 1).copied().unwrap_or(next.place.time.end);
+
+                if arrival > latest_arrival {
+                    return true;
+                }
+            }
+        }
+
+        // check stations after the target
+        let (_, detour) = calculate_travel_delta(route_ctx, activity_ctx, self.model.transport.as_ref());
+        let mut shift = shift + target_extra + detour.max(0.);
+        extras
+            .iter()
+            .filter_map(|(activity_idx, extra)| activity_idx.filter(|idx| *idx > prev_idx).map(|idx| (idx, *extra)))
+            .any(|(activity_idx, extra)| {
+                let is_violated = is_station_violated(activity_idx, shift, extra);
+                shift += extra;
+
+                is_violated
+            })
+    }
+
+    /// Updates durations of recharge activities according to the energy charged there.
+    fn update_charging_durations(&self, route_ctx: &mut RouteContext, consumptions: &[Float]) {
+        let route = route_ctx.route();
+        let Some(battery) = route.actor.vehicle.dimens.get_vehicle_battery() else { return };
+
+        let is_recharge = |activity_idx: usize| {
+            route.tour[activity_idx].job.as_ref().is_some_and(|single| (self.recharge_single_fn)(single))
+        };
+        let states = battery.get_states_of_charge(consumptions, is_recharge);
+
+        let durations = (1..route.tour.total())
+            .filter(|&activity_idx| is_recharge(activity_idx))
+            .filter_map(|activity_idx| {
+                let activity = &route.tour[activity_idx];
+                let arrival = states[activity_idx - 1] - consumptions[activity_idx];
+                let duration = get_base_duration(activity)
+                    + get_charging_duration(activity, battery, arrival, states[activity_idx]);
+
+                ((activity.place.duration - duration).abs() > 1E-6).then_some((activity_idx, duration))
+            })
+            .collect::<Vec<_>>();
+
+        if durations.is_empty() {
+            return;
+        }
+
+        durations.into_iter().for_each(|(activity_idx, duration)| {
+            route_ctx.route_mut().tour.get_mut(activity_idx).expect("invalid activity index").place.duration = duration;
+        });
+
+        update_route_schedule(route_ctx, self.activity.as_ref(), self.model.transport.as_ref());
     }
 }
 
+fn get_counter(route_ctx: &RouteContext, activity_idx: usize) -> Float {
+    route_ctx.state().get_recharge_consumption_at(activity_idx).copied().unwrap_or_default()
+}
+
+fn get_travelled(route_ctx: &RouteContext, activity_idx: usize) -> Distance {
+    route_ctx.state().get_travelled_distance_at(activity_idx).copied().unwrap_or_default()
+}
+
 fn get_end_idx(route_ctx: &RouteContext, end_idx: usize) -> usize {
     let last_idx = route_ctx.route().tour.total() - 1;
     end_idx + if end_idx == last_idx { 0 } else { 1 }
 }
+
+fn get_leg_distance(route: &Route, transport: &dyn TransportCost, from: &Activity, to: &Activity) -> Distance {
+    transport.distance(route, from.place.location, to.place.location, TravelTime::Departure(from.schedule.departure))
+}
+
+/// Returns a duration of the activity without charging.
+fn get_base_duration(activity: &Activity) -> Duration {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.places.get(activity.place.idx))
+        .map_or(activity.place.duration, |place| place.duration)
+}
+
+fn get_charging_duration(activity: &Activity, battery: &Battery, arrival: Float, departure: Float) -> Duration {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_job_charging_curve())
+        .map_or(Duration::default(), |curve| curve.get_duration(battery.capacity, arrival, departure))
+}
+
+/// Returns indices of the first and the last activities of the reload interval which contains
+/// the activity with given index.
+fn get_load_interval(route_ctx: &RouteContext, activity_idx: usize) -> (usize, usize) {
+    let last_idx = route_ctx.route().tour.total().saturating_sub(1);
+
+    route_ctx
+        .state()
+        .get_reload_intervals()
+        .and_then(|intervals| intervals.iter().find(|(start, end)| *start <= activity_idx && activity_idx <= *end))
+        .copied()
+        .unwrap_or((0, last_idx))
+}
+
+struct ChargersUsageKey;
+
+struct SharedChargersConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    chargers_fn: RechargeChargersFn,
+    multi_trip: Arc<RechargeableMultiTrip>,
+}
+
+impl FeatureConstraint for SharedChargersConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                self.evaluate_activity(solution_ctx, route_ctx, activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl SharedChargersConstraint {
+    fn evaluate_activity(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let (chargers, station) = (self.chargers_fn)(target)?;
+        let route = route_ctx.route();
+
+        let occupied = solution_ctx
+            .state
+            .get_value::<ChargersUsageKey, SharedResourceUsage>()
+            .and_then(|usage| usage.get(&station))
+            .iter()
+            .flat_map(|intervals| intervals.iter())
+            .filter(|(actor, _)| *actor != route.actor)
+            .map(|(_, time)| time.clone())
+            .collect::<Vec<_>>();
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        // NOTE the station is occupied while charging, so its duration is extended by estimated charging time
+        let duration = target.place.duration + self.multi_trip.estimate_charging_duration(route_ctx, activity_ctx);
+        let start = get_shared_resource_start(&occupied, chargers, arrival.max(target.place.time.start), duration);
+        if start > target.place.time.end {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let next = next?;
+        let departure = start + duration;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        let latest_arrival =
+            route_ctx.state().get_latest_arrival_at(activity_ctx.index + 1).copied().unwrap_or(next.place.time.end);
+
+        if arrival > latest_arrival { ConstraintViolation::skip(self.code) } else { None }
+    }
+}
+
+struct SharedChargersState {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    chargers_fn: RechargeChargersFn,
+    recharge_single_fn: RechargeSingleFn,
+}
+
+impl FeatureState for SharedChargersState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        // NOTE insertion shifts charging in other routes only when the changed route uses shared stations
+        let route = solution_ctx.routes[route_index].route();
+        let has_visits = route.tour.all_activities().any(|activity| (self.chargers_fn)(activity).is_some());
+        let had_visits = solution_ctx
+            .state
+            .get_value::<ChargersUsageKey, SharedResourceUsage>()
+            .is_some_and(|usage| usage.values().flatten().any(|(actor, _)| *actor == route.actor));
+
+        if has_visits || had_visits {
+            self.update_chargers_usage(solution_ctx);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_chargers_usage(solution_ctx);
+        self.remove_late_jobs(solution_ctx);
+    }
+}
+
+impl SharedChargersState {
+    /// Schedules charging on shared stations in order of arrival: when all chargers are busy, vehicle
+    /// waits for a free one. Waiting is modeled by moving the start of the station's time window.
+    fn update_chargers_usage(&self, solution_ctx: &mut SolutionContext) {
+        let visits = solution_ctx
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_idx, route_ctx)| {
+                route_ctx.route().tour.all_activities().enumerate().filter_map(move |(activity_idx, activity)| {
+                    (self.chargers_fn)(activity).map(|(chargers, station)| SharedResourceVisit {
+                        route_idx,
+                        start_idx: activity_idx,
+                        end_idx: activity_idx,
+                        slots: chargers,
+                        resource_id: station,
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let usage = update_shared_resource_usage(solution_ctx, self.activity.as_ref(), self.transport.as_ref(), visits);
+
+        solution_ctx.state.set_value::<ChargersUsageKey, _>(usage);
+    }
+
+    /// Removes jobs which cannot be served in time anymore due to waiting for a free charger.
+    /// This might happen when charging is shifted by insertions in other routes.
+    fn remove_late_jobs(&self, solution_ctx: &mut SolutionContext) {
+        let is_late =
+            |activity: &Activity| activity.schedule.arrival.max(activity.place.time.start) > activity.place.time.end;
+        let is_removable = |job: &Job| !solution_ctx.locked.contains(job);
+
+        let jobs_to_remove = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| route_ctx.route())
+            .filter(|route| {
+                route.tour.all_activities().any(|activity| {
+                    (self.chargers_fn)(activity).is_some()
+                        && get_original_start(route, activity).is_some_and(|start| activity.place.time.start > start)
+                })
+            })
+            .flat_map(|route| {
+                let late_jobs = route
+                    .tour
+                    .all_activities()
+                    .filter(|activity| is_late(activity))
+                    .filter_map(|activity| activity.retrieve_job())
+                    .filter(is_removable)
+                    .collect::<Vec<_>>();
+
+                // NOTE when only tour end is late, the last job is removed to make the tour shorter
+                let is_end_late = late_jobs.is_empty() && route.tour.end().is_some_and(is_late);
+                let last_job = route
+                    .tour
+                    .jobs()
+                    .filter(|_| is_end_late)
+                    .filter(|job| !self.is_station(job) && is_removable(job))
+                    .last()
+                    .cloned();
+
+                late_jobs.into_iter().chain(last_job)
+            })
+            .collect::<HashSet<_>>();
+
+        if jobs_to_remove.is_empty() {
+            return;
+        }
+
+        jobs_to_remove.iter().for_each(|job| {
+            solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.route().tour.contains(job)).for_each(
+                |route_ctx| {
+                    assert!(route_ctx.route_mut().tour.remove(job), "cannot remove job from the tour");
+                },
+            )
+        });
+
+        // NOTE stations are conditional jobs: they are either promoted or ignored later by multi trip
+        let (stations, jobs): (Vec<_>, Vec<_>) = jobs_to_remove.into_iter().partition(|job| self.is_station(job));
+        solution_ctx.required.extend(stations);
+        solution_ctx.unassigned.extend(jobs.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
+
+    fn is_station(&self, job: &Job) -> bool {
+        job.as_single().is_some_and(|single| (self.recharge_single_fn)(single))
+    }
+}
@@ -2,21 +2,27 @@
 //! the tour. This is used to overcome a vehicle capacity limit. The feature has two flavors:
 //!  - simple: a basic reload place with unlimited number of jobs which can be loaded/unloaded from there
 //!  - shared: a resource constrained reload place
+//!
+//! Additionally, it provides a way to schedule visits of shared resources with a limited amount of
+//! slots used at the same time, e.g. station chargers or site docks.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/reloads_test.rs"]
 mod reloads_test;
 
-use crate::construction::enablers::{FeatureCombinator, RouteIntervals, RouteIntervalsState};
+use crate::construction::enablers::{
+    FeatureCombinator, RouteIntervals, RouteIntervalsState, get_original_start, update_route_schedule,
+};
 use crate::construction::features::capacity::*;
 use crate::construction::heuristics::*;
-use crate::models::common::{Demand, LoadOps, MultiDimLoad, SingleDimLoad};
-use crate::models::problem::{Job, Single};
+use crate::models::common::{Demand, Duration, LoadOps, MultiDimLoad, SingleDimLoad, TimeWindow, Timestamp};
+use crate::models::problem::{ActivityCost, Actor, Job, Single, TransportCost};
 use crate::models::solution::{Activity, Route};
 use crate::models::*;
 use rosomaxa::utils::{GenericError, GenericResult};
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::iter::once;
 use std::ops::{Add, Range, RangeInclusive, Sub};
 use std::sync::Arc;
 
@@ -460,6 +466,131 @@ fn get_activity_by_idx(route: &Route, idx: usize) -> &Activity {
     route.tour.get(idx).expect("cannot get activity by idx")
 }
 
+/// Keeps track of time intervals when slots of shared resources are occupied.
+pub(crate) type SharedResourceUsage = HashMap<SharedResourceId, Vec<(Arc<Actor>, TimeWindow)>>;
+
+/// Specifies a visit of the shared resource with limited amount of slots: the route occupies one slot
+/// from the service start of the first activity till the departure from the last one.
+pub(crate) struct SharedResourceVisit {
+    pub route_idx: usize,
+    pub start_idx: usize,
+    pub end_idx: usize,
+    pub slots: usize,
+    pub resource_id: SharedResourceId,
+}
+
+/// Schedules visits of shared resources in order of arrival: when all slots are busy, vehicle waits
+/// for a free one. Waiting is modeled by moving the start of the first visit activity's time window.
+/// Returns time intervals when slots of shared resources are occupied.
+pub(crate) fn update_shared_resource_usage(
+    solution_ctx: &mut SolutionContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+    mut visits: Vec<SharedResourceVisit>,
+) -> SharedResourceUsage {
+    // reset waiting scheduled previously
+    visits.iter().map(|visit| visit.route_idx).collect::<HashSet<_>>().into_iter().for_each(|route_idx| {
+        let route_ctx = &mut solution_ctx.routes[route_idx];
+        let starts = visits
+            .iter()
+            .filter(|visit| visit.route_idx == route_idx)
+            .filter_map(|visit| {
+                let route = route_ctx.route();
+                let activity = &route.tour[visit.start_idx];
+
+                get_original_start(route, activity)
+                    .filter(|&start| start != activity.place.time.start)
+                    .map(|start| (visit.start_idx, start))
+            })
+            .collect::<Vec<_>>();
+
+        if !starts.is_empty() {
+            starts.into_iter().for_each(|(activity_idx, start)| {
+                route_ctx.route_mut().tour.get_mut(activity_idx).expect("invalid activity index").place.time.start =
+                    start;
+            });
+            update_route_schedule(route_ctx, activity, transport);
+        }
+    });
+
+    let get_start = |routes: &[RouteContext], visit: &SharedResourceVisit| {
+        let activity = &routes[visit.route_idx].route().tour[visit.start_idx];
+        activity.schedule.arrival.max(activity.place.time.start)
+    };
+    let get_duration = |routes: &[RouteContext], visit: &SharedResourceVisit, start: Timestamp| {
+        routes[visit.route_idx].route().tour[visit.end_idx].schedule.departure - start
+    };
+
+    let mut usage = SharedResourceUsage::default();
+    while !visits.is_empty() {
+        let visit_idx = (0..visits.len())
+            .min_by(|&a, &b| {
+                let routes = solution_ctx.routes.as_slice();
+                get_start(routes, &visits[a]).total_cmp(&get_start(routes, &visits[b]))
+            })
+            .expect("empty visits");
+        let visit = visits.swap_remove(visit_idx);
+
+        let start = get_start(solution_ctx.routes.as_slice(), &visit);
+        let duration = get_duration(solution_ctx.routes.as_slice(), &visit, start);
+
+        let intervals = usage.entry(visit.resource_id).or_default();
+        let occupied = intervals.iter().map(|(_, time)| time.clone()).collect::<Vec<_>>();
+        let slot_start = get_shared_resource_start(&occupied, visit.slots, start, duration);
+
+        let route_ctx = &mut solution_ctx.routes[visit.route_idx];
+        if slot_start > start {
+            route_ctx.route_mut().tour.get_mut(visit.start_idx).expect("invalid activity index").place.time.start =
+                slot_start;
+            update_route_schedule(route_ctx, activity, transport);
+        }
+
+        let end = route_ctx.route().tour[visit.end_idx].schedule.departure;
+        intervals.push((route_ctx.route().actor.clone(), TimeWindow::new(slot_start, end)));
+    }
+
+    usage
+}
+
+/// Returns the earliest time not before `start` when one of shared resource's slots is free for given duration.
+pub(crate) fn get_shared_resource_start(
+    occupied: &[TimeWindow],
+    slots: usize,
+    start: Timestamp,
+    duration: Duration,
+) -> Timestamp {
+    let mut candidates =
+        once(start).chain(occupied.iter().map(|time| time.end).filter(|&end| end > start)).collect::<Vec<_>>();
+    candidates.sort_by(|a, b| a.total_cmp(b));
+
+    // NOTE number of busy slots decreases only when some visit ends
+    candidates
+        .into_iter()
+        .find(|&candidate| get_max_occupied_slots(occupied, candidate, candidate + duration) < slots)
+        .unwrap_or(start)
+}
+
+/// Returns the maximum number of shared resource's slots occupied at the same time within given interval.
+fn get_max_occupied_slots(occupied: &[TimeWindow], start: Timestamp, end: Timestamp) -> usize {
+    let mut events = occupied
+        .iter()
+        .filter(|time| time.start < end && start < time.end)
+        .flat_map(|time| [(time.start.max(start), 1), (time.end, -1)])
+        .collect::<Vec<(Timestamp, i64)>>();
+
+    // NOTE a slot released at some time can be taken at the same time, so releases go first
+    events.sort_by(|(a, a_delta), (b, b_delta)| a.total_cmp(b).then(a_delta.cmp(b_delta)));
+
+    events
+        .into_iter()
+        .scan(0_i64, |busy, (_, delta)| {
+            *busy += delta;
+            Some(*busy)
+        })
+        .max()
+        .map_or(0, |busy| busy.max(0) as usize)
+}
+
 /// Implement `SharedResource` for multi dimensional load.
 impl SharedResource for MultiDimLoad {}
 
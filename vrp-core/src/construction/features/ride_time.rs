@@ -0,0 +1,196 @@
+//! A feature to limit ride time of multi jobs (e.g. pickup and delivery of a passenger in dial-a-ride problem).
+//!
+//! A ride time is the time between departure from the first activity of the multi job and service start at
+//! its last activity.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/ride_time_test.rs"]
+mod ride_time_test;
+
+use super::*;
+use crate::models::common::{Duration, Timestamp};
+use crate::models::problem::{ActivityCost, Multi, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use std::ops::ControlFlow;
+
+custom_dimension!(pub JobMaxRideTime typeof MaxRideTime);
+custom_activity_state!(RideTimeSlack typeof Duration);
+
+/// Specifies a maximum ride time of a multi job.
+#[derive(Clone, Copy, Debug)]
+pub enum MaxRideTime {
+    /// An absolute duration.
+    Absolute(Duration),
+    /// A factor applied to direct travel duration between locations of the first and the last activities.
+    Relative(Float),
+}
+
+/// Creates a feature to limit ride time of multi jobs having [`JobMaxRideTimeDimension`].
+/// This is a hard constraint.
+pub fn create_max_ride_time_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(RideTimeConstraint { code, transport: transport.clone(), activity })
+        .with_state(RideTimeState { transport })
+        .build()
+}
+
+struct RideTimeConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl RideTimeConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+
+        let departure = prev.schedule.departure;
+        let arrival_at_target = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        // NOTE activities of multi job are inserted in order, so the target is the last one when all others are
+        //      already in the tour. Its later insertion can only increase the ride time.
+        if let Some((first, max_ride_time)) = get_last_inserted_multi_job(route, target) {
+            let limit = get_ride_time_limit(self.transport.as_ref(), route, first, target, max_ride_time);
+            let ride_time = arrival_at_target.max(target.place.time.start) - first.schedule.departure;
+
+            if ride_time > limit {
+                return ConstraintViolation::fail(self.code);
+            }
+        }
+
+        // check that delay caused by insertion does not violate ride time of jobs already in the tour
+        let (Some(next), Some(&slack)) = (next, route_ctx.state().get_ride_time_slack_at(activity_ctx.index + 1))
+        else {
+            return None;
+        };
+
+        let ControlFlow::Continue(departure_at_target) =
+            self.activity.estimate_departure(route, target, arrival_at_target)
+        else {
+            return None;
+        };
+
+        let arrival_at_next = departure_at_target
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure_at_target),
+            );
+        if arrival_at_next - next.schedule.arrival > slack { ConstraintViolation::skip(self.code) } else { None }
+    }
+}
+
+impl FeatureConstraint for RideTimeConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct RideTimeState {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureState for RideTimeState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+
+        // NOTE slack at activity index is the minimum of remaining ride times of multi jobs which are in the
+        //      vehicle when it arrives at the activity.
+        let slacks = route
+            .tour
+            .jobs()
+            .filter_map(|job| match job {
+                Job::Multi(multi) => {
+                    multi.dimens.get_job_max_ride_time().map(|&max_ride_time| (job, multi, max_ride_time))
+                }
+                Job::Single(_) => None,
+            })
+            .filter(|(job, multi, _)| route.tour.job_activities(job).count() == multi.jobs.len())
+            .filter_map(|(job, _, max_ride_time)| {
+                let (first_idx, last_idx) = route.tour.index(job).zip(route.tour.index_last(job))?;
+                let (first, last) = route.tour.get(first_idx).zip(route.tour.get(last_idx))?;
+
+                let limit = get_ride_time_limit(self.transport.as_ref(), route, first, last, max_ride_time);
+                let ride_time = last.schedule.arrival.max(last.place.time.start) - first.schedule.departure;
+                Some((first_idx, last_idx, limit - ride_time))
+            })
+            .fold(None, |slacks: Option<Vec<Duration>>, (first_idx, last_idx, slack)| {
+                let mut slacks = slacks.unwrap_or_else(|| vec![Duration::MAX; route.tour.total()]);
+                slacks[first_idx + 1..=last_idx].iter_mut().for_each(|value| *value = value.min(slack));
+
+                Some(slacks)
+            });
+
+        // NOTE set empty states to reset values calculated for previous tour
+        route_ctx.state_mut().set_ride_time_slack_states(slacks.unwrap_or_default());
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+/// Returns the first activity and max ride time of the target's multi job if the target is its last activity
+/// to be inserted.
+fn get_last_inserted_multi_job<'a>(route: &'a Route, target: &Activity) -> Option<(&'a Activity, MaxRideTime)> {
+    let multi = target.job.as_ref().and_then(|single| Multi::roots(single))?;
+    let max_ride_time = *multi.dimens.get_job_max_ride_time()?;
+    let jobs_total = multi.jobs.len();
+
+    let job = Job::Multi(multi);
+    let mut activities = route.tour.all_activities().filter(|activity| activity.has_same_job(&job));
+    let first = activities.next()?;
+    let inserted = activities.count() + 1;
+
+    (inserted + 1 == jobs_total).then_some((first, max_ride_time))
+}
+
+fn get_ride_time_limit(
+    transport: &dyn TransportCost,
+    route: &Route,
+    first: &Activity,
+    last: &Activity,
+    max_ride_time: MaxRideTime,
+) -> Duration {
+    match max_ride_time {
+        MaxRideTime::Absolute(duration) => duration,
+        MaxRideTime::Relative(factor) => {
+            let departure: Timestamp = first.schedule.departure;
+            factor
+                * transport.duration(route, first.place.location, last.place.location, TravelTime::Departure(departure))
+        }
+    }
+}
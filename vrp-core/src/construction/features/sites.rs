@@ -0,0 +1,272 @@
+//! A feature to model customer sites shared by multiple jobs at the same location, e.g. a warehouse
+//! with a limited amount of loading docks.
+//!
+//! A site can limit the number of vehicles served there at the same time: a vehicle which arrives
+//! when all docks are busy waits until one of them becomes free. Additionally, a site can require a
+//! setup time (e.g. check-in or parking) which is applied once per stop and shared by all jobs served
+//! consecutively at the site.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/sites_test.rs"]
+mod sites_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::construction::features::reloads::{
+    SharedResourceUsage, SharedResourceVisit, get_shared_resource_start, update_shared_resource_usage,
+};
+use crate::models::common::Duration;
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::Activity;
+use rosomaxa::utils::UnwrapValue;
+
+/// Specifies a site shared by jobs at the same location.
+#[derive(Clone, Copy, Debug)]
+pub struct Site {
+    /// A unique site id.
+    pub id: SharedResourceId,
+    /// A maximum number of vehicles which can be served at the site at the same time.
+    pub max_vehicles: Option<usize>,
+    /// A setup time applied once per stop at the site.
+    pub setup_time: Duration,
+}
+
+/// Creates a feature to enforce site rules for activities which belong to some site according to the
+/// given function. This is a hard constraint.
+pub fn create_site_feature<F>(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    site_fn: F,
+) -> GenericResult<Feature>
+where
+    F: Fn(&Activity) -> Option<Site> + Send + Sync + 'static,
+{
+    let site_fn: SiteFn = Arc::new(site_fn);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(SiteConstraint {
+            code,
+            transport: transport.clone(),
+            activity: activity.clone(),
+            site_fn: site_fn.clone(),
+        })
+        .with_state(SiteState { code, transport, activity, site_fn })
+        .build()
+}
+
+type SiteFn = Arc<dyn Fn(&Activity) -> Option<Site> + Send + Sync>;
+
+struct SiteUsageKey;
+
+struct SiteConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    site_fn: SiteFn,
+}
+
+impl FeatureConstraint for SiteConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                self.evaluate_activity(solution_ctx, route_ctx, activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl SiteConstraint {
+    fn evaluate_activity(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let site = (self.site_fn)(target);
+
+        // NOTE when activity is inserted between two activities at the same site, the next one needs setup
+        let next_setup = next
+            .filter(|next| prev.place.location == next.place.location && target.place.location != next.place.location)
+            .and_then(|next| (self.site_fn)(next))
+            .map_or(Duration::default(), |site| site.setup_time);
+
+        if site.is_none() && next_setup == Duration::default() {
+            return None;
+        }
+
+        let route = route_ctx.route();
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        let is_new_stop = prev.place.location != target.place.location;
+        let setup_time = site.filter(|_| is_new_stop).map_or(Duration::default(), |site| site.setup_time);
+
+        let start = arrival.max(target.place.time.start);
+        let start = match site.and_then(|site| site.max_vehicles.filter(|_| is_new_stop).map(|max| (site.id, max))) {
+            Some((site_id, max_vehicles)) => {
+                let occupied = solution_ctx
+                    .state
+                    .get_value::<SiteUsageKey, SharedResourceUsage>()
+                    .and_then(|usage| usage.get(&site_id))
+                    .iter()
+                    .flat_map(|intervals| intervals.iter())
+                    .filter(|(actor, _)| *actor != route.actor)
+                    .map(|(_, time)| time.clone())
+                    .collect::<Vec<_>>();
+
+                get_shared_resource_start(&occupied, max_vehicles, start, target.place.duration + setup_time)
+            }
+            None => start,
+        };
+
+        if start > target.place.time.end {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let next = next?;
+        let departure = self.activity.estimate_departure(route, target, start).unwrap_value() + setup_time;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        let latest_arrival =
+            route_ctx.state().get_latest_arrival_at(activity_ctx.index + 1).copied().unwrap_or(next.place.time.end);
+
+        if arrival + next_setup > latest_arrival { ConstraintViolation::skip(self.code) } else { None }
+    }
+}
+
+struct SiteState {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    site_fn: SiteFn,
+}
+
+impl FeatureState for SiteState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+
+        // NOTE any insertion might shift visits of other routes at the same sites
+        self.update_site_usage(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        self.update_setup_times(route_ctx);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.update_setup_times(route_ctx));
+
+        self.update_site_usage(solution_ctx);
+        self.remove_late_jobs(solution_ctx);
+    }
+}
+
+impl SiteState {
+    /// Applies setup time to the first activity of each stop at the site by increasing its duration.
+    fn update_setup_times(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        let durations = route
+            .tour
+            .all_activities()
+            .enumerate()
+            .skip(1)
+            .filter_map(|(activity_idx, activity)| {
+                let site = (self.site_fn)(activity)?;
+                let original = activity.job.as_ref().and_then(|single| single.places.get(activity.place.idx))?.duration;
+
+                let prev = &route.tour[activity_idx - 1];
+                let setup_time =
+                    if prev.place.location != activity.place.location { site.setup_time } else { Duration::default() };
+
+                let duration = original + setup_time;
+                (duration != activity.place.duration).then_some((activity_idx, duration))
+            })
+            .collect::<Vec<_>>();
+
+        if durations.is_empty() {
+            return;
+        }
+
+        durations.into_iter().for_each(|(activity_idx, duration)| {
+            route_ctx.route_mut().tour.get_mut(activity_idx).expect("invalid activity index").place.duration = duration;
+        });
+        update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+    }
+
+    /// Schedules stops at sites with limited number of vehicles as shared resource visits.
+    fn update_site_usage(&self, solution_ctx: &mut SolutionContext) {
+        let visits = solution_ctx
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_idx, route_ctx)| {
+                let route = route_ctx.route();
+
+                route.tour.all_activities().enumerate().skip(1).fold(
+                    Vec::<SharedResourceVisit>::default(),
+                    |mut acc, item| {
+                        let (activity_idx, activity) = item;
+                        let Some((site_id, max_vehicles)) =
+                            (self.site_fn)(activity).and_then(|site| site.max_vehicles.map(|max| (site.id, max)))
+                        else {
+                            return acc;
+                        };
+
+                        let prev = &route.tour[activity_idx - 1];
+                        match acc.last_mut() {
+                            Some(visit)
+                                if visit.resource_id == site_id
+                                    && visit.end_idx + 1 == activity_idx
+                                    && prev.place.location == activity.place.location =>
+                            {
+                                visit.end_idx = activity_idx
+                            }
+                            _ => acc.push(SharedResourceVisit {
+                                route_idx,
+                                start_idx: activity_idx,
+                                end_idx: activity_idx,
+                                slots: max_vehicles,
+                                resource_id: site_id,
+                            }),
+                        }
+
+                        acc
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let usage = update_shared_resource_usage(solution_ctx, self.activity.as_ref(), self.transport.as_ref(), visits);
+
+        solution_ctx.state.set_value::<SiteUsageKey, _>(usage);
+    }
+
+    /// Removes jobs which cannot be served in time anymore due to waiting at the site.
+    fn remove_late_jobs(&self, solution_ctx: &mut SolutionContext) {
+        let jobs = get_late_jobs_after_waiting(solution_ctx, &|activity| {
+            (self.site_fn)(activity).is_some_and(|site| site.max_vehicles.is_some())
+        });
+
+        unassign_jobs(solution_ctx, jobs, self.code);
+    }
+}
@@ -1,4 +1,4 @@
-//! A job-vehicle skills feature.
+//! A job-vehicle skills feature. Driver skills, if defined, are added to vehicle skills.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/skills_test.rs"]
@@ -9,6 +9,7 @@ use std::collections::HashSet;
 
 custom_dimension!(pub JobSkills typeof JobSkills);
 custom_dimension!(pub VehicleSkills typeof HashSet<String>);
+custom_dimension!(pub DriverSkills typeof HashSet<String>);
 
 /// A job skills limitation for a vehicle.
 pub struct JobSkills {
@@ -44,7 +45,17 @@ impl FeatureConstraint for SkillsConstraint {
         match move_ctx {
             MoveContext::Route { route_ctx, job, .. } => {
                 if let Some(job_skills) = job.dimens().get_job_skills() {
-                    let vehicle_skills = route_ctx.route().actor.vehicle.dimens.get_vehicle_skills();
+                    let actor = route_ctx.route().actor.as_ref();
+                    let vehicle_skills = actor.vehicle.dimens.get_vehicle_skills();
+                    let actor_skills = actor.driver.dimens.get_driver_skills().map(|driver_skills| {
+                        driver_skills
+                            .iter()
+                            .chain(vehicle_skills.into_iter().flatten())
+                            .cloned()
+                            .collect::<HashSet<_>>()
+                    });
+                    let vehicle_skills = actor_skills.as_ref().or(vehicle_skills);
+
                     let is_ok = check_all_of(job_skills, &vehicle_skills)
                         && check_one_of(job_skills, &vehicle_skills)
                         && check_none_of(job_skills, &vehicle_skills);
@@ -0,0 +1,265 @@
+//! A feature to model soft time windows: a job can be served outside of its time windows, but
+//! earliness and lateness are penalized proportionally to their duration.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/soft_time_windows_test.rs"]
+mod soft_time_windows_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+use rosomaxa::utils::UnwrapValue;
+use std::ops::ControlFlow;
+
+custom_dimension!(pub JobSoftTimeWindows typeof SoftTimeWindows);
+custom_tour_state!(SoftTimeWindowsPenalty typeof Cost);
+
+/// Specifies soft time windows of job's places and penalty rates for serving outside of them.
+/// Hard time windows of the places are expected to be expanded by allowed earliness and lateness.
+#[derive(Clone, Debug)]
+pub struct SoftTimeWindows {
+    /// A penalty per time unit when service starts before a soft time window.
+    pub earliness: Cost,
+    /// A penalty per time unit when service starts after a soft time window.
+    pub lateness: Cost,
+    /// Soft time windows per job's place, in the same order as places are defined.
+    pub times: Vec<Vec<TimeWindow>>,
+}
+
+impl SoftTimeWindows {
+    /// Returns earliness and lateness of the service started at given time on the place with given
+    /// index. The soft time window with the lowest penalty is used.
+    pub fn get_deviation(&self, place_idx: usize, service_start: Timestamp) -> (Duration, Duration) {
+        self.times
+            .get(place_idx)
+            .and_then(|times| {
+                times
+                    .iter()
+                    .map(|time| ((time.start - service_start).max(0.), (service_start - time.end).max(0.)))
+                    .min_by(|&a, &b| self.get_cost(a).total_cmp(&self.get_cost(b)))
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns service start on the place with given index and hard time window when a vehicle
+    /// arrives at given time. Service is postponed till the start of the soft time window when
+    /// waiting is cheaper than earliness.
+    pub fn get_service_start(
+        &self,
+        place_idx: usize,
+        hard: &TimeWindow,
+        arrival: Timestamp,
+        waiting_rate: Cost,
+    ) -> Timestamp {
+        let start = arrival.max(hard.start);
+        if waiting_rate >= self.earliness {
+            return start;
+        }
+
+        let (earliness, _) = self.get_deviation(place_idx, start);
+
+        (start + earliness).min(hard.end).max(start)
+    }
+
+    /// Returns the latest arrival on the place with given index and hard time window which allows
+    /// to start service not later than given time. Returns `None` if there is no such arrival.
+    pub fn get_latest_arrival(
+        &self,
+        place_idx: usize,
+        hard: &TimeWindow,
+        latest_start: Timestamp,
+        waiting_rate: Cost,
+    ) -> Option<Timestamp> {
+        let is_feasible =
+            |arrival: Timestamp| self.get_service_start(place_idx, hard, arrival, waiting_rate) <= latest_start;
+        let latest = hard.end.min(latest_start);
+
+        if is_feasible(latest) {
+            return Some(latest);
+        }
+
+        // NOTE service start is not decreasing with arrival, so feasible arrivals end where switching
+        //      to waiting for a later soft time window happens: it is either the end of some window
+        //      or a point where lateness of one window costs the same as earliness of another
+        let times = self.times.get(place_idx)?;
+        let rates = self.earliness + self.lateness;
+        times
+            .iter()
+            .flat_map(|late| {
+                times
+                    .iter()
+                    .filter(move |_| rates > 0.)
+                    .map(move |early| (late.end * self.lateness + early.start * self.earliness) / rates)
+                    .chain(std::iter::once(late.end))
+            })
+            .chain(std::iter::once(hard.start))
+            .filter(|&arrival| arrival <= latest && is_feasible(arrival))
+            .max_by(|a, b| a.total_cmp(b))
+    }
+
+    /// Returns penalty of the service started at given time on the place with given index.
+    pub fn get_penalty(&self, place_idx: usize, service_start: Timestamp) -> Cost {
+        self.get_cost(self.get_deviation(place_idx, service_start))
+    }
+
+    fn get_cost(&self, (earliness, lateness): (Duration, Duration)) -> Cost {
+        earliness * self.earliness + lateness * self.lateness
+    }
+}
+
+/// Creates a soft time windows feature as an objective which penalizes serving jobs outside of
+/// their soft time windows. Service starts as soon as a vehicle arrives within hard time window
+/// unless waiting for a soft time window is cheaper, see [`get_service_start`].
+pub fn create_soft_time_windows_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(SoftTimeWindowsObjective { transport, activity })
+        .with_state(SoftTimeWindowsState {})
+        .build()
+}
+
+/// Returns earliness and lateness of the activity according to its schedule.
+pub fn get_activity_time_deviation(route: &Route, activity: &Activity) -> Option<(Duration, Duration)> {
+    get_soft_time_windows(activity).map(|soft| {
+        soft.get_deviation(activity.place.idx, get_service_start(route, activity, activity.schedule.arrival))
+    })
+}
+
+/// Returns service start of the activity when a vehicle arrives at given time. The vehicle waits
+/// for the start of a soft time window when waiting is cheaper than earliness penalty.
+pub fn get_service_start(route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+    let place = &activity.place;
+
+    get_soft_time_windows(activity).map_or(arrival.max(place.time.start), |soft| {
+        soft.get_service_start(place.idx, &place.time, arrival, get_waiting_rate(route))
+    })
+}
+
+/// Returns the latest arrival at the activity which allows to depart from it not later than given
+/// time. Returns `ControlFlow::Break` when no arrival allows that.
+pub fn get_latest_arrival(
+    route: &Route,
+    activity: &Activity,
+    departure: Timestamp,
+) -> ControlFlow<Timestamp, Timestamp> {
+    let place = &activity.place;
+    let latest_start = departure - place.duration;
+    let latest = place.time.end.min(latest_start);
+
+    get_soft_time_windows(activity)
+        .map_or(Some(latest), |soft| {
+            soft.get_latest_arrival(place.idx, &place.time, latest_start, get_waiting_rate(route))
+        })
+        .map_or(ControlFlow::Break(latest), ControlFlow::Continue)
+}
+
+struct SoftTimeWindowsObjective {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureObjective for SoftTimeWindowsObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                route_ctx
+                    .state()
+                    .get_soft_time_windows_penalty()
+                    .copied()
+                    .unwrap_or_else(|| get_route_penalty(route_ctx.route()))
+            })
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+impl SoftTimeWindowsObjective {
+    /// Estimates penalty of the target activity and penalty change of the next activities caused
+    /// by a delay which the target activity introduces.
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let (target, prev) = (activity_ctx.target, activity_ctx.prev);
+
+        let arrival = self.get_arrival(route, prev.place.location, prev.schedule.departure, target);
+        let target_penalty = get_activity_penalty(route, target, arrival);
+        let departure = self.activity.estimate_departure(route, target, arrival).unwrap_value();
+
+        let next_penalty = route
+            .tour
+            .all_activities()
+            .skip(activity_ctx.index + 1)
+            .try_fold((target.place.location, departure, Cost::default()), |(location, departure, acc), next| {
+                let arrival = self.get_arrival(route, location, departure, next);
+                if arrival <= next.schedule.arrival {
+                    return ControlFlow::Break((location, departure, acc));
+                }
+
+                let delta = get_activity_penalty(route, next, arrival)
+                    - get_activity_penalty(route, next, next.schedule.arrival);
+                let departure = self.activity.estimate_departure(route, next, arrival).unwrap_value();
+
+                ControlFlow::Continue((next.place.location, departure, acc + delta))
+            })
+            .unwrap_value()
+            .2;
+
+        target_penalty + next_penalty
+    }
+
+    fn get_arrival(&self, route: &Route, from: Location, departure: Timestamp, activity: &Activity) -> Timestamp {
+        departure + self.transport.duration(route, from, activity.place.location, TravelTime::Departure(departure))
+    }
+}
+
+struct SoftTimeWindowsState {}
+
+impl FeatureState for SoftTimeWindowsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(&mut solution_ctx.routes[route_index]);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let penalty = get_route_penalty(route_ctx.route());
+        route_ctx.state_mut().set_soft_time_windows_penalty(penalty);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx))
+    }
+}
+
+fn get_route_penalty(route: &Route) -> Cost {
+    route.tour.all_activities().map(|activity| get_activity_penalty(route, activity, activity.schedule.arrival)).sum()
+}
+
+fn get_activity_penalty(route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+    get_soft_time_windows(activity).map_or(Cost::default(), |soft| {
+        soft.get_penalty(activity.place.idx, get_service_start(route, activity, arrival))
+    })
+}
+
+fn get_soft_time_windows(activity: &Activity) -> Option<&SoftTimeWindows> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_soft_time_windows())
+}
+
+fn get_waiting_rate(route: &Route) -> Cost {
+    let actor = route.actor.as_ref();
+
+    actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time
+}
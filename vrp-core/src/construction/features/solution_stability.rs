@@ -0,0 +1,153 @@
+//! A feature to keep solution stable with respect to some reference plan: reassignments of jobs
+//! to other vehicles and changes of job sequence within tours are penalized.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/solution_stability_test.rs"]
+mod solution_stability_test;
+
+use super::*;
+use crate::models::problem::Vehicle;
+use crate::models::solution::Activity;
+use std::collections::{HashMap, HashSet};
+
+custom_solution_state!(SolutionDeviations typeof usize);
+
+/// Specifies a job assignment in the reference plan.
+pub struct JobReference {
+    /// A vehicle which serves the job.
+    pub vehicle: Arc<Vehicle>,
+    /// A position of the job in the vehicle's tour.
+    pub position: usize,
+}
+
+/// Creates a solution stability feature as a soft constraint. Each job served by other vehicle than
+/// specified in the reference plan, and each pair of consecutive jobs which order is different from
+/// the reference one, is counted as a single deviation multiplied by the given weight.
+/// Jobs without reference are ignored.
+pub fn create_solution_stability_feature(
+    name: &str,
+    references: HashMap<Job, JobReference>,
+    weight: Float,
+) -> Result<Feature, GenericError> {
+    if weight <= 0. {
+        return Err(format!("solution stability weight should be positive, got: {weight}").into());
+    }
+
+    let references = Arc::new(references);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(SolutionStabilityObjective { references: references.clone(), weight })
+        .with_state(SolutionStabilityState { references })
+        .build()
+}
+
+struct SolutionStabilityObjective {
+    references: Arc<HashMap<Job, JobReference>>,
+    weight: Float,
+}
+
+impl FeatureObjective for SolutionStabilityObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        let solution = &solution.solution;
+
+        let deviations = solution
+            .state
+            .get_solution_deviations()
+            .copied()
+            .unwrap_or_else(|| get_deviations(solution.routes.as_slice(), self.references.as_ref()));
+
+        deviations as Float * self.weight
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self
+                .references
+                .get(job)
+                .filter(|reference| !Arc::ptr_eq(&reference.vehicle, &route_ctx.route().actor.vehicle))
+                .map_or(Cost::default(), |_| self.weight),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let route = route_ctx.route();
+                let get_position = |activity: &Activity| {
+                    activity
+                        .retrieve_job()
+                        .and_then(|job| self.references.get(&job))
+                        .filter(|reference| Arc::ptr_eq(&reference.vehicle, &route.actor.vehicle))
+                        .map(|reference| reference.position)
+                };
+                // NOTE only first activity of the job is counted, the same as in fitness
+                let get_tour_position = |idx: usize| {
+                    route
+                        .tour
+                        .get(idx)
+                        .filter(|activity| activity.retrieve_job().and_then(|job| route.tour.index(&job)) == Some(idx))
+                        .and_then(get_position)
+                };
+                let is_descent = |left: Option<usize>, right: Option<usize>| match (left, right) {
+                    (Some(left), Some(right)) if left > right => 1.,
+                    _ => 0.,
+                };
+
+                // NOTE jobs without reference are skipped, so look for the nearest referenced neighbours
+                let prev = (0..=activity_ctx.index).rev().find_map(get_tour_position);
+                let next = (activity_ctx.index + 1..route.tour.total()).find_map(get_tour_position);
+                let target = get_position(activity_ctx.target).filter(|_| {
+                    let job = activity_ctx.target.retrieve_job();
+                    job.and_then(|job| route.tour.index(&job)).is_none_or(|idx| idx > activity_ctx.index)
+                });
+
+                let delta = if target.is_some() {
+                    is_descent(prev, target) + is_descent(target, next) - is_descent(prev, next)
+                } else {
+                    0.
+                };
+
+                delta * self.weight
+            }
+        }
+    }
+}
+
+struct SolutionStabilityState {
+    references: Arc<HashMap<Job, JobReference>>,
+}
+
+impl FeatureState for SolutionStabilityState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let deviations = get_deviations(solution_ctx.routes.as_slice(), self.references.as_ref());
+        solution_ctx.state.set_solution_deviations(deviations);
+    }
+}
+
+fn get_deviations(routes: &[RouteContext], references: &HashMap<Job, JobReference>) -> usize {
+    routes
+        .iter()
+        .map(|route_ctx| {
+            let vehicle = &route_ctx.route().actor.vehicle;
+            let mut visited = HashSet::new();
+
+            let (reassigned, positions) = route_ctx
+                .route()
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.retrieve_job())
+                .filter(|job| visited.insert(job.clone()))
+                .filter_map(|job| references.get(&job))
+                .fold((0_usize, Vec::new()), |(reassigned, mut positions), reference| {
+                    if Arc::ptr_eq(&reference.vehicle, vehicle) {
+                        positions.push(reference.position);
+                        (reassigned, positions)
+                    } else {
+                        (reassigned + 1, positions)
+                    }
+                });
+
+            reassigned + positions.windows(2).filter(|pair| pair[0] > pair[1]).count()
+        })
+        .sum()
+}
@@ -0,0 +1,129 @@
+//! A feature to model split jobs: job's demand is divided into parts which can be served by different vehicles.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/split_test.rs"]
+mod split_test;
+
+use super::*;
+use crate::construction::enablers::unassign_jobs;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+custom_dimension!(pub JobSplit typeof JobSplit);
+custom_tour_state!(CurrentSplits typeof HashSet<String>);
+
+/// Specifies a part of the split job: job's demand is divided into parts which can be served
+/// by different vehicles, so the amount delivered by each vehicle is decided during insertion.
+pub struct JobSplit {
+    /// A key which is shared by all parts of the same job.
+    pub key: String,
+    /// Maximum amount of vehicles which can serve parts of the same job.
+    pub max_splits: usize,
+}
+
+/// Creates a feature which limits amount of vehicles serving parts of the same split job.
+/// Parts are never merged, so the solver decides how they are distributed. This is a hard constraint.
+pub fn create_split_feature(name: &str, code: ViolationCode) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(SplitConstraint { code })
+        .with_state(SplitState { code })
+        .build()
+}
+
+struct SplitConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for SplitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                let split = job.dimens().get_job_split()?;
+
+                if has_split(route_ctx, &split.key) {
+                    return None;
+                }
+
+                let splits = solution_ctx.routes.iter().filter(|route_ctx| has_split(route_ctx, &split.key)).count();
+
+                if splits < split.max_splits { None } else { ConstraintViolation::fail(self.code) }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        // NOTE parts of split job are kept separately to let solver decide how they are distributed
+        match (source.dimens().get_job_split(), candidate.dimens().get_job_split()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct SplitState {
+    code: ViolationCode,
+}
+
+impl FeatureState for SplitState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if job.dimens().get_job_split().is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let splits = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter_map(|job| job.dimens().get_job_split())
+            .map(|split| split.key.clone())
+            .collect();
+
+        route_ctx.state_mut().set_current_splits(splits);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+
+        // NOTE routes can be modified separately (e.g. by decompose search), so parts of the same
+        // job can end up on more vehicles than allowed: unassign them from routes with fewer parts
+        let excess_parts = get_excess_parts(solution_ctx);
+        if !excess_parts.is_empty() {
+            unassign_jobs(solution_ctx, excess_parts, self.code);
+            solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+        }
+    }
+}
+
+fn has_split(route_ctx: &RouteContext, key: &str) -> bool {
+    route_ctx.state().get_current_splits().is_some_and(|splits| splits.contains(key))
+}
+
+/// Returns parts of split jobs which exceed max splits limit.
+fn get_excess_parts(solution_ctx: &SolutionContext) -> Vec<Job> {
+    let mut parts = HashMap::<String, (usize, BTreeMap<usize, Vec<Job>>)>::default();
+    solution_ctx.routes.iter().enumerate().for_each(|(route_idx, route_ctx)| {
+        route_ctx.route().tour.jobs().for_each(|job| {
+            if let Some(split) = job.dimens().get_job_split() {
+                let (_, routes) =
+                    parts.entry(split.key.clone()).or_insert_with(|| (split.max_splits, BTreeMap::default()));
+                routes.entry(route_idx).or_default().push(job.clone());
+            }
+        });
+    });
+
+    parts
+        .into_values()
+        .filter(|(max_splits, routes)| routes.len() > *max_splits)
+        .flat_map(|(max_splits, routes)| {
+            let has_locked = |jobs: &Vec<Job>| jobs.iter().any(|job| solution_ctx.locked.contains(job));
+            let mut routes = routes.into_values().collect::<Vec<_>>();
+            // NOTE keep routes with locked parts first, then the ones with more parts
+            routes.sort_by(|a, b| has_locked(b).cmp(&has_locked(a)).then_with(|| b.len().cmp(&a.len())));
+
+            routes.into_iter().skip(max_splits).flatten().filter(|job| !solution_ctx.locked.contains(job))
+        })
+        .collect()
+}
@@ -0,0 +1,242 @@
+//! A feature to model synchronized visits: a job which requires multiple vehicles to be present at
+//! the same time, e.g. two technicians or a crane truck plus a delivery truck.
+//!
+//! Such job is represented by multiple copies (one per required vehicle) which share the same key.
+//! Copies should be served by different vehicles and their service start times should be aligned
+//! within the given tolerance: a vehicle which arrives earlier waits for the others. The job is
+//! either served by all required vehicles or unassigned.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/synchronization_test.rs"]
+mod synchronization_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::common::{Duration, Timestamp};
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::Activity;
+use rosomaxa::utils::UnwrapValue;
+use std::collections::{HashMap, HashSet};
+
+custom_dimension!(pub JobSync typeof JobSync);
+custom_tour_state!(CurrentSyncs typeof HashSet<String>);
+
+/// Specifies a copy of the synchronized job.
+pub struct JobSync {
+    /// A key which is shared by all copies of the same job.
+    pub key: String,
+    /// A total amount of copies: how many vehicles are required to serve the job.
+    pub size: usize,
+    /// A maximum allowed difference between service start times of the copies.
+    pub tolerance: Duration,
+}
+
+/// Creates a feature to synchronize service start of jobs having [`JobSyncDimension`] across
+/// different routes. This is a hard constraint.
+pub fn create_synchronization_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(SyncConstraint { code, transport: transport.clone(), activity: activity.clone() })
+        .with_state(SyncState { code, transport, activity })
+        .build()
+}
+
+struct SyncConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureConstraint for SyncConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let sync = job.dimens().get_job_sync()?;
+
+                if has_sync(route_ctx, &sync.key) { ConstraintViolation::fail(self.code) } else { None }
+            }
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                self.evaluate_activity(solution_ctx, route_ctx, activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        // NOTE copies of synchronized job have to be served by different vehicles
+        if source.dimens().get_job_sync().is_some() || candidate.dimens().get_job_sync().is_some() {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+impl SyncConstraint {
+    fn evaluate_activity(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let sync = get_sync(target)?;
+        let route = route_ctx.route();
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let start = arrival.max(target.place.time.start);
+
+        let others = get_sync_visits(solution_ctx, sync.key.as_str())
+            .filter(|(actor, ..)| **actor != route.actor)
+            .map(|(_, start, latest)| (start, latest))
+            .collect::<Vec<_>>();
+        let earliest = others.iter().map(|(start, _)| *start).fold(start, Float::max) - sync.tolerance;
+
+        // NOTE copies which start too early have to wait, so they should not become late
+        if others.iter().any(|&(start, latest)| start < earliest && earliest > latest) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let start = start.max(earliest);
+        if start > target.place.time.end {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let next = next?;
+        let departure = self.activity.estimate_departure(route, target, start).unwrap_value();
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        let latest_arrival =
+            route_ctx.state().get_latest_arrival_at(activity_ctx.index + 1).copied().unwrap_or(next.place.time.end);
+
+        if arrival > latest_arrival { ConstraintViolation::skip(self.code) } else { None }
+    }
+}
+
+struct SyncState {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureState for SyncState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+
+        // NOTE any insertion into the route with synchronized jobs might shift them in other routes
+        if route_ctx.state().get_current_syncs().is_some_and(|syncs| !syncs.is_empty()) {
+            self.update_schedules(solution_ctx);
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let syncs = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter_map(|job| job.dimens().get_job_sync())
+            .map(|sync| sync.key.clone())
+            .collect::<HashSet<_>>();
+
+        route_ctx.state_mut().set_current_syncs(syncs);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+
+        self.update_schedules(solution_ctx);
+        self.remove_late_jobs(solution_ctx);
+        self.remove_incomplete_jobs(solution_ctx);
+    }
+}
+
+impl SyncState {
+    fn update_schedules(&self, solution_ctx: &mut SolutionContext) {
+        update_synchronized_schedules(solution_ctx, self.activity.as_ref(), self.transport.as_ref(), &|activity| {
+            get_sync(activity).map(|sync| (sync.key.clone(), sync.tolerance))
+        });
+    }
+
+    /// Removes jobs which cannot be served in time anymore due to waiting for other vehicles.
+    fn remove_late_jobs(&self, solution_ctx: &mut SolutionContext) {
+        let jobs = get_late_jobs_after_waiting(solution_ctx, &|activity| get_sync(activity).is_some());
+
+        unassign_jobs(solution_ctx, jobs, self.code);
+    }
+
+    /// Removes copies of synchronized jobs which are not served by all required vehicles.
+    fn remove_incomplete_jobs(&self, solution_ctx: &mut SolutionContext) {
+        let mut copies = HashMap::<String, (usize, Vec<Job>)>::default();
+        solution_ctx.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()).for_each(|job| {
+            if let Some(sync) = job.dimens().get_job_sync() {
+                copies.entry(sync.key.clone()).or_insert_with(|| (sync.size, Vec::default())).1.push(job.clone());
+            }
+        });
+
+        // NOTE missing copies might be still inserted when insertion process is not finished
+        let required = solution_ctx
+            .required
+            .iter()
+            .filter_map(|job| job.dimens().get_job_sync())
+            .map(|sync| sync.key.as_str())
+            .collect::<HashSet<_>>();
+
+        let jobs = copies
+            .iter()
+            .filter(|(key, (size, jobs))| jobs.len() < *size && !required.contains(key.as_str()))
+            .flat_map(|(_, (_, jobs))| jobs.iter())
+            .filter(|job| !solution_ctx.locked.contains(*job))
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        unassign_jobs(solution_ctx, jobs, self.code);
+    }
+}
+
+fn get_sync(activity: &Activity) -> Option<&JobSync> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_sync())
+}
+
+fn has_sync(route_ctx: &RouteContext, key: &str) -> bool {
+    route_ctx.state().get_current_syncs().is_some_and(|syncs| syncs.contains(key))
+}
+
+/// Returns actor, service start and latest arrival of already assigned copies of synchronized job.
+fn get_sync_visits<'a>(
+    solution_ctx: &'a SolutionContext,
+    key: &'a str,
+) -> impl Iterator<Item = (&'a Arc<Actor>, Timestamp, Timestamp)> + 'a {
+    solution_ctx.routes.iter().filter(move |route_ctx| has_sync(route_ctx, key)).flat_map(move |route_ctx| {
+        let route = route_ctx.route();
+
+        route
+            .tour
+            .all_activities()
+            .enumerate()
+            .filter(move |(_, activity)| get_sync(activity).is_some_and(|sync| sync.key == key))
+            .map(move |(activity_idx, activity)| {
+                let start = activity.schedule.arrival.max(activity.place.time.start);
+                let latest =
+                    route_ctx.state().get_latest_arrival_at(activity_idx).copied().unwrap_or(activity.place.time.end);
+
+                (&route.actor, start, latest)
+            })
+    })
+}
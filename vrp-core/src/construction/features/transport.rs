@@ -421,8 +421,9 @@ impl CostObjective {
         let (tp_cost_old, act_cost_old, dep_time_old) =
             self.analyze_route_leg(route_ctx, prev, next, prev.schedule.departure);
 
+        let actor = route_ctx.route().actor.as_ref();
         let waiting_cost = waiting_time.min(Float::default().max(dep_time_right - dep_time_old))
-            * route_ctx.route().actor.vehicle.costs.per_waiting_time;
+            * (actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time);
 
         let old_costs = tp_cost_old + act_cost_old + waiting_cost;
 
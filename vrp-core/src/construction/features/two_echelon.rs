@@ -0,0 +1,351 @@
+//! A feature to model two-echelon routing: first-level vehicles deliver goods from depot to satellites
+//! (intermediate facilities), second-level vehicles start from satellites and deliver goods to customers.
+//!
+//! Each satellite is modeled as a shared resource: its inventory is formed by static deliveries of
+//! supply jobs served by first-level vehicles and consumed by static deliveries of jobs served by
+//! second-level vehicles which start from it. Inventory timing is coupled across echelons: a second-level
+//! vehicle cannot start its shift before all supply jobs of its satellite are delivered.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/two_echelon_test.rs"]
+mod two_echelon_test;
+
+use super::*;
+use crate::construction::enablers::unassign_jobs;
+use crate::models::common::Timestamp;
+use crate::models::solution::Route;
+use std::collections::HashMap;
+
+custom_dimension!(pub JobSatellite typeof SharedResourceId);
+custom_dimension!(pub VehicleSatellite typeof SharedResourceId);
+custom_activity_state!(SatelliteLatestArrival typeof Timestamp);
+
+/// Creates a two-echelon feature as a hard constraint. Supply jobs are marked by [`JobSatelliteDimension`]
+/// and can be served only by first-level vehicles. Second-level vehicles are marked by [`VehicleSatelliteDimension`].
+pub fn create_two_echelon_feature<T: SharedResource>(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TwoEchelonConstraint::<T> {
+            code,
+            transport: transport.clone(),
+            activity: activity.clone(),
+            phantom: Default::default(),
+        })
+        .with_state(TwoEchelonState::<T> { code, transport, activity, phantom: Default::default() })
+        .build()
+}
+
+/// Keeps track of satellite usage in the solution.
+#[derive(Clone, Default)]
+struct SatelliteUsage<T: SharedResource> {
+    /// A total amount of goods delivered to the satellite by first-level vehicles.
+    supply: T,
+    /// A total amount of goods taken from the satellite by second-level vehicles.
+    demand: T,
+    /// A time when all supply jobs are delivered.
+    ready: Option<Timestamp>,
+    /// The earliest shift start among second-level vehicles which use the satellite.
+    deadline: Option<Timestamp>,
+}
+
+struct SatelliteUsageKey;
+
+struct TwoEchelonConstraint<T: SharedResource> {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: SharedResource> TwoEchelonConstraint<T> {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let satellite = route.actor.vehicle.dimens.get_vehicle_satellite()?;
+
+        if get_job_satellite(job).is_some() {
+            return ConstraintViolation::fail(self.code);
+        }
+
+        let usage = get_satellite_usage::<T>(solution_ctx).and_then(|usage| usage.get(satellite));
+        let supply = usage.map(|usage| usage.supply).unwrap_or_default();
+        let demand = usage.map(|usage| usage.demand).unwrap_or_default() + get_job_delivery::<T>(job);
+
+        let is_on_time = usage.and_then(|usage| usage.ready).is_none_or(|ready| get_shift_start(route) >= ready);
+
+        if is_on_time && supply.can_fit(&demand) { None } else { ConstraintViolation::fail(self.code) }
+    }
+
+    fn evaluate_activity(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        if route.actor.vehicle.dimens.get_vehicle_satellite().is_some() {
+            return None;
+        }
+
+        let deadline = activity_ctx
+            .target
+            .job
+            .as_ref()
+            .and_then(|single| single.dimens.get_job_satellite())
+            .and_then(|satellite| get_satellite_usage::<T>(solution_ctx).and_then(|usage| usage.get(satellite)))
+            .and_then(|usage| usage.deadline);
+        let latest_arrival_at_next = activity_ctx
+            .next
+            .and_then(|_| route_ctx.state().get_satellite_latest_arrival_at(activity_ctx.index + 1))
+            .copied();
+
+        if deadline.is_none() && latest_arrival_at_next.is_none() {
+            return None;
+        }
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let departure = self.activity.estimate_departure(route, target, arrival).unwrap_value();
+
+        if deadline.is_some_and(|deadline| departure > deadline) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        match (activity_ctx.next, latest_arrival_at_next) {
+            (Some(next), Some(latest_arrival)) => {
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(departure),
+                    );
+
+                if arrival > latest_arrival { ConstraintViolation::skip(self.code) } else { None }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<T: SharedResource> FeatureConstraint for TwoEchelonConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => self.evaluate_job(solution_ctx, route_ctx, job),
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                self.evaluate_activity(solution_ctx, route_ctx, activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if get_job_satellite(&source) == get_job_satellite(&candidate) { Ok(source) } else { Err(self.code) }
+    }
+}
+
+struct TwoEchelonState<T: SharedResource> {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: SharedResource> TwoEchelonState<T> {
+    /// Calculates satellite usage based on the whole solution and updates latest arrival times
+    /// in routes of first-level vehicles.
+    fn update_satellite_usage(&self, solution_ctx: &mut SolutionContext) {
+        let usage = calculate_satellite_usage::<T>(solution_ctx.routes.as_slice());
+
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.route().actor.vehicle.dimens.get_vehicle_satellite().is_none())
+            .for_each(|route_ctx| self.update_latest_arrivals(route_ctx, &usage));
+
+        solution_ctx.state.set_value::<SatelliteUsageKey, _>(usage);
+    }
+
+    /// Calculates latest arrival times which keep departures from supply activities not later
+    /// than the deadlines of their satellites.
+    fn update_latest_arrivals(
+        &self,
+        route_ctx: &mut RouteContext,
+        usage: &HashMap<SharedResourceId, SatelliteUsage<T>>,
+    ) {
+        let route = route_ctx.route();
+        let get_deadline = |idx: usize| {
+            route
+                .tour
+                .get(idx)
+                .and_then(|activity| activity.job.as_ref())
+                .and_then(|single| single.dimens.get_job_satellite())
+                .and_then(|satellite| usage.get(satellite))
+                .and_then(|usage| usage.deadline)
+        };
+
+        if !(0..route.tour.total()).any(|idx| get_deadline(idx).is_some()) {
+            if route_ctx.state().get_satellite_latest_arrival_at(0).is_some() {
+                route_ctx.state_mut().set_satellite_latest_arrival_states(vec![]);
+            }
+            return;
+        }
+
+        let mut latest_arrivals = vec![Float::MAX; route.tour.total()];
+        let mut latest_departure = Float::MAX;
+
+        for idx in (1..route.tour.total()).rev() {
+            let (prev, activity) = (route.tour.get(idx - 1).unwrap(), route.tour.get(idx).unwrap());
+            latest_departure = latest_departure.min(get_deadline(idx).unwrap_or(Float::MAX));
+
+            if latest_departure == Float::MAX {
+                continue;
+            }
+
+            let latest_arrival = self.activity.estimate_arrival(route, activity, latest_departure).unwrap_value();
+            latest_arrivals[idx] = latest_arrival;
+
+            latest_departure = latest_arrival
+                - self.transport.duration(
+                    route,
+                    prev.place.location,
+                    activity.place.location,
+                    TravelTime::Arrival(latest_arrival),
+                );
+        }
+
+        route_ctx.state_mut().set_satellite_latest_arrival_states(latest_arrivals);
+    }
+
+    /// Removes jobs served by second-level vehicles when satellite inventory is not enough or is not
+    /// ready in time. This might happen after ruin or departure time rescheduling.
+    fn remove_invalid_jobs(&self, solution_ctx: &mut SolutionContext) {
+        let usage = calculate_satellite_usage::<T>(solution_ctx.routes.as_slice());
+        let mut available =
+            usage.iter().map(|(satellite, usage)| (*satellite, usage.supply)).collect::<HashMap<_, _>>();
+
+        let jobs_to_remove = solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| {
+                let route = route_ctx.route();
+                route.actor.vehicle.dimens.get_vehicle_satellite().map(|satellite| (route, satellite))
+            })
+            .flat_map(|(route, satellite)| {
+                let ready = usage.get(satellite).and_then(|usage| usage.ready);
+                let is_on_time = ready.is_none_or(|ready| get_shift_start(route) >= ready);
+                let available = available.entry(*satellite).or_default();
+
+                route
+                    .tour
+                    .jobs()
+                    .filter(|job| !solution_ctx.locked.contains(*job))
+                    .filter(|job| {
+                        let demand = get_job_delivery::<T>(job);
+
+                        if is_on_time && available.can_fit(&demand) {
+                            *available = *available - demand;
+                            false
+                        } else {
+                            true
+                        }
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        unassign_jobs(solution_ctx, jobs_to_remove, self.code);
+    }
+}
+
+impl<T: SharedResource> FeatureState for TwoEchelonState<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        // NOTE any insertion might change satellite usage: either directly, or by shifting
+        //      supply activity schedules in routes of first-level vehicles
+        self.update_satellite_usage(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_invalid_jobs(solution_ctx);
+        self.update_satellite_usage(solution_ctx);
+    }
+}
+
+fn get_satellite_usage<T: SharedResource>(
+    solution_ctx: &SolutionContext,
+) -> Option<&HashMap<SharedResourceId, SatelliteUsage<T>>> {
+    solution_ctx.state.get_value::<SatelliteUsageKey, _>()
+}
+
+fn calculate_satellite_usage<T: SharedResource>(
+    routes: &[RouteContext],
+) -> HashMap<SharedResourceId, SatelliteUsage<T>> {
+    routes.iter().map(|route_ctx| route_ctx.route()).fold(HashMap::default(), |mut acc, route| {
+        match route.actor.vehicle.dimens.get_vehicle_satellite() {
+            Some(satellite) if route.tour.has_jobs() => {
+                let demand = route.tour.jobs().fold(T::default(), |acc, job| acc + get_job_delivery::<T>(job));
+                let shift_start = get_shift_start(route);
+
+                let usage = acc.entry(*satellite).or_default();
+                usage.demand = usage.demand + demand;
+                usage.deadline = Some(usage.deadline.map_or(shift_start, |deadline| deadline.min(shift_start)));
+            }
+            Some(_) => {}
+            None => route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.job.as_ref().map(|single| (activity, single)))
+                .filter_map(|(activity, single)| {
+                    single.dimens.get_job_satellite().map(|satellite| (activity, single, satellite))
+                })
+                .for_each(|(activity, single, satellite)| {
+                    let departure = activity.schedule.departure;
+
+                    let usage = acc.entry(*satellite).or_default();
+                    usage.supply = usage.supply + get_single_delivery::<T>(single);
+                    usage.ready = Some(usage.ready.map_or(departure, |ready| ready.max(departure)));
+                }),
+        }
+
+        acc
+    })
+}
+
+fn get_job_satellite(job: &Job) -> Option<SharedResourceId> {
+    match job {
+        Job::Single(single) => single.dimens.get_job_satellite().copied(),
+        Job::Multi(multi) => multi.jobs.iter().find_map(|single| single.dimens.get_job_satellite().copied()),
+    }
+}
+
+fn get_job_delivery<T: LoadOps>(job: &Job) -> T {
+    match job {
+        Job::Single(single) => get_single_delivery(single),
+        Job::Multi(multi) => multi.jobs.iter().fold(T::default(), |acc, single| acc + get_single_delivery(single)),
+    }
+}
+
+fn get_single_delivery<T: LoadOps>(single: &Single) -> T {
+    single.dimens.get_job_demand::<T>().map(|demand| demand.delivery.0).unwrap_or_default()
+}
+
+fn get_shift_start(route: &Route) -> Timestamp {
+    route.actor.detail.start.as_ref().and_then(|start| start.time.earliest).unwrap_or_default()
+}
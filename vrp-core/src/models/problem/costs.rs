@@ -2,6 +2,7 @@
 #[path = "../../../tests/unit/models/problem/costs_test.rs"]
 mod costs_test;
 
+use crate::construction::features::{get_latest_arrival, get_service_start};
 use crate::models::common::*;
 use crate::models::solution::{Activity, Route};
 use rosomaxa::prelude::{Float, GenericError, GenericResult};
@@ -25,7 +26,7 @@ pub trait ActivityCost: Send + Sync {
     fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
         let actor = route.actor.as_ref();
 
-        let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0. };
+        let waiting = get_service_start(route, activity, arrival) - arrival;
         let service = activity.place.duration;
 
         waiting * (actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time)
@@ -60,20 +61,20 @@ pub struct SimpleActivityCost {}
 impl ActivityCost for SimpleActivityCost {
     fn estimate_departure(
         &self,
-        _: &Route,
+        route: &Route,
         activity: &Activity,
         arrival: Timestamp,
     ) -> ControlFlow<Timestamp, Timestamp> {
-        ControlFlow::Continue(arrival.max(activity.place.time.start) + activity.place.duration)
+        ControlFlow::Continue(get_service_start(route, activity, arrival) + activity.place.duration)
     }
 
     fn estimate_arrival(
         &self,
-        _: &Route,
+        route: &Route,
         activity: &Activity,
         departure: Timestamp,
     ) -> ControlFlow<Timestamp, Timestamp> {
-        ControlFlow::Continue(activity.place.time.end.min(departure - activity.place.duration))
+        get_latest_arrival(route, activity, departure)
     }
 }
 
@@ -418,3 +419,208 @@ impl<T: TransportFallback> TransportCost for TimeAwareMatrixTransportCost<T> {
         self.size
     }
 }
+
+/// Specifies a piecewise-linear speed factor profile over time. A factor scales travel speed
+/// relative to free flow speed encoded in the base matrix: `0.5` means twice longer travel, `2` means
+/// twice faster. Factor is constant before the first and after the last point.
+///
+/// Travel duration is calculated by integrating speed over time, so the profile follows FIFO
+/// (no-overtaking) property: leaving later never results in an earlier arrival.
+#[derive(Clone, Debug)]
+pub struct SpeedProfile {
+    points: Vec<(Timestamp, Float)>,
+}
+
+impl SpeedProfile {
+    /// Creates a new instance of `SpeedProfile` from `(time, factor)` points.
+    pub fn new(points: Vec<(Timestamp, Float)>) -> GenericResult<Self> {
+        if points.is_empty() {
+            return Err("speed profile should have at least one point".into());
+        }
+
+        if points.iter().any(|(time, factor)| !time.is_finite() || !factor.is_finite() || *factor <= 0.) {
+            return Err("speed profile factors should be positive finite values".into());
+        }
+
+        if points.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            return Err("speed profile points should be sorted by time without duplicates".into());
+        }
+
+        Ok(Self { points })
+    }
+
+    /// Returns speed factor at given time.
+    pub fn factor(&self, time: Timestamp) -> Float {
+        let idx = self.points.partition_point(|(point_time, _)| *point_time <= time);
+
+        match idx {
+            0 => self.points.first().unwrap().1,
+            idx if idx == self.points.len() => self.points.last().unwrap().1,
+            idx => {
+                let (left_time, left_factor) = self.points[idx - 1];
+                let (right_time, right_factor) = self.points[idx];
+                let ratio = (time - left_time) / (right_time - left_time);
+
+                left_factor + ratio * (right_factor - left_factor)
+            }
+        }
+    }
+
+    /// Returns travel duration for given free flow duration when travel is started at `departure`.
+    pub fn duration_at_departure(&self, departure: Timestamp, free_flow: Duration) -> Duration {
+        let (mut time, mut remaining) = (departure, free_flow);
+
+        loop {
+            let idx = self.points.partition_point(|(point_time, _)| *point_time <= time);
+            let factor = self.factor(time);
+
+            let Some(&(next_time, next_factor)) = self.points.get(idx) else {
+                return time + remaining / factor - departure;
+            };
+
+            let span = next_time - time;
+            let area = span * (factor + next_factor) / 2.;
+
+            if area >= remaining {
+                return time + solve_travel_span(factor, (next_factor - factor) / span, remaining) - departure;
+            }
+
+            remaining -= area;
+            time = next_time;
+        }
+    }
+
+    /// Returns travel duration for given free flow duration when travel is finished at `arrival`.
+    pub fn duration_at_arrival(&self, arrival: Timestamp, free_flow: Duration) -> Duration {
+        let (mut time, mut remaining) = (arrival, free_flow);
+
+        loop {
+            let idx = self.points.partition_point(|(point_time, _)| *point_time < time);
+            let factor = self.factor(time);
+
+            let Some(&(prev_time, prev_factor)) = idx.checked_sub(1).and_then(|idx| self.points.get(idx)) else {
+                return arrival - time + remaining / factor;
+            };
+
+            let span = time - prev_time;
+            let area = span * (factor + prev_factor) / 2.;
+
+            if area >= remaining {
+                return arrival - time + solve_travel_span(factor, (prev_factor - factor) / span, remaining);
+            }
+
+            remaining -= area;
+            time = prev_time;
+        }
+    }
+}
+
+/// Solves `factor * x + slope * x^2 / 2 = area` equation for non-negative `x`.
+fn solve_travel_span(factor: Float, slope: Float, area: Float) -> Duration {
+    // NOTE use this form to avoid catastrophic cancellation when slope is close to zero
+    2. * area / (factor + (factor * factor + 2. * slope * area).max(0.).sqrt())
+}
+
+/// Contains time-dependent speed data for specific routing profile.
+pub struct SpeedProfileData {
+    /// A routing profile index.
+    pub index: usize,
+    /// Speed factor profiles, one per edge class.
+    pub profiles: Vec<SpeedProfile>,
+    /// Edge class (index in `profiles`) for each matrix entry. If omitted, the first profile is
+    /// applied to all edges.
+    pub edge_classes: Option<Vec<usize>>,
+}
+
+impl SpeedProfileData {
+    /// Creates `SpeedProfileData` instance.
+    pub fn new(index: usize, profiles: Vec<SpeedProfile>, edge_classes: Option<Vec<usize>>) -> Self {
+        Self { index, profiles, edge_classes }
+    }
+
+    /// Returns speed profile for given matrix entry.
+    pub fn get_profile(&self, matrix_idx: usize) -> Option<&SpeedProfile> {
+        let class = self.edge_classes.as_ref().map_or(Some(0), |classes| classes.get(matrix_idx).copied())?;
+
+        self.profiles.get(class)
+    }
+}
+
+/// Creates time dependent routing costs which use a single matrix per profile with free flow
+/// durations and piecewise-linear speed profiles applied on top of them.
+/// Panics at runtime if given route path is not present in matrix data.
+pub fn create_speed_profile_transport_cost(
+    costs: Vec<MatrixData>,
+    speeds: Vec<SpeedProfileData>,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    create_speed_profile_transport_cost_with_fallback(costs, speeds, NoFallback)
+}
+
+/// Creates time dependent routing costs based on speed profiles using a fallback function for unknown route.
+pub fn create_speed_profile_transport_cost_with_fallback<T: TransportFallback + 'static>(
+    costs: Vec<MatrixData>,
+    speeds: Vec<SpeedProfileData>,
+    fallback: T,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    if costs.is_empty() {
+        return Err("no matrix data found".into());
+    }
+
+    let size = (costs.first().unwrap().durations.len() as Float).sqrt().round() as usize;
+
+    if speeds.iter().any(|data| data.profiles.is_empty()) {
+        return Err("speed profile data should have at least one profile".into());
+    }
+
+    if speeds.iter().filter_map(|data| data.edge_classes.as_ref()).any(|classes| classes.len() != size * size) {
+        return Err("edge classes length does not match matrix size".into());
+    }
+
+    if speeds.iter().any(|data| {
+        data.edge_classes.as_ref().is_some_and(|classes| classes.iter().any(|&class| class >= data.profiles.len()))
+    }) {
+        return Err("edge class refers to unknown speed profile".into());
+    }
+
+    let speeds = speeds.into_iter().map(|data| (data.index, data)).collect::<HashMap<_, _>>();
+    let inner = TimeAgnosticMatrixTransportCost::new(costs, size, fallback)?;
+
+    Ok(Arc::new(SpeedProfileTransportCost { inner, speeds }))
+}
+
+/// A time dependent matrix costs defined by speed profiles.
+struct SpeedProfileTransportCost<T: TransportFallback> {
+    inner: TimeAgnosticMatrixTransportCost<T>,
+    speeds: HashMap<usize, SpeedProfileData>,
+}
+
+impl<T: TransportFallback> TransportCost for SpeedProfileTransportCost<T> {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let profile = &route.actor.vehicle.profile;
+        let free_flow = self.inner.duration_approx(profile, from, to);
+
+        match self.speeds.get(&profile.index).and_then(|data| data.get_profile(from * self.inner.size + to)) {
+            Some(speed) if free_flow > 0. => match travel_time {
+                TravelTime::Departure(departure) => speed.duration_at_departure(departure, free_flow),
+                TravelTime::Arrival(arrival) => speed.duration_at_arrival(arrival, free_flow),
+            },
+            _ => free_flow,
+        }
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.inner.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn size(&self) -> usize {
+        self.inner.size
+    }
+}
@@ -11,6 +11,7 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 custom_dimension!(pub VehicleId typeof String);
+custom_dimension!(pub DriverId typeof String);
 
 /// Represents operating costs for driver and vehicle.
 #[derive(Clone, Debug)]
@@ -27,12 +28,16 @@ pub struct Costs {
     pub per_service_time: Float,
 }
 
-/// Represents driver detail (reserved for future use).
-#[derive(Clone, Hash, Eq, PartialEq)]
-pub struct DriverDetail {}
+/// Represents driver detail (driver shift).
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct DriverDetail {
+    /// Time interval when driver is allowed to work.
+    pub time: TimeInterval,
+}
 
 /// Represents a driver, person who drives a [`Vehicle`].
-/// Reserved for future usage, e.g., to allow reusing the same vehicle more than once at different times.
+/// A driver without details is not considered as a limited resource: it is paired with every vehicle.
+/// Otherwise, each driver detail (shift) can be used by at most one actor in the solution.
 pub struct Driver {
     /// Specifies operating costs for a driver.
     pub costs: Costs,
@@ -146,6 +151,9 @@ pub struct Fleet {
 
     /// A grouped actors.
     pub groups: HashMap<usize, HashSet<Arc<Actor>>>,
+
+    /// Actors which share the same vehicle or driver shift and cannot be used simultaneously.
+    pub(crate) conflicts: Arc<HashMap<Arc<Actor>, Vec<Arc<Actor>>>>,
 }
 
 impl Fleet {
@@ -155,8 +163,7 @@ impl Fleet {
         vehicles: Vec<Arc<Vehicle>>,
         group_key: impl Fn(&[Arc<Actor>]) -> R,
     ) -> Fleet {
-        // TODO we should also consider multiple drivers to support smart vehicle-driver assignment.
-        assert_eq!(drivers.len(), 1);
+        assert!(!drivers.is_empty());
         assert!(!vehicles.is_empty());
 
         let profiles: HashMap<usize, Profile> = vehicles.iter().map(|v| (v.profile.index, v.profile.clone())).collect();
@@ -164,25 +171,39 @@ impl Fleet {
         profiles.sort_by_key(|(a, _)| *a);
         let (_, profiles): (Vec<_>, Vec<_>) = profiles.into_iter().unzip();
 
-        let actors = vehicles
+        let (actors, resources): (Vec<_>, Vec<_>) = vehicles
             .iter()
-            .flat_map(|vehicle| {
-                vehicle.details.iter().map(|detail| {
-                    Arc::new(Actor {
-                        vehicle: vehicle.clone(),
-                        driver: drivers.first().unwrap().clone(),
-                        detail: ActorDetail {
-                            start: detail.start.clone(),
-                            end: detail.end.clone(),
-                            time: TimeWindow {
-                                start: detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
-                                end: detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(Float::MAX),
-                            },
-                        },
+            .enumerate()
+            .flat_map(|(vehicle_idx, vehicle)| {
+                vehicle
+                    .details
+                    .iter()
+                    .enumerate()
+                    .map(move |(detail_idx, detail)| (vehicle_idx, vehicle, detail_idx, detail))
+            })
+            .flat_map(|(vehicle_idx, vehicle, vehicle_detail_idx, vehicle_detail)| {
+                drivers.iter().enumerate().flat_map(move |(driver_idx, driver)| {
+                    let driver_details = if driver.details.is_empty() {
+                        vec![None]
+                    } else {
+                        driver.details.iter().enumerate().map(Some).collect()
+                    };
+
+                    driver_details.into_iter().filter_map(move |driver_detail| {
+                        let detail = create_actor_detail(vehicle_detail, driver_detail.map(|(_, detail)| detail))?;
+                        let actor = Arc::new(Actor { vehicle: vehicle.clone(), driver: driver.clone(), detail });
+
+                        let vehicle_resource = ActorResource::Vehicle(vehicle_idx, vehicle_detail_idx);
+                        let driver_resource = driver_detail
+                            .map(|(driver_detail_idx, _)| ActorResource::Driver(driver_idx, driver_detail_idx));
+
+                        Some((actor, (vehicle_resource, driver_resource)))
                     })
                 })
             })
-            .collect::<Vec<_>>();
+            .unzip();
+
+        let conflicts = Arc::new(create_actor_conflicts(&actors, &resources));
 
         let group_key = (group_key)(&actors);
         let groups: HashMap<_, HashSet<_>> = actors.iter().cloned().fold(HashMap::new(), |mut acc, actor| {
@@ -190,10 +211,78 @@ impl Fleet {
             acc
         });
 
-        Fleet { drivers, vehicles, profiles, actors, groups }
+        Fleet { drivers, vehicles, profiles, actors, groups, conflicts }
     }
 }
 
+/// Specifies a resource exclusively used by an actor.
+#[derive(Clone, Copy, Hash, Eq, PartialEq)]
+enum ActorResource {
+    Vehicle(usize, usize),
+    Driver(usize, usize),
+}
+
+/// Creates actor detail from vehicle and, optionally, driver details.
+/// Returns `None` if vehicle and driver have no time intersection.
+fn create_actor_detail(vehicle_detail: &VehicleDetail, driver_detail: Option<&DriverDetail>) -> Option<ActorDetail> {
+    let time = TimeWindow {
+        start: vehicle_detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
+        end: vehicle_detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(Float::MAX),
+    };
+
+    let Some(driver_detail) = driver_detail else {
+        return Some(ActorDetail { start: vehicle_detail.start.clone(), end: vehicle_detail.end.clone(), time });
+    };
+
+    let time = TimeWindow {
+        start: time.start.max(driver_detail.time.earliest.unwrap_or(0.)),
+        end: time.end.min(driver_detail.time.latest.unwrap_or(Float::MAX)),
+    };
+
+    if time.start > time.end {
+        return None;
+    }
+
+    let start = vehicle_detail.start.clone().map(|mut start| {
+        start.time.earliest = Some(time.start);
+        start.time.latest = start.time.latest.map(|latest| latest.max(time.start).min(time.end));
+        start
+    });
+    let end = vehicle_detail.end.clone().map(|mut end| {
+        end.time.latest = Some(time.end);
+        end
+    });
+
+    Some(ActorDetail { start, end, time })
+}
+
+/// Creates a map of actors which share the same resources.
+fn create_actor_conflicts(
+    actors: &[Arc<Actor>],
+    resources: &[(ActorResource, Option<ActorResource>)],
+) -> HashMap<Arc<Actor>, Vec<Arc<Actor>>> {
+    let resource_map = actors.iter().zip(resources.iter()).fold(
+        HashMap::<ActorResource, Vec<Arc<Actor>>>::new(),
+        |mut acc, (actor, (vehicle_resource, driver_resource))| {
+            acc.entry(*vehicle_resource).or_default().push(actor.clone());
+            if let Some(driver_resource) = driver_resource {
+                acc.entry(*driver_resource).or_default().push(actor.clone());
+            }
+            acc
+        },
+    );
+
+    resource_map.into_values().filter(|actors| actors.len() > 1).fold(HashMap::new(), |mut acc, actors| {
+        actors.iter().for_each(|actor| {
+            let conflicts: &mut Vec<Arc<Actor>> = acc.entry(actor.clone()).or_default();
+            conflicts.extend(actors.iter().filter(|other| *other != actor).cloned());
+            conflicts.sort_by_key(Arc::as_ptr);
+            conflicts.dedup();
+        });
+        acc
+    })
+}
+
 impl Debug for Fleet {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(short_type_name::<Self>())
@@ -8,10 +8,14 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Specifies an entity responsible for providing actors and keeping track of their usage.
+/// Actors which share the same vehicle or driver shift are mutually exclusive: once one of them is
+/// used, others become unavailable.
 pub struct Registry {
     available: HashMap<usize, HashSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
     all: Vec<Arc<Actor>>,
+    used: HashSet<Arc<Actor>>,
+    conflicts: Arc<HashMap<Arc<Actor>, Vec<Arc<Actor>>>>,
     random: Arc<dyn Random>,
 }
 
@@ -24,19 +28,56 @@ impl Registry {
             .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
             .collect();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        Self {
+            available: fleet.groups.clone(),
+            index,
+            all: fleet.actors.to_vec(),
+            used: HashSet::default(),
+            conflicts: fleet.conflicts.clone(),
+            random,
+        }
     }
 
     /// Removes an actor from the list of available actors.
     /// Returns whether the actor was present in the registry.
     pub fn use_actor(&mut self, actor: &Actor) -> bool {
-        self.index.get(actor).and_then(|idx| self.available.get_mut(idx)).is_some_and(|set| set.remove(actor))
+        let is_removed = self.remove_available(actor);
+
+        if is_removed {
+            if let Some(actor) = self.index.get_key_value(actor).map(|(actor, _)| actor.clone()) {
+                self.used.insert(actor);
+            }
+
+            let conflicts = self.conflicts.clone();
+            conflicts.get(actor).iter().flat_map(|actors| actors.iter()).for_each(|other| {
+                self.remove_available(other);
+            });
+        }
+
+        is_removed
     }
 
     /// Adds actor to the list of available actors.
     /// Returns whether the actor was not present in the registry.
     pub fn free_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.index.get(actor).and_then(|idx| self.available.get_mut(idx)).is_some_and(|set| set.insert(actor.clone()))
+        self.used.remove(actor);
+
+        if !self.has_no_used_conflicts(actor) {
+            return false;
+        }
+
+        let is_inserted = self.insert_available(actor);
+
+        if is_inserted {
+            let conflicts = self.conflicts.clone();
+            conflicts.get(actor).iter().flat_map(|actors| actors.iter()).for_each(|other| {
+                if self.has_no_used_conflicts(other) {
+                    self.insert_available(other);
+                }
+            });
+        }
+
+        is_inserted
     }
 
     /// Returns all actors.
@@ -64,6 +105,8 @@ impl Registry {
             available: self.available.clone(),
             index: self.index.clone(),
             all: self.all.clone(),
+            used: self.used.clone(),
+            conflicts: self.conflicts.clone(),
             random: self.random.clone(),
         }
     }
@@ -86,7 +129,21 @@ impl Registry {
                 .map(|(actor, idx)| (actor.clone(), *idx))
                 .collect(),
             all: self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            used: self.used.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            conflicts: self.conflicts.clone(),
             random: self.random.clone(),
         }
     }
+
+    fn remove_available(&mut self, actor: &Actor) -> bool {
+        self.index.get(actor).and_then(|idx| self.available.get_mut(idx)).is_some_and(|set| set.remove(actor))
+    }
+
+    fn insert_available(&mut self, actor: &Arc<Actor>) -> bool {
+        self.index.get(actor).and_then(|idx| self.available.get_mut(idx)).is_some_and(|set| set.insert(actor.clone()))
+    }
+
+    fn has_no_used_conflicts(&self, actor: &Actor) -> bool {
+        self.conflicts.get(actor).is_none_or(|actors| actors.iter().all(|other| !self.used.contains(other)))
+    }
 }
@@ -63,12 +63,13 @@ pub struct VrpConfigBuilder {
     environment: Option<Arc<Environment>>,
     heuristic: Option<TargetHeuristic>,
     telemetry_mode: Option<TelemetryMode>,
+    cancellation: Option<CancellationToken>,
 }
 
 impl VrpConfigBuilder {
     /// Creates a new instance of `VrpConfigBuilder`.
     pub fn new(problem: Arc<Problem>) -> Self {
-        Self { problem, environment: None, heuristic: None, telemetry_mode: None }
+        Self { problem, environment: None, heuristic: None, telemetry_mode: None, cancellation: None }
     }
 
     /// Sets [Environment] instance to be used.
@@ -83,6 +84,13 @@ impl VrpConfigBuilder {
         self
     }
 
+    /// Sets [CancellationToken] which can be used to stop the search from another thread.
+    /// When cancelled, the solver returns the best known solution.
+    pub fn set_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
     /// Sets [TargetHeuristic] to be used.
     /// By default, it is used what is returned by [get_default_heuristic].
     pub fn set_heuristic(mut self, heuristic: TargetHeuristic) -> Self {
@@ -94,6 +102,10 @@ impl VrpConfigBuilder {
     pub fn prebuild(self) -> GenericResult<ProblemConfigBuilder> {
         let problem = self.problem;
         let environment = self.environment.unwrap_or_else(|| Arc::new(Environment::default()));
+        let environment = match self.cancellation {
+            Some(token) => Arc::new(environment.as_ref().clone().with_cancellation(token)),
+            None => environment,
+        };
         let telemetry_mode =
             self.telemetry_mode.unwrap_or_else(|| get_default_telemetry_mode(environment.logger.clone()));
 
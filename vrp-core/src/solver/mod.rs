@@ -163,6 +163,10 @@ impl HeuristicContext for RefinementContext {
         self.inner_context.environment()
     }
 
+    fn set_observer(&mut self, observer: TelemetryObserver<Self::Solution>) {
+        self.inner_context.set_observer(observer)
+    }
+
     fn on_initial(&mut self, mut solution: Self::Solution, name: &str, item_time: Timer) {
         self.initial_footprint.add(&Shadow::from(&solution));
         solution.solution.state.set_footprint(self.initial_footprint.clone());
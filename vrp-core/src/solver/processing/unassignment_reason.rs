@@ -27,34 +27,52 @@ impl HeuristicSolutionProcessing for UnassignmentReason {
                 leg_selection: &leg_selection,
                 result_selector: &result_selector,
             };
-            let details = insertion_ctx
-                .solution
-                .routes
-                .iter()
-                .filter_map(|route_ctx| {
-                    (0..route_ctx.route().tour.legs().count())
-                        .map(|leg_idx| {
-                            eval_job_insertion_in_route(
-                                &insertion_ctx,
-                                &eval_ctx,
-                                route_ctx,
-                                InsertionPosition::Concrete(leg_idx),
-                                InsertionResult::make_failure(),
-                            )
-                        })
-                        .filter_map(|result| match result {
-                            InsertionResult::Failure(failure) => Some(failure),
-                            _ => None,
-                        })
-                        .collect_group_by_key(|code| code.constraint)
-                        .into_iter()
-                        // NOTE: pick only the most frequent reason
-                        .max_by(|(_, a), (_, b)| a.len().cmp(&b.len()))
-                        .map(|(code, _)| (route_ctx.route().actor.clone(), code))
-                })
-                .collect::<Vec<_>>();
+            let get_details = |route_ctx: &RouteContext| {
+                (0..route_ctx.route().tour.legs().count())
+                    .map(|leg_idx| {
+                        eval_job_insertion_in_route(
+                            &insertion_ctx,
+                            &eval_ctx,
+                            route_ctx,
+                            InsertionPosition::Concrete(leg_idx),
+                            InsertionResult::make_failure(),
+                        )
+                    })
+                    .filter_map(|result| match result {
+                        InsertionResult::Failure(failure) => Some(failure),
+                        _ => None,
+                    })
+                    .collect_group_by_key(|code| code.constraint)
+                    .into_iter()
+                    // NOTE: pick only the most frequent reason
+                    .max_by(|(_, a), (_, b)| a.len().cmp(&b.len()))
+                    .map(|(code, _)| (route_ctx.route().actor.clone(), code))
+            };
+
+            let details = insertion_ctx.solution.routes.iter().filter_map(get_details).collect::<Vec<_>>();
 
-            let code = if details.is_empty() { code } else { UnassignmentInfo::Detailed(details) };
+            let is_unknown = match &code {
+                UnassignmentInfo::Simple(code) => code.is_unknown(),
+                UnassignmentInfo::Unknown => true,
+                UnassignmentInfo::Detailed(_) => false,
+            };
+
+            let code = if !details.is_empty() {
+                UnassignmentInfo::Detailed(details)
+            } else if is_unknown {
+                // NOTE: no route gives a reason (e.g. there are none), so check routes of unused actors
+                insertion_ctx
+                    .solution
+                    .registry
+                    .next_route()
+                    .filter_map(get_details)
+                    .collect_group_by_key(|(_, code)| *code)
+                    .into_iter()
+                    .max_by(|(_, a), (_, b)| a.len().cmp(&b.len()))
+                    .map_or(code, |(code, _)| UnassignmentInfo::Simple(code))
+            } else {
+                code
+            };
 
             (job, code)
         });
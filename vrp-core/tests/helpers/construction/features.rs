@@ -1,4 +1,15 @@
+use crate::construction::enablers::update_route_schedule;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{
+    FleetBuilder, TestActivityCost, TestTransportCost, test_driver, test_vehicle_with_id,
+};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::Feature;
 use crate::models::common::{Demand, MultiDimLoad, SingleDimLoad};
+use crate::models::problem::Single;
+use crate::models::solution::Activity;
+use std::sync::Arc;
 
 pub fn create_simple_demand(size: i32) -> Demand<SingleDimLoad> {
     if size > 0 {
@@ -35,3 +46,39 @@ pub fn single_demand_as_multi(pickup: (i32, i32), delivery: (i32, i32)) -> Deman
 
     Demand { pickup: (make(pickup.0), make(pickup.1)), delivery: (make(delivery.0), make(delivery.1)) }
 }
+
+/// Creates an activity which serves given single job at its first place and time window.
+pub fn create_activity_for_single(single: Arc<Single>) -> Activity {
+    let place = &single.places[0];
+    let (location, duration) = (place.location.unwrap(), place.duration);
+    let time = place.times[0].as_time_window().unwrap();
+
+    ActivityBuilder::with_location_tw_and_duration(location, time, duration).job(Some(single)).build()
+}
+
+/// Creates a solution with two routes: each vehicle serves given jobs in the given order.
+pub fn create_two_routes_solution_ctx(feature: &Feature, routes: Vec<Vec<Arc<Single>>>) -> SolutionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+
+    let routes = ["v1", "v2"]
+        .iter()
+        .zip(routes)
+        .map(|(vehicle_id, singles)| {
+            let activities = singles.into_iter().map(create_activity_for_single);
+            let mut route_ctx: RouteContext = RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(&fleet, vehicle_id).add_activities(activities).build())
+                .build();
+            update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+            if let Some(state) = feature.state.as_ref() {
+                state.accept_route_state(&mut route_ctx);
+            }
+
+            route_ctx
+        })
+        .collect();
+
+    TestInsertionContextBuilder::default().with_routes(routes).build().solution
+}
@@ -0,0 +1,133 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature() -> Feature {
+    create_alternative_feature("alternative", VIOLATION_CODE).unwrap()
+}
+
+fn create_job(alternative: Option<&str>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(key) = alternative {
+        builder.dimens_mut().set_job_alternative(JobAlternative { key: key.to_string(), is_optional: false });
+    }
+
+    Job::Single(builder.build_shared())
+}
+
+/// Creates a solution with two routes: each vehicle serves given jobs.
+fn create_solution_ctx(feature: &Feature, routes: Vec<Vec<Job>>) -> SolutionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+
+    let routes = ["v1", "v2"]
+        .iter()
+        .zip(routes)
+        .map(|(vehicle_id, jobs)| {
+            let activities = jobs
+                .into_iter()
+                .map(|job| ActivityBuilder::with_location(1).job(Some(job.to_single().clone())).build());
+            let mut route_ctx = RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(&fleet, vehicle_id).add_activities(activities).build())
+                .build();
+            feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+            route_ctx
+        })
+        .collect();
+
+    TestInsertionContextBuilder::default().with_routes(routes).build().solution
+}
+
+parameterized_test! {can_evaluate_route_insertion, (assigned, target, expected), {
+    can_evaluate_route_insertion_impl(assigned, target, expected);
+}}
+
+can_evaluate_route_insertion! {
+    case01_same_set_same_route: ((Some("set1"), None), Some("set1"), Some(VIOLATION_CODE)),
+    case02_same_set_other_route: ((None, Some("set1")), Some("set1"), Some(VIOLATION_CODE)),
+    case03_different_set: ((Some("set1"), Some("set2")), Some("set3"), None),
+    case04_no_set: ((Some("set1"), None), None, None),
+    case05_nothing_assigned: ((None, None), Some("set1"), None),
+}
+
+fn can_evaluate_route_insertion_impl(
+    assigned: (Option<&str>, Option<&str>),
+    target: Option<&str>,
+    expected: Option<ViolationCode>,
+) {
+    let feature = create_feature();
+    let solution_ctx = create_solution_ctx(&feature, vec![vec![create_job(assigned.0)], vec![create_job(assigned.1)]]);
+    let job = create_job(target);
+
+    let result = feature
+        .constraint
+        .as_ref()
+        .unwrap()
+        .evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job))
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case01_no_sets: (None, None, Ok(())),
+    case02_same_set: (Some("set1"), Some("set1"), Err(VIOLATION_CODE)),
+    case03_source_set: (Some("set1"), None, Err(VIOLATION_CODE)),
+    case04_candidate_set: (None, Some("set1"), Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(source: Option<&str>, candidate: Option<&str>, expected: Result<(), ViolationCode>) {
+    let feature = create_feature();
+
+    let result = feature.constraint.as_ref().unwrap().merge(create_job(source), create_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_ignore_other_jobs_of_assigned_set() {
+    let feature = create_feature();
+    let (assigned, required, unassigned, other) =
+        (create_job(Some("set1")), create_job(Some("set1")), create_job(Some("set1")), create_job(Some("set2")));
+    let mut solution_ctx = create_solution_ctx(&feature, vec![vec![assigned.clone()], vec![]]);
+    solution_ctx.required.extend([required.clone(), other.clone()]);
+    solution_ctx.unassigned.insert(unassigned.clone(), UnassignmentInfo::Unknown);
+
+    feature.state.as_ref().unwrap().accept_insertion(&mut solution_ctx, 0, &assigned);
+
+    assert_eq!(solution_ctx.required, vec![other]);
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(solution_ctx.ignored.len(), 2);
+    assert!(solution_ctx.ignored.contains(&required));
+    assert!(solution_ctx.ignored.contains(&unassigned));
+}
+
+#[test]
+fn can_promote_ignored_jobs_when_set_is_not_assigned() {
+    let feature = create_feature();
+    let state = feature.state.as_ref().unwrap();
+    let (assigned, ignored) = (create_job(Some("set1")), create_job(Some("set1")));
+    let mut solution_ctx = create_solution_ctx(&feature, vec![vec![assigned.clone()], vec![]]);
+    solution_ctx.required.push(ignored.clone());
+    state.accept_solution_state(&mut solution_ctx);
+    assert_eq!(solution_ctx.ignored, vec![ignored.clone()]);
+
+    solution_ctx.routes[0].route_mut().tour.remove(&assigned);
+    solution_ctx.required.push(assigned.clone());
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.ignored.is_empty());
+    assert_eq!(solution_ctx.required.len(), 2);
+    assert!(solution_ctx.required.contains(&assigned));
+    assert!(solution_ctx.required.contains(&ignored));
+}
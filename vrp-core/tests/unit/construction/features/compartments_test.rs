@@ -0,0 +1,184 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+use crate::models::problem::Vehicle;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+type CompartmentData<'a> = (i32, Option<Vec<&'a str>>, Vec<Vec<&'a str>>);
+
+fn create_test_vehicle(compartments: Vec<CompartmentData>) -> Vehicle {
+    let compartments = compartments
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (capacity, products, incompatible))| Compartment {
+            id: format!("c{idx}"),
+            capacity: SingleDimLoad::new(capacity),
+            products: products.map(|products| products.into_iter().map(|p| p.to_string()).collect()),
+            incompatible: incompatible
+                .into_iter()
+                .map(|group| group.into_iter().map(|p| p.to_string()).collect())
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").capacity(100);
+    builder.dimens_mut().set_vehicle_compartments(compartments);
+
+    builder.build()
+}
+
+fn create_test_activity(demand: i32, product: Option<&str>) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    builder.demand(create_simple_demand(demand));
+    if let Some(product) = product {
+        builder.dimens_mut().set_job_product(product.to_string());
+    }
+
+    ActivityBuilder::default().job(Some(builder.build_shared())).build()
+}
+
+fn create_route_ctx(vehicle: Vehicle, activities: Vec<Activity>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+
+    RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build()
+}
+
+parameterized_test! {can_assign_jobs_to_compartments, (compartments, jobs, expected), {
+    can_assign_jobs_to_compartments_impl(compartments, jobs, expected);
+}}
+
+can_assign_jobs_to_compartments! {
+    case01_fits_first: (vec![(5, None, vec![]), (5, None, vec![])], vec![(-2, None), (-3, None)], Some(vec![0, 0])),
+    case02_uses_second: (vec![(5, None, vec![]), (5, None, vec![])], vec![(-3, None), (-3, None)], Some(vec![0, 1])),
+    case03_too_much: (vec![(5, None, vec![]), (5, None, vec![])], vec![(-3, None), (-3, None), (-3, None)], None),
+    case04_pickup_after_delivery: (vec![(5, None, vec![])], vec![(-5, None), (5, None)], Some(vec![0, 0])),
+    case05_allowed_products: (
+        vec![(10, Some(vec!["diesel"]), vec![]), (10, Some(vec!["petrol"]), vec![])],
+        vec![(-2, Some("petrol")), (-2, Some("diesel"))],
+        Some(vec![1, 0])
+    ),
+    case06_product_not_allowed: (vec![(10, Some(vec!["diesel"]), vec![])], vec![(-2, Some("petrol"))], None),
+    case07_no_product_with_restriction: (vec![(10, Some(vec!["diesel"]), vec![])], vec![(-2, None)], None),
+    case08_incompatible_products: (
+        vec![(10, None, vec![vec!["meat", "fish"]]), (10, None, vec![])],
+        vec![(-2, Some("meat")), (-2, Some("fish")), (-2, Some("meat"))],
+        Some(vec![0, 1, 0])
+    ),
+}
+
+fn can_assign_jobs_to_compartments_impl(
+    compartments: Vec<CompartmentData>,
+    jobs: Vec<(i32, Option<&str>)>,
+    expected: Option<Vec<usize>>,
+) {
+    let activities = jobs.into_iter().map(|(demand, product)| create_test_activity(demand, product)).collect();
+    let route_ctx = create_route_ctx(create_test_vehicle(compartments), activities);
+
+    let result = get_compartment_assignment::<SingleDimLoad>(route_ctx.route());
+
+    assert_eq!(result.map(|assignment| assignment.into_iter().flatten().collect::<Vec<_>>()), expected);
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (compartments, jobs, target, expected), {
+    can_evaluate_activity_insertion_impl(compartments, jobs, target, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_fits: (vec![(5, None, vec![]), (5, None, vec![])], vec![(-3, None), (-3, None)], (-2, None), None),
+    case02_not_fits: (vec![(5, None, vec![]), (5, None, vec![])], vec![(-3, None), (-3, None)], (-3, None), Some(false)),
+    case03_incompatible: (
+        vec![(10, None, vec![vec!["meat", "fish"]])],
+        vec![(-2, Some("meat"))],
+        (-2, Some("fish")),
+        Some(false)
+    ),
+    case04_no_demand: (vec![(5, None, vec![])], vec![(-5, None)], (0, None), None),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    compartments: Vec<CompartmentData>,
+    jobs: Vec<(i32, Option<&str>)>,
+    target: (i32, Option<&str>),
+    expected: Option<bool>,
+) {
+    let activities = jobs.into_iter().map(|(demand, product)| create_test_activity(demand, product)).collect();
+    let route_ctx = create_route_ctx(create_test_vehicle(compartments), activities);
+    let target = create_test_activity(target.0, target.1);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let constraint =
+        create_compartments_feature::<SingleDimLoad>("compartments", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected.map(|stopped| ConstraintViolation { code: VIOLATION_CODE, stopped }));
+}
+
+#[test]
+fn can_reject_job_with_product_not_allowed_in_any_compartment() {
+    let route_ctx = create_route_ctx(create_test_vehicle(vec![(10, Some(vec!["diesel"]), vec![])]), vec![]);
+    let job = Job::Single(create_test_activity(-1, Some("petrol")).job.unwrap());
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let constraint =
+        create_compartments_feature::<SingleDimLoad>("compartments", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(result, ConstraintViolation::fail(VIOLATION_CODE));
+}
+
+parameterized_test! {can_evaluate_activity_insertion_with_route_state, (compartments, jobs, target), {
+    can_evaluate_activity_insertion_with_route_state_impl(compartments, jobs, target);
+}}
+
+can_evaluate_activity_insertion_with_route_state! {
+    case01_capacity: (vec![(5, None, vec![]), (5, None, vec![])], vec![(-3, None), (-2, None), (3, None)], (-2, None)),
+    case02_pickup: (vec![(5, None, vec![]), (4, None, vec![])], vec![(-4, None), (2, None), (-3, None)], (3, None)),
+    case03_products: (
+        vec![(10, None, vec![vec!["meat", "fish"]]), (10, Some(vec!["fish"]), vec![])],
+        vec![(-2, Some("meat")), (-2, Some("fish")), (-7, Some("fish"))],
+        (-2, Some("fish"))
+    ),
+}
+
+fn can_evaluate_activity_insertion_with_route_state_impl(
+    compartments: Vec<CompartmentData>,
+    jobs: Vec<(i32, Option<&str>)>,
+    target: (i32, Option<&str>),
+) {
+    let activities = jobs.into_iter().map(|(demand, product)| create_test_activity(demand, product)).collect();
+    let route_ctx = create_route_ctx(create_test_vehicle(compartments), activities);
+    let target = create_test_activity(target.0, target.1);
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let feature = create_compartments_feature::<SingleDimLoad>("compartments", VIOLATION_CODE).unwrap();
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    let mut cached_route_ctx = route_ctx.deep_copy();
+    state.accept_route_state(&mut cached_route_ctx);
+    assert!(cached_route_ctx.state().get_compartment_plan::<SingleDimLoad>().is_some());
+
+    (0..route_ctx.route().tour.total() - 1).for_each(|index| {
+        let evaluate = |route_ctx: &RouteContext| {
+            let activity_ctx = ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            };
+            constraint.evaluate(&MoveContext::activity(&solution_ctx, route_ctx, &activity_ctx))
+        };
+
+        assert_eq!(evaluate(&cached_route_ctx), evaluate(&route_ctx), "index: {index}");
+    });
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+use crate::models::problem::Fleet;
+
+fn create_consumption(drag: Float) -> FuelConsumption {
+    FuelConsumption { empty: 1., full: 2., drag, co2: 2. }
+}
+
+fn create_fleet(consumption: Option<FuelConsumption>) -> Fleet {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").capacity(10);
+    if let Some(consumption) = consumption {
+        builder.dimens_mut().set_vehicle_fuel_consumption(consumption);
+    }
+
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build()
+}
+
+fn create_route_ctx(fleet: &Fleet, loads: Vec<i32>) -> RouteContext {
+    let activities = [10, 20].into_iter().map(|location| ActivityBuilder::with_location(location).build());
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(fleet, "v1").add_activities(activities).build())
+        .build();
+
+    route_ctx.state_mut().set_current_capacity_states(loads.into_iter().map(SingleDimLoad::new).collect());
+    create_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn create_feature() -> Feature {
+    create_emissions_feature::<SingleDimLoad>("emissions", TestTransportCost::new_shared()).unwrap()
+}
+
+parameterized_test! {can_get_fuel, (distance, duration, load_ratio, drag, expected), {
+    can_get_fuel_impl(distance, duration, load_ratio, drag, expected);
+}}
+
+can_get_fuel! {
+    case01_empty: (10., 10., 0., 0., 10.),
+    case02_full: (10., 10., 1., 0., 20.),
+    case03_half: (10., 10., 0.5, 0., 15.),
+    case04_overloaded: (10., 10., 2., 0., 20.),
+    case05_drag: (10., 5., 0., 0.5, 30.),
+    case06_zero_duration: (10., 0., 0., 0.5, 10.),
+}
+
+fn can_get_fuel_impl(distance: Distance, duration: Duration, load_ratio: Float, drag: Float, expected: Float) {
+    let consumption = create_consumption(drag);
+
+    assert_eq!(consumption.get_fuel(distance, duration, load_ratio), expected);
+}
+
+parameterized_test! {can_calculate_fitness, (consumption, expected), {
+    can_calculate_fitness_impl(consumption, expected);
+}}
+
+can_calculate_fitness! {
+    case01_with_consumption: (Some(create_consumption(0.)), (10. * 1.4 + 10. * 1.2 + 20. * 1.) * 2.),
+    case02_without_consumption: (None, 0.),
+}
+
+fn can_calculate_fitness_impl(consumption: Option<FuelConsumption>, expected: Cost) {
+    let fleet = create_fleet(consumption);
+    let route_ctx = create_route_ctx(&fleet, vec![4, 2, 0, 0]);
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let fitness = create_feature().objective.unwrap().fitness(&insertion_ctx);
+
+    assert!((fitness - expected).abs() < 1E-6);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (demand, expected), {
+    can_estimate_activity_insertion_impl(demand, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_no_demand: (0, (5. * 1.2 + 5. * 1.2 - 10. * 1.2) * 2.),
+    case02_delivery: (-2, (5. * 1.4 + 5. * 1.2 - 10. * 1.2 + 10. * 0.2) * 2.),
+    case03_pickup: (2, (5. * 1.2 + 5. * 1.4 - 10. * 1.2 + 20. * 0.2) * 2.),
+}
+
+fn can_estimate_activity_insertion_impl(demand: i32, expected: Cost) {
+    let fleet = create_fleet(Some(create_consumption(0.)));
+    let route_ctx = create_route_ctx(&fleet, vec![4, 2, 0, 0]);
+    let mut job = TestSingleBuilder::default();
+    job.location(Some(15));
+    if demand != 0 {
+        job.demand(create_simple_demand(demand));
+    }
+    let target = ActivityBuilder::with_location(15).job(Some(job.build_shared())).build();
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+    let result =
+        create_feature().objective.unwrap().estimate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert!((result - expected).abs() < 1E-6);
+}
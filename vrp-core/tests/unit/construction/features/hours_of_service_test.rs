@@ -0,0 +1,288 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeInterval;
+use crate::models::problem::{Fleet, VehicleDetail, VehiclePlace};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_hours_of_service(
+    max_driving: Duration,
+    parts: Vec<Duration>,
+    max_tour_driving: Option<Duration>,
+) -> HoursOfService {
+    HoursOfService {
+        driving_break: Some(DrivingBreak { max_driving, parts }),
+        max_tour_driving,
+        ..HoursOfService::default()
+    }
+}
+
+fn create_fleet(hos: HoursOfService) -> Fleet {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").details(vec![VehicleDetail {
+        start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest: None } }),
+        end: None,
+    }]);
+    builder.dimens_mut().set_vehicle_hours_of_service(hos);
+
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build()
+}
+
+fn create_route_ctx(fleet: &Fleet, jobs: Vec<(Location, (Timestamp, Timestamp))>) -> RouteContext {
+    let activities = jobs.into_iter().map(|(location, (start, end))| {
+        ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(start, end), 0.).build()
+    });
+
+    RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(fleet, "v1").add_activities(activities).build())
+        .build()
+}
+
+fn create_feature() -> Feature {
+    create_hours_of_service_feature(
+        "hours_of_service",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+type ScheduleResult = Result<(Vec<(usize, Duration)>, Duration), usize>;
+
+parameterized_test! {can_schedule_route, (jobs, max_driving, parts, expected), {
+    can_schedule_route_impl(jobs, max_driving, parts, expected);
+}}
+
+can_schedule_route! {
+    case01_no_break: (vec![(10, (0., 100.)), (20, (0., 100.)), (30, (0., 100.))], 100., vec![5.], Ok((vec![], 30.))),
+    case02_single_break: (vec![(10, (0., 100.)), (20, (0., 100.)), (30, (0., 100.))], 20., vec![5.], Ok((vec![(2, 5.)], 30.))),
+    case03_too_long_leg: (vec![(10, (0., 100.)), (20, (0., 100.))], 5., vec![5.], Err(1)),
+    case04_waiting_as_break: (vec![(10, (0., 100.)), (20, (30., 100.)), (30, (0., 100.))], 20., vec![5.], Ok((vec![], 30.))),
+    case05_waiting_as_part: (vec![(10, (12., 100.)), (20, (0., 100.)), (30, (0., 100.))], 20., vec![2., 3.], Ok((vec![(2, 3.)], 30.))),
+    case06_short_waiting: (vec![(10, (11., 100.)), (20, (0., 100.)), (30, (0., 100.))], 20., vec![2., 3.], Ok((vec![(2, 5.)], 30.))),
+    case07_late_because_of_break: (vec![(10, (0., 100.)), (20, (0., 100.)), (30, (0., 30.))], 20., vec![5.], Err(3)),
+}
+
+fn can_schedule_route_impl(
+    jobs: Vec<(Location, (Timestamp, Timestamp))>,
+    max_driving: Duration,
+    parts: Vec<Duration>,
+    expected: ScheduleResult,
+) {
+    let hos = create_hours_of_service(max_driving, parts, None);
+    let fleet = create_fleet(hos.clone());
+    let route_ctx = create_route_ctx(&fleet, jobs);
+    let route = route_ctx.route();
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+    let result = schedule_route(
+        route,
+        &hos,
+        activities.as_slice(),
+        TestTransportCost::new_shared().as_ref(),
+        TestActivityCost::new_shared().as_ref(),
+    )
+    .map(|scheduled| (scheduled.info.breaks, scheduled.info.driving));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_shift_schedule_after_break() {
+    let fleet = create_fleet(create_hours_of_service(20., vec![5.], None));
+    let mut route_ctx = create_route_ctx(&fleet, vec![(10, (0., 100.)), (20, (0., 100.)), (30, (0., 100.))]);
+
+    create_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+    let schedules = route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .map(|activity| (activity.schedule.arrival, activity.schedule.departure))
+        .collect::<Vec<_>>();
+    assert_eq!(schedules, vec![(0., 0.), (10., 10.), (20., 25.), (35., 35.)]);
+    assert_eq!(route_ctx.state().get_hours_of_service_driving(), Some(&30.));
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (location, max_tour_driving, expected), {
+    can_evaluate_activity_insertion_impl(location, max_tour_driving, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_no_limits: (15, None, None),
+    case02_too_long_leg: (50, None, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_tour_driving_limit: (30, Some(25.), ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_within_tour_driving_limit: (15, Some(25.), None),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    location: Location,
+    max_tour_driving: Option<Duration>,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_fleet(create_hours_of_service(25., vec![5.], max_tour_driving));
+    let route_ctx = create_route_ctx(&fleet, vec![(10, (0., 100.)), (20, (0., 100.))]);
+    let target = ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 100.), 0.).build();
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx.deep_copy()]).build().solution;
+
+    let result =
+        create_feature().constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_remove_infeasible_jobs_on_solution_state() {
+    let fleet = create_fleet(create_hours_of_service(20., vec![5.], None));
+    let route_ctx = create_route_ctx(&fleet, vec![(10, (0., 100.)), (20, (0., 100.)), (30, (0., 30.))]);
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+
+    create_feature().state.unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count(), 2);
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+    assert!(solution_ctx.unassigned.values().all(|info| matches!(info, UnassignmentInfo::Simple(VIOLATION_CODE))));
+}
+
+parameterized_test! {can_schedule_route_with_daily_rest, (jobs, daily_rest, driving_break, expected), {
+    can_schedule_route_with_daily_rest_impl(jobs, daily_rest, driving_break, expected);
+}}
+
+can_schedule_route_with_daily_rest! {
+    case01_daily_rest: (vec![(10, (0., 100.)), (20, (0., 100.)), (30, (0., 100.))], (20., 8.), None, Ok((vec![(2, 8.)], 1))),
+    case02_break_and_daily_rest: (vec![(10, (0., 100.)), (20, (0., 100.)), (30, (0., 100.))], (20., 8.), Some((10., vec![5.])), Ok((vec![(1, 5.), (2, 8.)], 1))),
+    case03_waiting_as_daily_rest: (vec![(10, (0., 100.)), (20, (30., 100.)), (30, (0., 100.))], (20., 8.), None, Ok((vec![], 0))),
+    case04_too_long_leg: (vec![(10, (0., 100.)), (20, (0., 100.))], (5., 8.), None, Err(1)),
+}
+
+fn can_schedule_route_with_daily_rest_impl(
+    jobs: Vec<(Location, (Timestamp, Timestamp))>,
+    daily_rest: (Duration, Duration),
+    driving_break: Option<(Duration, Vec<Duration>)>,
+    expected: Result<(Vec<(usize, Duration)>, usize), usize>,
+) {
+    let hos = HoursOfService {
+        driving_break: driving_break.map(|(max_driving, parts)| DrivingBreak { max_driving, parts }),
+        daily_rest: Some(DailyRest { max_driving: daily_rest.0, duration: daily_rest.1 }),
+        ..HoursOfService::default()
+    };
+    let fleet = create_fleet(hos.clone());
+    let route_ctx = create_route_ctx(&fleet, jobs);
+    let route = route_ctx.route();
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+    let result = schedule_route(
+        route,
+        &hos,
+        activities.as_slice(),
+        TestTransportCost::new_shared().as_ref(),
+        TestActivityCost::new_shared().as_ref(),
+    )
+    .map(|scheduled| (scheduled.info.breaks, scheduled.info.daily_rests));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_driving_with_route_state, (location, index, max_tour_driving, expected), {
+    can_estimate_driving_with_route_state_impl(location, index, max_tour_driving, expected);
+}}
+
+can_estimate_driving_with_route_state! {
+    case01_same_driving: (15, 1, Some(30.), None),
+    case02_too_long_leg: (40, 1, None, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_detour_after_break: (25, 2, Some(29.), ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_first_activity: (5, 0, Some(30.), None),
+    case05_last_activity: (35, 3, Some(34.), ConstraintViolation::skip(VIOLATION_CODE)),
+    case06_last_activity_within_limit: (35, 3, Some(35.), None),
+}
+
+fn can_estimate_driving_with_route_state_impl(
+    location: Location,
+    index: usize,
+    max_tour_driving: Option<Duration>,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_fleet(create_hours_of_service(25., vec![5.], max_tour_driving));
+    let feature = create_feature();
+    let route_ctx = create_route_ctx(&fleet, vec![(10, (0., 100.)), (20, (0., 100.)), (30, (0., 100.))]);
+    let mut accepted_route_ctx = route_ctx.deep_copy();
+    feature.state.as_ref().unwrap().accept_route_state(&mut accepted_route_ctx);
+    let target = ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 100.), 0.).build();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+    [route_ctx, accepted_route_ctx].iter().for_each(|route_ctx| {
+        let activity_ctx = ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        };
+
+        let result = feature.constraint.as_ref().unwrap().evaluate(&MoveContext::activity(
+            &solution_ctx,
+            route_ctx,
+            &activity_ctx,
+        ));
+
+        assert_eq!(result, expected);
+    });
+}
+
+parameterized_test! {can_evaluate_weekly_driving_limit, (other_start, expected), {
+    can_evaluate_weekly_driving_limit_impl(other_start, expected);
+}}
+
+can_evaluate_weekly_driving_limit! {
+    case01_same_week: (100., ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_next_week: (WEEK_DURATION + 100., None),
+}
+
+fn can_evaluate_weekly_driving_limit_impl(other_start: Timestamp, expected: Option<ConstraintViolation>) {
+    let hos = HoursOfService {
+        weekly_driving: Some(WeeklyDriving { start: 0., max_driving: 50. }),
+        ..HoursOfService::default()
+    };
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").details(
+        [0., other_start]
+            .into_iter()
+            .map(|earliest| VehicleDetail {
+                start: Some(VehiclePlace {
+                    location: 0,
+                    time: TimeInterval { earliest: Some(earliest), latest: None },
+                }),
+                end: None,
+            })
+            .collect(),
+    );
+    builder.dimens_mut().set_vehicle_hours_of_service(hos);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build();
+    let route_ctx = create_route_ctx(&fleet, vec![(10, (0., 100.)), (20, (0., 100.))]);
+    let mut other_route_ctx = create_route_ctx(&fleet, vec![]);
+    other_route_ctx.route_mut().actor =
+        fleet.actors.iter().find(|actor| actor != &&route_ctx.route().actor).unwrap().clone();
+    other_route_ctx.route_mut().tour.get_mut(0).unwrap().schedule = Schedule::new(other_start, other_start);
+    other_route_ctx.state_mut().set_hours_of_service_driving(25.);
+    let target = ActivityBuilder::with_location_tw_and_duration(30, TimeWindow::new(0., 100.), 0.).build();
+    let activity_ctx = ActivityContext {
+        index: 2,
+        prev: route_ctx.route().tour.get(2).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(3),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![other_route_ctx]).build().solution;
+
+    let result =
+        create_feature().constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,78 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_multi_jobs() -> HashMap<char, Arc<Multi>> {
+    ['a', 'b', 'c']
+        .into_iter()
+        .map(|id| {
+            let jobs = (0..2).map(|_| TestSingleBuilder::default().build_shared()).collect();
+            (id, Multi::new_shared(jobs, Dimensions::default()))
+        })
+        .collect()
+}
+
+/// Creates activity from symbol: uppercase letter is the first activity of multi job, lowercase is the last one.
+fn create_activity(jobs: &HashMap<char, Arc<Multi>>, symbol: char) -> Activity {
+    let multi = jobs.get(&symbol.to_ascii_lowercase()).unwrap();
+    let single = if symbol.is_uppercase() { multi.jobs[0].clone() } else { multi.jobs[1].clone() };
+
+    ActivityBuilder::default().job(Some(single)).build()
+}
+
+parameterized_test! {can_evaluate_loading_order, (policy, existing, index, target, expected), {
+    can_evaluate_loading_order_impl(policy, existing, index, target, expected);
+}}
+
+can_evaluate_loading_order! {
+    case01_lifo_nested: (Some(LoadingPolicy::Lifo), "ABb", 3, 'a', None),
+    case02_lifo_crossed: (Some(LoadingPolicy::Lifo), "ABb", 2, 'a', Some(VIOLATION_CODE.0)),
+    case03_lifo_sequential: (Some(LoadingPolicy::Lifo), "ABb", 1, 'a', None),
+    case04_fifo_nested: (Some(LoadingPolicy::Fifo), "ABb", 3, 'a', Some(VIOLATION_CODE.0)),
+    case05_fifo_crossed: (Some(LoadingPolicy::Fifo), "ABb", 2, 'a', None),
+    case06_fifo_sequential: (Some(LoadingPolicy::Fifo), "ABb", 1, 'a', None),
+    case07_first_activity: (Some(LoadingPolicy::Lifo), "ABb", 2, 'C', None),
+    case08_lifo_deep_nested: (Some(LoadingPolicy::Lifo), "ABCcb", 5, 'a', None),
+    case09_lifo_deep_crossed: (Some(LoadingPolicy::Lifo), "ABCcb", 4, 'a', Some(VIOLATION_CODE.0)),
+    case10_no_policy: (None, "ABb", 2, 'a', None),
+}
+
+fn can_evaluate_loading_order_impl(
+    policy: Option<LoadingPolicy>,
+    existing: &str,
+    index: usize,
+    target: char,
+    expected: Option<i32>,
+) {
+    let jobs = create_multi_jobs();
+    let mut vehicle = TestVehicleBuilder::default();
+    vehicle.id("v1");
+    if let Some(policy) = policy {
+        vehicle.dimens_mut().set_vehicle_loading_policy(policy);
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle.build()).build();
+    let activities = existing.chars().map(|symbol| create_activity(&jobs, symbol)).collect::<Vec<_>>();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+    let target = create_activity(&jobs, target);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let feature = create_loading_policy_feature("loading_policy", VIOLATION_CODE).unwrap();
+
+    let result = feature
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx))
+        .map(|violation| violation.code.0);
+
+    assert_eq!(result, expected);
+}
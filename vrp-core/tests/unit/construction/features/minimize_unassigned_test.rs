@@ -1,5 +1,6 @@
 use super::*;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
 use crate::helpers::models::solution::RouteContextBuilder;
 
 #[test]
@@ -12,3 +13,58 @@ fn can_properly_estimate_empty_solution() {
     assert_eq!(objective.fitness(&empty), 0.);
     assert_eq!(objective.fitness(&non_empty), 0.);
 }
+
+parameterized_test! {can_estimate_unassigned_alternatives_once, (alternatives, expected), {
+    can_estimate_unassigned_alternatives_once_impl(alternatives, expected);
+}}
+
+can_estimate_unassigned_alternatives_once! {
+    case01_no_alternatives: (vec![None, None, None], 3.),
+    case02_one_set: (vec![Some(("set1", false)), Some(("set1", false)), None], 2.),
+    case03_two_sets: (vec![Some(("set1", false)), Some(("set2", false)), Some(("set1", false))], 2.),
+    case04_optional_set: (vec![Some(("set1", true)), Some(("set1", true)), None], 1.),
+    case05_mixed_sets: (vec![Some(("set1", true)), Some(("set2", false)), Some(("set2", false))], 1.),
+}
+
+fn can_estimate_unassigned_alternatives_once_impl(alternatives: Vec<Option<(&str, bool)>>, expected: Float) {
+    let unassigned = alternatives
+        .into_iter()
+        .map(|alternative| {
+            let mut builder = TestSingleBuilder::default();
+            if let Some((key, is_optional)) = alternative {
+                builder.dimens_mut().set_job_alternative(JobAlternative { key: key.to_string(), is_optional });
+            }
+
+            (Job::Single(builder.build_shared()), UnassignmentInfo::Unknown)
+        })
+        .collect();
+    let insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![RouteContextBuilder::default().build()])
+        .with_unassigned(unassigned)
+        .build();
+    let objective = MinimizeUnassignedBuilder::new("minimize_unassigned").build().unwrap().objective.unwrap();
+
+    assert_eq!(objective.fitness(&insertion_ctx), expected);
+}
+
+parameterized_test! {can_estimate_alternative_insertion, (is_optional, expected), {
+    can_estimate_alternative_insertion_impl(is_optional, expected);
+}}
+
+can_estimate_alternative_insertion! {
+    case01_exactly_one: (false, -1.),
+    case02_at_most_one: (true, 0.),
+}
+
+fn can_estimate_alternative_insertion_impl(is_optional: bool, expected: Float) {
+    let mut builder = TestSingleBuilder::default();
+    builder.dimens_mut().set_job_alternative(JobAlternative { key: "set1".to_string(), is_optional });
+    let job = Job::Single(builder.build_shared());
+    let insertion_ctx = TestInsertionContextBuilder::default().build();
+    let route_ctx = RouteContextBuilder::default().build();
+    let objective = MinimizeUnassignedBuilder::new("minimize_unassigned").build().unwrap().objective.unwrap();
+
+    let estimate = objective.estimate(&MoveContext::route(&insertion_ctx.solution, &route_ctx, &job));
+
+    assert_eq!(estimate, expected);
+}
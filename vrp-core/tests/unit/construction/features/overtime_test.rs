@@ -0,0 +1,101 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_overtime(start: Timestamp) -> Overtime {
+    Overtime { start, rates: vec![(0., 2.), (30., 3.)] }
+}
+
+fn create_route_ctx(overtime: Option<Overtime>, activities: Vec<Activity>) -> RouteContext {
+    let mut vehicle = TestVehicleBuilder::default();
+    vehicle.id("v1");
+    if let Some(overtime) = overtime {
+        vehicle.dimens_mut().set_vehicle_overtime(overtime);
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle.build()).build();
+    let mut route = RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build();
+    // NOTE route: 0 -> 10 (service 10) -> 0, so it starts at 0 and ends at 30
+    route.tour.get_mut(0).unwrap().schedule = Schedule::new(0., 0.);
+    route.tour.get_mut(2).unwrap().schedule = Schedule::new(30., 30.);
+
+    RouteContextBuilder::default().with_route(route).build()
+}
+
+fn create_activities() -> Vec<Activity> {
+    vec![ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::max(), 10.).build()]
+}
+
+fn create_feature() -> Feature {
+    create_overtime_feature("overtime", TestTransportCost::new_shared(), TestActivityCost::new_shared()).unwrap()
+}
+
+parameterized_test! {can_calculate_overtime, (interval, expected_duration, expected_cost), {
+    can_calculate_overtime_impl(interval, expected_duration, expected_cost);
+}}
+
+can_calculate_overtime! {
+    case01_before_start: ((0., 10.), 0., 0.),
+    case02_touches_start: ((0., 100.), 0., 0.),
+    case03_first_rate: ((90., 110.), 10., 10. * 1.),
+    case04_both_rates: ((100., 140.), 40., 30. * 1. + 10. * 2.),
+    case05_second_rate: ((130., 140.), 10., 10. * 2.),
+}
+
+fn can_calculate_overtime_impl(interval: (Float, Float), expected_duration: Float, expected_cost: Float) {
+    let overtime = create_overtime(100.);
+
+    assert_eq!(overtime.duration(interval.0, interval.1), expected_duration);
+    assert_eq!(overtime.cost(interval.0, interval.1, 1.), expected_cost);
+}
+
+parameterized_test! {can_calculate_route_overtime_cost_in_fitness, (overtime_start, expected), {
+    can_calculate_route_overtime_cost_in_fitness_impl(overtime_start, expected);
+}}
+
+can_calculate_route_overtime_cost_in_fitness! {
+    case01_no_overtime: (None, 0.),
+    case02_after_route_end: (Some(100.), 0.),
+    case03_within_last_leg: (Some(25.), 5. * 1.),
+    case04_whole_route: (Some(0.), 30. * 1.),
+}
+
+fn can_calculate_route_overtime_cost_in_fitness_impl(overtime_start: Option<Timestamp>, expected: Cost) {
+    let mut route_ctx = create_route_ctx(overtime_start.map(create_overtime), create_activities());
+    let feature = create_feature();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (overtime_start, expected), {
+    can_estimate_activity_insertion_impl(overtime_start, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_no_overtime: (None, 0.),
+    case02_after_route_end: (Some(100.), 0.),
+    case03_target_and_delayed_next: (Some(0.), 15. * 1. - 5. * 1. + 10. * 1.),
+    case04_only_delayed_next: (Some(20.), 10. * 1.),
+}
+
+fn can_estimate_activity_insertion_impl(overtime_start: Option<Timestamp>, expected: Cost) {
+    // NOTE target at 15 without service is inserted before 10: service at 10 and route end are delayed by 10
+    let route_ctx = create_route_ctx(overtime_start.map(create_overtime), create_activities());
+    let target = ActivityBuilder::with_location_tw_and_duration(15, TimeWindow::max(), 0.).build();
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+    let result =
+        create_feature().objective.unwrap().estimate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
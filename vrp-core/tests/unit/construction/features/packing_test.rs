@@ -0,0 +1,241 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+type ItemData = ((Float, Float, Float), Float, bool, bool);
+
+fn create_single_with_items(items: Vec<ItemData>) -> Arc<Single> {
+    create_single_with_items_and_demand(items, 0)
+}
+
+fn create_single_with_items_and_demand(items: Vec<ItemData>, demand: i32) -> Arc<Single> {
+    let items = items
+        .into_iter()
+        .enumerate()
+        .map(|(idx, ((length, width, height), weight, stackable, rotatable))| PackingItem {
+            id: format!("item{idx}"),
+            length,
+            width,
+            height,
+            weight,
+            stackable,
+            rotatable,
+        })
+        .collect();
+
+    let mut builder = TestSingleBuilder::default();
+    builder.demand(create_simple_demand(demand)).dimens_mut().set_job_packing_items(items);
+
+    builder.build_shared()
+}
+
+fn create_cargo_space(size: (Float, Float, Float), max_weight: Option<Float>) -> CargoSpace {
+    CargoSpace { length: size.0, width: size.1, height: size.2, max_weight }
+}
+
+fn create_reload() -> Arc<Single> {
+    TestSingleBuilder::default().id("reload").build_shared()
+}
+
+fn is_reload_activity(activity: &Activity) -> bool {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_id()).is_some_and(|id| id == "reload")
+}
+
+fn create_feature() -> Feature {
+    create_packing_feature::<SingleDimLoad>("packing", VIOLATION_CODE, Arc::new(is_reload_activity)).unwrap()
+}
+
+fn create_route_ctx(space: CargoSpace, singles: Vec<Arc<Single>>) -> RouteContext {
+    let mut vehicle = TestVehicleBuilder::default();
+    vehicle.id("v1").dimens_mut().set_vehicle_cargo_space(space);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle.build()).build();
+    let activities = singles.into_iter().map(|single| ActivityBuilder::default().job(Some(single)).build());
+
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+    create_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_pack_items, (space, max_weight, items, expected), {
+    can_pack_items_impl(space, max_weight, items, expected);
+}}
+
+can_pack_items! {
+    case01_side_by_side: ((4., 2., 2.), None, vec![((2., 2., 2.), 1., true, true), ((2., 2., 2.), 1., true, true)], true),
+    case02_too_tall: ((4., 2., 2.), None, vec![((2., 2., 3.), 1., true, true)], false),
+    case03_rotated: ((2., 4., 2.), None, vec![((4., 2., 2.), 1., true, true)], true),
+    case04_not_rotatable: ((2., 4., 2.), None, vec![((4., 2., 2.), 1., true, false)], false),
+    case05_stacked: ((2., 2., 4.), None, vec![((2., 2., 2.), 1., true, true), ((2., 2., 2.), 1., true, true)], true),
+    case06_not_stackable: ((2., 2., 4.), None, vec![((2., 2., 2.), 1., false, true), ((2., 2., 2.), 1., false, true)], false),
+    case07_stacked_different_heights: ((2., 2., 4.), None, vec![((2., 2., 2.), 1., true, true), ((2., 2., 1.), 1., true, true), ((1., 2., 1.), 1., true, true)], true),
+    case08_not_supported: ((3., 2., 4.), None, vec![((2., 2., 2.), 1., true, true), ((3., 2., 1.), 1., true, true)], false),
+    case09_weight_fits: ((4., 2., 2.), Some(2.), vec![((2., 2., 2.), 1., true, true), ((2., 2., 2.), 1., true, true)], true),
+    case10_weight_exceeds: ((4., 2., 2.), Some(1.5), vec![((2., 2., 2.), 1., true, true), ((2., 2., 2.), 1., true, true)], false),
+}
+
+fn can_pack_items_impl(space: (Float, Float, Float), max_weight: Option<Float>, items: Vec<ItemData>, expected: bool) {
+    let space = create_cargo_space(space, max_weight);
+    let single = create_single_with_items(items);
+    let route_ctx = create_route_ctx(space, vec![single]);
+
+    let result = get_loading_plan::<SingleDimLoad>(route_ctx.route(), is_reload_activity);
+
+    assert_eq!(result.is_some(), expected);
+    assert_eq!(route_ctx.state().get_packing_plan().is_some(), expected);
+    if let Some(plan) = result {
+        plan.iter().for_each(|placement| {
+            let item = get_item(&placement.job, placement.item_idx);
+            assert_eq!(placement.size.0 * placement.size.1 * placement.size.2, item.length * item.width * item.height);
+        });
+    }
+}
+
+parameterized_test! {can_evaluate_job_insertion, (existing, new_item, expected), {
+    can_evaluate_job_insertion_impl(existing, new_item, expected);
+}}
+
+can_evaluate_job_insertion! {
+    case01_fits_empty_space: (vec![(2., 2., 2.)], (3., 2., 2.), None),
+    case02_on_board_is_checked_later: (vec![(2., 2., 2.), (1., 2., 2.)], (1., 1., 1.), None),
+    case03_too_big: (vec![], (4., 2., 2.), Some(VIOLATION_CODE.0)),
+}
+
+fn can_evaluate_job_insertion_impl(
+    existing: Vec<(Float, Float, Float)>,
+    new_item: (Float, Float, Float),
+    expected: Option<i32>,
+) {
+    let singles = existing.into_iter().map(|size| create_single_with_items(vec![(size, 1., false, false)])).collect();
+    let route_ctx = create_route_ctx(create_cargo_space((3., 2., 2.), None), singles);
+    let job = Job::Single(create_single_with_items(vec![(new_item, 1., false, false)]));
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+    let result = create_feature()
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job))
+        .map(|violation| violation.code.0);
+
+    assert_eq!(result, expected);
+}
+
+type ActivityData = Option<((Float, Float, Float), i32)>;
+
+parameterized_test! {can_evaluate_activity_insertion, (existing, target, index, max_weight, expected), {
+    can_evaluate_activity_insertion_impl(existing, target, index, max_weight, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_deliveries_on_board: (vec![Some(((2., 2., 2.), -1)), Some(((1., 2., 2.), -1))], ((1., 1., 1.), -1), 2, None, Some(VIOLATION_CODE.0)),
+    case02_pickup_after_deliveries: (vec![Some(((2., 2., 2.), -1)), Some(((1., 2., 2.), -1))], ((2., 2., 2.), 1), 2, None, None),
+    case03_pickup_before_deliveries: (vec![Some(((2., 2., 2.), -1)), Some(((1., 2., 2.), -1))], ((2., 2., 2.), 1), 0, None, Some(VIOLATION_CODE.0)),
+    case04_pickup_after_first_delivery: (vec![Some(((2., 2., 2.), -1)), Some(((1., 2., 2.), -1))], ((2., 2., 2.), 1), 1, None, None),
+    case05_fits_after_reload: (vec![Some(((2., 2., 2.), -1)), None, Some(((2., 2., 2.), -1))], ((1., 2., 2.), -1), 2, None, None),
+    case06_not_fits_after_reload: (vec![Some(((2., 2., 2.), -1)), None, Some(((2., 2., 2.), -1))], ((2., 2., 2.), -1), 2, None, Some(VIOLATION_CODE.0)),
+    case07_delivery_before_reload: (vec![Some(((2., 2., 2.), -1)), None, Some(((2., 2., 2.), -1))], ((2., 2., 2.), -1), 0, None, Some(VIOLATION_CODE.0)),
+    case08_weight_not_on_board: (vec![Some(((1., 1., 1.), -1))], ((1., 1., 1.), 1), 1, Some(1.5), None),
+    case09_weight_on_board: (vec![Some(((1., 1., 1.), -1))], ((1., 1., 1.), 1), 0, Some(1.5), Some(VIOLATION_CODE.0)),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    existing: Vec<ActivityData>,
+    target: ((Float, Float, Float), i32),
+    index: usize,
+    max_weight: Option<Float>,
+    expected: Option<i32>,
+) {
+    let create_single = |(size, demand): ((Float, Float, Float), i32)| {
+        create_single_with_items_and_demand(vec![(size, 1., false, false)], demand)
+    };
+    let singles = existing.into_iter().map(|data| data.map_or_else(create_reload, &create_single)).collect();
+    let route_ctx = create_route_ctx(create_cargo_space((3., 2., 2.), max_weight), singles);
+    let target = ActivityBuilder::default().job(Some(create_single(target))).build();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let result = create_feature()
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx))
+        .map(|violation| violation.code.0);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_multi_job_activity_insertion, (is_delivery_first, index, expected), {
+    can_evaluate_multi_job_activity_insertion_impl(is_delivery_first, index, expected);
+}}
+
+can_evaluate_multi_job_activity_insertion! {
+    case01_delivered_before_pickup: (true, 2, None),
+    case02_delivered_after_pickup: (false, 2, Some(VIOLATION_CODE.0)),
+    case03_delivered_between: (false, 1, Some(VIOLATION_CODE.0)),
+}
+
+fn can_evaluate_multi_job_activity_insertion_impl(is_delivery_first: bool, index: usize, expected: Option<i32>) {
+    let multi = test_multi_with_id(
+        "multi",
+        vec![
+            create_single_with_items(vec![((2., 2., 2.), 1., false, false)]),
+            TestSingleBuilder::default().build_shared(),
+        ],
+    );
+    let delivery = create_single_with_items_and_demand(vec![((2., 2., 2.), 1., false, false)], -1);
+    let singles =
+        if is_delivery_first { vec![delivery, multi.jobs[0].clone()] } else { vec![multi.jobs[0].clone(), delivery] };
+    let route_ctx = create_route_ctx(create_cargo_space((3., 2., 2.), None), singles);
+    let target = ActivityBuilder::default().job(Some(multi.jobs[1].clone())).build();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let result = create_feature()
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx))
+        .map(|violation| violation.code.0);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_skip_incomplete_multi_job_activity_insertion() {
+    let multi = test_multi_with_id(
+        "multi",
+        vec![
+            create_single_with_items(vec![((4., 2., 2.), 1., false, false)]),
+            TestSingleBuilder::default().build_shared(),
+        ],
+    );
+    let route_ctx = create_route_ctx(create_cargo_space((3., 2., 2.), None), vec![]);
+    let target = ActivityBuilder::default().job(Some(multi.jobs[0].clone())).build();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result =
+        create_feature().constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, None);
+}
@@ -0,0 +1,206 @@
+use super::*;
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::construction::features::create_two_routes_solution_ctx;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{FleetBuilder, TestSingleBuilder, TestVehicleBuilder, test_driver};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::problem::Fleet;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_test_visit_job(key: &str, patterns: Option<Vec<Vec<usize>>>, min_spacing: usize) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    builder.dimens_mut().set_job_visit(JobVisit::new(key.to_string(), 2, patterns, min_spacing));
+
+    builder.build_as_job_ref()
+}
+
+fn create_test_fleet(days: &[Option<usize>]) -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(
+            days.iter()
+                .enumerate()
+                .map(|(idx, day)| {
+                    let mut builder = TestVehicleBuilder::default();
+                    if let Some(day) = day {
+                        builder.dimens_mut().set_vehicle_day(*day);
+                    }
+                    builder.id(format!("v{}", idx + 1).as_str()).build()
+                })
+                .collect(),
+        )
+        .build()
+}
+
+parameterized_test! {can_check_allowed_days, (patterns, min_spacing, days, expected), {
+    can_check_allowed_days_impl(patterns, min_spacing, days, expected);
+}}
+
+can_check_allowed_days! {
+    case01_any_days: (None, 0, vec![0, 1], true),
+    case02_same_day: (None, 0, vec![1, 1], false),
+    case03_spacing_ok: (None, 2, vec![0, 2, 4], true),
+    case04_spacing_violated: (None, 2, vec![4, 0, 1], false),
+    case05_pattern_ok: (Some(vec![vec![0, 2], vec![1, 3]]), 0, vec![3, 1], true),
+    case06_pattern_partial: (Some(vec![vec![0, 2], vec![1, 3]]), 0, vec![2], true),
+    case07_pattern_violated: (Some(vec![vec![0, 2], vec![1, 3]]), 0, vec![0, 3], false),
+}
+
+fn can_check_allowed_days_impl(
+    patterns: Option<Vec<Vec<usize>>>,
+    min_spacing: usize,
+    days: Vec<usize>,
+    expected: bool,
+) {
+    let visit = JobVisit::new("job".to_string(), 2, patterns, min_spacing);
+
+    assert_eq!(visit.is_allowed_days(days.as_slice()), expected);
+}
+
+parameterized_test! {can_evaluate_visit, (days, routes, route_idx, expected), {
+    can_evaluate_visit_impl(days, routes, route_idx, expected);
+}}
+
+can_evaluate_visit! {
+    case01_empty_routes: (vec![Some(0), Some(2)], vec![vec![], vec![]], 0, None),
+    case02_other_visit_far: (vec![Some(0), Some(2)], vec![vec![], vec!["job"]], 0, None),
+    case03_same_route: (vec![Some(0), Some(2)], vec![vec!["job"], vec![]], 0, Some(VIOLATION_CODE)),
+    case04_spacing_violated: (vec![Some(0), Some(1)], vec![vec![], vec!["job"]], 0, Some(VIOLATION_CODE)),
+    case05_same_day: (vec![Some(2), Some(2)], vec![vec![], vec!["job"]], 0, Some(VIOLATION_CODE)),
+    case06_no_day: (vec![None, Some(2)], vec![vec![], vec![]], 0, Some(VIOLATION_CODE)),
+    case07_other_job: (vec![Some(0), Some(1)], vec![vec![], vec!["other"]], 0, None),
+}
+
+fn can_evaluate_visit_impl(
+    days: Vec<Option<usize>>,
+    routes: Vec<Vec<&str>>,
+    route_idx: usize,
+    expected: Option<ViolationCode>,
+) {
+    let fleet = create_test_fleet(days.as_slice());
+    let feature = create_periodic_feature("periodic", VIOLATION_CODE).unwrap();
+    let mut solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(
+            routes
+                .into_iter()
+                .enumerate()
+                .map(|(idx, keys)| {
+                    RouteContextBuilder::default()
+                        .with_route(
+                            RouteBuilder::default()
+                                .with_vehicle(&fleet, format!("v{}", idx + 1).as_str())
+                                .add_activities(keys.into_iter().map(|key| {
+                                    ActivityBuilder::default()
+                                        .job(create_test_visit_job(key, None, 2).as_single().cloned())
+                                        .build()
+                                }))
+                                .build(),
+                        )
+                        .build()
+                })
+                .collect(),
+        )
+        .build()
+        .solution;
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.get(route_idx).unwrap();
+    let job = create_test_visit_job("job", None, 2);
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, route_ctx, &job));
+
+    assert_eq!(result, expected.map(|code| ConstraintViolation { code, stopped: true }));
+}
+
+parameterized_test! {can_merge_visits, (source, candidate, expected), {
+    can_merge_visits_impl(source, candidate, expected);
+}}
+
+can_merge_visits! {
+    case_01: (Some("job1"), Some("job1"), Err(VIOLATION_CODE)),
+    case_02: (None, Some("job1"), Err(VIOLATION_CODE)),
+    case_03: (Some("job1"), None, Err(VIOLATION_CODE)),
+    case_04: (None, None, Ok(())),
+}
+
+fn can_merge_visits_impl(source: Option<&str>, candidate: Option<&str>, expected: Result<(), ViolationCode>) {
+    let create_job = |key: Option<&str>| {
+        key.map_or_else(|| TestSingleBuilder::default().build_as_job_ref(), |key| create_test_visit_job(key, None, 0))
+    };
+    let constraint = create_periodic_feature("periodic", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.merge(create_job(source), create_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_restore_visits_in_route_state() {
+    let fleet = create_test_fleet(&[Some(0), Some(2)]);
+    let feature = create_periodic_feature("periodic", VIOLATION_CODE).unwrap();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(
+                    ActivityBuilder::default().job(create_test_visit_job("job", None, 0).as_single().cloned()).build(),
+                )
+                .build(),
+        )
+        .build();
+
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state().get_current_visits().cloned(), Some(HashSet::from(["job".to_string()])));
+}
+
+parameterized_test! {can_unassign_incomplete_visits, (routes, is_required, expected), {
+    can_unassign_incomplete_visits_impl(routes, is_required, expected);
+}}
+
+can_unassign_incomplete_visits! {
+    case01_all_visits_served: (vec![1, 1], false, 2),
+    case02_missing_visit_is_required: (vec![1, 0], true, 1),
+    case03_missing_visit_is_unassigned: (vec![1, 0], false, 0),
+}
+
+fn can_unassign_incomplete_visits_impl(routes: Vec<usize>, is_required: bool, expected: usize) {
+    let create_visit = || create_test_visit_job("job", None, 0).as_single().cloned().unwrap();
+    let feature = create_periodic_feature("periodic", VIOLATION_CODE).unwrap();
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        routes.iter().map(|&size| (0..size).map(|_| create_visit()).collect()).collect(),
+    );
+    if is_required {
+        solution_ctx.required.push(Job::Single(create_visit()));
+    }
+    let total = routes.iter().sum::<usize>();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), expected);
+    assert_eq!(solution_ctx.unassigned.len(), total - expected);
+    assert!(
+        solution_ctx
+            .unassigned
+            .values()
+            .all(|info| matches!(info, UnassignmentInfo::Simple(code) if *code == VIOLATION_CODE))
+    );
+}
+
+#[test]
+fn can_explain_unassigned_visit_without_reason() {
+    let feature = create_periodic_feature("periodic", VIOLATION_CODE).unwrap();
+    let mut solution_ctx = create_two_routes_solution_ctx(&feature, vec![vec![], vec![]]);
+    let other = TestSingleBuilder::default().build_as_job_ref();
+    solution_ctx.unassigned.insert(create_test_visit_job("job", None, 0), UnassignmentInfo::Unknown);
+    solution_ctx.unassigned.insert(other.clone(), UnassignmentInfo::Unknown);
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.iter().all(|(job, info)| match info {
+        UnassignmentInfo::Simple(code) => *job != other && *code == VIOLATION_CODE,
+        UnassignmentInfo::Unknown => *job == other,
+        UnassignmentInfo::Detailed(_) => false,
+    }));
+}
@@ -0,0 +1,233 @@
+use super::*;
+use crate::helpers::construction::features::{create_activity_for_single, create_two_routes_solution_ctx};
+use crate::helpers::models::problem::*;
+use crate::models::common::{Location, TimeWindow};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+const SERVICE_DURATION: Duration = 10.;
+
+fn create_feature() -> Feature {
+    create_precedence_feature(
+        "precedence",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_single(key: &str, location: Location, time_end: Timestamp, lag: Duration) -> Arc<Single> {
+    // NOTE "job2" is the only successor of "job1"
+    let (predecessors, successors) = match key {
+        "job1" => (vec![], vec![("job2".to_string(), lag)]),
+        _ => (vec![("job1".to_string(), lag)], vec![]),
+    };
+
+    let mut builder = TestSingleBuilder::default();
+    builder.id(key).location(Some(location)).duration(SERVICE_DURATION).times(vec![TimeWindow::new(0., time_end)]);
+    builder.dimens_mut().set_job_precedence(JobPrecedence { key: key.to_string(), predecessors, successors });
+
+    builder.build_shared()
+}
+
+fn get_starts(solution_ctx: &SolutionContext) -> Vec<(String, Timestamp)> {
+    solution_ctx
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+        .filter_map(|activity| {
+            get_precedence(activity)
+                .map(|precedence| (precedence.key.clone(), activity.schedule.arrival.max(activity.place.time.start)))
+        })
+        .collect()
+}
+
+fn evaluate_activity(
+    feature: &Feature,
+    solution_ctx: &SolutionContext,
+    route_idx: usize,
+    index: usize,
+    target: &Activity,
+) -> Option<i32> {
+    let route_ctx = &solution_ctx.routes[route_idx];
+
+    feature
+        .constraint
+        .as_ref()
+        .unwrap()
+        .evaluate(&MoveContext::activity(
+            solution_ctx,
+            route_ctx,
+            &ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target,
+                next: route_ctx.route().tour.get(index + 1),
+            },
+        ))
+        .map(|violation| violation.code.0)
+}
+
+parameterized_test! {can_shift_successor_start_across_routes, (successor_location, lag, expected), {
+    can_shift_successor_start_across_routes_impl(successor_location, lag, expected);
+}}
+
+can_shift_successor_start_across_routes! {
+    case01_no_lag: (5, 0., 20.),
+    case02_with_lag: (5, 5., 25.),
+    case03_arrives_late_enough: (30, 5., 30.),
+}
+
+fn can_shift_successor_start_across_routes_impl(successor_location: Location, lag: Duration, expected: Timestamp) {
+    let feature = create_feature();
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job1", 10, 100., lag)], vec![create_single("job2", successor_location, 100., lag)]],
+    );
+    let job = solution_ctx.routes[1].route().tour.jobs().next().cloned().unwrap();
+
+    feature.state.as_ref().unwrap().accept_insertion(&mut solution_ctx, 1, &job);
+
+    assert_eq!(get_starts(&solution_ctx), vec![("job1".to_string(), 10.), ("job2".to_string(), expected)]);
+}
+
+#[test]
+fn can_reset_waiting_when_predecessor_is_removed() {
+    let feature = create_feature();
+    let state = feature.state.as_ref().unwrap();
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job1", 10, 100., 0.)], vec![create_single("job2", 5, 100., 0.)]],
+    );
+    state.accept_solution_state(&mut solution_ctx);
+    assert_eq!(get_starts(&solution_ctx), vec![("job1".to_string(), 10.), ("job2".to_string(), 20.)]);
+
+    let job = solution_ctx.routes[0].route().tour.jobs().next().cloned().unwrap();
+    solution_ctx.routes[0].route_mut().tour.remove(&job);
+    solution_ctx.required.push(job);
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_starts(&solution_ctx), vec![("job2".to_string(), 5.)]);
+}
+
+parameterized_test! {can_evaluate_successor_insertion, (successor_time_end, lag, expected), {
+    can_evaluate_successor_insertion_impl(successor_time_end, lag, expected);
+}}
+
+can_evaluate_successor_insertion! {
+    case01_can_wait: (100., 0., None),
+    case02_can_wait_with_lag: (25., 5., None),
+    case03_cannot_wait_with_lag: (25., 10., Some(VIOLATION_CODE.0)),
+}
+
+fn can_evaluate_successor_insertion_impl(successor_time_end: Timestamp, lag: Duration, expected: Option<i32>) {
+    let feature = create_feature();
+    let solution_ctx =
+        create_two_routes_solution_ctx(&feature, vec![vec![create_single("job1", 10, 100., lag)], vec![]]);
+    let target = create_activity_for_single(create_single("job2", 5, successor_time_end, lag));
+
+    let result = evaluate_activity(&feature, &solution_ctx, 1, 0, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_predecessor_insertion, (successor_time_end, lag, expected), {
+    can_evaluate_predecessor_insertion_impl(successor_time_end, lag, expected);
+}}
+
+can_evaluate_predecessor_insertion! {
+    case01_successor_can_wait: (100., 0., None),
+    case02_successor_can_wait_with_lag: (25., 5., None),
+    case03_successor_cannot_wait_with_lag: (25., 10., Some(VIOLATION_CODE.0)),
+}
+
+fn can_evaluate_predecessor_insertion_impl(successor_time_end: Timestamp, lag: Duration, expected: Option<i32>) {
+    let feature = create_feature();
+    let solution_ctx =
+        create_two_routes_solution_ctx(&feature, vec![vec![], vec![create_single("job2", 5, successor_time_end, lag)]]);
+    let target = create_activity_for_single(create_single("job1", 10, 100., lag));
+
+    let result = evaluate_activity(&feature, &solution_ctx, 0, 0, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_insertion_in_the_same_route, (assigned, target, index, expected), {
+    can_evaluate_insertion_in_the_same_route_impl(assigned, target, index, expected);
+}}
+
+can_evaluate_insertion_in_the_same_route! {
+    case01_successor_after_predecessor: ("job1", "job2", 1, None),
+    case02_successor_before_predecessor: ("job1", "job2", 0, Some(VIOLATION_CODE.0)),
+    case03_predecessor_before_successor: ("job2", "job1", 0, None),
+    case04_predecessor_after_successor: ("job2", "job1", 1, Some(VIOLATION_CODE.0)),
+}
+
+fn can_evaluate_insertion_in_the_same_route_impl(assigned: &str, target: &str, index: usize, expected: Option<i32>) {
+    let feature = create_feature();
+    let solution_ctx =
+        create_two_routes_solution_ctx(&feature, vec![vec![create_single(assigned, 10, 100., 0.)], vec![]]);
+    let target = create_activity_for_single(create_single(target, 10, 100., 0.));
+
+    let result = evaluate_activity(&feature, &solution_ctx, 0, index, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_unassign_job_without_predecessor, (is_required, expected), {
+    can_unassign_job_without_predecessor_impl(is_required, expected);
+}}
+
+can_unassign_job_without_predecessor! {
+    case01_predecessor_is_required: (true, 1),
+    case02_predecessor_is_unassigned: (false, 0),
+}
+
+fn can_unassign_job_without_predecessor_impl(is_required: bool, expected: usize) {
+    let feature = create_feature();
+    let mut solution_ctx =
+        create_two_routes_solution_ctx(&feature, vec![vec![], vec![create_single("job2", 5, 100., 0.)]]);
+    if is_required {
+        solution_ctx.required.push(Job::Single(create_single("job1", 10, 100., 0.)));
+    }
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_starts(&solution_ctx).len(), expected);
+    assert_eq!(solution_ctx.unassigned.len(), 1 - expected);
+    assert!(
+        solution_ctx
+            .unassigned
+            .values()
+            .all(|info| matches!(info, UnassignmentInfo::Simple(code) if *code == VIOLATION_CODE))
+    );
+}
+
+#[test]
+fn can_unassign_successor_which_becomes_late() {
+    let feature = create_feature();
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job1", 10, 100., 10.)], vec![create_single("job2", 5, 25., 10.)]],
+    );
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_starts(&solution_ctx), vec![("job1".to_string(), 10.)]);
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+}
+
+#[test]
+fn can_unassign_successor_served_before_predecessor_in_the_same_route() {
+    let feature = create_feature();
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job2", 5, 1000., 0.), create_single("job1", 10, 1000., 0.)], vec![]],
+    );
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let keys = get_starts(&solution_ctx).into_iter().map(|(key, _)| key).collect::<Vec<_>>();
+    assert_eq!(keys, vec!["job1".to_string()]);
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+}
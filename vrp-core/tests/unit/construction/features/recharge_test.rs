@@ -5,17 +5,33 @@ use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteConte
 use crate::models::solution::Activity;
 
 const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+const CHARGERS_CODE: ViolationCode = ViolationCode(2);
 
 struct VehicleIdDimenKey;
 struct JobTypeDimenKey;
 
+fn is_recharge_single(single: &Single) -> bool {
+    single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "recharge")
+}
+
 fn create_recharge_feature(limit: Distance) -> Feature {
-    fn is_recharge_single(single: &Single) -> bool {
-        single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "recharge")
-    }
+    create_recharge_feature_builder(limit).build().unwrap()
+}
+
+fn create_shared_chargers_feature(chargers: usize) -> Feature {
+    create_recharge_feature_builder(Distance::MAX)
+        .set_chargers_code(CHARGERS_CODE)
+        .set_shared_chargers(move |activity| {
+            activity.job.as_ref().filter(|single| is_recharge_single(single)).map(|_| (chargers, 0))
+        })
+        .build()
+        .unwrap()
+}
 
+fn create_recharge_feature_builder(limit: Distance) -> RechargeFeatureBuilder {
     RechargeFeatureBuilder::new("recharge")
         .set_transport(TestTransportCost::new_shared())
+        .set_activity(TestActivityCost::new_shared())
         .set_violation_code(VIOLATION_CODE)
         .set_distance_limit(move |_: &Actor| Some(limit))
         .set_is_recharge_single(is_recharge_single)
@@ -26,27 +42,38 @@ fn create_recharge_feature(limit: Distance) -> Feature {
                 .zip(route.actor.vehicle.dimens.get_vehicle_id())
                 .is_some_and(|(a, b)| a == b)
         })
-        .build()
-        .unwrap()
 }
 
 fn recharge(location: Location) -> Activity {
-    ActivityBuilder::with_location(location)
-        .job(Some(
-            TestSingleBuilder::default()
-                .id("recharge")
-                .property::<JobTypeDimenKey, _>("recharge".to_string())
-                .property::<VehicleIdDimenKey, _>("v1".to_string())
-                .build_shared(),
-        ))
-        .build()
+    let mut single = TestSingleBuilder::default();
+    single
+        .id("recharge")
+        .property::<JobTypeDimenKey, _>("recharge".to_string())
+        .property::<VehicleIdDimenKey, _>("v1".to_string());
+    single.dimens_mut().set_job_charging_curve(ChargingCurve::new(vec![(0., 1.)]));
+
+    ActivityBuilder::with_location(location).job(Some(single.build_shared())).build()
+}
+
+fn create_battery() -> Battery {
+    Battery { capacity: 20., initial: 12., reserve: 2., empty: 1., full: 1. }
 }
 
 fn create_route_ctx(activities: &[Location], recharges: Vec<(usize, Location)>, is_open_end: bool) -> RouteContext {
-    let fleet = FleetBuilder::default()
-        .add_driver(test_driver())
-        .add_vehicle(if is_open_end { test_ovrp_vehicle("v1") } else { test_vehicle_with_id("v1") })
-        .build();
+    create_route_ctx_with_battery(activities, recharges, is_open_end, None)
+}
+
+fn create_route_ctx_with_battery(
+    activities: &[Location],
+    recharges: Vec<(usize, Location)>,
+    is_open_end: bool,
+    battery: Option<Battery>,
+) -> RouteContext {
+    let mut vehicle = if is_open_end { test_ovrp_vehicle("v1") } else { test_vehicle_with_id("v1") };
+    if let Some(battery) = battery {
+        vehicle.dimens.set_vehicle_battery(battery);
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
 
     let mut route_ctx = RouteContextBuilder::default()
         .with_route(
@@ -104,7 +131,7 @@ fn can_accumulate_distance_impl(
     state.accept_route_state(&mut route_ctx);
 
     (0..route_ctx.route().tour.total()).for_each(|activity_idx| {
-        let counter = route_ctx.state().get_recharge_distance_at(activity_idx).copied().unwrap_or_default();
+        let counter = route_ctx.state().get_recharge_consumption_at(activity_idx).copied().unwrap_or_default();
         assert_eq!(counter, expected_counters[activity_idx], "doesn't match for: {activity_idx}");
     });
 }
@@ -206,3 +233,290 @@ fn can_accept_recharge_in_long_empty_route() {
 
     assert_eq!(result, None);
 }
+
+parameterized_test! {can_get_charging_duration, (from, to, expected), {
+    can_get_charging_duration_impl(from, to, expected);
+}}
+
+can_get_charging_duration! {
+    case01_constant_rate: (2., 6., 4.),
+    case02_decreasing_rate: (7., 9., 1. + 10. * (0.75_f64).ln() / -2.5),
+    case03_charge_to_full: (9., 10., 10. * (0.5_f64 / 0.75).ln() / -2.5),
+    case04_no_charge: (6., 2., 0.),
+}
+
+fn can_get_charging_duration_impl(from: Float, to: Float, expected: Duration) {
+    let curve = ChargingCurve::new(vec![(0.8, 1.), (0., 1.), (1., 0.5)]);
+
+    let duration = curve.get_duration(10., from, to);
+
+    assert!((duration - expected).abs() < 1E-6, "expected {expected}, got {duration}");
+}
+
+parameterized_test! {can_get_states_of_charge, (initial, energies, recharges, expected), {
+    can_get_states_of_charge_impl(initial, energies, recharges, expected);
+}}
+
+can_get_states_of_charge! {
+    case01_no_recharges: (12., vec![0., 3., 2., 4.], vec![], vec![12., 9., 7., 3.]),
+    case02_charge_needed_only: (12., vec![0., 3., 2., 4., 3.], vec![2], vec![12., 9., 9., 5., 2.]),
+    case03_charge_up_to_capacity: (12., vec![0., 3., 2., 12., 8.], vec![2], vec![12., 9., 20., 8., 0.]),
+    case04_no_charge_needed: (12., vec![0., 3., 2., 1., 1.], vec![2], vec![12., 9., 7., 6., 5.]),
+    case05_two_recharges: (5., vec![0., 3., 6., 4., 2.], vec![1, 3], vec![5., 12., 6., 4., 2.]),
+}
+
+fn can_get_states_of_charge_impl(initial: Float, energies: Vec<Float>, recharges: Vec<usize>, expected: Vec<Float>) {
+    let battery = Battery { initial, ..create_battery() };
+
+    let states = battery.get_states_of_charge(&energies, |idx| recharges.contains(&idx));
+
+    assert_eq!(states, expected);
+}
+
+parameterized_test! {can_evaluate_insertion_with_battery, (insertion_data, expected), {
+    can_evaluate_insertion_with_battery_impl(insertion_data, expected);
+}}
+
+can_evaluate_insertion_with_battery! {
+    case01_reject_before_recharge: ((1, 16, (1, 2)), ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_accept_before_recharge: ((0, 3, (0, 1)), None),
+    case03_reject_after_recharge: ((2, 16, (2, 3)), ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_accept_after_recharge: ((2, 12, (2, 3)), None),
+}
+
+fn can_evaluate_insertion_with_battery_impl(
+    insertion_data: (usize, Location, (usize, usize)),
+    expected: Option<ConstraintViolation>,
+) {
+    let (index, new_location, (prev, next)) = insertion_data;
+    let mut route_ctx = create_route_ctx_with_battery(&[5, 10, 15], vec![(2, 8)], true, Some(create_battery()));
+    let feature = create_recharge_feature(Distance::MAX);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &TestInsertionContextBuilder::default().build().solution,
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(prev).unwrap(),
+            target: &ActivityBuilder::with_location(new_location)
+                .job(Some(TestSingleBuilder::default().build_shared()))
+                .build(),
+            next: route_ctx.route().tour.get(next),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_derive_recharge_duration_from_charged_energy() {
+    let mut route_ctx = create_route_ctx_with_battery(&[5, 10, 15], vec![(2, 8)], true, Some(create_battery()));
+    let state = create_recharge_feature(Distance::MAX).state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    // NOTE arrives with 4, needs 7 to reach the end keeping reserve of 2
+    let recharge = route_ctx.route().tour.get(2).unwrap();
+    assert_eq!(recharge.place.duration, 5.);
+    assert_eq!(recharge.schedule, Schedule::new(8., 13.));
+    assert_eq!(route_ctx.route().tour.get(3).unwrap().schedule.arrival, 15.);
+}
+
+parameterized_test! {can_evaluate_schedule_with_longer_charging, (latest, expected), {
+    can_evaluate_schedule_with_longer_charging_impl(latest, expected);
+}}
+
+can_evaluate_schedule_with_longer_charging! {
+    case01_no_time_for_charging: (16., ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_enough_time_for_charging: (17., None),
+}
+
+fn can_evaluate_schedule_with_longer_charging_impl(latest: Timestamp, expected: Option<ConstraintViolation>) {
+    let mut route_ctx = create_route_ctx_with_battery(&[5, 10, 15], vec![(2, 8)], true, Some(create_battery()));
+    route_ctx.route_mut().tour.get_mut(3).unwrap().place.time.end = latest;
+    let feature = create_recharge_feature(Distance::MAX);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    // NOTE detour requires to charge 2 more, so job at location 10 is reached later
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &TestInsertionContextBuilder::default().build().solution,
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index: 4,
+            prev: route_ctx.route().tour.get(4).unwrap(),
+            target: &ActivityBuilder::with_location(17).job(Some(TestSingleBuilder::default().build_shared())).build(),
+            next: None,
+        },
+    });
+
+    assert_eq!(result, expected);
+}
+
+fn create_solution_with_recharges(job_time_end: Timestamp) -> SolutionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+
+    let routes = ["v1", "v2"]
+        .iter()
+        .enumerate()
+        .map(|(idx, vehicle_id)| {
+            let mut station = recharge(8);
+            station.place.duration = 5.;
+
+            let activities = if idx == 0 { vec![station] } else { vec![] }
+                .into_iter()
+                .chain(once(ActivityBuilder::with_location_and_tw(10, TimeWindow::new(0., job_time_end)).build()));
+
+            let mut route_ctx = RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(&fleet, vehicle_id).add_activities(activities).build())
+                .build();
+            update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+            route_ctx
+        })
+        .collect();
+
+    TestInsertionContextBuilder::default().with_routes(routes).build().solution
+}
+
+parameterized_test! {can_evaluate_station_with_shared_chargers, (chargers, job_time_end, expected), {
+    can_evaluate_station_with_shared_chargers_impl(chargers, job_time_end, expected);
+}}
+
+can_evaluate_station_with_shared_chargers! {
+    case01_wait_for_charger: (1, 20., None),
+    case02_late_after_waiting: (1, 19., ConstraintViolation::skip(CHARGERS_CODE)),
+    case03_free_charger: (2, 19., None),
+}
+
+fn can_evaluate_station_with_shared_chargers_impl(
+    chargers: usize,
+    job_time_end: Timestamp,
+    expected: Option<ConstraintViolation>,
+) {
+    let mut solution_ctx = create_solution_with_recharges(job_time_end);
+    let feature = create_shared_chargers_feature(chargers);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    let job = solution_ctx.routes[0].route().tour.jobs().next().cloned().unwrap();
+    state.accept_insertion(&mut solution_ctx, 0, &job);
+
+    let mut station = recharge(8);
+    station.place.duration = 5.;
+    let route_ctx = &solution_ctx.routes[1];
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &solution_ctx,
+        route_ctx,
+        activity_ctx: &ActivityContext {
+            index: 0,
+            prev: route_ctx.route().tour.start().unwrap(),
+            target: &station,
+            next: route_ctx.route().tour.get(1),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_schedule_waiting_for_free_charger, (chargers, expected), {
+    can_schedule_waiting_for_free_charger_impl(chargers, expected);
+}}
+
+can_schedule_waiting_for_free_charger! {
+    case01_single_charger: (1, vec![(8., 13.), (13., 18.)]),
+    case02_two_chargers: (2, vec![(8., 13.), (8., 13.)]),
+}
+
+fn can_schedule_waiting_for_free_charger_impl(chargers: usize, expected: Vec<(Timestamp, Timestamp)>) {
+    let mut solution_ctx = create_solution_with_recharges(1000.);
+    let mut station = recharge(8);
+    station.place.duration = 5.;
+    solution_ctx.routes[1].route_mut().tour.insert_at(station, 1);
+    update_route_schedule(&mut solution_ctx.routes[1], &TestActivityCost::default(), &TestTransportCost::default());
+    let state = create_shared_chargers_feature(chargers).state.unwrap();
+    let job = solution_ctx.routes[1].route().tour.jobs().next().cloned().unwrap();
+
+    state.accept_insertion(&mut solution_ctx, 1, &job);
+
+    let result = solution_ctx
+        .routes
+        .iter()
+        .map(|route_ctx| {
+            let station = route_ctx.route().tour.get(1).unwrap();
+            (station.schedule.departure - station.place.duration, station.schedule.departure)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_station_with_estimated_charging, (chargers, job_time_end, expected), {
+    can_evaluate_station_with_estimated_charging_impl(chargers, job_time_end, expected);
+}}
+
+can_evaluate_station_with_estimated_charging! {
+    case01_wait_and_charge: (1, 20., None),
+    case02_late_after_waiting_and_charging: (1, 19., ConstraintViolation::skip(CHARGERS_CODE)),
+    case03_charge_on_free_charger: (2, 15., None),
+}
+
+fn can_evaluate_station_with_estimated_charging_impl(
+    chargers: usize,
+    job_time_end: Timestamp,
+    expected: Option<ConstraintViolation>,
+) {
+    let mut vehicle = test_ovrp_vehicle("v2");
+    vehicle.dimens.set_vehicle_battery(create_battery());
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), vehicle])
+        .build();
+    let mut station = recharge(8);
+    station.place.duration = 5.;
+    let jobs = [(5, Float::MAX), (10, job_time_end), (15, Float::MAX)]
+        .into_iter()
+        .map(|(location, end)| ActivityBuilder::with_location_and_tw(location, TimeWindow::new(0., end)).build());
+    let mut solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![
+            RouteContextBuilder::default()
+                .with_route(
+                    RouteBuilder::default()
+                        .with_vehicle(&fleet, "v1")
+                        .add_activities(vec![station, ActivityBuilder::with_location(10).build()])
+                        .build(),
+                )
+                .build(),
+            RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(&fleet, "v2").add_activities(jobs).build())
+                .build(),
+        ])
+        .build()
+        .solution;
+    let feature = create_shared_chargers_feature(chargers);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+        update_route_schedule(route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+        state.accept_route_state(route_ctx);
+    });
+    let job = solution_ctx.routes[0].route().tour.jobs().next().cloned().unwrap();
+    state.accept_insertion(&mut solution_ctx, 0, &job);
+
+    // NOTE station is occupied by other vehicle within [8, 13], vehicle arrives to the station with 4
+    //      and needs 5 more to reach the tour end keeping reserve, so charging takes 5
+    let route_ctx = &solution_ctx.routes[1];
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &solution_ctx,
+        route_ctx,
+        activity_ctx: &ActivityContext {
+            index: 1,
+            prev: route_ctx.route().tour.get(1).unwrap(),
+            target: &recharge(8),
+            next: route_ctx.route().tour.get(2),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
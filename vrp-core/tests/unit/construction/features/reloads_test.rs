@@ -543,3 +543,32 @@ fn can_constraint_activity_impl(
 
     assert_eq!(result.map(|result| result.code), expected)
 }
+
+parameterized_test! {can_get_shared_resource_start, (occupied, slots, start, duration, expected), {
+    can_get_shared_resource_start_impl(occupied, slots, start, duration, expected);
+}}
+
+can_get_shared_resource_start! {
+    case01_wait_for_slot: (vec![(0., 10.)], 1, 5., 5., 10.),
+    case02_free_slot: (vec![(0., 10.)], 2, 5., 5., 5.),
+    case03_no_gap_between: (vec![(0., 10.), (12., 20.)], 1, 5., 3., 20.),
+    case04_gap_between: (vec![(0., 10.), (14., 20.)], 1, 5., 3., 10.),
+    case05_no_overlap: (vec![(0., 5.)], 1, 5., 3., 5.),
+    case06_sequential_visits_in_one_slot: (vec![(0., 10.), (10., 20.)], 2, 5., 10., 5.),
+    case07_parallel_visits_in_two_slots: (vec![(0., 10.), (5., 20.)], 2, 5., 10., 10.),
+    case08_slots_taken_in_turn: (vec![(0., 8.), (8., 20.), (0., 30.)], 2, 5., 10., 20.),
+}
+
+fn can_get_shared_resource_start_impl(
+    occupied: Vec<(Timestamp, Timestamp)>,
+    slots: usize,
+    start: Timestamp,
+    duration: Duration,
+    expected: Timestamp,
+) {
+    let occupied = occupied.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect::<Vec<_>>();
+
+    let result = get_shared_resource_start(&occupied, slots, start, duration);
+
+    assert_eq!(result, expected);
+}
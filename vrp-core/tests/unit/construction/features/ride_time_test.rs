@@ -0,0 +1,112 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use std::iter::once;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature() -> Feature {
+    create_max_ride_time_feature(
+        "max_ride_time",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_multi_job(locations: (Location, Location), max_ride_time: MaxRideTime) -> Arc<Multi> {
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id("job1".to_string()).set_job_max_ride_time(max_ride_time);
+
+    let jobs = [locations.0, locations.1]
+        .into_iter()
+        .map(|location| TestSingleBuilder::default().location(Some(location)).build_shared())
+        .collect();
+
+    Multi::new_shared(jobs, dimens)
+}
+
+fn create_route_ctx(feature: &Feature, activities: Vec<Activity>) -> RouteContext {
+    let mut route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().add_activities(activities).build()).build();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn evaluate_insertion(feature: &Feature, route_ctx: &RouteContext, index: usize, target: Activity) -> Option<i32> {
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    feature
+        .constraint
+        .as_ref()
+        .unwrap()
+        .evaluate(&MoveContext::activity(&solution_ctx, route_ctx, &activity_ctx))
+        .map(|violation| violation.code.0)
+}
+
+parameterized_test! {can_evaluate_last_activity_of_multi_job, (prev_location, delivery_location, max_ride_time, expected), {
+    can_evaluate_last_activity_of_multi_job_impl(prev_location, delivery_location, max_ride_time, expected);
+}}
+
+can_evaluate_last_activity_of_multi_job! {
+    case01_absolute_fits: (None, 30, MaxRideTime::Absolute(20.), None),
+    case02_absolute_exceeds: (None, 30, MaxRideTime::Absolute(19.), Some(VIOLATION_CODE.0)),
+    case03_relative_fits_detour: (Some(15), 30, MaxRideTime::Relative(1.5), None),
+    case04_relative_exceeds_detour: (Some(45), 30, MaxRideTime::Relative(1.5), Some(VIOLATION_CODE.0)),
+}
+
+fn can_evaluate_last_activity_of_multi_job_impl(
+    prev_location: Option<Location>,
+    delivery_location: Location,
+    max_ride_time: MaxRideTime,
+    expected: Option<i32>,
+) {
+    let feature = create_feature();
+    let multi = create_multi_job((10, delivery_location), max_ride_time);
+    let activities = once(ActivityBuilder::with_location(10).job(Some(multi.jobs[0].clone())).build())
+        .chain(prev_location.map(|location| ActivityBuilder::with_location(location).build()))
+        .collect::<Vec<_>>();
+    let index = activities.len();
+    let route_ctx = create_route_ctx(&feature, activities);
+    let target = ActivityBuilder::with_location(delivery_location).job(Some(multi.jobs[1].clone())).build();
+
+    let result = evaluate_insertion(&feature, &route_ctx, index, target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_delay_of_multi_job_in_tour, (index, location, expected), {
+    can_evaluate_delay_of_multi_job_in_tour_impl(index, location, expected);
+}}
+
+can_evaluate_delay_of_multi_job_in_tour! {
+    case01_inside_no_delay: (1, 12, None),
+    case02_inside_delay_fits: (1, 22, None),
+    case03_inside_delay_exceeds: (1, 23, Some(VIOLATION_CODE.0)),
+    case04_inside_backward_delay_exceeds: (1, 7, Some(VIOLATION_CODE.0)),
+    case05_before_pickup: (0, 30, None),
+    case06_after_delivery: (2, 50, None),
+}
+
+fn can_evaluate_delay_of_multi_job_in_tour_impl(index: usize, location: Location, expected: Option<i32>) {
+    let feature = create_feature();
+    let multi = create_multi_job((10, 20), MaxRideTime::Absolute(15.));
+    let activities = multi.jobs.iter().map(|single| {
+        ActivityBuilder::with_location(single.places[0].location.unwrap()).job(Some(single.clone())).build()
+    });
+    let route_ctx = create_route_ctx(&feature, activities.collect());
+    let target = ActivityBuilder::with_location(location).build();
+
+    let result = evaluate_insertion(&feature, &route_ctx, index, target);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,191 @@
+use super::*;
+use crate::helpers::construction::features::{create_activity_for_single, create_two_routes_solution_ctx};
+use crate::helpers::models::problem::*;
+use crate::models::common::{Location, TimeWindow, Timestamp};
+use crate::models::problem::Single;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+const SERVICE_DURATION: Duration = 10.;
+
+/// Creates a feature where locations 10 and 11 belong to the same site.
+fn create_feature(max_vehicles: Option<usize>, setup_time: Duration) -> Feature {
+    create_site_feature(
+        "site",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        move |activity| {
+            activity.job.as_ref().filter(|_| matches!(activity.place.location, 10 | 11)).map(|_| Site {
+                id: 0,
+                max_vehicles,
+                setup_time,
+            })
+        },
+    )
+    .unwrap()
+}
+
+fn create_single(id: &str, location: Location, time_end: Timestamp) -> Arc<Single> {
+    TestSingleBuilder::default()
+        .id(id)
+        .location(Some(location))
+        .duration(SERVICE_DURATION)
+        .times(vec![TimeWindow::new(0., time_end)])
+        .build_shared()
+}
+
+fn get_schedules(route_ctx: &RouteContext) -> Vec<(Timestamp, Timestamp)> {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .filter(|activity| activity.job.is_some())
+        .map(|activity| (activity.schedule.arrival.max(activity.place.time.start), activity.schedule.departure))
+        .collect()
+}
+
+fn evaluate_activity(
+    feature: &Feature,
+    solution_ctx: &SolutionContext,
+    route_idx: usize,
+    index: usize,
+    target: &Activity,
+) -> Option<i32> {
+    let route_ctx = &solution_ctx.routes[route_idx];
+
+    feature
+        .constraint
+        .as_ref()
+        .unwrap()
+        .evaluate(&MoveContext::activity(
+            solution_ctx,
+            route_ctx,
+            &ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target,
+                next: route_ctx.route().tour.get(index + 1),
+            },
+        ))
+        .map(|violation| violation.code.0)
+}
+
+#[test]
+fn can_apply_setup_time_once_per_stop() {
+    let feature = create_feature(None, 5.);
+    let solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job1", 10, 100.), create_single("job2", 10, 100.), create_single("job3", 20, 100.)]],
+    );
+
+    assert_eq!(get_schedules(&solution_ctx.routes[0]), vec![(10., 25.), (25., 35.), (45., 55.)]);
+}
+
+#[test]
+fn can_move_setup_time_when_stop_is_changed() {
+    let feature = create_feature(None, 5.);
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job1", 10, 100.), create_single("job2", 10, 100.), create_single("job3", 20, 100.)]],
+    );
+    let job = solution_ctx.routes[0].route().tour[1].retrieve_job().unwrap();
+
+    solution_ctx.routes[0].route_mut().tour.remove(&job);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_schedules(&solution_ctx.routes[0]), vec![(10., 25.), (35., 45.)]);
+}
+
+parameterized_test! {can_wait_for_free_site_slot, (max_vehicles, expected), {
+    can_wait_for_free_site_slot_impl(max_vehicles, expected);
+}}
+
+can_wait_for_free_site_slot! {
+    case01_one_vehicle: (Some(1), vec![(10., 20.), (20., 30.)]),
+    case02_two_vehicles: (Some(2), vec![(10., 20.), (11., 21.)]),
+    case03_no_limit: (None, vec![(10., 20.), (11., 21.)]),
+}
+
+fn can_wait_for_free_site_slot_impl(max_vehicles: Option<usize>, expected: Vec<(Timestamp, Timestamp)>) {
+    let feature = create_feature(max_vehicles, 0.);
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job1", 10, 100.)], vec![create_single("job2", 11, 100.)]],
+    );
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let schedules = get_schedules(&solution_ctx.routes[0]).into_iter().chain(get_schedules(&solution_ctx.routes[1]));
+    assert_eq!(schedules.collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn can_remove_late_jobs_after_waiting() {
+    let feature = create_feature(Some(1), 0.);
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job1", 10, 100.)], vec![create_single("job2", 11, 15.)]],
+    );
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[1].route().tour.job_count(), 0);
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+    assert!(matches!(solution_ctx.unassigned.values().next(), Some(UnassignmentInfo::Simple(VIOLATION_CODE))));
+}
+
+parameterized_test! {can_evaluate_site_activity, (max_vehicles, setup_time, target_location, time_end, expected), {
+    can_evaluate_site_activity_impl(max_vehicles, setup_time, target_location, time_end, expected);
+}}
+
+can_evaluate_site_activity! {
+    case01_free_slot: (Some(2), 0., 10, 15., None),
+    case02_wait_in_time: (Some(1), 0., 10, 20., None),
+    case03_wait_too_long: (Some(1), 0., 10, 15., Some(VIOLATION_CODE.0)),
+    case04_not_a_site: (Some(1), 0., 5, 15., None),
+    case05_setup_in_time: (None, 5., 10, 100., None),
+}
+
+fn can_evaluate_site_activity_impl(
+    max_vehicles: Option<usize>,
+    setup_time: Duration,
+    target_location: Location,
+    time_end: Timestamp,
+    expected: Option<i32>,
+) {
+    let feature = create_feature(max_vehicles, setup_time);
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job1", 10, 100.)], vec![create_single("job2", 30, 100.)]],
+    );
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let target = create_activity_for_single(create_single("job3", target_location, time_end));
+
+    let result = evaluate_activity(&feature, &solution_ctx, 1, 0, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_setup_time_of_next_activity, (setup_time, time_end, expected), {
+    can_evaluate_setup_time_of_next_activity_impl(setup_time, time_end, expected);
+}}
+
+can_evaluate_setup_time_of_next_activity! {
+    case01_no_setup: (0., 50., None),
+    case02_setup_in_time: (5., 60., None),
+    case03_setup_too_late: (20., 60., Some(VIOLATION_CODE.0)),
+}
+
+fn can_evaluate_setup_time_of_next_activity_impl(setup_time: Duration, time_end: Timestamp, expected: Option<i32>) {
+    let feature = create_feature(None, setup_time);
+    let solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_single("job1", 10, 100.), create_single("job2", 10, time_end)], vec![]],
+    );
+    // NOTE inserting job between two jobs at the site splits the stop and requires one more setup
+    let target = create_activity_for_single(create_single("job3", 12, 100.));
+
+    let result = evaluate_activity(&feature, &solution_ctx, 0, 1, &target);
+
+    assert_eq!(result, expected);
+}
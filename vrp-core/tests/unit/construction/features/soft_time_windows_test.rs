@@ -0,0 +1,159 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_soft_time_windows(earliness: Cost, lateness: Cost, times: Vec<(Timestamp, Timestamp)>) -> SoftTimeWindows {
+    SoftTimeWindows {
+        earliness,
+        lateness,
+        times: vec![times.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect()],
+    }
+}
+
+fn create_activity(location: Location, soft: Option<SoftTimeWindows>) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(location));
+    if let Some(soft) = soft {
+        builder.dimens_mut().set_job_soft_time_windows(soft);
+    }
+
+    ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::max(), 0.)
+        .job(Some(builder.build_shared()))
+        .build()
+}
+
+fn create_feature() -> Feature {
+    create_soft_time_windows_feature("soft_tw", TestTransportCost::new_shared(), TestActivityCost::new_shared())
+        .unwrap()
+}
+
+parameterized_test! {can_get_deviation, (service_start, expected), {
+    can_get_deviation_impl(service_start, expected);
+}}
+
+can_get_deviation! {
+    case01_early: (5., (5., 0.)),
+    case02_inside_first: (15., (0., 0.)),
+    case03_late_for_first: (25., (0., 5.)),
+    case04_early_for_second: (35., (5., 0.)),
+    case05_inside_second: (45., (0., 0.)),
+    case06_late: (60., (0., 10.)),
+}
+
+fn can_get_deviation_impl(service_start: Timestamp, expected: (Duration, Duration)) {
+    let soft = create_soft_time_windows(1., 2., vec![(10., 20.), (40., 50.)]);
+
+    assert_eq!(soft.get_deviation(0, service_start), expected);
+}
+
+parameterized_test! {can_get_service_start, (earliness, waiting_rate, times, hard_end, arrival, expected), {
+    can_get_service_start_impl(earliness, waiting_rate, times, hard_end, arrival, expected);
+}}
+
+can_get_service_start! {
+    case01_waiting_is_cheaper: (2., 1., vec![(10., 20.)], 100., 5., 10.),
+    case02_waiting_is_expensive: (1., 2., vec![(10., 20.)], 100., 5., 5.),
+    case03_waiting_costs_the_same: (1., 1., vec![(10., 20.)], 100., 5., 5.),
+    case04_before_hard_start: (2., 1., vec![(10., 20.)], 100., 0., 10.),
+    case05_inside: (2., 1., vec![(10., 20.)], 100., 15., 15.),
+    case06_late_for_first_is_cheaper: (10., 1., vec![(10., 20.), (40., 50.)], 100., 22., 22.),
+    case07_early_for_second_is_cheaper: (1., 0., vec![(10., 20.), (40., 50.)], 100., 35., 40.),
+    case08_capped_by_hard_end: (2., 1., vec![(60., 70.)], 55., 52., 55.),
+}
+
+fn can_get_service_start_impl(
+    earliness: Cost,
+    waiting_rate: Cost,
+    times: Vec<(Timestamp, Timestamp)>,
+    hard_end: Timestamp,
+    arrival: Timestamp,
+    expected: Timestamp,
+) {
+    let soft = create_soft_time_windows(earliness, 2., times);
+
+    let result = soft.get_service_start(0, &TimeWindow::new(2., hard_end), arrival, waiting_rate);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_get_latest_arrival, (earliness, latest_start, expected), {
+    can_get_latest_arrival_impl(earliness, latest_start, expected);
+}}
+
+can_get_latest_arrival! {
+    case01_no_waiting: (0.5, 38., Some(38.)),
+    case02_no_waiting_needed: (3., 45., Some(45.)),
+    case03_waiting_for_second_is_too_long: (3., 38., Some(35.)),
+    case04_waiting_for_first_is_too_long: (3., 5., None),
+}
+
+fn can_get_latest_arrival_impl(earliness: Cost, latest_start: Timestamp, expected: Option<Timestamp>) {
+    let soft = create_soft_time_windows(earliness, 1., vec![(10., 20.), (40., 50.)]);
+    let hard = TimeWindow::new(0., 100.);
+
+    let result = soft.get_latest_arrival(0, &hard, latest_start, 1.);
+
+    assert_eq!(result, expected);
+    if let Some(arrival) = result {
+        assert!(soft.get_service_start(0, &hard, arrival, 1.) <= latest_start);
+    }
+}
+
+#[test]
+fn can_calculate_route_penalty_in_fitness() {
+    let activities = vec![
+        create_activity(10, Some(create_soft_time_windows(1., 2., vec![(0., 5.)]))),
+        create_activity(20, Some(create_soft_time_windows(1., 1., vec![(30., 40.)]))),
+        create_activity(30, None),
+        create_activity(40, Some(create_soft_time_windows(3., 1., vec![(50., 60.)]))),
+    ];
+    let mut route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().add_activities(activities).build()).build();
+    let feature = create_feature();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, 5. * 2. + 10. * 1.);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (target_soft, next_soft, expected), {
+    can_estimate_activity_insertion_impl(target_soft, next_soft, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_no_soft_windows: (None, None, 0.),
+    case02_delays_next: (None, Some((0., 1., (0., 10.))), 10.),
+    case03_target_late: (Some((0., 2., (0., 5.))), None, 20.),
+    case04_target_late_and_delays_next: (Some((0., 2., (0., 5.))), Some((0., 1., (0., 10.))), 30.),
+    case05_target_early: (Some((1., 0., (20., 30.))), None, 5.),
+    case06_next_already_late: (None, Some((0., 1., (0., 5.))), 10.),
+    case07_target_waits_when_cheaper: (Some((3., 0., (20., 30.))), None, 0.),
+    case08_target_waits_and_delays_next: (Some((3., 0., (20., 30.))), Some((0., 1., (0., 10.))), 15.),
+}
+
+fn can_estimate_activity_insertion_impl(
+    target_soft: Option<(Cost, Cost, (Timestamp, Timestamp))>,
+    next_soft: Option<(Cost, Cost, (Timestamp, Timestamp))>,
+    expected: Cost,
+) {
+    let to_soft = |(earliness, lateness, time)| create_soft_time_windows(earliness, lateness, vec![time]);
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().add_activity(create_activity(10, next_soft.map(to_soft))).build())
+        .build();
+    let target = create_activity(15, target_soft.map(to_soft));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+    let result =
+        create_feature().objective.unwrap().estimate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
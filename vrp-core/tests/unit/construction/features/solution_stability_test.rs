@@ -0,0 +1,159 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{FleetBuilder, TestSingleBuilder, test_driver, test_vehicle_with_id};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::problem::{Fleet, Single};
+
+const WEIGHT: Float = 2.;
+
+struct TestData {
+    fleet: Fleet,
+    jobs: HashMap<String, Arc<Single>>,
+}
+
+impl TestData {
+    fn new() -> Self {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+            .build();
+        let jobs = (1..=5)
+            .map(|idx| format!("job{idx}"))
+            .map(|id| (id.clone(), TestSingleBuilder::default().id(id.as_str()).build_shared()))
+            .collect();
+
+        Self { fleet, jobs }
+    }
+
+    fn job(&self, id: &str) -> Job {
+        Job::Single(self.jobs.get(id).unwrap().clone())
+    }
+
+    fn vehicle(&self, id: &str) -> Arc<Vehicle> {
+        self.fleet.vehicles.iter().find(|vehicle| vehicle.dimens.get_vehicle_id().unwrap() == id).unwrap().clone()
+    }
+
+    /// Reference plan: v1 serves job1, job2, job3; v2 serves job4. Job5 has no reference.
+    fn references(&self) -> HashMap<Job, JobReference> {
+        [("job1", "v1", 0), ("job2", "v1", 1), ("job3", "v1", 2), ("job4", "v2", 0)]
+            .into_iter()
+            .map(|(job_id, vehicle_id, position)| {
+                (self.job(job_id), JobReference { vehicle: self.vehicle(vehicle_id), position })
+            })
+            .collect()
+    }
+
+    fn route_ctx(&self, vehicle_id: &str, job_ids: &[&str]) -> RouteContext {
+        RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&self.fleet, vehicle_id)
+                    .add_activities(job_ids.iter().map(|job_id| {
+                        ActivityBuilder::default().job(Some(self.jobs.get(*job_id).unwrap().clone())).build()
+                    }))
+                    .build(),
+            )
+            .build()
+    }
+}
+
+parameterized_test! {can_calculate_fitness, (v1_jobs, v2_jobs, expected), {
+    can_calculate_fitness_impl(v1_jobs, v2_jobs, expected);
+}}
+
+can_calculate_fitness! {
+    case01_same_as_reference: (vec!["job1", "job2", "job3"], vec!["job4"], 0),
+    case02_changed_sequence: (vec!["job2", "job1", "job3"], vec!["job4"], 1),
+    case03_reassigned: (vec!["job1", "job3"], vec!["job4", "job2"], 1),
+    case04_reassigned_and_changed_sequence: (vec!["job3", "job1", "job4"], vec!["job2"], 3),
+    case05_no_reference: (vec!["job1", "job5", "job2"], vec![], 0),
+    case06_empty: (vec![], vec![], 0),
+}
+
+fn can_calculate_fitness_impl(v1_jobs: Vec<&str>, v2_jobs: Vec<&str>, expected: usize) {
+    let data = TestData::new();
+    let feature = create_solution_stability_feature("stability", data.references(), WEIGHT).unwrap();
+    let mut insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![data.route_ctx("v1", v1_jobs.as_slice()), data.route_ctx("v2", v2_jobs.as_slice())])
+        .build();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+    let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(insertion_ctx.solution.state.get_solution_deviations().copied(), Some(expected));
+    assert_eq!(fitness, expected as Float * WEIGHT);
+}
+
+parameterized_test! {can_estimate_route_move, (vehicle_id, job_id, expected), {
+    can_estimate_route_move_impl(vehicle_id, job_id, expected);
+}}
+
+can_estimate_route_move! {
+    case01_same_vehicle: ("v1", "job1", 0.),
+    case02_other_vehicle: ("v2", "job1", WEIGHT),
+    case03_no_reference: ("v2", "job5", 0.),
+}
+
+fn can_estimate_route_move_impl(vehicle_id: &str, job_id: &str, expected: Float) {
+    let data = TestData::new();
+    let objective =
+        create_solution_stability_feature("stability", data.references(), WEIGHT).unwrap().objective.unwrap();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let route_ctx = data.route_ctx(vehicle_id, &[]);
+
+    let result = objective.estimate(&MoveContext::route(&solution_ctx, &route_ctx, &data.job(job_id)));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_activity_move, (route_jobs, target_job, target_index, expected), {
+    can_estimate_activity_move_impl(route_jobs, target_job, target_index, expected);
+}}
+
+can_estimate_activity_move! {
+    case01_keeps_sequence: (vec!["job1", "job3"], "job2", 1, 0.),
+    case02_before_first: (vec!["job1", "job3"], "job2", 0, WEIGHT),
+    case03_after_last: (vec!["job1", "job3"], "job2", 2, WEIGHT),
+    case04_inside_descent: (vec!["job3", "job1"], "job2", 1, WEIGHT),
+    case05_no_reference_inside_descent: (vec!["job3", "job1"], "job5", 1, 0.),
+    case06_other_vehicle: (vec!["job1", "job2"], "job4", 1, 0.),
+    case07_after_no_reference: (vec!["job3", "job5"], "job2", 2, WEIGHT),
+    case08_before_no_reference: (vec!["job5", "job1"], "job2", 0, WEIGHT),
+    case09_between_no_reference: (vec!["job1", "job5", "job5", "job3"], "job2", 2, 0.),
+    case10_inside_descent_with_no_reference: (vec!["job3", "job5", "job1"], "job2", 1, WEIGHT),
+}
+
+fn can_estimate_activity_move_impl(route_jobs: Vec<&str>, target_job: &str, prev_index: usize, expected: Float) {
+    let data = TestData::new();
+    let objective =
+        create_solution_stability_feature("stability", data.references(), WEIGHT).unwrap().objective.unwrap();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let route_ctx = data.route_ctx("v1", route_jobs.as_slice());
+    let activity_ctx = ActivityContext {
+        index: prev_index,
+        prev: route_ctx.route().tour.get(prev_index).unwrap(),
+        target: &ActivityBuilder::default().job(Some(data.jobs.get(target_job).unwrap().clone())).build(),
+        next: route_ctx.route().tour.get(prev_index + 1),
+    };
+
+    let result = objective.estimate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+    let get_fitness = |job_ids: &[&str]| {
+        objective
+            .fitness(&TestInsertionContextBuilder::default().with_routes(vec![data.route_ctx("v1", job_ids)]).build())
+    };
+    let route_estimate = objective.estimate(&MoveContext::route(&solution_ctx, &route_ctx, &data.job(target_job)));
+    let mut new_route_jobs = route_jobs.clone();
+    new_route_jobs.insert(prev_index, target_job);
+    assert_eq!(get_fitness(new_route_jobs.as_slice()) - get_fitness(route_jobs.as_slice()), expected + route_estimate);
+}
+
+#[test]
+fn can_reject_non_positive_weight() {
+    let data = TestData::new();
+
+    let result = create_solution_stability_feature("stability", data.references(), 0.);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,140 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature() -> Feature {
+    create_split_feature("split", VIOLATION_CODE).unwrap()
+}
+
+fn create_job(split: Option<(&str, usize)>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    if let Some((key, max_splits)) = split {
+        builder.dimens_mut().set_job_split(JobSplit { key: key.to_string(), max_splits });
+    }
+
+    Job::Single(builder.build_shared())
+}
+
+/// Creates a solution where each vehicle serves given jobs.
+fn create_solution_ctx(feature: &Feature, routes: Vec<Vec<Job>>) -> SolutionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2"), test_vehicle_with_id("v3")])
+        .build();
+
+    let routes = ["v1", "v2", "v3"]
+        .iter()
+        .zip(routes)
+        .map(|(vehicle_id, jobs)| {
+            let activities = jobs
+                .into_iter()
+                .map(|job| ActivityBuilder::with_location(1).job(Some(job.to_single().clone())).build());
+            let mut route_ctx = RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(&fleet, vehicle_id).add_activities(activities).build())
+                .build();
+            feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+            route_ctx
+        })
+        .collect();
+
+    TestInsertionContextBuilder::default().with_routes(routes).build().solution
+}
+
+parameterized_test! {can_evaluate_split_job_on_route, (other_routes, has_part, max_splits, expected), {
+    can_evaluate_split_job_on_route_impl(other_routes, has_part, max_splits, expected);
+}}
+
+can_evaluate_split_job_on_route! {
+    case01_no_parts: (0, false, 1, None),
+    case02_other_route_limit: (1, false, 1, Some(VIOLATION_CODE)),
+    case03_other_route_no_limit: (1, false, 2, None),
+    case04_same_route: (1, true, 2, None),
+    case05_other_routes_limit: (2, false, 2, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_split_job_on_route_impl(
+    other_routes: usize,
+    has_part: bool,
+    max_splits: usize,
+    expected: Option<ViolationCode>,
+) {
+    let feature = create_feature();
+    let create_part = || create_job(Some(("job1", max_splits)));
+    let routes = std::iter::once(if has_part { vec![create_part()] } else { vec![] })
+        .chain((0..other_routes).map(|_| vec![create_part()]))
+        .collect();
+    let solution_ctx = create_solution_ctx(&feature, routes);
+    let job = create_part();
+
+    let result = feature
+        .constraint
+        .as_ref()
+        .unwrap()
+        .evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job))
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case01_no_splits: (None, None, Ok(())),
+    case02_source_split: (Some(("job1", 2)), None, Err(VIOLATION_CODE)),
+    case03_candidate_split: (None, Some(("job1", 2)), Err(VIOLATION_CODE)),
+    case04_same_split: (Some(("job1", 2)), Some(("job1", 2)), Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(
+    source: Option<(&str, usize)>,
+    candidate: Option<(&str, usize)>,
+    expected: Result<(), ViolationCode>,
+) {
+    let feature = create_feature();
+
+    let result = feature.constraint.unwrap().merge(create_job(source), create_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_unassign_excess_parts, (route_parts, max_splits, locked, expected), {
+    can_unassign_excess_parts_impl(route_parts, max_splits, locked, expected);
+}}
+
+can_unassign_excess_parts! {
+    case01_within_limit: (vec![2, 1], 2, None, vec![2, 1]),
+    case02_keep_more_parts: (vec![1, 2, 1], 1, None, vec![0, 2, 0]),
+    case03_keep_two_routes: (vec![1, 3, 2], 2, None, vec![0, 3, 2]),
+    case04_keep_locked: (vec![1, 2], 1, Some(0), vec![1, 0]),
+}
+
+fn can_unassign_excess_parts_impl(
+    route_parts: Vec<usize>,
+    max_splits: usize,
+    locked: Option<usize>,
+    expected: Vec<usize>,
+) {
+    let feature = create_feature();
+    let routes = route_parts
+        .iter()
+        .map(|&parts| (0..parts).map(|_| create_job(Some(("job1", max_splits)))).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let unassigned = route_parts.iter().sum::<usize>() - expected.iter().sum::<usize>();
+    let mut solution_ctx = create_solution_ctx(&feature, routes.clone());
+    if let Some(route_idx) = locked {
+        solution_ctx.locked.insert(routes[route_idx][0].clone());
+    }
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let result = solution_ctx.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).collect::<Vec<_>>();
+    assert_eq!(result, expected);
+    assert_eq!(solution_ctx.unassigned.len(), unassigned);
+    assert!(solution_ctx.unassigned.values().all(|info| matches!(info, UnassignmentInfo::Simple(VIOLATION_CODE))));
+}
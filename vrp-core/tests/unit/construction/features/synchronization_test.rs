@@ -0,0 +1,187 @@
+use super::*;
+use crate::helpers::construction::features::{create_activity_for_single, create_two_routes_solution_ctx};
+use crate::helpers::models::problem::*;
+use crate::models::common::{Location, TimeWindow};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature() -> Feature {
+    create_synchronization_feature(
+        "sync",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_sync_single(location: Location, time_end: Timestamp, tolerance: Duration) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(location)).times(vec![TimeWindow::new(0., time_end)]);
+    builder.dimens_mut().set_job_sync(JobSync { key: "job1".to_string(), size: 2, tolerance });
+
+    builder.build_shared()
+}
+
+fn get_sync_starts(solution_ctx: &SolutionContext) -> Vec<Timestamp> {
+    solution_ctx
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+        .filter(|activity| get_sync(activity).is_some())
+        .map(|activity| activity.schedule.arrival.max(activity.place.time.start))
+        .collect()
+}
+
+parameterized_test! {can_align_service_start_across_routes, (tolerance, expected), {
+    can_align_service_start_across_routes_impl(tolerance, expected);
+}}
+
+can_align_service_start_across_routes! {
+    case01_no_tolerance: (0., vec![20., 20.]),
+    case02_with_tolerance: (5., vec![15., 20.]),
+    case03_within_tolerance: (10., vec![10., 20.]),
+}
+
+fn can_align_service_start_across_routes_impl(tolerance: Duration, expected: Vec<Timestamp>) {
+    let feature = create_feature();
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_sync_single(10, 100., tolerance)], vec![create_sync_single(20, 100., tolerance)]],
+    );
+    let job = solution_ctx.routes[1].route().tour.jobs().next().cloned().unwrap();
+
+    feature.state.as_ref().unwrap().accept_insertion(&mut solution_ctx, 1, &job);
+
+    assert_eq!(get_sync_starts(&solution_ctx), expected);
+}
+
+#[test]
+fn can_reset_waiting_when_copy_is_removed() {
+    let feature = create_feature();
+    let state = feature.state.as_ref().unwrap();
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_sync_single(10, 100., 0.)], vec![create_sync_single(20, 100., 0.)]],
+    );
+    let job = solution_ctx.routes[1].route().tour.jobs().next().cloned().unwrap();
+    state.accept_insertion(&mut solution_ctx, 1, &job);
+    assert_eq!(get_sync_starts(&solution_ctx), vec![20., 20.]);
+
+    solution_ctx.routes[1].route_mut().tour.remove(&job);
+    solution_ctx.required.push(job);
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_sync_starts(&solution_ctx), vec![10.]);
+}
+
+parameterized_test! {can_evaluate_copy_insertion, (assigned, target, tolerance, expected), {
+    can_evaluate_copy_insertion_impl(assigned, target, tolerance, expected);
+}}
+
+can_evaluate_copy_insertion! {
+    case01_other_copy_can_wait: ((10, 100.), (20, 100.), 0., None),
+    case02_other_copy_cannot_wait: ((10, 15.), (20, 100.), 0., Some(VIOLATION_CODE.0)),
+    case03_other_copy_waits_within_tolerance: ((10, 15.), (20, 100.), 5., None),
+    case04_new_copy_can_wait: ((30, 100.), (20, 100.), 0., None),
+    case05_new_copy_cannot_wait: ((30, 100.), (20, 25.), 0., Some(VIOLATION_CODE.0)),
+    case06_new_copy_waits_within_tolerance: ((30, 100.), (20, 25.), 5., None),
+}
+
+fn can_evaluate_copy_insertion_impl(
+    assigned: (Location, Timestamp),
+    target: (Location, Timestamp),
+    tolerance: Duration,
+    expected: Option<i32>,
+) {
+    let feature = create_feature();
+    let solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_sync_single(assigned.0, assigned.1, tolerance)], vec![]],
+    );
+    let route_ctx = &solution_ctx.routes[1];
+    let target = create_activity_for_single(create_sync_single(target.0, target.1, tolerance));
+
+    let result = feature
+        .constraint
+        .as_ref()
+        .unwrap()
+        .evaluate(&MoveContext::activity(
+            &solution_ctx,
+            route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: route_ctx.route().tour.start().unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.end(),
+            },
+        ))
+        .map(|violation| violation.code.0);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_copies_in_the_same_route() {
+    let feature = create_feature();
+    let solution_ctx = create_two_routes_solution_ctx(&feature, vec![vec![create_sync_single(10, 100., 0.)], vec![]]);
+    let job = Job::Single(create_sync_single(20, 100., 0.));
+
+    let results = solution_ctx
+        .routes
+        .iter()
+        .map(|route_ctx| {
+            feature
+                .constraint
+                .as_ref()
+                .unwrap()
+                .evaluate(&MoveContext::route(&solution_ctx, route_ctx, &job))
+                .map(|violation| violation.code.0)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(results, vec![Some(VIOLATION_CODE.0), None]);
+}
+
+parameterized_test! {can_unassign_incomplete_job, (is_required, expected), {
+    can_unassign_incomplete_job_impl(is_required, expected);
+}}
+
+can_unassign_incomplete_job! {
+    case01_missing_copy_is_required: (true, 1),
+    case02_missing_copy_is_unassigned: (false, 0),
+}
+
+fn can_unassign_incomplete_job_impl(is_required: bool, expected: usize) {
+    let feature = create_feature();
+    let mut solution_ctx =
+        create_two_routes_solution_ctx(&feature, vec![vec![create_sync_single(10, 100., 0.)], vec![]]);
+    if is_required {
+        solution_ctx.required.push(Job::Single(create_sync_single(20, 100., 0.)));
+    }
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_sync_starts(&solution_ctx).len(), expected);
+    assert_eq!(solution_ctx.unassigned.len(), 1 - expected);
+    assert!(
+        solution_ctx
+            .unassigned
+            .values()
+            .all(|info| matches!(info, UnassignmentInfo::Simple(code) if *code == VIOLATION_CODE))
+    );
+}
+
+#[test]
+fn can_unassign_copies_which_become_late() {
+    let feature = create_feature();
+    let mut solution_ctx = create_two_routes_solution_ctx(
+        &feature,
+        vec![vec![create_sync_single(10, 15., 0.)], vec![create_sync_single(20, 100., 0.)]],
+    );
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert!(get_sync_starts(&solution_ctx).is_empty());
+    assert_eq!(solution_ctx.unassigned.len(), 2);
+}
@@ -0,0 +1,184 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, SingleDimLoad, TimeInterval};
+use crate::models::problem::{Fleet, VehicleDetail, VehiclePlace};
+use crate::models::solution::Activity;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+const SATELLITE: SharedResourceId = 0;
+
+fn create_fleet(shift_start: Timestamp) -> Fleet {
+    let mut second_level = TestVehicleBuilder::default();
+    second_level.id("v2").details(vec![VehicleDetail {
+        start: Some(VehiclePlace { location: 10, time: TimeInterval { earliest: Some(shift_start), latest: None } }),
+        end: None,
+    }]);
+    second_level.dimens_mut().set_vehicle_satellite(SATELLITE);
+
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(second_level.build())
+        .build()
+}
+
+fn create_supply_activity(amount: i32, departure: Timestamp) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    builder.demand(create_simple_demand(-amount));
+    builder.dimens_mut().set_job_satellite(SATELLITE);
+
+    ActivityBuilder::with_location(10)
+        .job(Some(builder.build_shared()))
+        .schedule(Schedule::new(departure, departure))
+        .build()
+}
+
+fn create_customer_single(amount: i32) -> Arc<Single> {
+    TestSingleBuilder::default().demand(create_simple_demand(-amount)).build_shared()
+}
+
+fn create_solution_ctx(fleet: &Fleet, supply: Vec<(i32, Timestamp)>, customers: Vec<i32>) -> SolutionContext {
+    let first_level = supply.into_iter().map(|(amount, departure)| create_supply_activity(amount, departure));
+    let second_level = customers
+        .into_iter()
+        .map(|amount| ActivityBuilder::with_location(20).job(Some(create_customer_single(amount))).build());
+
+    let routes = vec![
+        RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(fleet, "v1").add_activities(first_level).build())
+            .build(),
+        RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(fleet, "v2").add_activities(second_level).build())
+            .build(),
+    ];
+
+    TestInsertionContextBuilder::default().with_routes(routes).build().solution
+}
+
+fn create_feature() -> Feature {
+    create_two_echelon_feature::<SingleDimLoad>(
+        "two_echelon",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_evaluate_job_on_second_level_route, (supply, shift_start, customers, job_demand, is_supply, expected), {
+    can_evaluate_job_on_second_level_route_impl(supply, shift_start, customers, job_demand, is_supply, expected);
+}}
+
+can_evaluate_job_on_second_level_route! {
+    case01_enough_supply: (vec![(10, 10.)], 20., vec![3], 5, false, None),
+    case02_not_enough_supply: (vec![(10, 10.)], 20., vec![3], 8, false, Some(VIOLATION_CODE)),
+    case03_supply_from_two_jobs: (vec![(5, 10.), (5, 15.)], 20., vec![3], 7, false, None),
+    case04_supply_is_late: (vec![(10, 10.), (5, 30.)], 20., vec![], 2, false, Some(VIOLATION_CODE)),
+    case05_no_supply: (vec![], 20., vec![], 1, false, Some(VIOLATION_CODE)),
+    case06_supply_job: (vec![(10, 10.)], 20., vec![], 1, true, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_job_on_second_level_route_impl(
+    supply: Vec<(i32, Timestamp)>,
+    shift_start: Timestamp,
+    customers: Vec<i32>,
+    job_demand: i32,
+    is_supply: bool,
+    expected: Option<ViolationCode>,
+) {
+    let fleet = create_fleet(shift_start);
+    let mut solution_ctx = create_solution_ctx(&fleet, supply, customers);
+    let feature = create_feature();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let job = if is_supply {
+        Job::Single(create_supply_activity(job_demand, 0.).job.unwrap())
+    } else {
+        Job::Single(create_customer_single(job_demand))
+    };
+
+    let result =
+        feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[1], &job));
+
+    assert_eq!(result.map(|result| result.code), expected);
+}
+
+#[test]
+fn can_ignore_job_on_first_level_route() {
+    let fleet = create_fleet(20.);
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![], vec![]);
+    let feature = create_feature();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let job = Job::Single(create_customer_single(5));
+
+    let result =
+        feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job));
+
+    assert_eq!(result, None);
+}
+
+parameterized_test! {can_remove_jobs_without_supply, (supply, shift_start, customers, expected_left), {
+    can_remove_jobs_without_supply_impl(supply, shift_start, customers, expected_left);
+}}
+
+can_remove_jobs_without_supply! {
+    case01_enough_supply: (vec![(10, 10.)], 20., vec![5, 5], 2),
+    case02_not_enough_supply: (vec![(10, 10.)], 20., vec![5, 4, 2], 2),
+    case03_supply_is_late: (vec![(10, 30.)], 20., vec![5, 5], 0),
+}
+
+fn can_remove_jobs_without_supply_impl(
+    supply: Vec<(i32, Timestamp)>,
+    shift_start: Timestamp,
+    customers: Vec<i32>,
+    expected_left: usize,
+) {
+    let fleet = create_fleet(shift_start);
+    let total = customers.len();
+    let mut solution_ctx = create_solution_ctx(&fleet, supply, customers);
+    let state = create_feature().state.unwrap();
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[1].route().tour.job_count(), expected_left);
+    assert_eq!(solution_ctx.unassigned.len(), total - expected_left);
+    assert!(
+        solution_ctx
+            .unassigned
+            .values()
+            .all(|info| matches!(info, UnassignmentInfo::Simple(code) if *code == VIOLATION_CODE))
+    );
+}
+
+parameterized_test! {can_evaluate_supply_activity_timing, (shift_start, prev_departure, expected), {
+    can_evaluate_supply_activity_timing_impl(shift_start, prev_departure, expected);
+}}
+
+can_evaluate_supply_activity_timing! {
+    case01_on_time: (20., 0., None),
+    case02_too_late: (20., 15., Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_supply_activity_timing_impl(
+    shift_start: Timestamp,
+    prev_departure: Timestamp,
+    expected: Option<ViolationCode>,
+) {
+    let fleet = create_fleet(shift_start);
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![(5, 0.)], vec![1]);
+    let feature = create_feature();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let prev = ActivityBuilder::with_location(0).schedule(Schedule::new(prev_departure, prev_departure)).build();
+    let target = create_supply_activity(2, 0.);
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+        &solution_ctx,
+        &solution_ctx.routes[0],
+        &activity_ctx,
+    ));
+
+    assert_eq!(result.map(|result| result.code), expected);
+}
@@ -181,3 +181,112 @@ mod objective {
         assert_eq!(result, expected);
     }
 }
+
+mod speed_profiles {
+    use super::*;
+
+    fn create_speed_profile(points: &[(Timestamp, Float)]) -> SpeedProfile {
+        SpeedProfile::new(points.to_vec()).expect("cannot create speed profile")
+    }
+
+    parameterized_test! {can_validate_speed_profile, (points, expected), {
+        can_validate_speed_profile_impl(points, expected);
+    }}
+
+    can_validate_speed_profile! {
+        case01_empty: (vec![], Some("speed profile should have at least one point")),
+        case02_zero_factor: (vec![(0., 1.), (10., 0.)], Some("speed profile factors should be positive finite values")),
+        case03_unsorted: (vec![(10., 1.), (0., 1.)], Some("speed profile points should be sorted by time without duplicates")),
+        case04_duplicates: (vec![(0., 1.), (0., 2.)], Some("speed profile points should be sorted by time without duplicates")),
+        case05_valid: (vec![(0., 1.), (10., 2.)], None),
+    }
+
+    fn can_validate_speed_profile_impl(points: Vec<(Timestamp, Float)>, expected: Option<&str>) {
+        let result = SpeedProfile::new(points);
+
+        assert_eq!(result.err(), expected.map(GenericError::from));
+    }
+
+    parameterized_test! {can_calculate_duration_at_departure, (points, departure, free_flow, expected), {
+        can_calculate_duration_at_departure_impl(points, departure, free_flow, expected);
+    }}
+
+    can_calculate_duration_at_departure! {
+        case01_constant: (vec![(0., 0.5)], 0., 10., 20.),
+        case02_before_first: (vec![(100., 2.)], 0., 10., 5.),
+        case03_step_like: (vec![(0., 1.), (10., 1.), (10.0001, 0.5)], 5., 10., 15.),
+        case04_after_last: (vec![(0., 1.), (10., 0.5)], 20., 10., 20.),
+        case05_linear: (vec![(0., 1.), (10., 0.5)], 0., 7.5, 10.),
+        case06_linear_mid: (vec![(0., 2.), (10., 0.5), (20., 0.5)], 0., 12.5, 10.),
+    }
+
+    fn can_calculate_duration_at_departure_impl(
+        points: Vec<(Timestamp, Float)>,
+        departure: Timestamp,
+        free_flow: Duration,
+        expected: Duration,
+    ) {
+        let profile = create_speed_profile(points.as_slice());
+
+        let duration = profile.duration_at_departure(departure, free_flow);
+
+        assert!((duration - expected).abs() < 0.01, "expected: {expected}, got: {duration}");
+    }
+
+    #[test]
+    fn can_calculate_consistent_duration_at_arrival() {
+        let profile = create_speed_profile(&[(0., 1.), (100., 0.4), (200., 0.4), (300., 1.5)]);
+
+        for departure in [-50., 0., 50., 99., 150., 250., 299., 400.] {
+            let duration = profile.duration_at_departure(departure, 60.);
+            let back_duration = profile.duration_at_arrival(departure + duration, 60.);
+
+            assert!((duration - back_duration).abs() < 1E-6, "departure: {departure}: {duration} vs {back_duration}");
+        }
+    }
+
+    #[test]
+    fn can_keep_fifo_property() {
+        let profile = create_speed_profile(&[(0., 2.), (100., 0.2), (150., 0.2), (200., 3.)]);
+
+        let arrivals = (0..300)
+            .map(|departure| departure as Float)
+            .map(|departure| departure + profile.duration_at_departure(departure, 50.));
+
+        arrivals.collect::<Vec<_>>().windows(2).for_each(|pair| assert!(pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn can_use_edge_classes_in_transport_cost() {
+        let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+        let costs = create_speed_profile_transport_cost(
+            vec![create_matrix_data(Profile::default(), None, (100., 4), (10., 4))],
+            vec![SpeedProfileData::new(
+                0,
+                vec![create_speed_profile(&[(0., 0.5)]), create_speed_profile(&[(0., 1.), (100., 2.)])],
+                Some(vec![0, 0, 1, 0]),
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(costs.duration(&route, 0, 1, TravelTime::Departure(0.)), 200.);
+        assert_eq!(costs.duration(&route, 1, 0, TravelTime::Departure(100.)), 50.);
+        assert_eq!(costs.duration(&route, 1, 0, TravelTime::Arrival(150.)), 50.);
+        assert_eq!(costs.distance(&route, 1, 0, TravelTime::Departure(100.)), 10.);
+        assert_eq!(costs.duration_approx(&Profile::default(), 1, 0), 100.);
+    }
+
+    #[test]
+    fn can_return_error_when_edge_classes_are_invalid() {
+        let create = |edge_classes: Vec<usize>| {
+            create_speed_profile_transport_cost(
+                vec![create_matrix_data(Profile::default(), None, (1., 4), (1., 4))],
+                vec![SpeedProfileData::new(0, vec![create_speed_profile(&[(0., 1.)])], Some(edge_classes))],
+            )
+            .err()
+        };
+
+        assert_eq!(create(vec![0, 0, 0]), Some("edge classes length does not match matrix size".into()));
+        assert_eq!(create(vec![0, 0, 0, 1]), Some("edge class refers to unknown speed profile".into()));
+    }
+}
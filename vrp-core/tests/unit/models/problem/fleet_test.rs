@@ -1,4 +1,7 @@
-use crate::helpers::models::problem::{FleetBuilder, test_driver, test_vehicle};
+use crate::helpers::models::problem::{FleetBuilder, test_driver, test_vehicle, test_vehicle_with_id};
+use crate::models::common::TimeInterval;
+use crate::models::problem::{Driver, DriverDetail};
+use crate::prelude::Float;
 
 #[test]
 fn fleet_creates_unique_profiles_from_vehicles() {
@@ -19,3 +22,33 @@ fn fleet_creates_unique_profiles_from_vehicles() {
         vec![profile1, profile2]
     )
 }
+
+fn create_driver_with_shifts(shifts: Vec<(Float, Float)>) -> Driver {
+    Driver {
+        details: shifts
+            .into_iter()
+            .map(|(earliest, latest)| DriverDetail {
+                time: TimeInterval { earliest: Some(earliest), latest: Some(latest) },
+            })
+            .collect(),
+        ..test_driver()
+    }
+}
+
+#[test]
+fn fleet_creates_actors_for_each_vehicle_and_driver_shift() {
+    let fleet = FleetBuilder::default()
+        .add_driver(create_driver_with_shifts(vec![(0., 100.), (200., 300.), (2000., 3000.)]))
+        .add_driver(create_driver_with_shifts(vec![(50., 500.)]))
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .build();
+
+    let mut times =
+        fleet.actors.iter().map(|actor| (actor.detail.time.start, actor.detail.time.end)).collect::<Vec<_>>();
+    times.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    assert_eq!(times, vec![(0., 100.), (50., 500.), (200., 300.)]);
+    assert_eq!(fleet.actors.iter().filter_map(|actor| actor.detail.start.as_ref()?.time.earliest).sum::<Float>(), 250.);
+    assert_eq!(fleet.actors.iter().filter_map(|actor| actor.detail.end.as_ref()?.time.latest).sum::<Float>(), 900.);
+    assert!(fleet.actors.iter().all(|actor| fleet.conflicts.get(actor).map_or(0, |c| c.len()) == 2));
+}
@@ -1,7 +1,7 @@
 use crate::helpers::models::domain::test_random;
 use crate::helpers::models::problem::{FleetBuilder, TestVehicleBuilder, test_driver, test_vehicle_detail};
 use crate::models::common::TimeInterval;
-use crate::models::problem::{Actor, VehicleDetail, VehiclePlace};
+use crate::models::problem::{Actor, Driver, DriverDetail, VehicleDetail, VehiclePlace};
 use crate::models::solution::Registry;
 use std::sync::Arc;
 
@@ -65,3 +65,29 @@ fn create_two_test_vehicle_details() -> Vec<VehicleDetail> {
         },
     ]
 }
+
+#[test]
+fn can_handle_actors_sharing_driver_shift_in_registry() {
+    let driver = Driver {
+        details: vec![DriverDetail { time: TimeInterval { earliest: Some(0.), latest: Some(100.) } }],
+        ..test_driver()
+    };
+    let fleet = FleetBuilder::default()
+        .add_driver(driver)
+        .add_vehicles(vec![
+            TestVehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build(),
+            TestVehicleBuilder::default().id("v2").details(vec![test_vehicle_detail()]).build(),
+        ])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    let actor = registry.available().next().unwrap();
+    assert_eq!(registry.available().count(), 2);
+
+    assert!(registry.use_actor(&actor));
+    assert_eq!(registry.available().count(), 0);
+    assert!(!registry.use_actor(&actor));
+
+    assert!(registry.free_actor(&actor));
+    assert_eq!(registry.available().count(), 2);
+    assert!(!registry.free_actor(&actor));
+}
@@ -11,16 +11,20 @@ use rosomaxa::evolution::HeuristicSolutionProcessing;
 
 const UNASSIGNMENT_CODE: ViolationCode = ViolationCode(1);
 
-fn create_test_insertion_ctx(unassigned: Vec<(Job, UnassignmentInfo)>) -> InsertionContext {
+fn create_test_insertion_ctx(unassigned: Vec<(Job, UnassignmentInfo)>, vehicle_ids: &[&str]) -> InsertionContext {
     let fleet = FleetBuilder::default()
         .add_driver(test_driver())
         .add_vehicle(test_vehicle_with_id("v1"))
         .add_vehicle(test_vehicle_with_id("v2"))
         .build();
-    let routes = vec![
-        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build(),
-        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v2").build()).build(),
-    ];
+    let routes = vehicle_ids
+        .iter()
+        .map(|vehicle_id| {
+            RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(&fleet, vehicle_id).build())
+                .build()
+        })
+        .collect();
     let mut insertion_ctx = TestInsertionContextBuilder::default()
         .with_problem(
             ProblemBuilder::default()
@@ -68,7 +72,7 @@ fn can_combine_vehicle_details_impl(
     unassigned: Vec<(Job, UnassignmentInfo)>,
     expected_details: Vec<(&str, Vec<(&str, ViolationCode)>)>,
 ) {
-    let insertion_ctx = create_test_insertion_ctx(unassigned);
+    let insertion_ctx = create_test_insertion_ctx(unassigned, &["v1", "v2"]);
 
     let insertion_ctx = UnassignmentReason::default().post_process(insertion_ctx);
 
@@ -102,7 +106,7 @@ can_handle_assignable_job! {
 
 fn can_handle_assignable_job_impl(code: UnassignmentInfo) {
     let expected = (create_assignable_delivery("job1"), code);
-    let insertion_ctx = create_test_insertion_ctx(vec![expected.clone()]);
+    let insertion_ctx = create_test_insertion_ctx(vec![expected.clone()], &["v1", "v2"]);
 
     let insertion_ctx = UnassignmentReason::default().post_process(insertion_ctx);
 
@@ -119,3 +123,24 @@ fn can_handle_assignable_job_impl(code: UnassignmentInfo) {
         _ => unreachable!(),
     }
 }
+
+parameterized_test! {can_find_unknown_reason_without_routes, code, {
+    can_find_unknown_reason_without_routes_impl(code);
+}}
+
+can_find_unknown_reason_without_routes! {
+    case_01_unknown: UnassignmentInfo::Unknown,
+    case_02_unknown_code: UnassignmentInfo::Simple(ViolationCode::unknown()),
+}
+
+fn can_find_unknown_reason_without_routes_impl(code: UnassignmentInfo) {
+    let insertion_ctx = create_test_insertion_ctx(vec![(create_early_delivery("job1"), code)], &[]);
+
+    let insertion_ctx = UnassignmentReason::default().post_process(insertion_ctx);
+
+    let (_, code) = insertion_ctx.solution.unassigned.into_iter().next().unwrap();
+    match code {
+        UnassignmentInfo::Simple(code) => assert_eq!(code, UNASSIGNMENT_CODE),
+        _ => unreachable!(),
+    }
+}
@@ -8,13 +8,25 @@ use crate::format::solution::activity_matcher::*;
 use crate::utils::combine_error_results;
 use std::collections::HashSet;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::construction::features::{JobChargingCurveDimension, JobSoftTimeWindowsDimension};
 use vrp_core::models::solution::Place;
 use vrp_core::prelude::GenericResult;
 use vrp_core::utils::GenericError;
 
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicles(ctx), check_jobs_presence(ctx), check_jobs_match(ctx), check_groups(ctx)])
+    combine_error_results(&[
+        check_vehicles(ctx),
+        check_drivers(ctx),
+        check_jobs_presence(ctx),
+        check_jobs_match(ctx),
+        check_lateness(ctx),
+        check_groups(ctx),
+        check_splits(ctx),
+        check_syncs(ctx),
+        check_alternatives(ctx),
+        check_mandatory(ctx),
+    ])
 }
 
 /// Checks that vehicles in each tour are used once per shift and they are known in problem.
@@ -37,6 +49,53 @@ fn check_vehicles(ctx: &CheckerContext) -> GenericResult<()> {
     Ok(())
 }
 
+/// Checks that drivers are known in problem, each tour fits a driver shift and each shift is used once.
+fn check_drivers(ctx: &CheckerContext) -> GenericResult<()> {
+    let all_drivers = ctx
+        .problem
+        .fleet
+        .drivers
+        .iter()
+        .flat_map(|drivers| drivers.iter())
+        .map(|d| (&d.id, d))
+        .collect::<HashMap<_, _>>();
+    let mut used_shifts = HashSet::<(String, usize)>::new();
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let driver = match (all_drivers.is_empty(), tour.driver_id.as_ref()) {
+            (true, None) => return Ok(()),
+            (true, Some(driver_id)) => return Err(format!("used driver with unknown id: '{driver_id}'")),
+            (false, None) => return Err(format!("tour of vehicle '{}' has no driver assigned", tour.vehicle_id)),
+            (false, Some(driver_id)) => {
+                all_drivers.get(driver_id).ok_or_else(|| format!("used driver with unknown id: '{driver_id}'"))?
+            }
+        };
+
+        let (start, end) = match (tour.stops.first(), tour.stops.last()) {
+            (Some(first), Some(last)) => {
+                (parse_time(&first.schedule().departure), parse_time(&last.schedule().arrival))
+            }
+            _ => return Err(format!("tour of vehicle '{}' has no stops", tour.vehicle_id)),
+        };
+
+        let shift_index = driver
+            .shifts
+            .iter()
+            .position(|shift| parse_time(&shift.earliest) <= start && end <= parse_time(&shift.latest))
+            .ok_or_else(|| {
+                format!("tour of vehicle '{}' is outside of shifts of driver '{}'", tour.vehicle_id, driver.id)
+            })?;
+
+        if !used_shifts.insert((driver.id.clone(), shift_index)) {
+            Err(format!("driver with '{}' id used more than once for shift {}", driver.id, shift_index))
+        } else {
+            Ok(())
+        }
+    })?;
+
+    Ok(())
+}
+
 /// Checks job task rules.
 fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
     struct JobAssignment {
@@ -55,7 +114,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
     };
     let activity_types: HashSet<_> = vec!["pickup", "delivery", "service", "replacement"].into_iter().collect();
 
-    let all_jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect::<HashMap<_, _>>();
+    let all_jobs = &ctx.job_map;
     let mut used_jobs = HashMap::<String, JobAssignment>::new();
 
     ctx.solution.tours.iter().try_for_each(|tour| {
@@ -133,7 +192,19 @@ fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
         Ok(())
     })?;
 
-    let all_used_job = unique_unassigned_jobs.into_iter().chain(used_jobs.into_keys()).collect::<Vec<_>>();
+    // NOTE jobs of the alternative set with served job are neither assigned nor unassigned
+    let skipped_jobs = ctx
+        .problem
+        .plan
+        .alternatives
+        .iter()
+        .flatten()
+        .filter(|alternative| alternative.jobs.iter().any(|job_id| used_jobs.contains_key(job_id)))
+        .flat_map(|alternative| alternative.jobs.iter().filter(|job_id| !used_jobs.contains_key(*job_id)).cloned())
+        .collect::<Vec<_>>();
+
+    let all_used_job =
+        unique_unassigned_jobs.into_iter().chain(used_jobs.into_keys()).chain(skipped_jobs).collect::<HashSet<_>>();
 
     if all_used_job.len() != all_jobs.len() {
         return Err(format!(
@@ -176,8 +247,9 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
                                                 true
                                             }
                                         }
-                                        Ok(Some(JobInfo(_, _, place, time))) => {
-                                            is_valid_job_info(ctx, stop, activity, *idx, place, time)
+                                        Ok(Some(JobInfo(_, single, place, time))) => {
+                                            let is_charging = single.dimens.get_job_charging_curve().is_some();
+                                            is_valid_job_info(ctx, stop, activity, *idx, place, time, is_charging)
                                         }
                                         _ => false,
                                     }
@@ -206,6 +278,62 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
     Ok(())
 }
 
+/// Checks that service of jobs with soft time windows does not start early when waiting is cheaper
+/// and that reported activity lateness matches job's soft time windows.
+fn check_lateness(ctx: &CheckerContext) -> GenericResult<()> {
+    let (job_index, coord_index) = get_indices(&ctx.core_problem.extras)?;
+    let (job_index, coord_index) = (job_index.as_ref(), coord_index.as_ref());
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let driver_rate = tour
+            .driver_id
+            .as_ref()
+            .and_then(|driver_id| ctx.problem.fleet.drivers.iter().flatten().find(|driver| driver.id == *driver_id))
+            .map_or(0., |driver| driver.costs.time);
+        let waiting_rate = ctx.get_vehicle(&tour.vehicle_id)?.costs.time + driver_rate;
+
+        tour.stops.iter().filter_map(|stop| stop.as_point()).try_for_each(|stop| {
+            stop.activities.iter().try_for_each(|activity| {
+                let Ok(Some(JobInfo(_, single, place, time))) =
+                    try_match_point_job(tour, stop, activity, job_index, coord_index)
+                else {
+                    return Ok(());
+                };
+                let Some(soft) = single.dimens.get_job_soft_time_windows() else {
+                    return if activity.lateness.is_none() {
+                        Ok(())
+                    } else {
+                        Err(format!("unexpected lateness for '{}' job activity", activity.job_id).into())
+                    };
+                };
+
+                let service_start = time.start.max(place.time.start);
+                let expected_start = soft.get_service_start(place.idx, &place.time, service_start, waiting_rate);
+                if service_start != expected_start {
+                    return Err(format!(
+                        "service of '{}' job activity in tour '{}' starts before soft time window although waiting \
+                         is cheaper: expected: {}, actual: {}",
+                        activity.job_id, tour.vehicle_id, expected_start, service_start
+                    )
+                    .into());
+                }
+
+                let expected =
+                    Some(soft.get_deviation(place.idx, service_start).1 as i64).filter(|&lateness| lateness > 0);
+                if expected == activity.lateness {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "lateness mismatch for '{}' job activity in tour '{}': expected: {:?}, actual: {:?}",
+                        activity.job_id, tour.vehicle_id, expected, activity.lateness
+                    )
+                    .into())
+                }
+            })
+        })
+    })
+}
+
 fn is_valid_job_info(
     ctx: &CheckerContext,
     stop: &PointStop,
@@ -213,18 +341,23 @@ fn is_valid_job_info(
     activity_idx: usize,
     place: Place,
     time: TimeWindow,
+    is_charging: bool,
 ) -> bool {
     let not_equal = |left: Float, right: Float| left != right;
     let parking = ctx.clustering.as_ref().map(|config| config.serving.get_parking()).unwrap_or(0.);
     let commute_profile = ctx.clustering.as_ref().map(|config| config.profile.clone());
     let domain_commute = ctx.get_commute_info(commute_profile, parking, stop, activity_idx);
     let extra_time = get_extra_time(stop, activity, &place).unwrap_or(0.);
+    // NOTE site setup time is applied once per stop to its first activity
+    let extra_time = extra_time
+        + ctx.get_site(&stop.location).filter(|_| activity_idx == 0).and_then(|site| site.setup_time).unwrap_or(0.);
 
     match (&ctx.clustering, &activity.commute, domain_commute) {
         (_, _, Err(_)) | (_, None, Ok(Some(_))) | (_, Some(_), Ok(None)) | (&None, &Some(_), Ok(Some(_))) => true,
         (_, None, Ok(None)) => {
             let expected_departure = time.start.max(place.time.start) + place.duration + extra_time;
-            not_equal(time.end, expected_departure)
+            // NOTE charging duration depends on the charged energy and is checked within recharge limits
+            if is_charging { time.end < expected_departure } else { not_equal(time.end, expected_departure) }
         }
         (Some(config), Some(commute), Ok(Some(d_commute))) => {
             let (service_time, parking) = match config.serving {
@@ -287,3 +420,181 @@ fn check_groups(ctx: &CheckerContext) -> GenericResult<()> {
         Err(format!("job groups are not respected: '{err_info}'").into())
     }
 }
+
+/// Checks that parts of split jobs are served by allowed amount of tours with reported quantities.
+fn check_splits(ctx: &CheckerContext) -> GenericResult<()> {
+    let part_tours = ctx
+        .solution
+        .tours
+        .iter()
+        .enumerate()
+        .flat_map(|(tour_idx, tour)| {
+            tour.stops.iter().flat_map(|stop| stop.activities()).map(move |activity| (activity, tour_idx))
+        })
+        .map(|(activity, tour_idx)| (activity.job_id.clone(), (tour_idx, activity.quantity.clone())))
+        .collect::<HashMap<_, _>>();
+
+    ctx.problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.split.as_ref().zip(job.split_quantities()).map(|split| (job, split)))
+        .try_for_each(|(job, (split, quantities))| {
+            let tours = job
+                .visit_ids()
+                .iter()
+                .zip(quantities.iter())
+                .filter_map(|(part_id, quantity)| part_tours.get(part_id).map(|part| (part_id, quantity, part)))
+                .map(|(part_id, &quantity, (tour_idx, actual))| {
+                    if actual.as_ref().is_some_and(|actual| actual.as_slice() == [quantity]) {
+                        Ok(*tour_idx)
+                    } else {
+                        Err(format!(
+                            "split job part '{part_id}' has wrong quantity: expected {quantity}, got {actual:?}"
+                        ))
+                    }
+                })
+                .collect::<Result<HashSet<_>, _>>()?;
+
+            let max_splits = split.max_splits.unwrap_or(usize::MAX);
+            if tours.len() > max_splits {
+                return Err(format!(
+                    "split job '{}' is served by {} tours, expected not more than {max_splits}",
+                    job.id,
+                    tours.len()
+                )
+                .into());
+            }
+
+            Ok(())
+        })
+}
+
+/// Checks that copies of synchronized jobs are served by required amount of different tours and
+/// their service start times are aligned within the tolerance.
+fn check_syncs(ctx: &CheckerContext) -> GenericResult<()> {
+    const TOLERANCE: Float = 1E-3;
+
+    let copies = ctx
+        .solution
+        .tours
+        .iter()
+        .enumerate()
+        .flat_map(|(tour_idx, tour)| {
+            tour.stops.iter().flat_map(move |stop| {
+                stop.activities().iter().map(move |activity| {
+                    (activity.job_id.clone(), (tour_idx, ctx.get_activity_time(stop, activity).start))
+                })
+            })
+        })
+        .collect::<HashMap<_, _>>();
+
+    ctx.problem.plan.jobs.iter().filter_map(|job| job.sync.as_ref().map(|sync| (job, sync))).try_for_each(
+        |(job, sync)| {
+            let served = job.visit_ids().iter().filter_map(|copy_id| copies.get(copy_id)).copied().collect::<Vec<_>>();
+            if served.is_empty() {
+                return Ok(());
+            }
+
+            if served.len() != sync.vehicles {
+                return Err(format!(
+                    "synchronized job '{}' is served by {} vehicles, expected {}",
+                    job.id,
+                    served.len(),
+                    sync.vehicles
+                )
+                .into());
+            }
+
+            if served.iter().map(|(tour_idx, _)| tour_idx).collect::<HashSet<_>>().len() != served.len() {
+                return Err(format!("synchronized job '{}' is served multiple times by the same tour", job.id).into());
+            }
+
+            let (earliest, latest) = served.iter().fold((Float::MAX, Float::MIN), |(earliest, latest), (_, start)| {
+                (earliest.min(*start), latest.max(*start))
+            });
+            let tolerance = sync.tolerance.unwrap_or_default();
+
+            if latest - earliest > tolerance + TOLERANCE {
+                return Err(format!(
+                    "synchronized job '{}' has service start difference {}, expected not more than {tolerance}",
+                    job.id,
+                    latest - earliest
+                )
+                .into());
+            }
+
+            Ok(())
+        },
+    )
+}
+
+/// Checks that at most one job of each alternative set is served and the rest of the set is either
+/// skipped, when a job is served, or unassigned otherwise. Each job of the unassigned set should be explained
+/// by the alternative reason: for `exactlyOne` set, it should have also the reason why it is not served.
+fn check_alternatives(ctx: &CheckerContext) -> GenericResult<()> {
+    let served = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+        .map(|activity| activity.job_id.as_str())
+        .collect::<HashSet<_>>();
+    let unassigned = ctx
+        .solution
+        .unassigned
+        .iter()
+        .flatten()
+        .map(|job| (job.job_id.as_str(), job.reasons.iter().map(|reason| reason.code.as_str()).collect::<Vec<_>>()))
+        .collect::<HashMap<_, _>>();
+
+    ctx.problem.plan.alternatives.iter().flatten().try_for_each(|alternative| {
+        let served_jobs = alternative.jobs.iter().filter(|job_id| served.contains(job_id.as_str())).collect::<Vec<_>>();
+
+        match served_jobs.len() {
+            0 => alternative.jobs.iter().try_for_each(|job_id| {
+                let codes = unassigned.get(job_id.as_str()).ok_or_else(|| {
+                    format!(
+                        "alternative jobs are not served, but not all of them are unassigned: {:?}",
+                        alternative.jobs
+                    )
+                })?;
+
+                if !codes.contains(&"ALTERNATIVE_CONSTRAINT") {
+                    return Err(format!("unassigned alternative job '{job_id}' has no alternative reason").into());
+                }
+
+                let is_explained = codes.iter().any(|code| *code != "ALTERNATIVE_CONSTRAINT");
+                if matches!(alternative.type_field, AlternativeType::ExactlyOne) && !is_explained {
+                    return Err(format!("unassigned exactly one alternative job '{job_id}' has no reason").into());
+                }
+
+                Ok(())
+            }),
+            1 if alternative.jobs.iter().any(|job_id| unassigned.contains_key(job_id.as_str())) => {
+                Err(format!("alternative job '{}' is served, but other jobs of its set are unassigned", served_jobs[0])
+                    .into())
+            }
+            1 => Ok(()),
+            _ => Err(format!("more than one alternative job is served: {served_jobs:?}").into()),
+        }
+    })
+}
+
+/// Checks that mandatory jobs are not reported as unassigned.
+fn check_mandatory(ctx: &CheckerContext) -> GenericResult<()> {
+    let job_ids = ctx
+        .solution
+        .unassigned
+        .iter()
+        .flatten()
+        .filter(|job| ctx.get_job_by_id(&job.job_id).is_some_and(|job| job.mandatory.unwrap_or(false)))
+        .map(|job| job.job_id.clone())
+        .collect::<Vec<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("mandatory jobs are unassigned: '{}'", job_ids.join(", ")).into())
+    }
+}
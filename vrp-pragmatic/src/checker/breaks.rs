@@ -10,7 +10,7 @@ use vrp_core::utils::GenericError;
 
 /// Checks that breaks are properly assigned.
 pub fn check_breaks(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_break_assignment(context)])
+    combine_error_results(&[check_break_assignment(context), check_hours_of_service(context)])
 }
 
 fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
@@ -126,6 +126,139 @@ fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+fn check_hours_of_service(context: &CheckerContext) -> GenericResult<()> {
+    const WEEK_DURATION: Float = 7. * 24. * 3600.;
+
+    let vehicle_driving = context.solution.tours.iter().try_fold(
+        HashMap::<(String, Option<i64>), Float>::new(),
+        |mut acc, tour| {
+            let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+            let Some(hours_of_service) = vehicle.hours_of_service.as_ref() else {
+                return Ok(acc);
+            };
+
+            let driving = get_tour_driving(tour, hours_of_service)?;
+
+            if let Some(max_tour_driving) = hours_of_service.max_tour_driving
+                && driving > max_tour_driving
+            {
+                return Err(GenericError::from(format!(
+                    "tour driving time '{driving}' exceeds limit '{max_tour_driving}' for vehicle '{}', shift index '{}'",
+                    tour.vehicle_id, tour.shift_index
+                )));
+            }
+
+            // NOTE weeks are counted from the earliest shift start of the vehicle type
+            let week = vehicle
+                .shifts
+                .iter()
+                .map(|shift| parse_time(&shift.start.earliest))
+                .min_by(|a, b| a.total_cmp(b))
+                .zip(tour.stops.first())
+                .map(|(week_start, stop)| ((parse_time(&stop.schedule().departure) - week_start) / WEEK_DURATION).floor() as i64);
+
+            *acc.entry((tour.vehicle_id.clone(), None)).or_default() += driving;
+            if week.is_some() {
+                *acc.entry((tour.vehicle_id.clone(), week)).or_default() += driving;
+            }
+
+            Ok(acc)
+        },
+    )?;
+
+    vehicle_driving.into_iter().try_for_each(|((vehicle_id, week), driving)| {
+        let hours_of_service = context.get_vehicle(&vehicle_id)?.hours_of_service.as_ref();
+        let (limit, kind) = match week {
+            Some(_) => (hours_of_service.and_then(|hos| hos.max_weekly_driving), "weekly"),
+            None => (hours_of_service.and_then(|hos| hos.max_vehicle_driving), "vehicle"),
+        };
+
+        match limit {
+            Some(limit) if driving > limit => {
+                Err(format!("{kind} driving time '{driving}' exceeds limit '{limit}' for vehicle '{vehicle_id}'")
+                    .into())
+            }
+            _ => Ok(()),
+        }
+    })
+}
+
+/// Returns total driving time of the tour checking that driving breaks and daily rests are taken in time.
+fn get_tour_driving(tour: &Tour, hours_of_service: &VehicleHoursOfService) -> GenericResult<Float> {
+    let (driving_break, daily_rest) = (hours_of_service.driving_break.as_ref(), hours_of_service.daily_rest.as_ref());
+    let departure = tour
+        .stops
+        .first()
+        .map(|stop| parse_time(&stop.schedule().departure))
+        .ok_or_else(|| GenericError::from(format!("cannot get departure for tour '{}'", tour.vehicle_id)))?;
+
+    let (_, driving, _, _, _) = tour.stops.iter().skip(1).try_fold(
+        (departure, Float::default(), Float::default(), Float::default(), 0_usize),
+        |(departure, driving, daily, continuous, part_idx), stop| {
+            let schedule = stop.schedule();
+            let arrival = parse_time(&schedule.arrival);
+            let leg_driving = arrival - departure;
+            let (mut daily, mut continuous, mut part_idx) = (daily + leg_driving, continuous + leg_driving, part_idx);
+
+            if let Some(driving_break) = driving_break
+                && continuous > driving_break.max_driving
+            {
+                return Err(GenericError::from(format!(
+                    "continuous driving time '{continuous}' exceeds limit '{}' for vehicle '{}', shift index '{}'",
+                    driving_break.max_driving, tour.vehicle_id, tour.shift_index
+                )));
+            }
+
+            if let Some(daily_rest) = daily_rest
+                && daily > daily_rest.max_driving
+            {
+                return Err(GenericError::from(format!(
+                    "daily driving time '{daily}' exceeds limit '{}' for vehicle '{}', shift index '{}'",
+                    daily_rest.max_driving, tour.vehicle_id, tour.shift_index
+                )));
+            }
+
+            if driving_break.is_some() || daily_rest.is_some() {
+                let mut prev_end = arrival;
+                for activity in stop.activities() {
+                    let time = activity.time.as_ref().map_or_else(
+                        || TimeWindow::new(arrival, parse_time(&schedule.departure)),
+                        |time| TimeWindow::new(parse_time(&time.start), parse_time(&time.end)),
+                    );
+
+                    let is_rest = activity.activity_type == "rest";
+                    let pause = if is_rest { time.duration() } else { time.start - prev_end };
+                    let remaining = driving_break
+                        .map_or(Float::MAX, |driving_break| driving_break.parts.iter().skip(part_idx).sum());
+
+                    if daily_rest.is_some_and(|daily_rest| pause > 0. && pause >= daily_rest.duration) {
+                        (daily, continuous, part_idx) = (0., 0., 0);
+                    } else if pause > 0. && pause >= remaining {
+                        (continuous, part_idx) = (0., 0);
+                    } else if is_rest {
+                        let required = daily_rest.map_or(remaining, |daily_rest| remaining.min(daily_rest.duration));
+                        return Err(GenericError::from(format!(
+                            "rest duration '{pause}' is less than required '{required}' for vehicle '{}', shift index '{}'",
+                            tour.vehicle_id, tour.shift_index
+                        )));
+                    } else if driving_break
+                        .and_then(|driving_break| driving_break.parts.get(part_idx))
+                        .is_some_and(|&part| pause > 0. && pause >= part)
+                    {
+                        part_idx += 1;
+                    }
+
+                    prev_end = prev_end.max(time.end);
+                }
+            }
+
+            Ok((parse_time(&schedule.departure), driving + leg_driving, daily, continuous, part_idx))
+        },
+    )?;
+
+    Ok(driving)
+}
+
 /// Represents information about break and neighbour activity.
 type LegBreakInfo<'a> = (Option<Location>, (Option<&'a Activity>, &'a Activity), (&'a Activity, VehicleBreak));
 
@@ -4,6 +4,7 @@ mod capacity_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use std::collections::VecDeque;
 use std::iter::once;
 use vrp_core::models::common::{Load, MultiDimLoad};
 use vrp_core::prelude::GenericResult;
@@ -11,8 +12,19 @@ use vrp_core::prelude::GenericResult;
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
 /// * max vehicle's capacity is not violated
 /// * load change is correct
+/// * compartments are used according to their capacity and product restrictions
+/// * satellites have enough inventory delivered in time for second-level vehicles
+/// * pickup and delivery jobs follow vehicle's loading policy
+/// * job items are placed correctly within vehicle's cargo space
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicle_load_assignment(context), check_resource_consumption(context)])
+    combine_error_results(&[
+        check_vehicle_load_assignment(context),
+        check_resource_consumption(context),
+        check_compartment_load(context),
+        check_satellite_inventory(context),
+        check_loading_policy(context),
+        check_loading_plan(context),
+    ])
 }
 
 fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()> {
@@ -95,8 +107,9 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
         .resources
         .iter()
         .flat_map(|resources| resources.iter().cloned())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id, MultiDimLoad::new(capacity)),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id, MultiDimLoad::new(capacity))),
+            VehicleResource::Recharge { .. } | VehicleResource::Satellite { .. } => None,
         })
         .collect::<HashMap<_, _>>();
 
@@ -156,6 +169,456 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+fn check_compartment_load(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let compartments = match context.get_vehicle(&tour.vehicle_id)?.compartments.as_ref() {
+            Some(compartments) => compartments,
+            None => return Ok(()),
+        };
+        let get_compartment_idx = |id: &String| {
+            compartments.iter().position(|compartment| compartment.id == *id).ok_or_else(|| {
+                GenericError::from(format!("unknown compartment '{id}' is used in tour '{}'", tour.vehicle_id))
+            })
+        };
+
+        let activities = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .map(|(stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let (demand_type, demand) = get_demand(context, activity, &activity_type)?;
+                let product = context.visit_job(activity, &activity_type, |_, task| task.product.clone(), || None)?;
+
+                let compartment_idx = match (&demand_type, activity.compartment.as_ref()) {
+                    (DemandType::None, None) => None,
+                    (_, Some(id)) => Some(get_compartment_idx(id)?),
+                    (_, None) => {
+                        return Err(GenericError::from(format!(
+                            "job '{}' has no compartment assigned in tour '{}'",
+                            activity.job_id, tour.vehicle_id
+                        )));
+                    }
+                };
+
+                Ok((activity, product, compartment_idx, (demand_type, demand)))
+            })
+            .collect::<GenericResult<Vec<_>>>()?;
+
+        let mut job_compartments = HashMap::<&String, usize>::default();
+        let mut products = vec![HashSet::<&String>::default(); compartments.len()];
+        activities.iter().try_for_each::<_, GenericResult<_>>(|(activity, product, compartment_idx, _)| {
+            let Some(compartment_idx) = *compartment_idx else { return Ok(()) };
+            let compartment = &compartments[compartment_idx];
+
+            if *job_compartments.entry(&activity.job_id).or_insert(compartment_idx) != compartment_idx {
+                return Err(format!(
+                    "job '{}' uses more than one compartment in tour '{}'",
+                    activity.job_id, tour.vehicle_id
+                )
+                .into());
+            }
+
+            let is_allowed = compartment
+                .products
+                .as_ref()
+                .is_none_or(|allowed| product.as_ref().is_some_and(|product| allowed.contains(product)));
+            if !is_allowed {
+                return Err(format!(
+                    "job '{}' has product which is not allowed in compartment '{}' in tour '{}'",
+                    activity.job_id, compartment.id, tour.vehicle_id
+                )
+                .into());
+            }
+
+            if let Some(product) = product {
+                products[compartment_idx].insert(product);
+            }
+
+            Ok(())
+        })?;
+
+        compartments.iter().zip(products.iter()).try_for_each::<_, GenericResult<_>>(|(compartment, products)| {
+            let has_incompatible = compartment
+                .incompatible
+                .iter()
+                .flatten()
+                .any(|group| products.iter().filter(|product| group.contains(product)).count() > 1);
+
+            if has_incompatible {
+                Err(format!(
+                    "incompatible products share compartment '{}' in tour '{}'",
+                    compartment.id, tour.vehicle_id
+                )
+                .into())
+            } else {
+                Ok(())
+            }
+        })?;
+
+        let empty_loads = compartments.iter().map(|c| MultiDimLoad::new(vec![0; c.capacity.len()])).collect::<Vec<_>>();
+        let start_loads = activities.iter().fold(empty_loads.clone(), |mut acc, (_, _, compartment_idx, demand)| {
+            if let (Some(compartment_idx), (DemandType::StaticDelivery | DemandType::StaticPickupDelivery, demand)) =
+                (*compartment_idx, demand)
+            {
+                acc[compartment_idx] = acc[compartment_idx] + *demand;
+            }
+
+            acc
+        });
+
+        tour.stops.iter().enumerate().try_fold::<_, _, GenericResult<_>>(start_loads, |acc, (stop_idx, stop)| {
+            let loads = stop.activities().iter().try_fold::<_, _, GenericResult<_>>(acc, |mut acc, activity| {
+                if activity.activity_type == "arrival" {
+                    return Ok(empty_loads.clone());
+                }
+
+                if let Some(compartment_idx) = activity.compartment.as_ref().map(get_compartment_idx).transpose()? {
+                    let activity_type = context.get_activity_type(tour, stop, activity)?;
+                    acc[compartment_idx] = match get_demand(context, activity, &activity_type)? {
+                        (DemandType::StaticDelivery | DemandType::DynamicDelivery, demand) => {
+                            acc[compartment_idx] - demand
+                        }
+                        (DemandType::StaticPickup | DemandType::DynamicPickup, demand) => acc[compartment_idx] + demand,
+                        (DemandType::None | DemandType::StaticPickupDelivery, _) => acc[compartment_idx],
+                    };
+                }
+
+                Ok(acc)
+            })?;
+
+            let stop_loads = stop.compartments().ok_or_else(|| {
+                GenericError::from(format!("stop {stop_idx} has no compartment loads in tour '{}'", tour.vehicle_id))
+            })?;
+
+            compartments.iter().zip(loads.iter()).try_for_each::<_, GenericResult<_>>(|(compartment, load)| {
+                let stop_load = stop_loads
+                    .iter()
+                    .find(|stop_load| stop_load.id == compartment.id)
+                    .map(|stop_load| MultiDimLoad::new(stop_load.load.clone()))
+                    .ok_or_else(|| {
+                        GenericError::from(format!(
+                            "stop {stop_idx} has no load of compartment '{}' in tour '{}'",
+                            compartment.id, tour.vehicle_id
+                        ))
+                    })?;
+
+                if !MultiDimLoad::new(compartment.capacity.clone()).can_fit(&stop_load) {
+                    return Err(format!(
+                        "load exceeds capacity of compartment '{}' at stop {stop_idx} in tour '{}'",
+                        compartment.id, tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                if stop_load != *load {
+                    return Err(format!(
+                        "load mismatch of compartment '{}' at stop {stop_idx} in tour '{}'",
+                        compartment.id, tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                Ok(())
+            })?;
+
+            Ok(loads)
+        })?;
+
+        Ok(())
+    })
+}
+
+fn check_satellite_inventory(context: &CheckerContext) -> GenericResult<()> {
+    let mut supply = HashMap::<String, (MultiDimLoad, Float)>::default();
+    let mut demand = HashMap::<String, (MultiDimLoad, Float)>::default();
+
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let satellite = context.get_vehicle_shift(tour)?.start.satellite;
+
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .try_for_each::<_, GenericResult<_>>(|(stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let job_satellite = match &activity_type {
+                    ActivityType::Job(job) => job.satellite.clone(),
+                    _ => None,
+                };
+                let load = match get_demand(context, activity, &activity_type)? {
+                    (DemandType::StaticDelivery, load) => load,
+                    _ => MultiDimLoad::default(),
+                };
+
+                match (satellite.as_ref(), job_satellite) {
+                    (Some(_), Some(_)) => {
+                        return Err(format!(
+                            "supply job '{}' is served by second-level vehicle in tour '{}'",
+                            activity.job_id, tour.vehicle_id
+                        )
+                        .into());
+                    }
+                    (None, Some(job_satellite)) => {
+                        let departure = context.get_activity_time(stop, activity).end;
+                        let (total, ready) = supply.entry(job_satellite).or_insert((MultiDimLoad::default(), 0.));
+                        *total = *total + load;
+                        *ready = ready.max(departure);
+                    }
+                    (Some(satellite), None) => {
+                        let departure = parse_time(&tour.stops.first().expect("empty tour").schedule().departure);
+                        let (total, start) =
+                            demand.entry(satellite.clone()).or_insert((MultiDimLoad::default(), Float::MAX));
+                        *total = *total + load;
+                        *start = start.min(departure);
+                    }
+                    (None, None) => {}
+                }
+
+                Ok(())
+            })
+    })?;
+
+    demand.iter().try_for_each(|(satellite, (demand, start))| {
+        let (supply, ready) = supply.get(satellite).cloned().unwrap_or_default();
+
+        if !supply.can_fit(demand) {
+            return Err(format!("satellite '{satellite}' has not enough inventory for second-level vehicles").into());
+        }
+
+        if ready > *start {
+            return Err(format!(
+                "satellite '{satellite}' inventory is not ready before second-level vehicles departure"
+            )
+            .into());
+        }
+
+        Ok(())
+    })
+}
+
+fn check_loading_policy(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let Some(policy) = context.get_vehicle(&tour.vehicle_id)?.loading_policy.as_ref() else { return Ok(()) };
+
+        let job_ids = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .map(|(stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                Ok(match get_demand(context, activity, &activity_type)? {
+                    (DemandType::DynamicPickup | DemandType::DynamicDelivery, _) => Some(&activity.job_id),
+                    _ => None,
+                })
+            })
+            .collect::<GenericResult<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let bounds =
+            job_ids.iter().enumerate().fold(HashMap::<&String, (usize, usize)>::default(), |mut acc, (idx, job_id)| {
+                acc.entry(job_id).or_insert((idx, idx)).1 = idx;
+                acc
+            });
+
+        let mut on_board = VecDeque::<&String>::default();
+        job_ids.iter().enumerate().try_for_each(|(idx, job_id)| {
+            let (first, last) = bounds[job_id];
+
+            if idx == first {
+                on_board.push_back(job_id);
+            } else if idx == last {
+                let unloaded = match policy {
+                    VehicleLoadingPolicy::Lifo => on_board.pop_back(),
+                    VehicleLoadingPolicy::Fifo => on_board.pop_front(),
+                };
+
+                if unloaded != Some(job_id) {
+                    return Err(
+                        format!("loading policy violation for job '{job_id}' in tour '{}'", tour.vehicle_id).into()
+                    );
+                }
+            }
+
+            Ok(())
+        })
+    })
+}
+
+fn check_loading_plan(context: &CheckerContext) -> GenericResult<()> {
+    const TOLERANCE: Float = 1E-6;
+
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let Some(space) = context.get_vehicle(&tour.vehicle_id)?.cargo_space.as_ref() else { return Ok(()) };
+        let err =
+            |message: String| GenericError::from(format!("{message} in loading plan of tour '{}'", tour.vehicle_id));
+
+        let activities = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .collect::<Vec<_>>();
+
+        // NOTE a reload activity starts a new segment of the tour
+        let mut segments = vec![(0, activities.len()); activities.len()];
+        let mut start = 0;
+        (1..activities.len())
+            .filter(|&idx| activities[idx].1.activity_type == "reload")
+            .chain(once(activities.len()))
+            .for_each(|end| {
+                segments[start..end].iter_mut().for_each(|segment| *segment = (start, end));
+                start = end;
+            });
+
+        let is_multi_job = |job_id: &str| {
+            context.get_job_by_id(job_id).is_some_and(|job| {
+                job_task_size(&job.pickups)
+                    + job_task_size(&job.deliveries)
+                    + job_task_size(&job.services)
+                    + job_task_size(&job.replacements)
+                    > 1
+            })
+        };
+        let multi_intervals = activities
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, activity))| is_multi_job(activity.job_id.as_str()))
+            .fold(HashMap::<&str, (usize, usize)>::new(), |mut acc, (idx, (_, activity))| {
+                acc.entry(activity.job_id.as_str()).and_modify(|interval| interval.1 = idx).or_insert((idx, idx));
+                acc
+            });
+
+        let mut items = activities
+            .iter()
+            .enumerate()
+            .map(|(idx, (stop, activity))| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let items = context.visit_job(activity, &activity_type, |_, task| task.items.clone(), || None)?;
+
+                let (start, end) = segments[idx];
+                let interval = match (multi_intervals.get(activity.job_id.as_str()), activity.activity_type.as_str()) {
+                    (Some(&(first, last)), _) => (first, last.max(first + 1)),
+                    (None, "pickup") => (idx, end),
+                    (None, "delivery") => (start, idx),
+                    _ => (start, end),
+                };
+
+                Ok(items
+                    .into_iter()
+                    .flatten()
+                    .map(move |item| ((activity.job_id.clone(), item.id.clone()), (item, interval))))
+            })
+            .collect::<GenericResult<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<HashMap<_, _>>();
+
+        let placements = tour.loading_plan.as_deref().unwrap_or_default();
+        let placements = placements
+            .iter()
+            .map(|placement| {
+                let (item, interval) =
+                    items.remove(&(placement.job_id.clone(), placement.item_id.clone())).ok_or_else(|| {
+                        err(format!("unexpected item '{}' of job '{}'", placement.item_id, placement.job_id))
+                    })?;
+
+                let (position, size) = match (placement.position.as_slice(), placement.size.as_slice()) {
+                    (&[x, y, z], &[length, width, height]) => ((x, y, z), (length, width, height)),
+                    _ => return Err(err(format!("invalid position or size of item '{}'", item.id))),
+                };
+
+                let is_same = |a: Float, b: Float| (a - b).abs() < TOLERANCE;
+                let is_valid_size = is_same(size.2, item.height)
+                    && ((is_same(size.0, item.length) && is_same(size.1, item.width))
+                        || (item.rotatable.unwrap_or(true)
+                            && is_same(size.0, item.width)
+                            && is_same(size.1, item.length)));
+                let is_inside = position.0 >= -TOLERANCE
+                    && position.1 >= -TOLERANCE
+                    && position.2 >= -TOLERANCE
+                    && position.0 + size.0 <= space.length + TOLERANCE
+                    && position.1 + size.1 <= space.width + TOLERANCE
+                    && position.2 + size.2 <= space.height + TOLERANCE;
+
+                if !is_valid_size || !is_inside {
+                    return Err(err(format!(
+                        "item '{}' of job '{}' has invalid size or position",
+                        item.id, placement.job_id
+                    )));
+                }
+
+                Ok((item, interval, position, size))
+            })
+            .collect::<GenericResult<Vec<_>>>()?;
+
+        if let Some(((job_id, item_id), _)) = items.into_iter().next() {
+            return Err(err(format!("missing item '{item_id}' of job '{job_id}'")));
+        }
+
+        let max_weight = placements
+            .iter()
+            .map(|(_, (start, _), ..)| {
+                placements
+                    .iter()
+                    .filter(|(_, other, ..)| other.0 <= *start && *start < other.1)
+                    .map(|(item, ..)| item.weight.unwrap_or_default())
+                    .sum::<Float>()
+            })
+            .fold(0., Float::max);
+        if space.max_weight.is_some_and(|limit| max_weight > limit + TOLERANCE) {
+            return Err(err("total weight of items on board exceeds max weight".to_string()));
+        }
+
+        let is_on_board_together = |a: &(usize, usize), b: &(usize, usize)| a.0 < b.1 && b.0 < a.1;
+
+        placements.iter().enumerate().try_for_each(|(idx, (item, interval, (x, y, z), (length, width, height)))| {
+            let has_overlap = placements
+                .iter()
+                .enumerate()
+                .filter(|(other_idx, (_, other, ..))| *other_idx != idx && is_on_board_together(interval, other))
+                .any(|(_, (_, _, (ox, oy, oz), (ol, ow, oh)))| {
+                    x + TOLERANCE < ox + ol
+                        && ox + TOLERANCE < x + length
+                        && y + TOLERANCE < oy + ow
+                        && oy + TOLERANCE < y + width
+                        && z + TOLERANCE < oz + oh
+                        && oz + TOLERANCE < z + height
+                });
+            if has_overlap {
+                return Err(err(format!("item '{}' overlaps with other item", item.id)));
+            }
+
+            if *z < TOLERANCE {
+                return Ok(());
+            }
+
+            // NOTE items below should stay on board while the item is loaded
+            let supports = placements
+                .iter()
+                .filter(|(_, other, ..)| other.0 <= interval.0 && interval.1 <= other.1)
+                .filter(|(_, _, (_, _, oz), (_, _, oh))| (oz + oh - z).abs() < TOLERANCE)
+                .map(|(other, _, (ox, oy, _), (ol, ow, _))| {
+                    let overlap_x = (x + length).min(ox + ol) - x.max(*ox);
+                    let overlap_y = (y + width).min(oy + ow) - y.max(*oy);
+
+                    (other, overlap_x.max(0.) * overlap_y.max(0.))
+                })
+                .filter(|(_, area)| *area > TOLERANCE)
+                .collect::<Vec<_>>();
+
+            let is_stackable = supports.iter().all(|(other, _)| other.stackable.unwrap_or(true));
+            let supported_area = supports.iter().map(|(_, area)| *area).sum::<Float>();
+
+            if is_stackable && supported_area + TOLERANCE >= length * width {
+                Ok(())
+            } else {
+                Err(err(format!("item '{}' is not supported by stackable items", item.id)))
+            }
+        })
+    })
+}
+
 enum DemandType {
     None,
     StaticPickup,
@@ -4,12 +4,21 @@ mod limits_test;
 
 use super::*;
 use crate::utils::combine_error_results;
-use vrp_core::models::common::Distance;
+use std::collections::HashMap;
+use vrp_core::construction::features::ChargingCurve;
+use vrp_core::models::common::{Distance, Duration};
 use vrp_core::prelude::GenericResult;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context), check_recharge_limits(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_shift_time(context),
+        check_recharge_limits(context),
+        check_recharge_resources(context),
+        check_sites(context),
+        check_ride_times(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
@@ -99,6 +108,12 @@ fn check_recharge_limits(context: &CheckerContext) -> GenericResult<()> {
             return Ok(());
         }
 
+        if let Some(battery) = recharge.battery.as_ref() {
+            return check_battery(context, tour, battery, stops.as_slice());
+        }
+
+        let Some(max_distance) = recharge.max_distance else { return Ok(()) };
+
         stops
             .windows(2)
             .try_fold(Distance::default(), |acc, stops| {
@@ -110,10 +125,10 @@ fn check_recharge_limits(context: &CheckerContext) -> GenericResult<()> {
                 let delta = (next.distance - prev.distance) as Distance;
                 let total_distance = acc + delta;
 
-                if total_distance > recharge.max_distance {
+                if total_distance > max_distance {
                     return Err(format!(
                         "recharge distance violation: expected limit is {}, got {}, vehicle id '{}', shift index: {}",
-                        recharge.max_distance, total_distance, tour.vehicle_id, tour.shift_index
+                        max_distance, total_distance, tour.vehicle_id, tour.shift_index
                     )
                     .into());
                 }
@@ -125,3 +140,339 @@ fn check_recharge_limits(context: &CheckerContext) -> GenericResult<()> {
             .map(|_| ())
     })
 }
+
+/// Checks that battery state of charge never drops below the reserve, is reported correctly and
+/// that recharge stations are visited long enough to charge the reported amount of energy.
+fn check_battery(
+    context: &CheckerContext,
+    tour: &Tour,
+    battery: &VehicleBattery,
+    stops: &[&PointStop],
+) -> GenericResult<()> {
+    const TOLERANCE: Float = 1E-3;
+
+    let capacity = context.get_vehicle(&tour.vehicle_id)?.capacity.clone();
+    let reserve = battery.reserve.unwrap_or_default();
+    let initial = battery.initial.unwrap_or(battery.capacity);
+
+    let get_error = |message: String| -> GenericError {
+        format!("{message}, vehicle id '{}', shift index: {}", tour.vehicle_id, tour.shift_index).into()
+    };
+
+    let first = stops[0].state_of_charge.unwrap_or(initial);
+    if (first - initial).abs() > TOLERANCE {
+        return Err(get_error(format!("state of charge violation: expected {initial} at start, got {first}")));
+    }
+
+    stops
+        .windows(2)
+        .try_fold(initial, |soc, stops| {
+            let (prev, next) = match stops {
+                [prev, next] => (prev, next),
+                _ => unreachable!(),
+            };
+
+            let arrival = soc - get_leg_energy(battery, capacity.as_slice(), prev, next);
+
+            if arrival < reserve - TOLERANCE {
+                return Err(get_error(format!(
+                    "state of charge violation: expected at least {reserve}, got {arrival} at stop {:?}",
+                    next.location
+                )));
+            }
+
+            let departure = next.state_of_charge.unwrap_or(arrival);
+            let stop = Stop::Point((*next).clone());
+            let recharge = next.activities.iter().find(|activity| activity.activity_type == "recharge");
+
+            match recharge {
+                Some(activity) => {
+                    if departure > battery.capacity + TOLERANCE || departure < arrival - TOLERANCE {
+                        return Err(get_error(format!(
+                            "state of charge violation: cannot charge from {arrival} to {departure} at stop {:?}",
+                            next.location
+                        )));
+                    }
+
+                    let ActivityType::Recharge(station) = context.get_activity_type(tour, &stop, activity)? else {
+                        return Err(get_error("cannot find recharge station".to_string()));
+                    };
+
+                    let charging = get_charging_duration(battery, &station, arrival, departure);
+                    let time = context.get_activity_time(&stop, activity);
+
+                    if time.duration() + TOLERANCE < station.duration + charging {
+                        return Err(get_error(format!(
+                            "recharge duration violation: expected at least {}, got {} at stop {:?}",
+                            station.duration + charging,
+                            time.duration(),
+                            next.location
+                        )));
+                    }
+                }
+                None if (departure - arrival).abs() > TOLERANCE => {
+                    return Err(get_error(format!(
+                        "state of charge violation: expected {arrival}, got {departure} at stop {:?}",
+                        next.location
+                    )));
+                }
+                None => {}
+            }
+
+            Ok(departure)
+        })
+        .map(|_| ())
+}
+
+/// Checks that shared recharge resources are not used by more vehicles at the same time than
+/// the amount of available chargers.
+fn check_recharge_resources(context: &CheckerContext) -> GenericResult<()> {
+    const TOLERANCE: Float = 1E-3;
+
+    let chargers = context
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Recharge { id, chargers } => Some((id.clone(), *chargers)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    if chargers.is_empty() {
+        return Ok(());
+    }
+
+    let mut usage: HashMap<String, Vec<(TimeWindow, String)>> = HashMap::new();
+
+    context.solution.tours.iter().filter(|tour| tour.stops.len() > 1).try_for_each::<_, GenericResult<_>>(|tour| {
+        let shift = context.get_vehicle_shift(tour)?;
+        let Some(recharge) = shift.recharges.as_ref() else { return Ok(()) };
+
+        let capacity = context.get_vehicle(&tour.vehicle_id)?.capacity.clone();
+        let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
+
+        stops.windows(2).try_for_each(|stops| {
+            let (prev, next) = match stops {
+                [prev, next] => (prev, next),
+                _ => unreachable!(),
+            };
+
+            let stop = Stop::Point((*next).clone());
+            let Some(activity) = next.activities.iter().find(|activity| activity.activity_type == "recharge") else {
+                return Ok(());
+            };
+            let ActivityType::Recharge(station) = context.get_activity_type(tour, &stop, activity)? else {
+                return Err("cannot find recharge station".into());
+            };
+            let Some(resource_id) = station.resource_id.as_ref() else { return Ok(()) };
+
+            let charging = match (recharge.battery.as_ref(), prev.state_of_charge, next.state_of_charge) {
+                (Some(battery), Some(soc), Some(departure)) => {
+                    let arrival = soc - get_leg_energy(battery, capacity.as_slice(), prev, next);
+                    get_charging_duration(battery, &station, arrival, departure)
+                }
+                _ => Duration::default(),
+            };
+
+            let end = context.get_activity_time(&stop, activity).end;
+            let time = TimeWindow::new(end - station.duration - charging, end);
+
+            usage.entry(resource_id.clone()).or_default().push((time, tour.vehicle_id.clone()));
+
+            Ok(())
+        })
+    })?;
+
+    usage.iter().try_for_each(|(resource_id, intervals)| {
+        let limit =
+            chargers.get(resource_id).copied().ok_or_else(|| format!("cannot find resource '{resource_id}'"))?;
+
+        intervals.iter().try_for_each(|(time, vehicle_id)| {
+            let concurrent = intervals
+                .iter()
+                .filter(|(other, _)| other.start <= time.start + TOLERANCE && time.start + TOLERANCE < other.end)
+                .count();
+
+            if concurrent > limit {
+                Err(format!(
+                    "recharge resource violation: '{resource_id}' is used by {concurrent} vehicles at {}, \
+                     expected not more than {limit}, vehicle id '{vehicle_id}'",
+                    time.start
+                )
+                .into())
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+/// Checks that number of vehicles served at the same time at customer sites does not exceed their limits.
+fn check_sites(context: &CheckerContext) -> GenericResult<()> {
+    const TOLERANCE: Float = 1E-3;
+
+    let mut usage: HashMap<usize, Vec<(TimeWindow, String)>> = HashMap::new();
+
+    context.solution.tours.iter().for_each(|tour| {
+        tour.stops.iter().filter_map(|stop| stop.as_point()).for_each(|point| {
+            let Some(site_idx) = context
+                .problem
+                .plan
+                .sites
+                .iter()
+                .flatten()
+                .position(|site| site.location == point.location && site.max_vehicles.is_some())
+            else {
+                return;
+            };
+
+            let stop = Stop::Point(point.clone());
+            let visits = point.activities.iter().fold(Vec::<Option<TimeWindow>>::default(), |mut acc, activity| {
+                if matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement") {
+                    let time = context.get_activity_time(&stop, activity);
+                    match acc.last_mut() {
+                        Some(Some(visit)) => visit.end = time.end,
+                        _ => acc.push(Some(time)),
+                    }
+                } else {
+                    acc.push(None);
+                }
+
+                acc
+            });
+
+            usage
+                .entry(site_idx)
+                .or_default()
+                .extend(visits.into_iter().flatten().map(|time| (time, tour.vehicle_id.clone())));
+        })
+    });
+
+    usage.iter().try_for_each(|(site_idx, intervals)| {
+        let limit = context.problem.plan.sites.iter().flatten().nth(*site_idx).and_then(|site| site.max_vehicles);
+        let limit = limit.ok_or_else(|| format!("cannot find site with index {site_idx}"))?;
+
+        intervals.iter().try_for_each(|(time, vehicle_id)| {
+            let concurrent = intervals
+                .iter()
+                .filter(|(other, _)| other.start <= time.start + TOLERANCE && time.start + TOLERANCE < other.end)
+                .count();
+
+            if concurrent > limit {
+                Err(format!(
+                    "site violation: site with index {site_idx} is used by {concurrent} vehicles at {}, \
+                     expected not more than {limit}, vehicle id '{vehicle_id}'",
+                    time.start
+                )
+                .into())
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+/// Checks that time between departure from the first pickup and service start at the last delivery
+/// does not exceed job's max ride time.
+fn check_ride_times(context: &CheckerContext) -> GenericResult<()> {
+    const TOLERANCE: Float = 1E-3;
+
+    let jobs = context
+        .problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.max_ride_time.as_ref().map(|max_ride_time| (job, max_ride_time)))
+        .flat_map(|(job, max_ride_time)| job.visit_ids().into_iter().map(move |job_id| (job_id, max_ride_time)))
+        .collect::<HashMap<_, _>>();
+
+    if jobs.is_empty() {
+        return Ok(());
+    }
+
+    context.solution.tours.iter().try_for_each(|tour| {
+        let profile = context.get_vehicle_profile(&tour.vehicle_id)?;
+        let activities = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .collect::<Vec<_>>();
+
+        jobs.iter().try_for_each(|(job_id, max_ride_time)| {
+            let mut job_activities = activities.iter().filter(|(_, activity)| activity.job_id == *job_id);
+            let (Some((first_stop, first)), Some((last_stop, last))) =
+                (job_activities.clone().next(), job_activities.next_back())
+            else {
+                return Ok(());
+            };
+
+            let departure = context.get_activity_time(first_stop, first).end;
+            let service_start = context.get_activity_time(last_stop, last).start;
+            let ride_time = service_start - departure;
+
+            let limit = match (max_ride_time.duration, max_ride_time.factor) {
+                (Some(duration), _) => duration,
+                (None, Some(factor)) => {
+                    let from = context.get_activity_location(first_stop, first).ok_or("cannot get pickup location")?;
+                    let to = context.get_activity_location(last_stop, last).ok_or("cannot get delivery location")?;
+                    let (from_idx, to_idx) = (context.get_location_index(&from)?, context.get_location_index(&to)?);
+                    let (_, duration) = context.get_matrix_data(&profile, from_idx, to_idx)?;
+                    let duration =
+                        context.get_travel_duration(&profile, from_idx, to_idx, departure as i64, duration)?;
+
+                    factor * duration as Float
+                }
+                (None, None) => return Ok(()),
+            };
+
+            if ride_time > limit + TOLERANCE {
+                return Err(format!(
+                    "max ride time violation for job '{job_id}': expected not more than {limit}, got {ride_time}, \
+                     vehicle id '{}', shift index: {}",
+                    tour.vehicle_id, tour.shift_index
+                )
+                .into());
+            }
+
+            Ok(())
+        })
+    })
+}
+
+/// Returns energy consumed on the leg between two stops taking into account vehicle load on it.
+fn get_leg_energy(battery: &VehicleBattery, capacity: &[i32], prev: &PointStop, next: &PointStop) -> Float {
+    let (empty, full) = (battery.consumption.empty, battery.consumption.full.unwrap_or(battery.consumption.empty));
+
+    let ratio = prev
+        .load
+        .iter()
+        .zip(capacity.iter())
+        .fold(
+            0.,
+            |acc: Float, (&load, &capacity)| {
+                if capacity > 0 { acc.max(load as Float / capacity as Float) } else { acc }
+            },
+        )
+        .clamp(0., 1.);
+
+    (next.distance - prev.distance) as Float * (empty + (full - empty) * ratio)
+}
+
+/// Returns time needed to charge the battery at the station.
+fn get_charging_duration(
+    battery: &VehicleBattery,
+    station: &VehicleRechargeStation,
+    arrival: Float,
+    departure: Float,
+) -> Duration {
+    station.charging_curve.as_ref().map_or(Duration::default(), |curve| {
+        ChargingCurve::new(curve.iter().map(|point| (point.soc, point.power / 3600.)).collect()).get_duration(
+            battery.capacity,
+            arrival,
+            departure,
+        )
+    })
+}
@@ -15,6 +15,7 @@ use vrp_core::construction::clustering::vicinity::ClusterConfig;
 use vrp_core::construction::clustering::vicinity::VisitPolicy;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::models::common::{Duration, Profile, TimeWindow};
+use vrp_core::models::problem::SpeedProfileData;
 use vrp_core::models::solution::{Commute as DomainCommute, CommuteInfo as DomainCommuteInfo};
 use vrp_core::prelude::{GenericError, GenericResult};
 use vrp_core::solver::processing::ClusterConfigExtraProperty;
@@ -32,6 +33,7 @@ pub struct CheckerContext {
     job_map: HashMap<String, Job>,
     coord_index: CoordIndex,
     profile_index: HashMap<String, usize>,
+    speed_profiles: HashMap<usize, SpeedProfileData>,
     core_problem: Arc<CoreProblem>,
     clustering: Option<ClusterConfig>,
 }
@@ -40,10 +42,11 @@ pub struct CheckerContext {
 #[allow(dead_code)] // NOTE: keep data in each variant for future use
 enum ActivityType {
     Terminal,
-    Job(Job),
+    Job(Box<Job>),
     Break(VehicleBreak),
     Reload(VehicleReload),
     Recharge(VehicleRechargeStation),
+    Rest(VehicleHoursOfService),
 }
 
 impl CheckerContext {
@@ -54,7 +57,18 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Result<Self, Vec<GenericError>> {
-        let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
+        let job_map = problem
+            .plan
+            .jobs
+            .iter()
+            .flat_map(|job| {
+                let quantities = job.split_quantities();
+                job.visit_ids().into_iter().enumerate().map(move |(idx, job_id)| {
+                    let quantity = quantities.as_ref().and_then(|quantities| quantities.get(idx)).copied();
+                    (job_id, quantity.map_or_else(|| job.clone(), |quantity| get_split_part(job, quantity)))
+                })
+            })
+            .collect();
         let clustering = core_problem.extras.get_cluster_config().map(|config| config.as_ref().clone());
         let coord_index = CoordIndex::new(&problem);
         let profile_index = if matrices.is_none() {
@@ -64,8 +78,19 @@ impl CheckerContext {
                 .and_then(|matrices| get_profile_index(&problem, matrices.as_slice()))
                 .map_err(|err| vec![err])?
         };
-
-        Ok(Self { problem, matrices, solution, job_map, coord_index, profile_index, core_problem, clustering })
+        let speed_profiles = get_speed_profiles(&problem, &matrices).map_err(|err| vec![err])?;
+
+        Ok(Self {
+            problem,
+            matrices,
+            solution,
+            job_map,
+            coord_index,
+            profile_index,
+            speed_profiles,
+            core_problem,
+            clustering,
+        })
     }
 
     /// Performs solution check.
@@ -175,7 +200,7 @@ impl CheckerContext {
             "pickup" | "delivery" | "service" | "replacement" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id).into()),
-                    |job| Ok(ActivityType::Job(job.clone())),
+                    |job| Ok(ActivityType::Job(Box::new(job.clone()))),
                 )
             }
 
@@ -214,12 +239,29 @@ impl CheckerContext {
                 .map(|r| ActivityType::Recharge(r.clone()))
                 .ok_or_else(|| format!("cannot find recharge for tour '{}'", tour.vehicle_id).into()),
 
+            "rest" => self
+                .get_vehicle(&tour.vehicle_id)?
+                .hours_of_service
+                .as_ref()
+                .filter(|hours_of_service| {
+                    hours_of_service.driving_break.is_some() || hours_of_service.daily_rest.is_some()
+                })
+                .map(|hours_of_service| ActivityType::Rest(hours_of_service.clone()))
+                .ok_or_else(|| {
+                    format!("cannot find driving break or daily rest for tour '{}'", tour.vehicle_id).into()
+                }),
+
             _ => Err(format!("unknown activity type: '{}'", activity.activity_type).into()),
         }
     }
 
     fn get_job_by_id(&self, job_id: &str) -> Option<&Job> {
-        self.problem.plan.jobs.iter().find(|job| job.id == job_id)
+        self.job_map.get(job_id)
+    }
+
+    /// Gets customer site at given location.
+    fn get_site(&self, location: &Location) -> Option<&Site> {
+        self.problem.plan.sites.iter().flatten().find(|site| site.location == *location)
     }
 
     fn get_commute_info(
@@ -371,6 +413,28 @@ impl CheckerContext {
 
         Ok((distance, duration))
     }
+
+    /// Gets travel duration adjusted by time-dependent speed profile, if it is defined.
+    fn get_travel_duration(
+        &self,
+        profile: &Profile,
+        from_idx: usize,
+        to_idx: usize,
+        departure: i64,
+        duration: i64,
+    ) -> GenericResult<i64> {
+        let Some(data) = self.speed_profiles.get(&profile.index) else { return Ok(duration) };
+        if duration <= 0 {
+            return Ok(duration);
+        }
+
+        let matrix_size = get_matrix_size(get_matrices(&self.matrices)?.as_slice());
+        let speed = data
+            .get_profile(from_idx * matrix_size + to_idx)
+            .ok_or_else(|| format!("cannot find speed profile for {from_idx}->{to_idx}"))?;
+
+        Ok(speed.duration_at_departure(departure as Float, duration as Float).round() as i64)
+    }
 }
 
 fn job_task_size(tasks: &Option<Vec<JobTask>>) -> usize {
@@ -428,6 +492,16 @@ fn get_matrices(matrices: &Option<Vec<Matrix>>) -> GenericResult<&Vec<Matrix>> {
     Ok(matrices)
 }
 
+fn get_speed_profiles(
+    problem: &Problem,
+    matrices: &Option<Vec<Matrix>>,
+) -> GenericResult<HashMap<usize, SpeedProfileData>> {
+    let matrices = matrices.as_deref().unwrap_or_default();
+    let speed_profiles = read_speed_profiles(problem, matrices, &get_profile_index_map(problem))?;
+
+    Ok(speed_profiles.into_iter().map(|data| (data.index, data)).collect())
+}
+
 fn get_profile_index(problem: &Problem, matrices: &[Matrix]) -> GenericResult<HashMap<String, usize>> {
     let profiles = problem.fleet.profiles.len();
     if profiles != matrices.len() {
@@ -465,3 +539,14 @@ use crate::checker::relations::check_relations;
 
 mod routing;
 use crate::checker::routing::check_routing;
+
+/// Returns a part of split job with demand replaced by the part's quantity.
+fn get_split_part(job: &Job, quantity: i32) -> Job {
+    let get_tasks = |tasks: &Option<Vec<JobTask>>| {
+        tasks.as_ref().map(|tasks| {
+            tasks.iter().map(|task| JobTask { demand: Some(vec![quantity]), ..task.clone() }).collect::<Vec<_>>()
+        })
+    };
+
+    Job { pickups: get_tasks(&job.pickups), deliveries: get_tasks(&job.deliveries), ..job.clone() }
+}
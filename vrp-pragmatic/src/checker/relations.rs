@@ -5,15 +5,16 @@ mod relations_test;
 use super::*;
 use crate::utils::combine_error_results;
 use std::collections::HashSet;
+use vrp_core::construction::features::JobVisit;
 use vrp_core::prelude::GenericResult;
 
 /// Checks relation rules.
 pub fn check_relations(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_relations_assignment(context)])
+    combine_error_results(&[check_relations_assignment(context), check_precedences(context), check_visits(context)])
 }
 
 fn check_relations_assignment(context: &CheckerContext) -> GenericResult<()> {
-    let reserved_ids = vec!["departure", "arrival", "break", "reload"].into_iter().collect::<HashSet<_>>();
+    let reserved_ids = vec!["departure", "arrival", "break", "reload", "rest"].into_iter().collect::<HashSet<_>>();
 
     (0_usize..)
         .zip(context.problem.plan.relations.as_ref().map_or([].iter(), |relations| relations.iter()))
@@ -95,6 +96,105 @@ fn check_relations_assignment(context: &CheckerContext) -> GenericResult<()> {
     Ok(())
 }
 
+/// Checks that successor is served only when its predecessor is served and it starts not earlier
+/// than the time lag after the end of predecessor.
+fn check_precedences(context: &CheckerContext) -> GenericResult<()> {
+    const TOLERANCE: Float = 1E-3;
+
+    let times = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().flat_map(move |stop| {
+                stop.activities()
+                    .iter()
+                    .map(move |activity| (activity.job_id.as_str(), context.get_activity_time(stop, activity)))
+            })
+        })
+        .collect::<HashMap<_, _>>();
+
+    context.problem.plan.precedences.iter().flatten().try_for_each(|precedence| {
+        let (predecessor, successor) = (precedence.predecessor.as_str(), precedence.successor.as_str());
+
+        match (times.get(predecessor), times.get(successor)) {
+            (_, None) => Ok(()),
+            (None, Some(_)) => {
+                Err(format!("job '{successor}' is served without its predecessor '{predecessor}'").into())
+            }
+            (Some(predecessor_time), Some(successor_time)) => {
+                let earliest = predecessor_time.end + precedence.lag.unwrap_or_default();
+
+                if successor_time.start + TOLERANCE < earliest {
+                    Err(format!(
+                        "job '{successor}' starts at {}, but not earlier than {earliest} is expected by precedence \
+                         with '{predecessor}'",
+                        successor_time.start
+                    )
+                    .into())
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    })
+}
+
+/// Checks that visits of periodic jobs are either all served in different tours on allowed days or unassigned.
+fn check_visits(context: &CheckerContext) -> GenericResult<()> {
+    let get_shift_day = get_shift_day_fn(&context.problem);
+
+    let visit_tours = context.solution.tours.iter().enumerate().try_fold(
+        HashMap::<String, (usize, usize)>::new(),
+        |mut acc, (tour_idx, tour)| {
+            let shift =
+                context.get_vehicle(&tour.vehicle_id)?.shifts.get(tour.shift_index).ok_or_else(|| {
+                    format!("cannot find shift {} for vehicle '{}'", tour.shift_index, tour.vehicle_id)
+                })?;
+            let day = get_shift_day(&shift.start.earliest);
+
+            tour.stops.iter().flat_map(|stop| stop.activities()).for_each(|activity| {
+                acc.insert(activity.job_id.clone(), (tour_idx, day));
+            });
+
+            Ok::<_, GenericError>(acc)
+        },
+    )?;
+
+    context.problem.plan.jobs.iter().filter_map(|job| job.visits.as_ref().map(|visits| (job, visits))).try_for_each(
+        |(job, visits)| {
+            let (tours, days): (HashSet<_>, Vec<_>) =
+                job.visit_ids().iter().filter_map(|visit_id| visit_tours.get(visit_id)).cloned().unzip();
+
+            if !days.is_empty() && days.len() != visits.frequency {
+                return Err(format!(
+                    "job '{}' has {} visits served instead of {}",
+                    job.id,
+                    days.len(),
+                    visits.frequency
+                )
+                .into());
+            }
+
+            if tours.len() != days.len() {
+                return Err(format!("visits of job '{}' are served in the same tour", job.id).into());
+            }
+
+            let visit = JobVisit::new(
+                job.id.clone(),
+                visits.frequency,
+                visits.patterns.clone(),
+                visits.min_spacing.unwrap_or_default(),
+            );
+            if !visit.is_allowed_days(days.as_slice()) {
+                return Err(format!("visits of job '{}' are served on invalid days: {days:?}", job.id).into());
+            }
+
+            Ok(())
+        },
+    )
+}
+
 fn get_tour_by_vehicle_id(vehicle_id: &str, shift_index: Option<usize>, solution: &Solution) -> GenericResult<Tour> {
     solution
         .tours
@@ -24,6 +24,7 @@ mod location_fallback;
 pub use self::location_fallback::*;
 
 pub mod problem;
+pub mod replanning;
 pub mod solution;
 
 /// Represents a location type.
@@ -195,6 +196,18 @@ const GROUP_CONSTRAINT_CODE: ViolationCode = ViolationCode(12);
 const COMPATIBILITY_CONSTRAINT_CODE: ViolationCode = ViolationCode(13);
 const RELOAD_RESOURCE_CONSTRAINT_CODE: ViolationCode = ViolationCode(14);
 const RECHARGE_CONSTRAINT_CODE: ViolationCode = ViolationCode(15);
+const PERIODIC_CONSTRAINT_CODE: ViolationCode = ViolationCode(16);
+const COMPARTMENT_CONSTRAINT_CODE: ViolationCode = ViolationCode(17);
+const SATELLITE_CONSTRAINT_CODE: ViolationCode = ViolationCode(18);
+const HOURS_OF_SERVICE_CONSTRAINT_CODE: ViolationCode = ViolationCode(19);
+const RECHARGE_RESOURCE_CONSTRAINT_CODE: ViolationCode = ViolationCode(20);
+const RIDE_TIME_CONSTRAINT_CODE: ViolationCode = ViolationCode(21);
+const LOADING_POLICY_CONSTRAINT_CODE: ViolationCode = ViolationCode(22);
+const PACKING_CONSTRAINT_CODE: ViolationCode = ViolationCode(23);
+const SYNC_CONSTRAINT_CODE: ViolationCode = ViolationCode(24);
+const PRECEDENCE_CONSTRAINT_CODE: ViolationCode = ViolationCode(25);
+const ALTERNATIVE_CONSTRAINT_CODE: ViolationCode = ViolationCode(26);
+const SITE_CONSTRAINT_CODE: ViolationCode = ViolationCode(27);
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
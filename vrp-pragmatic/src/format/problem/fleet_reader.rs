@@ -9,11 +9,18 @@ use crate::get_unique_locations;
 use crate::utils::get_approx_transportation;
 use std::collections::HashSet;
 use vrp_core::construction::enablers::create_typed_actor_groups;
-use vrp_core::construction::features::{VehicleCapacityDimension, VehicleSkillsDimension};
+use vrp_core::construction::features::{
+    Battery, CargoSpace, Compartment, DailyRest, DriverSkillsDimension, DrivingBreak, FuelConsumption, HoursOfService,
+    LoadingPolicy, Overtime, SharedResourceId, VehicleBatteryDimension, VehicleCapacityDimension,
+    VehicleCargoSpaceDimension, VehicleCompartmentsDimension, VehicleDayDimension, VehicleFuelConsumptionDimension,
+    VehicleHoursOfServiceDimension, VehicleLoadingPolicyDimension, VehicleOvertimeDimension, VehicleSatelliteDimension,
+    VehicleSkillsDimension, WeeklyDriving,
+};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
+use vrp_core::models::problem::{Driver as CoreDriver, DriverIdDimension};
 
-pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String, usize> {
+pub(crate) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String, usize> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if !acc.contains_key(&profile.name) {
             acc.insert(profile.name.clone(), acc.len());
@@ -22,6 +29,33 @@ pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String,
     })
 }
 
+/// Finds routing matrix of the profile by its name. Matrices without profile are matched by index.
+fn get_profile_matrix<'a>(matrices: &'a [Matrix], profile_name: &str, index: usize) -> Option<&'a Matrix> {
+    matrices
+        .iter()
+        .enumerate()
+        .find(|(idx, matrix)| matrix.profile.as_ref().map_or(*idx == index, |name| name == profile_name))
+        .map(|(_, matrix)| matrix)
+}
+
+pub(super) fn get_satellite_index_map(api_problem: &ApiProblem) -> HashMap<String, SharedResourceId> {
+    api_problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Satellite { id, .. } => Some(id),
+            VehicleResource::Reload { .. } | VehicleResource::Recharge { .. } => None,
+        })
+        .fold(Default::default(), |mut acc, id| {
+            if !acc.contains_key(id) {
+                acc.insert(id.clone(), acc.len());
+            }
+            acc
+        })
+}
+
 pub(super) fn create_transport_costs(
     api_problem: &ApiProblem,
     matrices: &[Matrix],
@@ -88,6 +122,23 @@ pub(super) fn create_transport_costs(
         return Err("amount of fleet profiles does not match matrix profiles".into());
     }
 
+    let speed_data = read_speed_profiles(api_problem, matrices, &matrix_profiles)?;
+    if !speed_data.is_empty() {
+        if matrix_data.iter().any(|data| data.timestamp.is_some()) {
+            return Err("speed profiles cannot be used together with time aware routing matrices".into());
+        }
+
+        return if coord_index.has_custom() {
+            create_speed_profile_transport_cost_with_fallback(
+                matrix_data,
+                speed_data,
+                UnknownLocationFallback::new(coord_index),
+            )
+        } else {
+            create_speed_profile_transport_cost(matrix_data, speed_data)
+        };
+    }
+
     if coord_index.has_custom() {
         create_matrix_transport_cost_with_fallback(matrix_data, UnknownLocationFallback::new(coord_index))
     } else {
@@ -95,8 +146,39 @@ pub(super) fn create_transport_costs(
     }
 }
 
+/// Reads speed profiles of the fleet profiles keyed by routing profile index.
+pub(crate) fn read_speed_profiles(
+    api_problem: &ApiProblem,
+    matrices: &[Matrix],
+    matrix_profiles: &HashMap<String, usize>,
+) -> GenericResult<Vec<SpeedProfileData>> {
+    api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.speed_profiles.as_ref().map(|speed_profiles| (profile, speed_profiles)))
+        .map(|(profile, speed_profiles)| {
+            let index = *matrix_profiles.get(&profile.name).expect("cannot find matrix profile");
+            let profiles = speed_profiles
+                .iter()
+                .map(|speed_profile| {
+                    SpeedProfile::new(
+                        speed_profile.points.iter().map(|point| (parse_time(&point.time), point.factor)).collect(),
+                    )
+                })
+                .collect::<GenericResult<Vec<_>>>()?;
+            let edge_classes =
+                get_profile_matrix(matrices, &profile.name, index).and_then(|matrix| matrix.edge_classes.clone());
+
+            Ok(SpeedProfileData::new(index, profiles, edge_classes))
+        })
+        .collect()
+}
+
 pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> CoreFleet {
     let profile_indices = get_profile_index_map(api_problem);
+    let satellite_indices = get_satellite_index_map(api_problem);
+    let get_shift_day = get_shift_day_fn(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
@@ -150,12 +232,76 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_tour_size(tour_size);
                 }
 
+                if props.has_periodic {
+                    dimens.set_vehicle_day(get_shift_day(&shift.start.earliest));
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_vehicle_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
                     dimens.set_vehicle_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
                 }
 
+                if let Some(compartments) = vehicle.compartments.as_ref().filter(|c| !c.is_empty()) {
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_vehicle_compartments(read_compartments(compartments, MultiDimLoad::new));
+                    } else {
+                        dimens.set_vehicle_compartments(read_compartments(compartments, |capacity| {
+                            SingleDimLoad::new(capacity.first().cloned().unwrap_or_default())
+                        }));
+                    }
+                }
+
+                if let Some(satellite) = shift.start.satellite.as_ref().and_then(|id| satellite_indices.get(id)) {
+                    dimens.set_vehicle_satellite(*satellite);
+                }
+
+                if let Some(overtime) = shift.overtime.as_ref() {
+                    dimens.set_vehicle_overtime(Overtime {
+                        start: parse_time(&overtime.regular_end),
+                        rates: overtime.rates.iter().map(|rate| (rate.after, rate.time)).collect(),
+                    });
+                }
+
+                if let Some(hours_of_service) = vehicle.hours_of_service.as_ref() {
+                    dimens.set_vehicle_hours_of_service(read_hours_of_service(vehicle, hours_of_service));
+                }
+
+                if let Some(consumption) = vehicle.consumption.as_ref() {
+                    dimens.set_vehicle_fuel_consumption(FuelConsumption {
+                        empty: consumption.empty,
+                        full: consumption.full,
+                        drag: consumption.drag.unwrap_or_default(),
+                        co2: consumption.co2,
+                    });
+                }
+
+                if let Some(loading_policy) = vehicle.loading_policy.as_ref() {
+                    dimens.set_vehicle_loading_policy(match loading_policy {
+                        VehicleLoadingPolicy::Lifo => LoadingPolicy::Lifo,
+                        VehicleLoadingPolicy::Fifo => LoadingPolicy::Fifo,
+                    });
+                }
+
+                if let Some(cargo_space) = vehicle.cargo_space.as_ref() {
+                    dimens.set_vehicle_cargo_space(CargoSpace {
+                        length: cargo_space.length,
+                        width: cargo_space.width,
+                        height: cargo_space.height,
+                        max_weight: cargo_space.max_weight,
+                    });
+                }
+
+                if let Some(battery) = shift.recharges.as_ref().and_then(|recharges| recharges.battery.as_ref()) {
+                    dimens.set_vehicle_battery(Battery {
+                        capacity: battery.capacity,
+                        initial: battery.initial.unwrap_or(battery.capacity),
+                        reserve: battery.reserve.unwrap_or_default(),
+                        empty: battery.consumption.empty,
+                        full: battery.consumption.full.unwrap_or(battery.consumption.empty),
+                    });
+                }
+
                 if let Some(skills) = vehicle.skills.as_ref() {
                     dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
                 }
@@ -170,25 +316,113 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         }
     });
 
-    let drivers = vec![Arc::new(Driver {
-        costs: Costs {
-            fixed: 0.0,
-            per_distance: 0.0,
-            per_driving_time: 0.0,
-            per_waiting_time: 0.0,
-            per_service_time: 0.0,
-        },
-        dimens: Default::default(),
-        details: vec![],
-    })];
+    let drivers = read_drivers(api_problem);
 
     CoreFleet::new(drivers, vehicles, |actors| {
         create_typed_actor_groups(actors, |a| {
-            a.vehicle.dimens.get_vehicle_type().cloned().expect("vehicle has no type defined")
+            let vehicle_type = a.vehicle.dimens.get_vehicle_type().cloned().expect("vehicle has no type defined");
+            match a.driver.dimens.get_driver_id() {
+                Some(driver_id) => format!("{vehicle_type}:{driver_id}"),
+                None => vehicle_type,
+            }
         })
     })
 }
 
+fn read_compartments<T: LoadOps>(
+    compartments: &[VehicleCompartment],
+    capacity_map: fn(Vec<i32>) -> T,
+) -> Vec<Compartment<T>> {
+    compartments
+        .iter()
+        .map(|compartment| Compartment {
+            id: compartment.id.clone(),
+            capacity: capacity_map(compartment.capacity.clone()),
+            products: compartment.products.as_ref().map(|products| products.iter().cloned().collect()),
+            incompatible: compartment
+                .incompatible
+                .iter()
+                .flatten()
+                .map(|group| group.iter().cloned().collect())
+                .collect(),
+        })
+        .collect()
+}
+
+fn read_hours_of_service(vehicle: &VehicleType, hours_of_service: &VehicleHoursOfService) -> HoursOfService {
+    // NOTE weeks are counted from the earliest shift start of the vehicle type
+    let week_start = vehicle
+        .shifts
+        .iter()
+        .map(|shift| parse_time(&shift.start.earliest))
+        .min_by(|a, b| a.total_cmp(b))
+        .unwrap_or_default();
+
+    HoursOfService {
+        driving_break: hours_of_service.driving_break.as_ref().map(|driving_break| DrivingBreak {
+            max_driving: driving_break.max_driving,
+            parts: driving_break.parts.clone(),
+        }),
+        daily_rest: hours_of_service
+            .daily_rest
+            .as_ref()
+            .map(|daily_rest| DailyRest { max_driving: daily_rest.max_driving, duration: daily_rest.duration }),
+        max_tour_driving: hours_of_service.max_tour_driving,
+        max_vehicle_driving: hours_of_service.max_vehicle_driving,
+        weekly_driving: hours_of_service
+            .max_weekly_driving
+            .map(|max_driving| WeeklyDriving { start: week_start, max_driving }),
+    }
+}
+
+fn read_drivers(api_problem: &ApiProblem) -> Vec<Arc<CoreDriver>> {
+    match api_problem.fleet.drivers.as_ref() {
+        Some(drivers) if !drivers.is_empty() => drivers
+            .iter()
+            .map(|driver| {
+                let mut dimens: Dimensions = Default::default();
+                dimens.set_driver_id(driver.id.clone());
+
+                if let Some(skills) = driver.skills.as_ref() {
+                    dimens.set_driver_skills(skills.iter().cloned().collect::<HashSet<_>>());
+                }
+
+                Arc::new(CoreDriver {
+                    costs: Costs {
+                        fixed: driver.costs.fixed.unwrap_or(0.),
+                        per_distance: 0.,
+                        per_driving_time: driver.costs.time,
+                        per_waiting_time: driver.costs.time,
+                        per_service_time: driver.costs.time,
+                    },
+                    dimens,
+                    details: driver
+                        .shifts
+                        .iter()
+                        .map(|shift| DriverDetail {
+                            time: TimeInterval {
+                                earliest: Some(parse_time(&shift.earliest)),
+                                latest: Some(parse_time(&shift.latest)),
+                            },
+                        })
+                        .collect(),
+                })
+            })
+            .collect(),
+        _ => vec![Arc::new(CoreDriver {
+            costs: Costs {
+                fixed: 0.0,
+                per_distance: 0.0,
+                per_driving_time: 0.0,
+                per_waiting_time: 0.0,
+                per_service_time: 0.0,
+            },
+            dimens: Default::default(),
+            details: vec![],
+        })],
+    }
+}
+
 /// Creates a matrices using approximation.
 pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
     const DEFAULT_SPEED: Float = 10.;
@@ -222,6 +456,7 @@ pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
                 travel_times: approx_data[idx].0.clone(),
                 distances: approx_data[idx].1.clone(),
                 error_codes: None,
+                edge_classes: None,
             }
         })
         .collect()
@@ -3,6 +3,7 @@ use std::ops::Mul;
 use vrp_core::algorithms::clustering::kmedoids::create_hierarchical_kmedoids;
 use vrp_core::construction::clustering::vicinity::ClusterInfoDimension;
 use vrp_core::construction::enablers::FeatureCombinator;
+use vrp_core::construction::features::Site as CoreSite;
 use vrp_core::construction::features::*;
 use vrp_core::models::common::{Demand, LoadOps, MultiDimLoad, SingleDimLoad};
 use vrp_core::models::problem::{Actor, Single, TransportCost};
@@ -23,6 +24,11 @@ pub(super) fn create_goal_context(
         features.push(create_reachable_feature("reachable", blocks.transport.clone(), REACHABLE_CONSTRAINT_CODE)?)
     }
 
+    // NOTE site setup time changes activity durations, so it should be applied before other time dependent features
+    if props.has_sites {
+        features.push(get_site_feature("site", api_problem, blocks)?);
+    }
+
     features.push(get_capacity_feature("capacity", api_problem, blocks, props)?);
 
     if props.has_tour_travel_limits {
@@ -39,7 +45,7 @@ pub(super) fn create_goal_context(
     }
 
     if props.has_recharges {
-        features.push(get_recharge_feature("recharge", api_problem, blocks.transport.clone())?);
+        features.push(get_recharge_feature("recharge", api_problem, blocks, props)?);
     }
 
     if props.has_order && !features.iter().any(|f| f.name == "tour_order") {
@@ -50,10 +56,99 @@ pub(super) fn create_goal_context(
         features.push(create_compatibility_feature("compatibility", COMPATIBILITY_CONSTRAINT_CODE)?);
     }
 
+    if props.has_compartments {
+        features.push(if props.has_multi_dimen_capacity {
+            create_compartments_feature::<MultiDimLoad>("compartments", COMPARTMENT_CONSTRAINT_CODE)?
+        } else {
+            create_compartments_feature::<SingleDimLoad>("compartments", COMPARTMENT_CONSTRAINT_CODE)?
+        });
+    }
+
+    if props.has_satellites {
+        features.push(if props.has_multi_dimen_capacity {
+            create_two_echelon_feature::<MultiDimLoad>(
+                "two_echelon",
+                SATELLITE_CONSTRAINT_CODE,
+                blocks.transport.clone(),
+                blocks.activity.clone(),
+            )?
+        } else {
+            create_two_echelon_feature::<SingleDimLoad>(
+                "two_echelon",
+                SATELLITE_CONSTRAINT_CODE,
+                blocks.transport.clone(),
+                blocks.activity.clone(),
+            )?
+        });
+    }
+
+    if props.has_hours_of_service {
+        features.push(create_hours_of_service_feature(
+            "hours_of_service",
+            HOURS_OF_SERVICE_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_loading_policy {
+        features.push(create_loading_policy_feature("loading_policy", LOADING_POLICY_CONSTRAINT_CODE)?);
+    }
+
+    if props.has_cargo_space {
+        let is_reload_activity: ReloadActivityFn = Arc::new(|activity| {
+            activity.job.as_ref().and_then(|single| single.dimens.get_job_type()).is_some_and(|t| t == "reload")
+        });
+        features.push(if props.has_multi_dimen_capacity {
+            create_packing_feature::<MultiDimLoad>("packing", PACKING_CONSTRAINT_CODE, is_reload_activity)?
+        } else {
+            create_packing_feature::<SingleDimLoad>("packing", PACKING_CONSTRAINT_CODE, is_reload_activity)?
+        });
+    }
+
+    if props.has_max_ride_time {
+        features.push(create_max_ride_time_feature(
+            "max_ride_time",
+            RIDE_TIME_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_splits {
+        features.push(create_split_feature("split", CAPACITY_CONSTRAINT_CODE)?);
+    }
+
+    if props.has_sync {
+        features.push(create_synchronization_feature(
+            "sync",
+            SYNC_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_precedences {
+        features.push(create_precedence_feature(
+            "precedence",
+            PRECEDENCE_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_alternatives {
+        features.push(create_alternative_feature("alternative", ALTERNATIVE_CONSTRAINT_CODE)?);
+    }
+
     if props.has_group {
         features.push(create_group_feature("group", blocks.jobs.size(), GROUP_CONSTRAINT_CODE)?);
     }
 
+    if props.has_periodic {
+        features.push(create_periodic_feature("periodic", PERIODIC_CONSTRAINT_CODE)?);
+    }
+
     if props.has_skills {
         features.push(create_skills_feature("skills", SKILL_CONSTRAINT_CODE)?)
     }
@@ -106,11 +201,7 @@ fn get_objective_feature_layer(
     props: &ProblemProperties,
 ) -> GenericResult<FeatureLayer> {
     let feature = match objective {
-        Objective::MinimizeCost => TransportFeatureBuilder::new("min_cost")
-            .set_violation_code(TIME_CONSTRAINT_CODE)
-            .set_transport_cost(blocks.transport.clone())
-            .set_activity_cost(blocks.activity.clone())
-            .build_minimize_cost(),
+        Objective::MinimizeCost => get_min_cost_feature(blocks, props),
         Objective::MinimizeDistance => TransportFeatureBuilder::new("min_distance")
             .set_violation_code(TIME_CONSTRAINT_CODE)
             .set_transport_cost(blocks.transport.clone())
@@ -153,8 +244,12 @@ fn get_objective_feature_layer(
             .set_job_estimator({
                 let break_value = *breaks;
                 let default_value = 1.;
+                // NOTE a single unassigned mandatory job should outweigh all other unassigned jobs
+                let mandatory_value = (blocks.jobs.size() + 1) as Float * default_value;
                 move |_, job| {
-                    if let Some(clusters) = job.dimens().get_cluster_info() {
+                    if job.dimens().get_job_mandatory().copied().unwrap_or(false) {
+                        mandatory_value
+                    } else if let Some(clusters) = job.dimens().get_cluster_info() {
                         clusters.len() as Float * default_value
                     } else {
                         job.dimens().get_job_type().map_or(default_value, |job_type| match job_type.as_str() {
@@ -167,6 +262,13 @@ fn get_objective_feature_layer(
             .build(),
 
         Objective::MinimizeArrivalTime => create_minimize_arrival_time_feature("min_arrival_time"),
+        Objective::MinimizeEmissions => {
+            if props.has_multi_dimen_capacity {
+                create_emissions_feature::<MultiDimLoad>("min_emissions", blocks.transport.clone())
+            } else {
+                create_emissions_feature::<SingleDimLoad>("min_emissions", blocks.transport.clone())
+            }
+        }
         Objective::BalanceMaxLoad => {
             if props.has_multi_dimen_capacity {
                 create_max_load_balanced_feature::<MultiDimLoad>(
@@ -200,6 +302,9 @@ fn get_objective_feature_layer(
         Objective::TourOrder => create_tour_order_soft_feature("tour_order", get_tour_order_fn()),
         Objective::FastService => get_fast_service_feature("fast_service", blocks),
         Objective::HierarchicalAreas { levels } => get_hierarchical_areas_feature(blocks, *levels),
+        Objective::MinimizeDeviation { reference, weight } => {
+            get_solution_stability_feature(blocks, reference.as_slice(), *weight)
+        }
         Objective::MultiObjective { objectives, strategy: composition_type } => {
             let features = objectives
                 .iter()
@@ -243,6 +348,64 @@ fn get_hierarchical_areas_feature(blocks: &ProblemBlocks, levels: usize) -> Gene
     })
 }
 
+fn get_min_cost_feature(blocks: &ProblemBlocks, props: &ProblemProperties) -> GenericResult<Feature> {
+    let cost_feature = TransportFeatureBuilder::new("min_cost")
+        .set_violation_code(TIME_CONSTRAINT_CODE)
+        .set_transport_cost(blocks.transport.clone())
+        .set_activity_cost(blocks.activity.clone())
+        .build_minimize_cost()?;
+
+    if !props.has_soft_time_windows && !props.has_overtime {
+        return Ok(cost_feature);
+    }
+
+    let mut features = vec![cost_feature];
+
+    if props.has_soft_time_windows {
+        features.push(create_soft_time_windows_feature(
+            "soft_time_windows",
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_overtime {
+        features.push(create_overtime_feature("overtime", blocks.transport.clone(), blocks.activity.clone())?);
+    }
+
+    // NOTE: objectives are summed, so earliness and lateness penalties and overtime are expressed in cost units
+    FeatureCombinator::default().use_name("min_cost").add_features(&features).combine()
+}
+
+fn get_solution_stability_feature(
+    blocks: &ProblemBlocks,
+    reference: &[ReferenceTour],
+    weight: Float,
+) -> GenericResult<Feature> {
+    let job_index = blocks.job_index.as_ref().ok_or("misconfiguration in goal reader: job index is not set")?;
+
+    let references = reference.iter().try_fold(HashMap::new(), |mut acc, tour| {
+        let shift_index = tour.shift_index.unwrap_or_default();
+        let vehicle = blocks
+            .fleet
+            .vehicles
+            .iter()
+            .find(|vehicle| {
+                vehicle.dimens.get_vehicle_id() == Some(&tour.vehicle_id)
+                    && vehicle.dimens.get_shift_index() == Some(&shift_index)
+            })
+            .ok_or_else(|| format!("unknown vehicle in reference tour: '{}', shift {shift_index}", tour.vehicle_id))?;
+
+        tour.jobs.iter().filter_map(|job_id| job_index.get(job_id)).enumerate().for_each(|(position, job)| {
+            acc.entry(job.clone()).or_insert_with(|| JobReference { vehicle: vehicle.clone(), position });
+        });
+
+        Ok::<_, GenericError>(acc)
+    })?;
+
+    create_solution_stability_feature("min_deviation", references, weight)
+}
+
 fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Objective> {
     if let Some(objectives) = api_problem.objectives.clone() {
         objectives
@@ -453,7 +616,8 @@ fn get_tour_limit_feature(
 fn get_recharge_feature(
     name: &str,
     api_problem: &ApiProblem,
-    transport: Arc<dyn TransportCost>,
+    blocks: &ProblemBlocks,
+    props: &ProblemProperties,
 ) -> GenericResult<Feature> {
     fn is_recharge_single(single: &Single) -> bool {
         single.dimens.get_job_type().is_some_and(|job_type| job_type == "recharge")
@@ -466,7 +630,11 @@ fn get_recharge_feature(
                 .iter()
                 .enumerate()
                 .flat_map(|(shift_idx, shift)| {
-                    shift.recharges.as_ref().map(|recharges| (shift_idx, recharges.max_distance))
+                    shift
+                        .recharges
+                        .as_ref()
+                        .and_then(|recharges| recharges.max_distance)
+                        .map(|limit| (shift_idx, limit))
                 })
                 .for_each(|(shift_idx, max_distance)| {
                     acc.entry(vehicle_type.type_id.clone()).or_default().insert(shift_idx, max_distance);
@@ -475,9 +643,13 @@ fn get_recharge_feature(
             acc
         });
 
-    RechargeFeatureBuilder::new(name)
+    let job_index = blocks.job_index.as_ref().ok_or("misconfiguration in goal reader: job index is not set")?;
+    let recharge_resources = get_recharge_resources(api_problem, job_index);
+
+    let builder = RechargeFeatureBuilder::new(name)
         .set_violation_code(RECHARGE_CONSTRAINT_CODE)
-        .set_transport(transport)
+        .set_transport(blocks.transport.clone())
+        .set_activity(blocks.activity.clone())
         .set_is_recharge_single(is_recharge_single)
         .set_belongs_to_route(|route, job| {
             job.as_single()
@@ -487,8 +659,110 @@ fn get_recharge_feature(
             actor.vehicle.dimens.get_vehicle_type().zip(actor.vehicle.dimens.get_shift_index().copied()).and_then(
                 |(type_id, shift_idx)| distance_limit_index.get(type_id).and_then(|idx| idx.get(&shift_idx).copied()),
             )
+        });
+
+    let builder = if recharge_resources.is_empty() {
+        builder
+    } else {
+        builder.set_chargers_code(RECHARGE_RESOURCE_CONSTRAINT_CODE).set_shared_chargers(move |activity| {
+            activity
+                .job
+                .as_ref()
+                .filter(|single| is_recharge_single(single.as_ref()))
+                .and_then(|single| recharge_resources.get(&CoreJob::Single(single.clone())).cloned())
         })
-        .build()
+    };
+
+    if props.has_multi_dimen_capacity {
+        builder.set_load_type::<MultiDimLoad>().build()
+    } else {
+        builder.set_load_type::<SingleDimLoad>().build()
+    }
+}
+
+fn get_site_feature(name: &str, api_problem: &ApiProblem, blocks: &ProblemBlocks) -> GenericResult<Feature> {
+    let sites = api_problem
+        .plan
+        .sites
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(idx, site)| {
+            blocks.coord_index.get_by_loc(&site.location).map(|location| {
+                let max_vehicles = site.max_vehicles;
+                let setup_time = site.setup_time.unwrap_or_default();
+
+                (location, CoreSite { id: idx, max_vehicles, setup_time })
+            })
+        })
+        .collect::<HashMap<_, _>>();
+
+    create_site_feature(
+        name,
+        SITE_CONSTRAINT_CODE,
+        blocks.transport.clone(),
+        blocks.activity.clone(),
+        move |activity| {
+            // NOTE only activities of plan jobs are served at the site
+            activity
+                .job
+                .as_ref()
+                .and_then(|single| single.dimens.get_job_type())
+                .filter(|job_type| matches!(job_type.as_str(), "pickup" | "delivery" | "replacement" | "service"))
+                .and_then(|_| sites.get(&activity.place.location).copied())
+        },
+    )
+}
+
+fn get_recharge_resources(
+    api_problem: &ApiProblem,
+    job_index: &JobIndex,
+) -> HashMap<CoreJob, (usize, SharedResourceId)> {
+    let available_resources = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Recharge { id, chargers } => Some((id.clone(), *chargers)),
+            VehicleResource::Reload { .. } | VehicleResource::Satellite { .. } => None,
+        })
+        .enumerate()
+        .map(|(idx, (id, chargers))| (id, (chargers, idx)))
+        .collect::<HashMap<_, _>>();
+
+    api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            vehicle
+                .shifts
+                .iter()
+                .enumerate()
+                .flat_map(|(shift_idx, vehicle_shift)| {
+                    vehicle_shift
+                        .recharges
+                        .iter()
+                        .flat_map(|recharges| recharges.stations.iter())
+                        .enumerate()
+                        .map(move |(station_idx, station)| (shift_idx, station_idx + 1, station))
+                })
+                .filter_map(|(shift_idx, place_idx, station)| {
+                    station
+                        .resource_id
+                        .as_ref()
+                        .and_then(|resource_id| available_resources.get(resource_id))
+                        .map(|resource| (shift_idx, place_idx, *resource))
+                })
+                .flat_map(move |(shift_idx, place_idx, resource)| {
+                    vehicle.vehicle_ids.iter().filter_map(move |vehicle_id| {
+                        let job_id = format!("{vehicle_id}_recharge_{shift_idx}_{place_idx}");
+                        job_index.get(&job_id).map(|job| (job.clone(), resource))
+                    })
+                })
+        })
+        .collect()
 }
 
 fn get_reload_resources<T>(
@@ -506,8 +780,9 @@ where
         .as_ref()
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id.clone(), capacity.clone()),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id.clone(), capacity.clone())),
+            VehicleResource::Recharge { .. } | VehicleResource::Satellite { .. } => None,
         })
         .collect::<Vec<_>>();
     let total_resources_specified = available_resources.len();
@@ -1,5 +1,6 @@
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::JobSkills as ApiJobSkills;
+use crate::format::problem::fleet_reader::get_satellite_index_map;
 use crate::format::problem::*;
 use crate::format::{JobIndex, Location};
 use crate::utils::VariableJobPermutation;
@@ -7,8 +8,12 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use vrp_core::{
     construction::features::{
-        BreakPolicy, JobCompatibilityDimension, JobDemandDimension, JobGroupDimension, JobSkills as FeatureJobSkills,
-        JobSkillsDimension,
+        BreakPolicy, ChargingCurve, JobAlternative, JobAlternativeDimension, JobChargingCurveDimension,
+        JobCompatibilityDimension, JobDemandDimension, JobGroupDimension, JobMaxRideTimeDimension,
+        JobPackingItemsDimension, JobPrecedence, JobPrecedenceDimension, JobProductDimension, JobSatelliteDimension,
+        JobSkills as FeatureJobSkills, JobSkillsDimension, JobSoftTimeWindowsDimension, JobSplit as CoreJobSplit,
+        JobSplitDimension, JobSync as CoreJobSync, JobSyncDimension, JobVisit, JobVisitDimension, MaxRideTime,
+        PackingItem, SoftTimeWindows,
     },
     models::common::*,
     models::problem::{
@@ -114,6 +119,10 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let satellite_indices = get_satellite_index_map(api_problem);
+    let precedences = get_precedence_map(api_problem);
+    let alternatives = get_alternative_map(api_problem);
+    let site_hours = get_site_opening_hours(api_problem, coord_index);
 
     let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
         let absent = (empty(), empty());
@@ -134,20 +143,62 @@ fn read_required_jobs(
             .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
             .collect();
 
-        get_single_with_dimens(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        let mut single =
+            get_single_with_dimens(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+
+        if let Some(product) = task.product.clone() {
+            single.dimens.set_job_product(product);
+        }
+
+        if let Some(items) = task.items.as_ref().filter(|items| !items.is_empty()) {
+            single.dimens.set_job_packing_items(
+                items
+                    .iter()
+                    .map(|item| PackingItem {
+                        id: item.id.clone(),
+                        length: item.length,
+                        width: item.width,
+                        height: item.height,
+                        weight: item.weight.unwrap_or_default(),
+                        stackable: item.stackable.unwrap_or(true),
+                        rotatable: item.rotatable.unwrap_or(true),
+                    })
+                    .collect(),
+            );
+        }
+
+        single
     };
 
-    api_problem.plan.jobs.iter().for_each(|job| {
-        let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
-        let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
-        let is_static_demand = pickups == 0 || deliveries == 0;
+    api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| {
+            let split_quantities = job.split_quantities();
+            job.visit_ids().into_iter().enumerate().map(move |(idx, job_id)| {
+                (job, job_id, split_quantities.as_ref().and_then(|quantities| quantities.get(idx).copied()))
+            })
+        })
+        .for_each(|(job, job_id, split_quantity)| {
+            let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
+            let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
+            let is_static_demand = pickups == 0 || deliveries == 0;
+
+            // NOTE split job has only one task, its demand is replaced by quantity of the part
+            let get_task = |task: &JobTask| match split_quantity {
+                Some(quantity) => JobTask { demand: Some(vec![quantity]), ..task.clone() },
+                None => task.clone(),
+            };
 
-        let singles =
-            job.pickups
+            let mut singles = job
+                .pickups
                 .iter()
-                .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
+                .flat_map(|tasks| {
+                    tasks.iter().map(|task| get_single_from_task(&get_task(task), "pickup", is_static_demand))
+                })
                 .chain(job.deliveries.iter().flat_map(|tasks| {
-                    tasks.iter().map(|task| get_single_from_task(task, "delivery", is_static_demand))
+                    tasks.iter().map(|task| get_single_from_task(&get_task(task), "delivery", is_static_demand))
                 }))
                 .chain(
                     job.replacements
@@ -161,22 +212,127 @@ fn read_required_jobs(
                 )
                 .collect::<Vec<_>>();
 
-        assert!(!singles.is_empty());
+            assert!(!singles.is_empty());
 
-        let problem_job = if singles.len() > 1 {
-            let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
-            get_multi_job(job, singles, deliveries_start_index, random)
-        } else {
-            get_single_job(job, singles.into_iter().next().unwrap())
-        };
+            if let Some(satellite) = job.satellite.as_ref().and_then(|id| satellite_indices.get(id)) {
+                singles.iter_mut().for_each(|single| {
+                    single.dimens.set_job_satellite(*satellite);
+                });
+            }
 
-        job_index.insert(job.id.clone(), problem_job.clone());
-        jobs.push(problem_job);
-    });
+            if let Some(soft_time_windows) = job.soft_time_windows.as_ref() {
+                singles.iter_mut().for_each(|single| set_soft_time_windows(single, soft_time_windows));
+            }
+
+            if !site_hours.is_empty() {
+                singles.iter_mut().for_each(|single| set_site_opening_hours(single, &site_hours));
+            }
+
+            if let Some(split) = job.split.as_ref() {
+                let max_splits = split.max_splits.unwrap_or(usize::MAX);
+                singles.iter_mut().for_each(|single| {
+                    single.dimens.set_job_split(CoreJobSplit { key: job.id.clone(), max_splits });
+                });
+            }
+
+            if let Some(precedence) = precedences.get(job.id.as_str()) {
+                singles.iter_mut().for_each(|single| {
+                    single.dimens.set_job_precedence(JobPrecedence {
+                        key: job.id.clone(),
+                        predecessors: precedence.predecessors.clone(),
+                        successors: precedence.successors.clone(),
+                    });
+                });
+            }
+
+            let alternative = alternatives
+                .get(job.id.as_str())
+                .map(|(key, is_optional)| JobAlternative { key: key.clone(), is_optional: *is_optional });
+
+            let problem_job = if singles.len() > 1 {
+                let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
+                get_multi_job(job, &job_id, alternative, singles, deliveries_start_index, random)
+            } else {
+                get_single_job(job, &job_id, alternative, singles.into_iter().next().unwrap())
+            };
+
+            job_index.insert(job_id, problem_job.clone());
+            jobs.push(problem_job);
+        });
 
     (jobs, vec![])
 }
 
+/// Returns predecessors and successors with their time lags for each job used in precedences.
+fn get_precedence_map(api_problem: &ApiProblem) -> HashMap<&str, JobPrecedence> {
+    fn get_precedence<'a, 'b>(
+        precedences: &'b mut HashMap<&'a str, JobPrecedence>,
+        job_id: &'a str,
+    ) -> &'b mut JobPrecedence {
+        precedences.entry(job_id).or_insert_with(|| JobPrecedence {
+            key: job_id.to_string(),
+            predecessors: vec![],
+            successors: vec![],
+        })
+    }
+
+    api_problem.plan.precedences.iter().flatten().fold(HashMap::default(), |mut acc, precedence| {
+        let lag = precedence.lag.unwrap_or_default();
+
+        get_precedence(&mut acc, &precedence.predecessor).successors.push((precedence.successor.clone(), lag));
+        get_precedence(&mut acc, &precedence.successor).predecessors.push((precedence.predecessor.clone(), lag));
+
+        acc
+    })
+}
+
+/// Returns alternative set key and whether the set is optional for each job used in alternatives.
+fn get_alternative_map(api_problem: &ApiProblem) -> HashMap<&str, (String, bool)> {
+    api_problem
+        .plan
+        .alternatives
+        .iter()
+        .flatten()
+        .enumerate()
+        .flat_map(|(idx, alternative)| {
+            let is_optional = matches!(alternative.type_field, AlternativeType::AtMostOne);
+            alternative.jobs.iter().map(move |job_id| (job_id.as_str(), (format!("alternative{idx}"), is_optional)))
+        })
+        .collect()
+}
+
+/// Returns opening hours of the sites with their location indices.
+fn get_site_opening_hours(api_problem: &ApiProblem, coord_index: &CoordIndex) -> HashMap<usize, Vec<TimeWindow>> {
+    api_problem
+        .plan
+        .sites
+        .iter()
+        .flatten()
+        .filter_map(|site| {
+            let location = coord_index.get_by_loc(&site.location)?;
+            let opening_hours = site.opening_hours.as_ref()?;
+
+            Some((location, opening_hours.iter().map(|tw| parse_time_window(tw)).collect()))
+        })
+        .collect()
+}
+
+/// Restricts hard time windows of the places at the site locations by site opening hours.
+/// NOTE offsets cannot be resolved without a shift start, so they are dropped: job places have only absolute
+/// time windows (see `parse_times`), so this never happens for plan jobs.
+fn set_site_opening_hours(single: &mut Single, site_hours: &HashMap<usize, Vec<TimeWindow>>) {
+    single.places.iter_mut().for_each(|place| {
+        let Some(opening_hours) = place.location.and_then(|location| site_hours.get(&location)) else { return };
+
+        place.times = place
+            .times
+            .iter()
+            .filter_map(|time| time.as_time_window())
+            .flat_map(|tw| opening_hours.iter().filter_map(move |hours| tw.overlapping(hours)).map(TimeSpan::Window))
+            .collect();
+    });
+}
+
 fn read_conditional_jobs(api_problem: &ApiProblem, coord_index: &CoordIndex, job_index: &mut JobIndex) -> Vec<Job> {
     let mut jobs = vec![];
 
@@ -270,11 +426,15 @@ fn read_reloads(
         jobs,
         vehicle,
         shift_index,
-        reloads.iter().map(|reload| JobPlace {
-            location: reload.location.clone(),
-            duration: reload.duration,
-            times: reload.times.clone(),
-            tag: reload.tag.clone(),
+        reloads.iter().map(|reload| {
+            let place = JobPlace {
+                location: reload.location.clone(),
+                duration: reload.duration,
+                times: reload.times.clone(),
+                tag: reload.tag.clone(),
+            };
+
+            (place, |_: &mut Single| {})
         }),
     )
 }
@@ -294,22 +454,38 @@ fn read_recharges(
         jobs,
         vehicle,
         shift_index,
-        recharges.stations.iter().cloned(),
+        recharges.stations.iter().map(|station| {
+            let place = JobPlace {
+                location: station.location.clone(),
+                duration: station.duration,
+                times: station.times.clone(),
+                tag: station.tag.clone(),
+            };
+
+            (place, move |single: &mut Single| {
+                if let Some(curve) = station.charging_curve.as_ref() {
+                    // NOTE power is specified per hour, but time is measured in seconds
+                    single.dimens.set_job_charging_curve(ChargingCurve::new(
+                        curve.iter().map(|point| (point.soc, point.power / 3600.)).collect(),
+                    ));
+                }
+            })
+        }),
     )
 }
 
-fn read_specific_job_places(
+fn read_specific_job_places<F: Fn(&mut Single)>(
     job_type: &str,
     coord_index: &CoordIndex,
     job_index: &mut JobIndex,
     jobs: &mut Vec<Job>,
     vehicle: &VehicleType,
     shift_index: usize,
-    get_places: impl Iterator<Item = JobPlace>,
+    get_places: impl Iterator<Item = (JobPlace, F)>,
 ) {
     (1..)
         .zip(get_places)
-        .flat_map(|(place_idx, place)| {
+        .flat_map(|(place_idx, (place, extend_fn))| {
             vehicle
                 .vehicle_ids
                 .iter()
@@ -317,7 +493,7 @@ fn read_specific_job_places(
                     let job_id = format!("{vehicle_id}_{job_type}_{shift_index}_{place_idx}");
                     let times = parse_times(&place.times);
 
-                    let job = get_conditional_job(
+                    let mut job = get_conditional_job(
                         coord_index,
                         vehicle_id.clone(),
                         &job_id,
@@ -325,6 +501,7 @@ fn read_specific_job_places(
                         shift_index,
                         vec![(Some(place.location.clone()), place.duration, times, place.tag.clone())],
                     );
+                    extend_fn(&mut job);
 
                     (job_id, job)
                 })
@@ -410,13 +587,49 @@ fn get_single_with_dimens(
     single
 }
 
-fn fill_dimens(job: &ApiJob, dimens: &mut Dimensions) {
-    dimens.set_job_id(job.id.clone());
+/// Keeps original time windows as soft ones and expands hard time windows by allowed earliness
+/// and lateness.
+fn set_soft_time_windows(single: &mut Single, soft_time_windows: &JobSoftTimeWindows) {
+    let times = single
+        .places
+        .iter()
+        .map(|place| place.times.iter().filter_map(|time| time.as_time_window()).collect())
+        .collect();
+
+    single.places.iter_mut().flat_map(|place| place.times.iter_mut()).for_each(|time| {
+        if let TimeSpan::Window(time) = time {
+            if soft_time_windows.earliness.is_some() {
+                time.start = soft_time_windows.max_earliness.map_or(0., |earliness| (time.start - earliness).max(0.));
+            }
+
+            if soft_time_windows.lateness.is_some() {
+                time.end = soft_time_windows.max_lateness.map_or(Float::MAX, |lateness| time.end + lateness);
+            }
+        }
+    });
+
+    single.dimens.set_job_soft_time_windows(SoftTimeWindows {
+        earliness: soft_time_windows.earliness.unwrap_or_default(),
+        lateness: soft_time_windows.lateness.unwrap_or_default(),
+        times,
+    });
+}
+
+fn fill_dimens(job: &ApiJob, job_id: &str, alternative: Option<JobAlternative>, dimens: &mut Dimensions) {
+    dimens.set_job_id(job_id.to_string());
+
+    if let Some(alternative) = alternative {
+        dimens.set_job_alternative(alternative);
+    }
 
     if let Some(value) = job.value {
         dimens.set_job_value(value);
     }
 
+    if job.mandatory.unwrap_or(false) {
+        dimens.set_job_mandatory(true);
+    }
+
     if let Some(group) = job.group.clone() {
         dimens.set_job_group(group);
     }
@@ -428,18 +641,50 @@ fn fill_dimens(job: &ApiJob, dimens: &mut Dimensions) {
     if let Some(skills) = get_skills(&job.skills) {
         dimens.set_job_skills(skills);
     }
+
+    if let Some(visits) = job.visits.as_ref() {
+        dimens.set_job_visit(JobVisit::new(
+            job.id.clone(),
+            visits.frequency,
+            visits.patterns.clone(),
+            visits.min_spacing.unwrap_or_default(),
+        ));
+    }
+
+    if let Some(sync) = job.sync.as_ref() {
+        dimens.set_job_sync(CoreJobSync {
+            key: job.id.clone(),
+            size: sync.vehicles,
+            tolerance: sync.tolerance.unwrap_or_default(),
+        });
+    }
+
+    if let Some(max_ride_time) = job.max_ride_time.as_ref() {
+        match (max_ride_time.duration, max_ride_time.factor) {
+            (Some(duration), _) => dimens.set_job_max_ride_time(MaxRideTime::Absolute(duration)),
+            (None, Some(factor)) => dimens.set_job_max_ride_time(MaxRideTime::Relative(factor)),
+            (None, None) => dimens,
+        };
+    }
 }
 
-fn get_single_job(job: &ApiJob, single: Single) -> Job {
+fn get_single_job(job: &ApiJob, job_id: &str, alternative: Option<JobAlternative>, single: Single) -> Job {
     let mut single = single;
-    fill_dimens(job, &mut single.dimens);
+    fill_dimens(job, job_id, alternative, &mut single.dimens);
 
     Job::Single(Arc::new(single))
 }
 
-fn get_multi_job(job: &ApiJob, singles: Vec<Single>, deliveries_start_index: usize, random: &Arc<dyn Random>) -> Job {
+fn get_multi_job(
+    job: &ApiJob,
+    job_id: &str,
+    alternative: Option<JobAlternative>,
+    singles: Vec<Single>,
+    deliveries_start_index: usize,
+    random: &Arc<dyn Random>,
+) -> Job {
     let mut dimens: Dimensions = Default::default();
-    fill_dimens(job, &mut dimens);
+    fill_dimens(job, job_id, alternative, &mut dimens);
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
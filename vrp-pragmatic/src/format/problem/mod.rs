@@ -22,6 +22,7 @@ mod clustering_reader;
 
 mod fleet_reader;
 pub use self::fleet_reader::create_approx_matrices;
+pub(crate) use self::fleet_reader::{get_profile_index_map, read_speed_profiles};
 
 mod goal_reader;
 mod job_reader;
@@ -107,8 +108,23 @@ struct ProblemProperties {
     has_group: bool,
     has_value: bool,
     has_compatibility: bool,
+    has_compartments: bool,
+    has_satellites: bool,
+    has_soft_time_windows: bool,
+    has_overtime: bool,
+    has_hours_of_service: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_drivers: bool,
+    has_periodic: bool,
+    has_max_ride_time: bool,
+    has_loading_policy: bool,
+    has_cargo_space: bool,
+    has_sync: bool,
+    has_splits: bool,
+    has_precedences: bool,
+    has_alternatives: bool,
+    has_sites: bool,
 }
 
 /// Keeps track of materialized problem building blocks.
@@ -116,12 +132,31 @@ struct ProblemBlocks {
     jobs: Arc<CoreJobs>,
     fleet: Arc<CoreFleet>,
     job_index: Option<Arc<JobIndex>>,
+    coord_index: Arc<CoordIndex>,
     transport: Arc<dyn TransportCost>,
     activity: Arc<dyn ActivityCost>,
     locks: Vec<Arc<Lock>>,
     reserved_times_index: ReservedTimesIndex,
 }
 
+/// Returns a function which maps shift start time to a day index counted from the day of
+/// the earliest vehicle shift start. Days are defined by UTC calendar.
+pub(crate) fn get_shift_day_fn(api_problem: &ApiProblem) -> impl Fn(&str) -> usize {
+    const SECONDS_PER_DAY: f64 = 86400.;
+
+    let get_day = |time: &str| (parse_time(time) / SECONDS_PER_DAY).floor() as i64;
+    let first_day = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .map(|shift| get_day(&shift.start.earliest))
+        .min()
+        .unwrap_or_default();
+
+    move |time: &str| (get_day(time) - first_day).max(0) as usize
+}
+
 fn parse_time_window(tw: &[String]) -> TimeWindow {
     assert_eq!(tw.len(), 2);
     TimeWindow::new(parse_time(tw.first().unwrap()), parse_time(tw.last().unwrap()))
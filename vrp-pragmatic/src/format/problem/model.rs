@@ -38,6 +38,58 @@ pub struct Relation {
     pub shift_index: Option<usize>,
 }
 
+/// Precedence specifies that one job should be started not earlier than the given time lag after
+/// the end of another job, independently of which vehicles serve them.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Precedence {
+    /// Id of the job which should be served first.
+    pub predecessor: String,
+    /// Id of the job which should be served after the predecessor.
+    pub successor: String,
+    /// A minimum time between the end of predecessor and the start of successor. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lag: Option<Float>,
+}
+
+/// Alternative type.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlternativeType {
+    /// Exactly one job of the set should be assigned.
+    ExactlyOne,
+    /// At most one job of the set can be assigned.
+    AtMostOne,
+}
+
+/// Alternative specifies a set of jobs from which only one job should be served.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alternative {
+    /// Alternative type.
+    #[serde(rename(deserialize = "type", serialize = "type"))]
+    pub type_field: AlternativeType,
+    /// List of job ids.
+    pub jobs: Vec<String>,
+}
+
+/// Site specifies rules shared by all jobs at the same location, e.g. a warehouse with loading docks.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Site {
+    /// Site location: all jobs at this location are served at the site.
+    pub location: Location,
+    /// Site opening hours: jobs can be served only within them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opening_hours: Option<Vec<Vec<String>>>,
+    /// A maximum number of vehicles which can be served at the site at the same time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_vehicles: Option<usize>,
+    /// A setup time applied once per stop at the site, shared by all jobs served in that stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_time: Option<Float>,
+}
+
 /// A job skills limitation for a vehicle.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -80,6 +132,34 @@ pub struct JobTask {
     /// An order, bigger value - later assignment in the route.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
+    /// A product type used to assign task's demand to vehicle compartment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+    /// Items to be loaded into vehicle's cargo space.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<JobItem>>,
+}
+
+/// Specifies an item with its physical dimensions used to check loading feasibility.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobItem {
+    /// An item id.
+    pub id: String,
+    /// An item length.
+    pub length: Float,
+    /// An item width.
+    pub width: Float,
+    /// An item height.
+    pub height: Float,
+    /// An item weight. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<Float>,
+    /// Specifies whether other items can be put on top of this one. Default is true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stackable: Option<bool>,
+    /// Specifies whether item can be rotated around vertical axis. Default is true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotatable: Option<bool>,
 }
 
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
@@ -122,6 +202,116 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// Job visits: a periodic job which has to be served multiple times within planning horizon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visits: Option<JobVisits>,
+
+    /// A satellite id: marks a supply job which delivers goods to the satellite and can be
+    /// served only by first-level vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub satellite: Option<String>,
+
+    /// Soft time windows: allows serving a job outside of its time windows at penalty cost.
+    #[serde(rename = "softTimeWindows", skip_serializing_if = "Option::is_none")]
+    pub soft_time_windows: Option<JobSoftTimeWindows>,
+
+    /// Split delivery: allows job's demand to be served by multiple vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split: Option<JobSplit>,
+
+    /// Maximum ride time: limits time between departure from the first pickup and service start at the last
+    /// delivery of pickup and delivery job.
+    #[serde(rename = "maxRideTime", skip_serializing_if = "Option::is_none")]
+    pub max_ride_time: Option<JobMaxRideTime>,
+
+    /// Synchronization: a job which requires multiple vehicles to be present at the same time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync: Option<JobSync>,
+
+    /// Mandatory job: if it cannot be assigned, the solver fails with infeasibility diagnosis instead of
+    /// reporting the job as unassigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandatory: Option<bool>,
+}
+
+/// Specifies how job's demand can be split between multiple vehicles.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSplit {
+    /// A minimum quantity served by one vehicle. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_quantity: Option<i32>,
+
+    /// A maximum amount of vehicles which can serve the job. If not set, it is not limited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_splits: Option<usize>,
+}
+
+/// Specifies maximum ride time of pickup and delivery job. Only one of the properties should be set.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobMaxRideTime {
+    /// An absolute maximum ride time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<Float>,
+
+    /// A maximum ride time relative to direct travel time between pickup and delivery locations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub factor: Option<Float>,
+}
+
+/// Specifies how many vehicles are required to serve the job at the same time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSync {
+    /// Amount of vehicles required to serve the job.
+    pub vehicles: usize,
+
+    /// A maximum allowed difference between service start times of the vehicles. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<Float>,
+}
+
+/// Specifies penalties for serving a job outside of its time windows. Penalty rates are applied
+/// per time unit of deviation from the closest time window.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSoftTimeWindows {
+    /// A penalty per time unit when job is served before its time window. If not set, earliness
+    /// is not allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliness: Option<Float>,
+
+    /// A penalty per time unit when job is served after its time window. If not set, lateness
+    /// is not allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<Float>,
+
+    /// A maximum allowed earliness. If not set, earliness is not limited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_earliness: Option<Float>,
+
+    /// A maximum allowed lateness. If not set, lateness is not limited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_lateness: Option<Float>,
+}
+
+/// Specifies how often a periodic job has to be visited within planning horizon.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobVisits {
+    /// Amount of visits.
+    pub frequency: usize,
+
+    /// Allowed day patterns: each pattern is a list of day indices, counted from the day of
+    /// the earliest vehicle shift start. If specified, all visits should happen on days of one pattern.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patterns: Option<Vec<Vec<usize>>>,
+
+    /// Minimum amount of days between two consecutive visits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_spacing: Option<usize>,
 }
 
 // region Clustering
@@ -222,6 +412,18 @@ pub struct Plan {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relations: Option<Vec<Relation>>,
 
+    /// List of precedences between jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precedences: Option<Vec<Precedence>>,
+
+    /// List of alternative job sets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternatives: Option<Vec<Alternative>>,
+
+    /// List of customer sites.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sites: Option<Vec<Site>>,
+
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
@@ -259,6 +461,11 @@ pub struct ShiftStart {
 
     /// Shift start location.
     pub location: Location,
+
+    /// A satellite id: marks a second-level vehicle shift which starts from the satellite and
+    /// takes goods delivered there by first-level vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub satellite: Option<String>,
 }
 
 /// Specifies vehicle shift end.
@@ -298,6 +505,32 @@ pub struct VehicleShift {
     /// Vehicle recharge stations information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recharges: Option<VehicleRecharges>,
+
+    /// Vehicle overtime: a regular shift end with overtime cost schedule after it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime: Option<VehicleOvertime>,
+}
+
+/// Specifies a regular end of vehicle shift and costs of work done after it. Shift end's latest
+/// time is still a hard limit.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleOvertime {
+    /// A regular shift end date time in RFC3339 format.
+    pub regular_end: String,
+
+    /// Overtime cost schedule sorted by offset.
+    pub rates: Vec<VehicleOvertimeRate>,
+}
+
+/// Specifies a cost per time unit applied after some offset from a regular shift end.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleOvertimeRate {
+    /// An offset from a regular shift end (in seconds) when the rate starts to apply.
+    pub after: Float,
+
+    /// A cost per time unit which is used instead of vehicle's time cost.
+    pub time: Float,
 }
 
 /// Specifies a place where vehicle can load or unload cargo.
@@ -328,14 +561,84 @@ pub struct VehicleReload {
 #[serde(rename_all = "camelCase")]
 pub struct VehicleRecharges {
     /// Maximum traveled distance before recharge station has to be visited.
-    pub max_distance: Float,
+    /// Ignored when battery is specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<Float>,
+
+    /// Vehicle battery which state of charge is tracked along the tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battery: Option<VehicleBattery>,
 
     /// Specifies list of recharge station. Each can be visited only once.
     pub stations: Vec<VehicleRechargeStation>,
 }
 
-/// Specifies type alias for vehicle recharge station.
-pub type VehicleRechargeStation = JobPlace;
+/// Specifies vehicle battery and its energy consumption.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleBattery {
+    /// Battery capacity (e.g. in kWh).
+    pub capacity: Float,
+
+    /// A state of charge at the start of the shift. Default is full battery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial: Option<Float>,
+
+    /// A minimum state of charge which has to be kept all the time. Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserve: Option<Float>,
+
+    /// Energy consumption model.
+    pub consumption: VehicleEnergyConsumption,
+}
+
+/// Specifies energy consumed per distance unit. It grows linearly with carried load.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleEnergyConsumption {
+    /// Energy consumed per distance unit by empty vehicle.
+    pub empty: Float,
+
+    /// Energy consumed per distance unit by fully loaded vehicle. Default is the same as for empty one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full: Option<Float>,
+}
+
+/// Specifies vehicle recharge station.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleRechargeStation {
+    /// A station location.
+    pub location: Location,
+
+    /// A fixed duration of the visit, charging time is added on top of it when battery is used.
+    pub duration: Float,
+
+    /// A list of time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// A piecewise-linear charging curve of the station's power level. When omitted,
+    /// charging time is not taken into account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_curve: Option<Vec<VehicleChargingPoint>>,
+
+    /// A shared recharge resource id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+}
+
+/// Specifies a point of charging curve.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleChargingPoint {
+    /// A state of charge as a share of battery capacity in `[0, 1]`.
+    pub soc: Float,
+
+    /// A charging power: energy charged per hour (e.g. in kW).
+    pub power: Float,
+}
 
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize)]
@@ -465,6 +768,137 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle compartments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<VehicleCompartment>>,
+
+    /// Vehicle driver's hours-of-service rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours_of_service: Option<VehicleHoursOfService>,
+
+    /// Vehicle fuel consumption model used to estimate emissions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumption: Option<VehicleConsumption>,
+
+    /// Vehicle loading policy applied to pickup and delivery jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loading_policy: Option<VehicleLoadingPolicy>,
+
+    /// Vehicle cargo space used to check loading feasibility of job items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cargo_space: Option<VehicleCargoSpace>,
+}
+
+/// Specifies vehicle's cargo space dimensions.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleCargoSpace {
+    /// A cargo space length.
+    pub length: Float,
+    /// A cargo space width.
+    pub width: Float,
+    /// A cargo space height.
+    pub height: Float,
+    /// A max total weight of loaded items. No weight restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_weight: Option<Float>,
+}
+
+/// Specifies loading order of pickup and delivery jobs, e.g. for rear-loaded vehicles.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VehicleLoadingPolicy {
+    /// Last in, first out: job can be delivered only if its pickup is the most recent one on board.
+    Lifo,
+    /// First in, first out: job can be delivered only if its pickup is the earliest one on board.
+    Fifo,
+}
+
+/// Specifies a vehicle compartment: a part of vehicle with its own capacity.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleCompartment {
+    /// A compartment id.
+    pub id: String,
+
+    /// A compartment capacity.
+    pub capacity: Vec<i32>,
+
+    /// Products which can be loaded into the compartment. If omitted, any product can be loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products: Option<Vec<String>>,
+
+    /// Groups of products which cannot share the compartment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incompatible: Option<Vec<Vec<String>>>,
+}
+
+/// Specifies hours-of-service rules of a vehicle's driver, e.g. EU 561/2006 or US FMCSA regulations.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleHoursOfService {
+    /// A break which has to be taken after some continuous driving time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driving_break: Option<VehicleDrivingBreak>,
+
+    /// A rest which has to be taken after some daily driving time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_rest: Option<VehicleDailyRest>,
+
+    /// A maximum driving time within a single tour (vehicle shift).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tour_driving: Option<Float>,
+
+    /// A maximum driving time within all tours (vehicle shifts) of the same vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_vehicle_driving: Option<Float>,
+
+    /// A maximum driving time within all tours (vehicle shifts) of the same vehicle which start in the
+    /// same week. Weeks are counted from the earliest shift start of the vehicle type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_weekly_driving: Option<Float>,
+}
+
+/// Specifies a rest which has to be taken after some daily driving time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleDailyRest {
+    /// A maximum driving time between daily rests.
+    pub max_driving: Float,
+
+    /// A rest duration. Waiting time long enough is counted as a daily rest.
+    pub duration: Float,
+}
+
+/// Specifies a break which has to be taken after some continuous driving time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleDrivingBreak {
+    /// A maximum continuous driving time.
+    pub max_driving: Float,
+
+    /// Break parts in the order they have to be taken, e.g. 45min break splittable into 15 + 30min.
+    /// Waiting time long enough is counted as a break part.
+    pub parts: Vec<Float>,
+}
+
+/// Specifies a vehicle fuel consumption model: consumption per distance unit grows linearly with
+/// carried load and quadratically with speed.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleConsumption {
+    /// Fuel consumed per distance unit by empty vehicle.
+    pub empty: Float,
+
+    /// Fuel consumed per distance unit by fully loaded vehicle.
+    pub full: Float,
+
+    /// Extra fuel consumed per distance unit and squared speed (aerodynamic drag).
+    /// Default value is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drag: Option<Float>,
+
+    /// CO2 emitted per fuel unit.
+    pub co2: Float,
 }
 
 /// Specifies a vehicle profile.
@@ -489,6 +923,29 @@ pub struct MatrixProfile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<Float>,
+
+    /// Time-dependent speed profiles. When specified, travel times are treated as free flow values
+    /// and adjusted by speed factor at departure time. Each edge uses a profile selected by its class
+    /// in routing matrix (see `Matrix::edge_classes`) or the first profile if no class is specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed_profiles: Option<Vec<MatrixSpeedProfile>>,
+}
+
+/// Specifies a piecewise-linear speed profile.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MatrixSpeedProfile {
+    /// Speed factor points sorted by time. Factor is linearly interpolated between points and
+    /// kept constant before the first and after the last point.
+    pub points: Vec<MatrixSpeedPoint>,
+}
+
+/// Specifies a speed factor at specific time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MatrixSpeedPoint {
+    /// A time in RFC3339 format.
+    pub time: String,
+    /// A speed factor relative to free flow speed, must be positive.
+    pub factor: Float,
 }
 
 /// Specifies vehicle resource type.
@@ -503,6 +960,62 @@ pub enum VehicleResource {
         /// A total resource capacity.
         capacity: Vec<i32>,
     },
+
+    /// A shared recharge resource.
+    #[serde(rename(deserialize = "recharge", serialize = "recharge"))]
+    Recharge {
+        /// Resource id.
+        id: String,
+        /// A total number of chargers which can be used at the same time.
+        chargers: usize,
+    },
+
+    /// A satellite used as a transshipment point in two-echelon routing.
+    #[serde(rename(deserialize = "satellite", serialize = "satellite"))]
+    Satellite {
+        /// Satellite id.
+        id: String,
+        /// Satellite location.
+        location: Location,
+    },
+}
+
+/// Specifies driver costs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverCosts {
+    /// Fixed is cost of driver usage per tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<Float>,
+
+    /// Cost per time unit.
+    pub time: Float,
+}
+
+/// Specifies driver shift.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverShift {
+    /// Earliest possible start date time of the shift in RFC3339 format.
+    pub earliest: String,
+
+    /// Latest possible end date time of the shift in RFC3339 format.
+    pub latest: String,
+}
+
+/// Specifies a driver: a separate from vehicles resource which is required to operate a vehicle.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct Driver {
+    /// Driver id.
+    pub id: String,
+
+    /// Driver costs.
+    pub costs: DriverCosts,
+
+    /// Driver shifts. Each shift can be used to serve at most one tour.
+    pub shifts: Vec<DriverShift>,
+
+    /// Driver skills. They are combined with skills of the vehicle driver is assigned to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
 }
 
 /// Specifies fleet.
@@ -511,6 +1024,10 @@ pub struct Fleet {
     /// Vehicle types.
     pub vehicles: Vec<VehicleType>,
 
+    /// Drivers. If specified, each tour has to be served by a vehicle paired with one of the drivers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drivers: Option<Vec<Driver>>,
+
     /// Routing profiles.
     pub profiles: Vec<MatrixProfile>,
 
@@ -560,6 +1077,10 @@ pub enum Objective {
     /// An objective to minimize sum of arrival times from all routes.
     MinimizeArrivalTime,
 
+    /// An objective to minimize CO2 emissions which depend on vehicle fuel consumption, distance,
+    /// speed and carried load.
+    MinimizeEmissions,
+
     /// An objective to balance max load across all tours.
     BalanceMaxLoad,
 
@@ -590,6 +1111,15 @@ pub enum Objective {
         levels: usize,
     },
 
+    /// An objective to minimize deviation from the reference plan: each reassignment of a job to other
+    /// vehicle and each change of job sequence within a tour adds a penalty.
+    MinimizeDeviation {
+        /// Tours of the reference plan.
+        reference: Vec<ReferenceTour>,
+        /// A penalty of a single deviation.
+        weight: Float,
+    },
+
     /// A multi objective allows to define multiple competitive objectives at the same layer of hierarchy.
     MultiObjective {
         /// An objective composition type.
@@ -614,6 +1144,20 @@ pub enum MultiStrategy {
     },
 }
 
+/// Specifies a tour of the reference plan used by minimize deviation objective.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceTour {
+    /// A vehicle id.
+    pub vehicle_id: String,
+    /// A vehicle shift index. Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+    /// Ids of jobs in the order they are served in the tour. Ids which are not present in the plan
+    /// are ignored.
+    pub jobs: Vec<String>,
+}
+
 // endregion
 
 // region Common
@@ -652,6 +1196,10 @@ pub struct Matrix {
     /// Error codes to mark unreachable locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_codes: Option<Vec<i64>>,
+
+    /// Edge classes used to select speed profile of the corresponding matrix profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edge_classes: Option<Vec<usize>>,
 }
 
 // endregion
@@ -666,6 +1214,38 @@ impl Job {
             .chain(self.replacements.iter())
             .flatten()
     }
+
+    /// Returns ids of the job as they are used in solution: a periodic job is expanded into
+    /// multiple visits with `{job_id}_visit_{idx}` ids, a split job is expanded into multiple
+    /// parts with `{job_id}_split_{idx}` ids, a synchronized job is expanded into copies (one
+    /// per required vehicle) with `{job_id}_sync_{idx}` ids, otherwise job id is returned as is.
+    pub fn visit_ids(&self) -> Vec<String> {
+        match (&self.visits, self.split_quantities(), &self.sync) {
+            (Some(visits), _, _) => (1..=visits.frequency).map(|idx| format!("{}_visit_{idx}", self.id)).collect(),
+            (None, Some(quantities), _) => {
+                (1..=quantities.len()).map(|idx| format!("{}_split_{idx}", self.id)).collect()
+            }
+            (None, None, Some(sync)) => (1..=sync.vehicles).map(|idx| format!("{}_sync_{idx}", self.id)).collect(),
+            (None, None, None) => vec![self.id.clone()],
+        }
+    }
+
+    /// Returns quantities of split job parts: demand is divided into parts of minimum quantity, the
+    /// last part takes the rest. Parts are portions of demand, so how much each vehicle serves is
+    /// decided by the solver when it inserts parts into routes. Returns `None` if job is not split.
+    pub fn split_quantities(&self) -> Option<Vec<i32>> {
+        let split = self.split.as_ref()?;
+        let quantity = self.all_tasks_iter().next()?.demand.as_ref()?.first().copied()?;
+
+        let min_quantity = split.min_quantity.unwrap_or(1).max(1);
+        let parts = (quantity / min_quantity).max(1) as usize;
+
+        Some(
+            (0..parts)
+                .map(|idx| if idx + 1 < parts { min_quantity } else { quantity - min_quantity * idx as i32 })
+                .collect(),
+        )
+    }
 }
 
 /// Deserializes problem in json format from `BufReader`.
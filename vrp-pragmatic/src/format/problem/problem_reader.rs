@@ -9,6 +9,7 @@ use crate::{CoordIndex, parse_time};
 use vrp_core::construction::enablers::*;
 use vrp_core::models::Extras;
 use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
+use vrp_core::models::problem::SimpleActivityCost;
 use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
 
 pub(super) fn map_to_problem_with_approx(problem: ApiProblem) -> Result<CoreProblem, MultiFormatError> {
@@ -120,12 +121,13 @@ fn to_multi_format_error(error: GenericError) -> MultiFormatError {
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
     let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
-    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
-        || api_problem
-            .plan
-            .jobs
-            .iter()
-            .any(|job| job.all_tasks_iter().any(|task| task.demand.as_ref().is_some_and(|d| d.len() > 1)));
+    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| {
+        t.capacity.len() > 1 || t.compartments.iter().flatten().any(|compartment| compartment.capacity.len() > 1)
+    }) || api_problem
+        .plan
+        .jobs
+        .iter()
+        .any(|job| job.all_tasks_iter().any(|task| task.demand.as_ref().is_some_and(|d| d.len() > 1)));
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
 
     let shift_has_fn = |shift_has: fn(&VehicleShift) -> bool| {
@@ -147,6 +149,14 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
     let has_value = api_problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value != 0.);
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
+    let has_compartments =
+        api_problem.fleet.vehicles.iter().any(|v| v.compartments.as_ref().is_some_and(|c| !c.is_empty()));
+    let has_satellites = api_problem.plan.jobs.iter().any(|job| job.satellite.is_some())
+        || api_problem.fleet.vehicles.iter().flat_map(|v| v.shifts.iter()).any(|shift| shift.start.satellite.is_some());
+    let has_soft_time_windows = api_problem.plan.jobs.iter().any(|job| job.soft_time_windows.is_some());
+    let has_overtime =
+        api_problem.fleet.vehicles.iter().flat_map(|v| v.shifts.iter()).any(|shift| shift.overtime.is_some());
+    let has_hours_of_service = api_problem.fleet.vehicles.iter().any(|v| v.hours_of_service.is_some());
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().is_some_and(|l| l.tour_size.is_some()));
 
@@ -156,6 +166,22 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .iter()
         .any(|v| v.limits.as_ref().is_some_and(|l| l.max_duration.or(l.max_distance).is_some()));
 
+    let has_drivers = api_problem.fleet.drivers.as_ref().is_some_and(|drivers| !drivers.is_empty());
+    let has_periodic = api_problem.plan.jobs.iter().any(|job| job.visits.is_some());
+    let has_max_ride_time = api_problem.plan.jobs.iter().any(|job| job.max_ride_time.is_some());
+    let has_loading_policy = api_problem.fleet.vehicles.iter().any(|v| v.loading_policy.is_some());
+    let has_cargo_space = api_problem.fleet.vehicles.iter().any(|v| v.cargo_space.is_some());
+    let has_sync = api_problem.plan.jobs.iter().any(|job| job.sync.is_some());
+    let has_splits = api_problem.plan.jobs.iter().any(|job| job.split.is_some());
+    let has_precedences = api_problem.plan.precedences.as_ref().is_some_and(|precedences| !precedences.is_empty());
+    let has_alternatives = api_problem.plan.alternatives.as_ref().is_some_and(|alternatives| !alternatives.is_empty());
+    let has_sites = api_problem
+        .plan
+        .sites
+        .iter()
+        .flatten()
+        .any(|site| site.max_vehicles.is_some() || site.setup_time.is_some_and(|setup_time| setup_time > 0.));
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
@@ -167,8 +193,23 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_group,
         has_value,
         has_compatibility,
+        has_compartments,
+        has_satellites,
+        has_soft_time_windows,
+        has_overtime,
+        has_hours_of_service,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_drivers,
+        has_periodic,
+        has_max_ride_time,
+        has_loading_policy,
+        has_cargo_space,
+        has_sync,
+        has_splits,
+        has_precedences,
+        has_alternatives,
+        has_sites,
     }
 }
 
@@ -199,7 +240,11 @@ fn get_problem_blocks(
             (environment.logger)(format!("fleet index created in {}ms", duration.as_millis()).as_str());
         },
     )?;
-    let activity: Arc<dyn ActivityCost> = Arc::new(OnlyVehicleActivityCost::default());
+    let activity: Arc<dyn ActivityCost> = if problem_props.has_drivers {
+        Arc::new(SimpleActivityCost::default())
+    } else {
+        Arc::new(OnlyVehicleActivityCost::default())
+    };
 
     let (transport, activity) = if reserved_times_index.is_empty() {
         (transport, activity)
@@ -235,6 +280,7 @@ fn get_problem_blocks(
         jobs: Arc::new(jobs),
         fleet: Arc::new(fleet),
         job_index: None,
+        coord_index,
         transport,
         activity,
         locks,
@@ -0,0 +1,477 @@
+//! This module provides logic to re-optimize routes which are already in execution: some vehicles
+//! are on their way, some activities are completed, new jobs arrive and some jobs are cancelled.
+//!
+//! The execution state is applied to the original problem and solution, so the result can be solved
+//! as a normal problem using the updated solution as an initial one.
+
+#[cfg(test)]
+#[path = "../../tests/unit/format/replanning_test.rs"]
+mod replanning_test;
+
+use crate::format::Location;
+use crate::format::problem::*;
+use crate::format::solution::{Activity, PointStop, Schedule, Solution, Stop, Tour};
+use crate::parse_time_safe;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, Read};
+use vrp_core::prelude::{GenericError, GenericResult};
+
+/// Specifies a state of plan execution at specific moment of time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionState {
+    /// Current time in RFC3339 format.
+    pub time: String,
+
+    /// States of vehicles which are already on their tours.
+    pub vehicles: Vec<VehicleState>,
+
+    /// New jobs to be added to the plan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_jobs: Option<Vec<Job>>,
+
+    /// Ids of cancelled jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_jobs: Option<Vec<String>>,
+}
+
+/// Specifies a state of the vehicle which is already on its tour.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleState {
+    /// Vehicle id.
+    pub vehicle_id: String,
+
+    /// Vehicle shift index. Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+
+    /// Current vehicle location.
+    pub location: Location,
+
+    /// Activities completed by the vehicle.
+    pub completed: Vec<CompletedActivity>,
+}
+
+/// Specifies an activity completed by the vehicle.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletedActivity {
+    /// Job id. Use `break` for vehicle breaks.
+    pub job_id: String,
+
+    /// Activity type: pickup, delivery, replacement, service or break.
+    #[serde(rename(deserialize = "type", serialize = "type"))]
+    pub activity_type: String,
+}
+
+/// Deserializes execution state in json format from `BufReader`.
+pub fn deserialize_execution_state<R: Read>(reader: BufReader<R>) -> Result<ExecutionState, GenericError> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize execution state: '{err}'").into())
+}
+
+/// Applies execution state to the problem and its solution and returns a problem with the rest of
+/// the work and the solution which can be used as initial one to keep changes minimal:
+/// * completed tasks are removed from the jobs, fully completed jobs are removed from the plan
+/// * partially completed jobs are locked to the vehicle which served them using relations
+/// * precedences are kept only between remaining jobs
+/// * remaining jobs of alternative set with completed job are removed, other sets keep only remaining jobs
+/// * cancelled jobs are removed, new jobs are added to the plan
+/// * each vehicle on its tour starts its shift at current location and time without departure
+///   time flexibility. As shifts are defined on vehicle type level, such vehicle is moved to
+///   a separate vehicle type with `{type_id}_{vehicle_id}` id.
+pub fn create_replanning_problem(
+    problem: &Problem,
+    solution: &Solution,
+    state: &ExecutionState,
+) -> GenericResult<(Problem, Solution)> {
+    let time = state.time.clone();
+    parse_time_safe(&time)?;
+
+    let job_ids = problem.plan.jobs.iter().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    let cancelled = state.cancelled_jobs.iter().flatten().map(|job_id| job_id.as_str()).collect::<HashSet<_>>();
+    if let Some(job_id) = cancelled.iter().find(|job_id| !job_ids.contains(*job_id)) {
+        return Err(format!("unknown cancelled job id: '{job_id}'").into());
+    }
+
+    let completed = get_completed_tasks(state, &job_ids)?;
+    let (jobs, partial_jobs) = get_remaining_jobs(problem, &cancelled, &completed)?;
+    let (jobs, alternatives) = get_remaining_alternatives(problem, jobs, &completed);
+    let remaining_ids = jobs.iter().map(|job| job.id.clone()).collect::<HashSet<_>>();
+    let fleet = get_replanning_fleet(problem, state, &completed)?;
+    let type_ids = get_vehicle_type_ids(&fleet);
+
+    let relations = problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .filter_map(|relation| {
+            let jobs = relation
+                .jobs
+                .iter()
+                .filter(|job_id| !job_ids.contains(job_id.as_str()) || remaining_ids.contains(job_id.as_str()))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            jobs.iter().any(|job_id| remaining_ids.contains(job_id)).then(|| Relation { jobs, ..relation.clone() })
+        })
+        .chain(partial_jobs.into_iter().map(|((vehicle_id, shift_index), jobs)| Relation {
+            type_field: RelationType::Any,
+            jobs,
+            vehicle_id,
+            shift_index: Some(shift_index),
+        }))
+        .collect::<Vec<_>>();
+
+    let precedences = problem
+        .plan
+        .precedences
+        .iter()
+        .flatten()
+        .filter(|precedence| {
+            remaining_ids.contains(&precedence.predecessor) && remaining_ids.contains(&precedence.successor)
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let new_jobs = state.new_jobs.iter().flatten().cloned();
+    let problem = Problem {
+        plan: Plan {
+            jobs: jobs.into_iter().chain(new_jobs).collect(),
+            relations: if relations.is_empty() { None } else { Some(relations) },
+            precedences: if precedences.is_empty() { None } else { Some(precedences) },
+            alternatives: if alternatives.is_empty() { None } else { Some(alternatives) },
+            ..problem.plan.clone()
+        },
+        fleet,
+        ..problem.clone()
+    };
+
+    let solution = get_replanning_solution(solution, state, &remaining_ids, &completed, &type_ids)?;
+
+    Ok((problem, solution))
+}
+
+/// Removes remaining jobs of alternative sets which have at least one job (partially) completed and
+/// returns alternative sets with at least two remaining jobs.
+fn get_remaining_alternatives(
+    problem: &Problem,
+    jobs: Vec<Job>,
+    completed: &CompletedTasks,
+) -> (Vec<Job>, Vec<Alternative>) {
+    let completed_ids = completed.keys().map(|(job_id, _)| job_id.as_str()).collect::<HashSet<_>>();
+    let (served, alternatives): (Vec<_>, Vec<_>) = problem
+        .plan
+        .alternatives
+        .iter()
+        .flatten()
+        .partition(|alternative| alternative.jobs.iter().any(|job_id| completed_ids.contains(job_id.as_str())));
+
+    let skipped_ids = served
+        .iter()
+        .flat_map(|alternative| alternative.jobs.iter())
+        .filter(|job_id| !completed_ids.contains(job_id.as_str()))
+        .collect::<HashSet<_>>();
+    let jobs = jobs.into_iter().filter(|job| !skipped_ids.contains(&job.id)).collect::<Vec<_>>();
+
+    let remaining_ids = jobs.iter().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    let alternatives = alternatives
+        .into_iter()
+        .filter_map(|alternative| {
+            let jobs = alternative
+                .jobs
+                .iter()
+                .filter(|job_id| remaining_ids.contains(job_id.as_str()))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            (jobs.len() > 1).then(|| Alternative { jobs, ..alternative.clone() })
+        })
+        .collect();
+
+    (jobs, alternatives)
+}
+
+/// A vehicle id and shift index pair.
+type ShiftKey = (String, usize);
+
+/// Keeps amount of completed tasks per job and activity type together with vehicle shift which served them.
+type CompletedTasks = HashMap<(String, String), (usize, ShiftKey)>;
+
+/// Keeps ids of partially completed jobs per vehicle shift which served them.
+type PartialJobs = HashMap<ShiftKey, Vec<String>>;
+
+fn get_completed_tasks(state: &ExecutionState, job_ids: &HashSet<&str>) -> GenericResult<CompletedTasks> {
+    state.vehicles.iter().try_fold(CompletedTasks::default(), |acc, vehicle| {
+        let shift_key = (vehicle.vehicle_id.clone(), vehicle.shift_index.unwrap_or_default());
+
+        vehicle.completed.iter().try_fold(acc, |mut acc, activity| {
+            let is_job = matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service");
+            let is_break = activity.activity_type == "break";
+
+            if is_job && !job_ids.contains(activity.job_id.as_str()) {
+                return Err(format!("unknown completed job id: '{}'", activity.job_id).into());
+            }
+
+            if !is_job && !is_break {
+                return Err(format!("unsupported completed activity type: '{}'", activity.activity_type).into());
+            }
+
+            // NOTE break is a vehicle specific activity
+            let job_id = if is_break { get_break_key(&shift_key) } else { activity.job_id.clone() };
+            let entry = acc.entry((job_id, activity.activity_type.clone())).or_insert((0, shift_key.clone()));
+
+            if entry.1 != shift_key {
+                return Err(format!("job '{}' is completed by different vehicles", activity.job_id).into());
+            }
+            entry.0 += 1;
+
+            Ok(acc)
+        })
+    })
+}
+
+fn get_remaining_jobs(
+    problem: &Problem,
+    cancelled: &HashSet<&str>,
+    completed: &CompletedTasks,
+) -> GenericResult<(Vec<Job>, PartialJobs)> {
+    problem.plan.jobs.iter().filter(|job| !cancelled.contains(job.id.as_str())).try_fold(
+        (Vec::default(), PartialJobs::default()),
+        |(mut jobs, mut partial_jobs), job| {
+            let mut job = job.clone();
+            let mut shift_key = None;
+
+            for (activity_type, tasks) in [
+                ("pickup", &mut job.pickups),
+                ("delivery", &mut job.deliveries),
+                ("replacement", &mut job.replacements),
+                ("service", &mut job.services),
+            ] {
+                let Some((count, key)) = completed.get(&(job.id.clone(), activity_type.to_string())) else {
+                    continue;
+                };
+
+                let tasks = tasks.as_mut().filter(|tasks| tasks.len() >= *count).ok_or_else(|| {
+                    format!("job '{}' has less {activity_type} tasks than completed: {count}", job.id)
+                })?;
+                tasks.drain(..*count);
+
+                shift_key = Some(key.clone());
+            }
+
+            if job.all_tasks_iter().next().is_none() {
+                return Ok((jobs, partial_jobs));
+            }
+
+            if let Some(shift_key) = shift_key {
+                partial_jobs.entry(shift_key).or_default().push(job.id.clone());
+            }
+
+            job.pickups = job.pickups.filter(|tasks| !tasks.is_empty());
+            job.deliveries = job.deliveries.filter(|tasks| !tasks.is_empty());
+            job.replacements = job.replacements.filter(|tasks| !tasks.is_empty());
+            job.services = job.services.filter(|tasks| !tasks.is_empty());
+
+            jobs.push(job);
+
+            Ok((jobs, partial_jobs))
+        },
+    )
+}
+
+fn get_replanning_fleet(problem: &Problem, state: &ExecutionState, completed: &CompletedTasks) -> GenericResult<Fleet> {
+    let vehicle_states =
+        state.vehicles.iter().map(|vehicle| (vehicle.vehicle_id.as_str(), vehicle)).collect::<HashMap<_, _>>();
+    if vehicle_states.len() != state.vehicles.len() {
+        return Err("execution state has duplicated vehicle ids".into());
+    }
+
+    let jobs = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+
+    let vehicles = problem.fleet.vehicles.iter().try_fold(Vec::default(), |mut acc, vehicle_type| {
+        let (active, idle): (Vec<_>, Vec<_>) =
+            vehicle_type.vehicle_ids.iter().partition(|vehicle_id| vehicle_states.contains_key(vehicle_id.as_str()));
+
+        if !idle.is_empty() {
+            acc.push(VehicleType { vehicle_ids: idle.into_iter().cloned().collect(), ..vehicle_type.clone() });
+        }
+
+        for vehicle_id in active {
+            let vehicle_state = vehicle_states[vehicle_id.as_str()];
+            let shift_index = vehicle_state.shift_index.unwrap_or_default();
+            let shift_key = (vehicle_id.clone(), shift_index);
+
+            let mut vehicle_type = VehicleType {
+                type_id: format!("{}_{vehicle_id}", vehicle_type.type_id),
+                vehicle_ids: vec![vehicle_id.clone()],
+                ..vehicle_type.clone()
+            };
+
+            let shift = vehicle_type
+                .shifts
+                .get_mut(shift_index)
+                .ok_or_else(|| format!("vehicle '{vehicle_id}' has no shift with index {shift_index}"))?;
+
+            shift.start = ShiftStart {
+                earliest: state.time.clone(),
+                latest: Some(state.time.clone()),
+                location: vehicle_state.location.clone(),
+                satellite: None,
+            };
+
+            if let Some((count, _)) = completed.get(&(get_break_key(&shift_key), "break".to_string())) {
+                let breaks = shift
+                    .breaks
+                    .as_mut()
+                    .filter(|breaks| breaks.len() >= *count)
+                    .ok_or_else(|| format!("vehicle '{vehicle_id}' has less breaks than completed: {count}"))?;
+                breaks.drain(..*count);
+                shift.breaks = shift.breaks.take().filter(|breaks| !breaks.is_empty());
+            }
+
+            // NOTE goods of completed pickup only jobs stay on board till the end of the tour
+            completed
+                .iter()
+                .filter(|((_, activity_type), (_, key))| activity_type == "pickup" && *key == shift_key)
+                .filter_map(|((job_id, _), (count, _))| jobs.get(job_id.as_str()).map(|job| (job, count)))
+                .filter(|(job, _)| job.deliveries.is_none())
+                .flat_map(|(job, count)| job.pickups.iter().flatten().take(*count))
+                .filter_map(|task| task.demand.as_ref())
+                .for_each(|demand| {
+                    vehicle_type.capacity.iter_mut().zip(demand.iter()).for_each(|(capacity, demand)| {
+                        *capacity = (*capacity - *demand).max(0);
+                    })
+                });
+
+            acc.push(vehicle_type);
+        }
+
+        Ok::<_, GenericError>(acc)
+    })?;
+
+    let known_vehicles = vehicles.iter().flat_map(|vehicle| vehicle.vehicle_ids.iter()).collect::<HashSet<_>>();
+    if let Some(vehicle) = state.vehicles.iter().find(|vehicle| !known_vehicles.contains(&vehicle.vehicle_id)) {
+        return Err(format!("unknown vehicle id in execution state: '{}'", vehicle.vehicle_id).into());
+    }
+
+    Ok(Fleet { vehicles, ..problem.fleet.clone() })
+}
+
+fn get_replanning_solution(
+    solution: &Solution,
+    state: &ExecutionState,
+    remaining_ids: &HashSet<String>,
+    completed: &CompletedTasks,
+    type_ids: &HashMap<String, String>,
+) -> GenericResult<Solution> {
+    let vehicle_states = state
+        .vehicles
+        .iter()
+        .map(|vehicle| ((vehicle.vehicle_id.clone(), vehicle.shift_index.unwrap_or_default()), vehicle))
+        .collect::<HashMap<_, _>>();
+    let mut completed = completed.iter().map(|(key, (count, _))| (key.clone(), *count)).collect::<HashMap<_, _>>();
+
+    let tours = solution
+        .tours
+        .iter()
+        .filter_map(|tour| {
+            let shift_key = (tour.vehicle_id.clone(), tour.shift_index);
+
+            let mut stops = tour
+                .stops
+                .iter()
+                .cloned()
+                .filter_map(|mut stop| {
+                    stop.activities_mut().retain(|activity| {
+                        let job_id = if activity.activity_type == "break" {
+                            get_break_key(&shift_key)
+                        } else {
+                            activity.job_id.clone()
+                        };
+
+                        match completed.get_mut(&(job_id, activity.activity_type.clone())) {
+                            Some(count) if *count > 0 => {
+                                *count -= 1;
+                                false
+                            }
+                            _ => is_remaining_activity(activity, remaining_ids),
+                        }
+                    });
+
+                    (!stop.activities().is_empty()).then_some(stop)
+                })
+                .collect::<Vec<_>>();
+
+            let has_jobs = stops.iter().flat_map(|stop| stop.activities()).any(|a| remaining_ids.contains(&a.job_id));
+            if !has_jobs {
+                return None;
+            }
+
+            if let Some(vehicle_state) = vehicle_states.get(&shift_key) {
+                let departure = stops.first().filter(|stop| {
+                    stop.activities().first().is_some_and(|activity| activity.activity_type == "departure")
+                });
+                let load = departure.map(|stop| stop.load().clone()).unwrap_or_default();
+                let compartments = departure.and_then(|stop| stop.compartments().cloned());
+                let departure_activity = departure
+                    .and_then(|stop| stop.activities().first().cloned())
+                    .map(|activity| Activity { location: None, time: None, ..activity });
+
+                if let Some(activity) = departure_activity {
+                    stops[0] = Stop::Point(PointStop {
+                        location: vehicle_state.location.clone(),
+                        time: Schedule { arrival: state.time.clone(), departure: state.time.clone() },
+                        distance: 0,
+                        load,
+                        compartments,
+                        state_of_charge: None,
+                        overtime: None,
+                        parking: None,
+                        activities: vec![activity],
+                    });
+                }
+            }
+
+            let type_id = type_ids.get(&tour.vehicle_id).cloned().unwrap_or_else(|| tour.type_id.clone());
+
+            Some(Tour { type_id, stops, ..tour.clone() })
+        })
+        .collect::<Vec<_>>();
+
+    let unassigned = solution
+        .unassigned
+        .iter()
+        .flatten()
+        .filter(|job| remaining_ids.contains(&job.job_id))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    Ok(Solution {
+        tours,
+        unassigned: if unassigned.is_empty() { None } else { Some(unassigned) },
+        violations: None,
+        ..solution.clone()
+    })
+}
+
+fn is_remaining_activity(activity: &Activity, remaining_ids: &HashSet<String>) -> bool {
+    match activity.activity_type.as_str() {
+        "pickup" | "delivery" | "replacement" | "service" => remaining_ids.contains(&activity.job_id),
+        _ => true,
+    }
+}
+
+fn get_vehicle_type_ids(fleet: &Fleet) -> HashMap<String, String> {
+    fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter().map(|vehicle_id| (vehicle_id.clone(), vehicle.type_id.clone())))
+        .collect()
+}
+
+fn get_break_key((vehicle_id, shift_index): &ShiftKey) -> String {
+    format!("{vehicle_id}_break_{shift_index}")
+}
@@ -37,7 +37,7 @@ pub(crate) fn try_match_point_job(
     };
 
     match activity.activity_type.as_str() {
-        "departure" | "arrival" => Ok(None),
+        "departure" | "arrival" | "rest" => Ok(None),
         "pickup" | "delivery" | "replacement" | "service" => {
             let job =
                 job_index.get(&activity.job_id).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
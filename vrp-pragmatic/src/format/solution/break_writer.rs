@@ -38,7 +38,11 @@ pub(super) fn insert_reserved_times_as_breaks(
                             let break_tw = TimeWindow::new(travel_tw.start - reserved_tw.duration(), travel_tw.start);
                             Some(BreakInsertion::TransitBreakMoved { leg_idx, break_tw })
                         } else {
-                            Some(BreakInsertion::TransitBreakUsed { leg_idx, load: prev.load().clone() })
+                            Some(BreakInsertion::TransitBreakUsed {
+                                leg_idx,
+                                load: prev.load().clone(),
+                                compartments: prev.compartments().cloned(),
+                            })
                         };
                     }
                 }
@@ -46,7 +50,7 @@ pub(super) fn insert_reserved_times_as_breaks(
                 None
             });
 
-            if let Some(BreakInsertion::TransitBreakUsed { leg_idx, load }) = break_info.clone() {
+            if let Some(BreakInsertion::TransitBreakUsed { leg_idx, load, compartments }) = break_info.clone() {
                 tour.stops.insert(
                     leg_idx + 1,
                     Stop::Transit(TransitStop {
@@ -55,13 +59,15 @@ pub(super) fn insert_reserved_times_as_breaks(
                             departure: format_time(reserved_tw.end),
                         },
                         load,
+                        compartments,
                         activities: vec![],
                     }),
                 )
             }
 
             let break_time = reserved_time.duration as i64;
-            let break_cost = break_time as Float * route.actor.vehicle.costs.per_service_time;
+            let break_cost = break_time as Float
+                * (route.actor.driver.costs.per_service_time + route.actor.vehicle.costs.per_service_time);
 
             for (stop_idx, stop) in tour.stops.iter_mut().enumerate() {
                 let stop_tw =
@@ -133,6 +139,9 @@ fn insert_break(
             time: Some(Interval { start: format_time(activity_time.start), end: format_time(activity_time.end) }),
             job_tag: None,
             commute: None,
+            compartment: None,
+            lateness: None,
+            quantity: None,
         },
     );
 
@@ -159,6 +168,6 @@ fn insert_break(
 
 #[derive(Clone)]
 enum BreakInsertion {
-    TransitBreakUsed { leg_idx: usize, load: Vec<i32> },
+    TransitBreakUsed { leg_idx: usize, load: Vec<i32>, compartments: Option<Vec<CompartmentLoad>> },
     TransitBreakMoved { leg_idx: usize, break_tw: TimeWindow },
 }
@@ -0,0 +1,281 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/diagnosis_test.rs"]
+mod diagnosis_test;
+
+use super::solution_writer::{create_unassigned_reasons, get_capacity, get_vehicle_capacity};
+use super::*;
+use crate::format::ShiftIndexDimension;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::UnassignmentInfo;
+use vrp_core::models::common::{Demand, Load, MultiDimLoad, TimeWindow};
+use vrp_core::models::problem::{Actor, JobIdDimension, TravelTime};
+use vrp_core::models::solution::{Route, Tour};
+use vrp_core::prelude::Float;
+
+/// Checks that all mandatory jobs are assigned. Otherwise, returns an error with infeasibility
+/// diagnosis of each unassigned mandatory job serialized in error details.
+pub fn check_mandatory_jobs(problem: &DomainProblem, solution: &DomainSolution) -> Result<(), FormatError> {
+    let diagnosis = diagnose_mandatory_jobs(problem, solution);
+
+    if diagnosis.is_empty() {
+        return Ok(());
+    }
+
+    let job_ids = diagnosis.iter().map(|diagnosis| diagnosis.job_id.as_str()).collect::<Vec<_>>().join(", ");
+
+    let details = serde_json::to_string_pretty(&diagnosis).map_err(|err| {
+        FormatError::new(
+            "E0005".to_string(),
+            "cannot assign mandatory jobs".to_string(),
+            format!("cannot serialize diagnosis of mandatory jobs '{job_ids}': {err}"),
+        )
+    })?;
+
+    Err(FormatError::new_with_details(
+        "E0005".to_string(),
+        "cannot assign mandatory jobs".to_string(),
+        format!(
+            "apply one of suggested relaxations for each mandatory job (delta is provided only for time window \
+             and capacity constraints), ids: '{job_ids}'"
+        ),
+        details,
+    ))
+}
+
+/// Creates infeasibility diagnosis for each mandatory job which is not assigned.
+pub fn diagnose_mandatory_jobs(problem: &DomainProblem, solution: &DomainSolution) -> Vec<MandatoryJobDiagnosis> {
+    let mut diagnosis = solution
+        .unassigned
+        .iter()
+        .filter(|(job, _)| job.dimens().get_job_mandatory().copied().unwrap_or(false))
+        .map(|(job, info)| {
+            let job_id = job.dimens().get_job_id().expect("job id expected").clone();
+            let reasons = create_unassigned_reasons(info);
+
+            let mut relaxations = reasons
+                .iter()
+                .map(|reason| create_relaxation(problem, solution, job, info, reason))
+                .collect::<Vec<_>>();
+            // NOTE each relaxation targets a single constraint: the one which unblocks more vehicles goes first
+            let get_vehicles = |relaxation: &Relaxation| relaxation.details.as_ref().map_or(usize::MAX, |d| d.len());
+            relaxations.sort_by(|a, b| get_vehicles(b).cmp(&get_vehicles(a)).then_with(|| a.code.cmp(&b.code)));
+
+            MandatoryJobDiagnosis { job_id, reasons, relaxations }
+        })
+        .collect::<Vec<_>>();
+
+    diagnosis.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    diagnosis
+}
+
+fn create_relaxation(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    job: &CoreJob,
+    info: &UnassignmentInfo,
+    reason: &UnassignedJobReason,
+) -> Relaxation {
+    let code = map_reason_code(reason.code.as_str());
+    let (type_field, description) = match code {
+        TIME_CONSTRAINT_CODE => ("extendShift", "extend vehicle shift time or job time windows"),
+        CAPACITY_CONSTRAINT_CODE => ("addCapacity", "increase vehicle capacity"),
+        DISTANCE_LIMIT_CONSTRAINT_CODE => ("increaseMaxDistance", "increase vehicle max distance limit"),
+        DURATION_LIMIT_CONSTRAINT_CODE => ("increaseMaxDuration", "increase vehicle max duration limit"),
+        SKILL_CONSTRAINT_CODE => ("addSkills", "add required job skills to vehicle"),
+        AREA_CONSTRAINT_CODE => ("extendArea", "allow vehicle to serve job location area"),
+        TOUR_SIZE_CONSTRAINT_CODE => ("increaseTourSize", "increase vehicle tour size limit"),
+        REACHABLE_CONSTRAINT_CODE => ("addRoute", "make job location reachable in routing matrix"),
+        HOURS_OF_SERVICE_CONSTRAINT_CODE => ("relaxHoursOfService", "relax driver hours-of-service rules"),
+        _ => ("relaxConstraint", "review job and vehicle properties related to the constraint"),
+    };
+
+    let details = reason.details.as_ref().map(|details| {
+        details
+            .iter()
+            .map(|detail| {
+                let delta = find_actor(info, detail).and_then(|actor| {
+                    let empty_route;
+                    let route = match solution.routes.iter().find(|route| route.actor == *actor) {
+                        Some(route) => route,
+                        None => {
+                            empty_route = create_empty_route(problem, actor);
+                            &empty_route
+                        }
+                    };
+
+                    match code {
+                        TIME_CONSTRAINT_CODE => get_time_delta(problem, route, job).map(|delta| vec![delta]),
+                        CAPACITY_CONSTRAINT_CODE => get_capacity_delta(route, job),
+                        _ => None,
+                    }
+                });
+
+                RelaxationDetail { vehicle_id: detail.vehicle_id.clone(), shift_index: detail.shift_index, delta }
+            })
+            .collect()
+    });
+
+    Relaxation {
+        type_field: type_field.to_string(),
+        code: reason.code.clone(),
+        description: description.to_string(),
+        details,
+    }
+}
+
+fn find_actor<'a>(info: &'a UnassignmentInfo, detail: &UnassignedJobDetail) -> Option<&'a Arc<Actor>> {
+    match info {
+        UnassignmentInfo::Detailed(details) => details.iter().map(|(actor, _)| actor).find(|actor| {
+            let dimens = &actor.vehicle.dimens;
+            dimens.get_vehicle_id() == Some(&detail.vehicle_id) && dimens.get_shift_index() == Some(&detail.shift_index)
+        }),
+        _ => None,
+    }
+}
+
+fn create_empty_route(problem: &DomainProblem, actor: &Arc<Actor>) -> Route {
+    let mut route = Route { actor: actor.clone(), tour: Tour::new(actor) };
+
+    // NOTE a new tour does not have the end activity scheduled
+    let start = route.tour.start().map(|start| (start.place.location, start.schedule.departure));
+    let end = route
+        .tour
+        .end_idx()
+        .filter(|idx| *idx > 0)
+        .and_then(|idx| route.tour.get(idx).map(|end| (idx, end.place.location)));
+
+    if let (Some((location, departure)), Some((end_idx, end_location))) = (start, end) {
+        let arrival =
+            departure + problem.transport.duration(&route, location, end_location, TravelTime::Departure(departure));
+        if let Some(end) = route.tour.get_mut(end_idx) {
+            end.schedule.arrival = arrival;
+            end.schedule.departure = arrival;
+        }
+    }
+
+    route
+}
+
+/// Returns the smallest time overrun (in seconds) of job or route activity time windows when the job is inserted
+/// into the route. The job activities are inserted one after another at each route leg.
+fn get_time_delta(problem: &DomainProblem, route: &Route, job: &CoreJob) -> Option<i64> {
+    let singles = get_singles(job);
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let shift_start = route.tour.start()?.schedule.departure;
+
+    route
+        .tour
+        .legs()
+        .filter_map(|(items, idx)| {
+            let prev = items.first()?;
+            let (mut location, mut time, mut overrun) = (prev.place.location, prev.schedule.departure, Float::MIN);
+
+            for single in singles.iter() {
+                // NOTE pick the place and time window which is the least late
+                let (place_location, place_overrun, departure) = single
+                    .places
+                    .iter()
+                    .flat_map(|place| {
+                        let place_location = place.location.unwrap_or(location);
+                        let arrival = time
+                            + problem.transport.duration(route, location, place_location, TravelTime::Departure(time));
+                        let windows = if place.times.is_empty() {
+                            vec![TimeWindow::max()]
+                        } else {
+                            place.times.iter().map(|span| span.to_time_window(shift_start)).collect()
+                        };
+
+                        windows
+                            .into_iter()
+                            .map(move |tw| (place_location, arrival - tw.end, arrival.max(tw.start) + place.duration))
+                    })
+                    .min_by(|(_, a, a_time), (_, b, b_time)| {
+                        a.max(0.).total_cmp(&b.max(0.)).then(a_time.total_cmp(b_time))
+                    })?;
+
+                location = place_location;
+                overrun = overrun.max(place_overrun);
+                time = departure;
+            }
+
+            // NOTE the delay is propagated to the next activities until it is absorbed by waiting time
+            if let Some(next) = activities.get(idx + 1) {
+                let arrival = time
+                    + problem.transport.duration(route, location, next.place.location, TravelTime::Departure(time));
+                let mut delay = arrival - next.schedule.arrival;
+
+                for activity in activities.iter().skip(idx + 1) {
+                    if delay <= 0. {
+                        break;
+                    }
+
+                    overrun = overrun.max(activity.schedule.arrival + delay - activity.place.time.end);
+                    delay -=
+                        (activity.schedule.departure - activity.schedule.arrival - activity.place.duration).max(0.);
+                }
+            }
+
+            Some(overrun.max(0.))
+        })
+        .min_by(|a, b| a.total_cmp(b))
+        .map(|overrun| overrun.ceil() as i64)
+}
+
+/// Returns the smallest capacity excess (per load dimension) when the job is inserted into the route.
+/// The job activities are inserted one after another at each route leg.
+fn get_capacity_delta(route: &Route, job: &CoreJob) -> Option<Vec<i64>> {
+    let capacity = get_vehicle_capacity(&route.actor.vehicle.dimens)?;
+    let get_change = |load: MultiDimLoad, demand: &Demand<MultiDimLoad>| {
+        load - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
+    };
+    let get_max = |loads: &[MultiDimLoad]| loads.iter().fold(MultiDimLoad::default(), |acc, load| acc.max_load(*load));
+
+    let singles = get_singles(job);
+    let demands = singles.iter().filter_map(|single| get_capacity(&single.dimens)).collect::<Vec<_>>();
+    let route_demands = route
+        .tour
+        .all_activities()
+        .map(|activity| activity.job.as_ref().and_then(|single| get_capacity(&single.dimens)))
+        .collect::<Vec<_>>();
+
+    // NOTE static delivery is loaded at the start, other demand changes the load at the activity
+    let start_load =
+        route_demands.iter().flatten().fold(MultiDimLoad::default(), |acc, demand| acc + demand.delivery.0);
+    let loads = route_demands
+        .iter()
+        .scan(start_load, |load, demand| {
+            *load = demand.as_ref().map_or(*load, |demand| get_change(*load, demand));
+            Some(*load)
+        })
+        .collect::<Vec<_>>();
+
+    let delivery = demands.iter().fold(MultiDimLoad::default(), |acc, demand| acc + demand.delivery.0);
+    let pickup = demands.iter().fold(MultiDimLoad::default(), |acc, demand| acc + demand.pickup.0);
+
+    route
+        .tour
+        .legs()
+        .map(|(_, idx)| {
+            let before = get_max(&loads[..=idx]) + delivery;
+            let after = get_max(&loads[idx + 1..]) + pickup;
+            let load = loads[idx] + delivery;
+            let (_, inside) = demands.iter().fold((load, load), |(load, peak), demand| {
+                let load = get_change(load, demand);
+                (load, peak.max_load(load))
+            });
+            let required = before.max_load(after).max_load(inside);
+
+            (0..capacity.size.max(required.size))
+                .map(|dim| (required.load[dim] - capacity.load[dim]).max(0) as i64)
+                .collect::<Vec<_>>()
+        })
+        .min_by_key(|delta| delta.iter().sum::<i64>())
+}
+
+fn get_singles(job: &CoreJob) -> Vec<Arc<Single>> {
+    match job {
+        CoreJob::Single(single) => vec![single.clone()],
+        CoreJob::Multi(multi) => multi.jobs.clone(),
+    }
+}
@@ -1,4 +1,4 @@
-use crate::format::solution::{Statistic, Timing};
+use crate::format::solution::{Emissions, Statistic, Timing};
 use std::ops::Add;
 
 impl Add for Statistic {
@@ -16,6 +16,11 @@ impl Add for Statistic {
                 break_time: self.times.break_time + rhs.times.break_time,
                 commuting: self.times.commuting + rhs.times.commuting,
                 parking: self.times.parking + rhs.times.parking,
+                overtime: self.times.overtime + rhs.times.overtime,
+            },
+            emissions: match (self.emissions, rhs.emissions) {
+                (Some(lhs), Some(rhs)) => Some(Emissions { fuel: lhs.fuel + rhs.fuel, co2: lhs.co2 + rhs.co2 }),
+                (lhs, rhs) => lhs.or(rhs),
             },
         }
     }
@@ -14,12 +14,12 @@ use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Job, JobIdDimension, VehicleIdDimension};
+use vrp_core::models::problem::{Actor, DriverIdDimension, Job, JobIdDimension, VehicleIdDimension};
 use vrp_core::models::solution::Tour as CoreTour;
 use vrp_core::models::solution::{Activity, Registry, Route};
 use vrp_core::prelude::*;
 
-type ActorKey = (String, String, usize);
+type ActorKey = (String, String, usize, Option<String>);
 
 /// Reads initial solution from buffer.
 /// NOTE: Solution feasibility is not checked.
@@ -38,7 +38,7 @@ pub fn read_init_solution<R: Read>(
 
     let routes =
         solution.tours.iter().try_fold::<_, _, Result<_, GenericError>>(Vec::<_>::default(), |mut routes, tour| {
-            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
+            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index, tour.driver_id.clone());
             let actor =
                 actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"))?.clone();
             registry.use_actor(&actor);
@@ -156,7 +156,9 @@ fn get_actor_key(actor: &Actor) -> ActorKey {
     let type_id = dimens.get_vehicle_type().cloned().expect("cannot get type id!");
     let shift_index = dimens.get_shift_index().copied().expect("cannot get shift index!");
 
-    (vehicle_id, type_id, shift_index)
+    let driver_id = actor.driver.dimens.get_driver_id().cloned();
+
+    (vehicle_id, type_id, shift_index, driver_id)
 }
 
 fn create_core_route(actor: Arc<Actor>, format_tour: &FormatTour) -> Result<Route, GenericError> {
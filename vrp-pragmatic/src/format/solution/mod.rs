@@ -5,6 +5,9 @@ pub(crate) mod activity_matcher;
 mod break_writer;
 use self::break_writer::insert_reserved_times_as_breaks;
 
+mod diagnosis;
+pub use self::diagnosis::{check_mandatory_jobs, diagnose_mandatory_jobs};
+
 mod extensions;
 
 mod geo_serializer;
@@ -97,6 +100,28 @@ fn map_code_reason(code: ViolationCode) -> (&'static str, &'static str) {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
         RECHARGE_CONSTRAINT_CODE => ("RECHARGE_CONSTRAINT_CODE", "cannot be assigned due to recharge constraint"),
+        PERIODIC_CONSTRAINT_CODE => ("PERIODIC_CONSTRAINT", "cannot be assigned due to periodic visits constraint"),
+        COMPARTMENT_CONSTRAINT_CODE => {
+            ("COMPARTMENT_CONSTRAINT", "cannot be assigned due to vehicle compartments constraint")
+        }
+        SATELLITE_CONSTRAINT_CODE => {
+            ("SATELLITE_CONSTRAINT", "cannot be assigned due to satellite inventory or timing constraint")
+        }
+        HOURS_OF_SERVICE_CONSTRAINT_CODE => {
+            ("HOURS_OF_SERVICE_CONSTRAINT", "cannot be assigned due to driver hours-of-service rules")
+        }
+        RECHARGE_RESOURCE_CONSTRAINT_CODE => {
+            ("RECHARGE_RESOURCE_CONSTRAINT", "cannot be assigned due to recharge station chargers constraint")
+        }
+        RIDE_TIME_CONSTRAINT_CODE => ("RIDE_TIME_CONSTRAINT", "cannot be assigned due to max ride time constraint"),
+        LOADING_POLICY_CONSTRAINT_CODE => {
+            ("LOADING_POLICY_CONSTRAINT", "cannot be assigned due to vehicle loading policy constraint")
+        }
+        PACKING_CONSTRAINT_CODE => ("PACKING_CONSTRAINT", "cannot be assigned due to vehicle cargo space constraint"),
+        SYNC_CONSTRAINT_CODE => ("SYNC_CONSTRAINT", "cannot be assigned due to synchronization constraint"),
+        PRECEDENCE_CONSTRAINT_CODE => ("PRECEDENCE_CONSTRAINT", "cannot be assigned due to precedence constraint"),
+        ALTERNATIVE_CONSTRAINT_CODE => ("ALTERNATIVE_CONSTRAINT", "cannot be assigned due to alternative constraint"),
+        SITE_CONSTRAINT_CODE => ("SITE_CONSTRAINT", "cannot be assigned due to site constraint"),
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -118,6 +143,18 @@ fn map_reason_code(reason: &str) -> ViolationCode {
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
         "RECHARGE_CONSTRAINT_CODE" => RECHARGE_CONSTRAINT_CODE,
+        "PERIODIC_CONSTRAINT" => PERIODIC_CONSTRAINT_CODE,
+        "COMPARTMENT_CONSTRAINT" => COMPARTMENT_CONSTRAINT_CODE,
+        "SATELLITE_CONSTRAINT" => SATELLITE_CONSTRAINT_CODE,
+        "HOURS_OF_SERVICE_CONSTRAINT" => HOURS_OF_SERVICE_CONSTRAINT_CODE,
+        "RECHARGE_RESOURCE_CONSTRAINT" => RECHARGE_RESOURCE_CONSTRAINT_CODE,
+        "RIDE_TIME_CONSTRAINT" => RIDE_TIME_CONSTRAINT_CODE,
+        "LOADING_POLICY_CONSTRAINT" => LOADING_POLICY_CONSTRAINT_CODE,
+        "PACKING_CONSTRAINT" => PACKING_CONSTRAINT_CODE,
+        "SYNC_CONSTRAINT" => SYNC_CONSTRAINT_CODE,
+        "PRECEDENCE_CONSTRAINT" => PRECEDENCE_CONSTRAINT_CODE,
+        "ALTERNATIVE_CONSTRAINT" => ALTERNATIVE_CONSTRAINT_CODE,
+        "SITE_CONSTRAINT" => SITE_CONSTRAINT_CODE,
         _ => ViolationCode::unknown(),
     }
 }
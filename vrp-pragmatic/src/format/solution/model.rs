@@ -26,6 +26,9 @@ pub struct Timing {
     /// Parking time.
     #[serde(default = "i64::default")]
     pub parking: i64,
+    /// Overtime: working time after regular end of the vehicle shift.
+    #[serde(default = "i64::default")]
+    pub overtime: i64,
 }
 
 /// Represents statistic.
@@ -39,6 +42,18 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Fuel consumption and emissions statistic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emissions: Option<Emissions>,
+}
+
+/// Represents fuel consumption and emissions statistic.
+#[derive(Clone, Deserialize, Default, Serialize, PartialEq, Debug)]
+pub struct Emissions {
+    /// Consumed fuel.
+    pub fuel: Float,
+    /// Emitted CO2.
+    pub co2: Float,
 }
 
 /// Represents a schedule.
@@ -102,6 +117,24 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// Vehicle compartment id used to load job's demand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartment: Option<String>,
+    /// Lateness: how late activity has started after job's soft time window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<i64>,
+    /// Quantity served by the activity when job's demand is split between multiple vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<Vec<i32>>,
+}
+
+/// Specifies load of vehicle compartment.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct CompartmentLoad {
+    /// Compartment id.
+    pub id: String,
+    /// Compartment load after departure from the stop.
+    pub load: Vec<i32>,
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
@@ -147,6 +180,14 @@ impl Stop {
         }
     }
 
+    /// Returns stop's compartment loads if vehicle has compartments.
+    pub fn compartments(&self) -> Option<&Vec<CompartmentLoad>> {
+        match self {
+            Self::Transit(transit) => transit.compartments.as_ref(),
+            Self::Point(point) => point.compartments.as_ref(),
+        }
+    }
+
     /// Returns stop activities.
     pub fn activities(&self) -> &Vec<Activity> {
         match self {
@@ -195,6 +236,9 @@ pub struct TransitStop {
     pub time: Schedule,
     /// Vehicle load after departure from this stop.
     pub load: Vec<i32>,
+    /// Vehicle compartments load after departure from this stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<CompartmentLoad>>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
 }
@@ -210,6 +254,16 @@ pub struct PointStop {
     pub distance: i64,
     /// Vehicle load after departure from this stop.
     pub load: Vec<i32>,
+    /// Vehicle compartments load after departure from this stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<CompartmentLoad>>,
+    /// Vehicle battery state of charge after departure from this stop.
+    #[serde(rename = "stateOfCharge", skip_serializing_if = "Option::is_none")]
+    pub state_of_charge: Option<Float>,
+    /// Overtime spent since departure from start till departure from this stop. Specified only when
+    /// vehicle shift has overtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime: Option<i64>,
     /// Parking time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parking: Option<Interval>,
@@ -228,10 +282,48 @@ pub struct Tour {
     /// Shift index.
     #[serde(default)]
     pub shift_index: usize,
+    /// Driver id. Specified only when drivers are defined in the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_id: Option<String>,
     /// List of stops.
     pub stops: Vec<Stop>,
     /// Tour statistic.
     pub statistic: Statistic,
+    /// Driver hours-of-service details. Specified only when vehicle has hours-of-service rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours_of_service: Option<TourHoursOfService>,
+    /// Placements of job items in vehicle's cargo space. Specified only when vehicle has cargo space
+    /// and tour has job items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loading_plan: Option<Vec<ItemPlacement>>,
+}
+
+/// Specifies placement of job's item in vehicle's cargo space.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemPlacement {
+    /// A job id.
+    pub job_id: String,
+    /// An item id.
+    pub item_id: String,
+    /// A position of the item's corner closest to the origin of cargo space: length, width and height offsets.
+    pub position: Vec<Float>,
+    /// An item size after rotation: length, width and height.
+    pub size: Vec<Float>,
+}
+
+/// Driver hours-of-service details of the tour.
+#[derive(Clone, Default, Deserialize, Serialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourHoursOfService {
+    /// Total driving time.
+    pub driving: i64,
+    /// The longest continuous driving time between breaks.
+    pub continuous_driving: i64,
+    /// Amount of driving breaks and daily rests inserted into the tour.
+    pub breaks: usize,
+    /// Amount of daily rests inserted into the tour.
+    pub daily_rests: usize,
 }
 
 /// Unassigned job reason.
@@ -266,6 +358,47 @@ pub struct UnassignedJob {
     pub reasons: Vec<UnassignedJobReason>,
 }
 
+/// Infeasibility diagnosis of mandatory job which cannot be assigned.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MandatoryJobDiagnosis {
+    /// Job id.
+    pub job_id: String,
+    /// Constraints which block the job with affected vehicles.
+    pub reasons: Vec<UnassignedJobReason>,
+    /// Relaxations which might make the job assignable, the most promising one goes first.
+    pub relaxations: Vec<Relaxation>,
+}
+
+/// A suggested relaxation of a single constraint.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+pub struct Relaxation {
+    /// A relaxation type, e.g. `extendShift` or `addCapacity`.
+    #[serde(rename(deserialize = "type", serialize = "type"))]
+    pub type_field: String,
+    /// A code of the relaxed constraint.
+    pub code: String,
+    /// Description.
+    pub description: String,
+    /// Vehicle shifts to relax, if relaxation is vehicle specific.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<Vec<RelaxationDetail>>,
+}
+
+/// A relaxation of a single vehicle shift.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RelaxationDetail {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// A minimal amount by which the constraint has to be relaxed for the vehicle shift: time in seconds
+    /// for time windows or load per dimension for capacity. Not set for other constraints.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta: Option<Vec<i64>>,
+}
+
 /// Specifies a type of violation.
 #[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
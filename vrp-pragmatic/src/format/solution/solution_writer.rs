@@ -7,10 +7,15 @@ use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use vrp_core::construction::enablers::{ReservedTimesIndex, get_route_intervals};
-use vrp_core::construction::features::JobDemandDimension;
+use vrp_core::construction::features::{
+    JobAlternativeDimension, JobDemandDimension, JobPackingItemsDimension, JobSoftTimeWindowsDimension,
+    JobSplitDimension, VehicleCapacityDimension, VehicleCompartmentsDimension, VehicleFuelConsumptionDimension,
+    get_compartment_assignment, get_hours_of_service_info, get_loading_plan, get_route_fuel_consumption,
+    get_route_overtime, get_route_overtime_cost, get_route_states_of_charge, get_service_start,
+};
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{JobIdDimension, Multi, TravelTime, VehicleIdDimension};
+use vrp_core::models::problem::{DriverIdDimension, JobIdDimension, Multi, TravelTime, VehicleIdDimension};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::prelude::Float;
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
@@ -80,11 +85,20 @@ fn create_tour(
         vehicle_id: vehicle.dimens.get_vehicle_id().unwrap().clone(),
         type_id: vehicle.dimens.get_vehicle_type().unwrap().clone(),
         shift_index: vehicle.dimens.get_shift_index().copied().unwrap(),
+        driver_id: actor.driver.dimens.get_driver_id().cloned(),
         stops: vec![],
         statistic: Statistic::default(),
+        hours_of_service: None,
+        loading_plan: None,
     };
 
+    let hours_of_service = get_hours_of_service_info(route, transport, problem.activity.as_ref());
+    let driving_breaks = hours_of_service.as_ref().map(|info| info.breaks.clone()).unwrap_or_default();
+
     let intervals = get_route_intervals(route, |a| get_activity_type(a).is_some_and(|t| t == "reload"));
+    let compartments = CompartmentLoads::new(route);
+    let mut loads = vec![MultiDimLoad::default(); route.tour.total()];
+    let mut stop_indices = vec![];
 
     let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
         let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
@@ -101,6 +115,8 @@ fn create_tour(
 
         let (start_idx, start) = if start_idx == 0 {
             let start = route.tour.start().unwrap();
+            loads[0] = start_delivery;
+            stop_indices.push(0);
             let is_same_location =
                 route.tour.get(1).is_some_and(|activity| start.place.location == activity.place.location);
 
@@ -108,6 +124,9 @@ fn create_tour(
                 location: coord_index.get_by_idx(start.place.location).unwrap(),
                 time: format_schedule(&start.schedule),
                 load: start_delivery.as_vec(),
+                compartments: compartments.as_ref().map(|compartments| compartments.get_loads(0)),
+                state_of_charge: None,
+                overtime: None,
                 distance: 0,
                 activities: vec![ApiActivity {
                     job_id: "departure".to_string(),
@@ -123,6 +142,9 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    compartment: None,
+                    lateness: None,
+                    quantity: None,
                 }],
                 parking: None,
             }));
@@ -131,9 +153,10 @@ fn create_tour(
             (start_idx, route.tour.get(start_idx - 1).unwrap())
         };
 
-        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
+        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().enumerate().fold(
             Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
-            |leg, act| {
+            |leg, (idx, act)| {
+                let activity_idx = start_idx + idx;
                 let activity_type = get_activity_type(act).cloned();
                 let (prev_location, prev_departure) = leg.last_detail.unwrap();
                 let prev_load = if activity_type.is_some() {
@@ -171,7 +194,7 @@ fn create_tour(
                     (duration, transport_cost)
                 } else {
                     // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
-                    (0., commuting * vehicle.costs.per_service_time)
+                    (0., commuting * (actor.driver.costs.per_service_time + vehicle.costs.per_service_time))
                 };
 
                 // NOTE two clusters at the same stop location
@@ -182,7 +205,7 @@ fn create_tour(
                     };
 
                 let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
-                let service_start = activity_arrival.max(act.place.time.start);
+                let service_start = get_service_start(route, act, activity_arrival);
                 let waiting = service_start - activity_arrival;
                 let serving = act.place.duration - parking;
                 let service_end = service_start + serving;
@@ -190,7 +213,9 @@ fn create_tour(
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
+                let total_cost = serving_cost
+                    + transport_cost
+                    + waiting * (actor.driver.costs.per_waiting_time + vehicle.costs.per_waiting_time);
 
                 let location_distance =
                     transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
@@ -208,6 +233,9 @@ fn create_tour(
                         location: coord_index.get_by_idx(act.place.location).unwrap(),
                         time: format_schedule(&act.schedule),
                         load: prev_load.as_vec(),
+                        compartments: None,
+                        state_of_charge: None,
+                        overtime: None,
                         distance,
                         parking: if parking > 0. {
                             Some(Interval {
@@ -222,6 +250,13 @@ fn create_tour(
                 }
 
                 let load = calculate_load(prev_load, act);
+                loads[activity_idx] = load;
+
+                if is_new_stop {
+                    stop_indices.push(activity_idx);
+                } else if let Some(last_idx) = stop_indices.last_mut() {
+                    *last_idx = activity_idx;
+                }
 
                 let last = tour.stops.len() - 1;
                 let last = match tour.stops.get_mut(last).unwrap() {
@@ -231,21 +266,55 @@ fn create_tour(
 
                 last.time.departure = format_time(act.schedule.departure);
                 last.load = load.as_vec();
+                last.compartments = compartments.as_ref().map(|compartments| compartments.get_loads(activity_idx));
                 last.activities.push(ApiActivity {
                     job_id,
                     activity_type: activity_type.clone(),
                     location: Some(coord_index.get_by_idx(act.place.location).unwrap()),
-                    time: Some(Interval {
-                        start: format_time(activity_arrival.max(act.place.time.start)),
-                        end: format_time(activity_departure),
-                    }),
+                    time: Some(Interval { start: format_time(service_start), end: format_time(activity_departure) }),
                     job_tag,
                     commute: act
                         .commute
                         .as_ref()
                         .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    compartment: compartments.as_ref().and_then(|compartments| compartments.get_id(activity_idx)),
+                    lateness: act
+                        .job
+                        .as_ref()
+                        .and_then(|single| single.dimens.get_job_soft_time_windows())
+                        .map(|soft| soft.get_deviation(act.place.idx, service_start).1 as i64)
+                        .filter(|&lateness| lateness > 0),
+                    quantity: act.job.as_ref().filter(|single| single.dimens.get_job_split().is_some()).and_then(
+                        |single| {
+                            get_capacity(&single.dimens).map(|demand| (demand.delivery.0 + demand.pickup.0).as_vec())
+                        },
+                    ),
                 });
 
+                // NOTE driving break or daily rest required by hours-of-service rules is taken right after the activity
+                let rest = driving_breaks
+                    .iter()
+                    .find(|(break_idx, _)| *break_idx == activity_idx)
+                    .map_or(0., |(_, duration)| *duration);
+
+                if rest > 0. {
+                    last.activities.push(ApiActivity {
+                        job_id: "rest".to_string(),
+                        activity_type: "rest".to_string(),
+                        location: Some(coord_index.get_by_idx(act.place.location).unwrap()),
+                        time: Some(Interval {
+                            start: format_time(activity_departure),
+                            end: format_time(activity_departure + rest),
+                        }),
+                        job_tag: None,
+                        commute: None,
+                        compartment: None,
+                        lateness: None,
+                        quantity: None,
+                    });
+                }
+                let rest_cost = rest * (actor.driver.costs.per_service_time + vehicle.costs.per_service_time);
+
                 // NOTE detect when vehicle returns after activity to stop point
                 let end_location = if commute.backward.is_zero_distance() {
                     act.place.location
@@ -260,17 +329,21 @@ fn create_tour(
                 Leg {
                     last_detail: Some((end_location, act.schedule.departure)),
                     statistic: Statistic {
-                        cost: leg.statistic.cost + total_cost,
+                        cost: leg.statistic.cost + total_cost + rest_cost,
                         distance,
                         duration: leg.statistic.duration + act.schedule.departure as i64 - prev_departure as i64,
                         times: Timing {
                             driving: leg.statistic.times.driving + driving as i64,
                             serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i64 }),
                             waiting: leg.statistic.times.waiting + waiting as i64,
-                            break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
+                            break_time: leg.statistic.times.break_time
+                                + (if is_break { serving as i64 } else { 0 })
+                                + rest as i64,
                             commuting: leg.statistic.times.commuting + commuting as i64,
                             parking: leg.statistic.times.parking + parking as i64,
+                            overtime: leg.statistic.times.overtime,
                         },
+                        emissions: None,
                     },
                     load: Some(load),
                 }
@@ -282,8 +355,65 @@ fn create_tour(
         leg
     });
 
-    leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.cost += actor.driver.costs.fixed + vehicle.costs.fixed + get_route_overtime_cost(route);
     tour.statistic = leg.statistic;
+    tour.statistic.times.overtime =
+        get_route_overtime(route, route.tour.total().saturating_sub(1)).map_or(0, |overtime| overtime as i64);
+
+    tour.stops.iter_mut().zip(stop_indices.iter()).for_each(|(stop, &activity_idx)| {
+        if let Stop::Point(point) = stop {
+            point.overtime = get_route_overtime(route, activity_idx).map(|overtime| overtime as i64);
+        }
+    });
+
+    let capacity = get_vehicle_capacity(&vehicle.dimens);
+    let load_ratio_fn = |idx: usize| capacity.as_ref().map_or(0., |capacity| loads[idx].ratio(capacity));
+
+    tour.statistic.emissions = vehicle.dimens.get_vehicle_fuel_consumption().and_then(|consumption| {
+        get_route_fuel_consumption(route, transport, load_ratio_fn)
+            .map(|fuel| Emissions { fuel, co2: fuel * consumption.co2 })
+    });
+
+    let is_recharge_fn = |activity: &Activity| get_activity_type(activity).is_some_and(|t| t == "recharge");
+    if let Some(states) = get_route_states_of_charge(route, transport, is_recharge_fn, load_ratio_fn) {
+        tour.stops.iter_mut().zip(stop_indices).for_each(|(stop, activity_idx)| {
+            if let Stop::Point(point) = stop {
+                point.state_of_charge = Some(states[activity_idx]);
+            }
+        });
+    }
+
+    tour.hours_of_service = hours_of_service.map(|info| TourHoursOfService {
+        driving: info.driving as i64,
+        continuous_driving: info.continuous_driving as i64,
+        breaks: info.breaks.len(),
+        daily_rests: info.daily_rests,
+    });
+
+    let is_reload_activity = |a: &Activity| get_activity_type(a).is_some_and(|t| t == "reload");
+    let loading_plan = if route.actor.vehicle.dimens.get_vehicle_capacity::<MultiDimLoad>().is_some() {
+        get_loading_plan::<MultiDimLoad>(route, is_reload_activity)
+    } else {
+        get_loading_plan::<SingleDimLoad>(route, is_reload_activity)
+    };
+    tour.loading_plan = loading_plan.map(|plan| {
+        plan.into_iter()
+            .map(|placement| {
+                let single = placement.job.as_ref();
+                let job_id = single.dimens.get_job_id().cloned();
+                let job_id =
+                    job_id.unwrap_or_else(|| Multi::roots(single).unwrap().dimens.get_job_id().unwrap().clone());
+                let item = &single.dimens.get_job_packing_items().expect("no packing items")[placement.item_idx];
+
+                ItemPlacement {
+                    job_id,
+                    item_id: item.id.clone(),
+                    position: vec![placement.position.0, placement.position.1, placement.position.2],
+                    size: vec![placement.size.0, placement.size.1, placement.size.2],
+                }
+            })
+            .collect()
+    });
 
     insert_reserved_times_as_breaks(route, &mut tour, reserved_times_index);
 
@@ -325,10 +455,98 @@ fn calculate_load(current: MultiDimLoad, act: &Activity) -> MultiDimLoad {
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
+/// Keeps compartment loads at each activity of the tour.
+struct CompartmentLoads {
+    ids: Vec<String>,
+    assignment: Vec<Option<usize>>,
+    loads: Vec<Vec<MultiDimLoad>>,
+}
+
+impl CompartmentLoads {
+    fn new(route: &Route) -> Option<Self> {
+        let dimens = &route.actor.vehicle.dimens;
+        let (ids, empty_loads): (Vec<_>, Vec<_>) = dimens
+            .get_vehicle_compartments::<MultiDimLoad>()
+            .map(|compartments| {
+                compartments.iter().map(|c| (c.id.clone(), MultiDimLoad::new(vec![0; c.capacity.size]))).unzip()
+            })
+            .or_else(|| {
+                dimens
+                    .get_vehicle_compartments::<SingleDimLoad>()
+                    .map(|compartments| compartments.iter().map(|c| (c.id.clone(), MultiDimLoad::new(vec![0]))).unzip())
+            })?;
+        let assignment = get_compartment_assignment::<MultiDimLoad>(route)
+            .or_else(|| get_compartment_assignment::<SingleDimLoad>(route))?;
+
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+        let start_loads = activities.iter().zip(assignment.iter()).fold(empty_loads.clone(), |mut acc, item| {
+            if let (activity, Some(compartment_idx)) = item {
+                let demand = activity.job.as_ref().and_then(|job| get_capacity(&job.dimens)).unwrap_or_default();
+                acc[*compartment_idx] = acc[*compartment_idx] + demand.delivery.0;
+            }
+
+            acc
+        });
+
+        let loads = activities
+            .iter()
+            .zip(assignment.iter())
+            .enumerate()
+            .scan(start_loads, |current, (activity_idx, (activity, assigned))| {
+                if activity_idx > 0 && activity.job.is_none() {
+                    // NOTE arrival must have zero load
+                    current.clone_from(&empty_loads);
+                }
+
+                if let Some(compartment_idx) = *assigned {
+                    current[compartment_idx] = calculate_load(current[compartment_idx], activity);
+                }
+
+                Some(current.clone())
+            })
+            .collect();
+
+        Some(Self { ids, assignment, loads })
+    }
+
+    fn get_loads(&self, activity_idx: usize) -> Vec<CompartmentLoad> {
+        self.ids
+            .iter()
+            .zip(self.loads[activity_idx].iter())
+            .map(|(id, load)| CompartmentLoad { id: id.clone(), load: load.as_vec() })
+            .collect()
+    }
+
+    fn get_id(&self, activity_idx: usize) -> Option<String> {
+        self.assignment[activity_idx].map(|compartment_idx| self.ids[compartment_idx].clone())
+    }
+}
+
 fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
-    let create_simple_reasons = |code: ViolationCode| {
-        let (code, reason) = map_code_reason(code);
-        vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string(), details: None }]
+    // NOTE when alternative set is unassigned, all its jobs are unassigned too
+    let alternatives = solution
+        .unassigned
+        .iter()
+        .filter_map(|(job, _)| job.dimens().get_job_alternative().zip(job.dimens().get_job_id()))
+        .collect_group_by_key(|(alternative, _)| alternative.key.as_str());
+
+    let create_alternative_reason = |job: &CoreJob| {
+        job.dimens().get_job_alternative().and_then(|alternative| alternatives.get(alternative.key.as_str())).map(
+            |jobs| {
+                let (code, reason) = map_code_reason(ALTERNATIVE_CONSTRAINT_CODE);
+                let mut job_ids = jobs.iter().map(|(_, job_id)| job_id.as_str()).collect::<Vec<_>>();
+                job_ids.sort();
+
+                UnassignedJobReason {
+                    code: code.to_string(),
+                    description: format!(
+                        "{reason}: none of alternative jobs is assigned, ids: '{}'",
+                        job_ids.join(", ")
+                    ),
+                    details: None,
+                }
+            },
+        )
     };
 
     let unassigned = solution
@@ -338,40 +556,8 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
         .map(|(job, code)| {
             let job_id = job.dimens().get_job_id().expect("job id expected").clone();
 
-            let reasons = match code {
-                UnassignmentInfo::Simple(code) => create_simple_reasons(*code),
-                UnassignmentInfo::Detailed(details) if !details.is_empty() => details
-                    .iter()
-                    .collect_group_by_key(|(_, code)| *code)
-                    .into_iter()
-                    .map(|(code, group)| {
-                        let (code, reason) = map_code_reason(code);
-                        let mut vehicle_details = group
-                            .iter()
-                            .map(|(actor, _)| {
-                                let dimens = &actor.vehicle.dimens;
-                                let vehicle_id = dimens.get_vehicle_id().cloned().unwrap();
-                                let shift_index = dimens.get_shift_index().copied().unwrap();
-                                (vehicle_id, shift_index)
-                            })
-                            .collect::<Vec<_>>();
-                        // NOTE sort to have consistent order
-                        vehicle_details.sort();
-
-                        UnassignedJobReason {
-                            details: Some(
-                                vehicle_details
-                                    .into_iter()
-                                    .map(|(vehicle_id, shift_index)| UnassignedJobDetail { vehicle_id, shift_index })
-                                    .collect(),
-                            ),
-                            code: code.to_string(),
-                            description: reason.to_string(),
-                        }
-                    })
-                    .collect(),
-                _ => create_simple_reasons(ViolationCode(0)),
-            };
+            let mut reasons = create_unassigned_reasons(code);
+            reasons.extend(create_alternative_reason(job));
 
             UnassignedJob { job_id, reasons }
         })
@@ -380,6 +566,49 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
     if unassigned.is_empty() { None } else { Some(unassigned) }
 }
 
+/// Creates unassignment reasons grouped by violation code with vehicles which have the same reason.
+pub(super) fn create_unassigned_reasons(code: &UnassignmentInfo) -> Vec<UnassignedJobReason> {
+    let create_simple_reasons = |code: ViolationCode| {
+        let (code, reason) = map_code_reason(code);
+        vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string(), details: None }]
+    };
+
+    match code {
+        UnassignmentInfo::Simple(code) => create_simple_reasons(*code),
+        UnassignmentInfo::Detailed(details) if !details.is_empty() => details
+            .iter()
+            .collect_group_by_key(|(_, code)| *code)
+            .into_iter()
+            .map(|(code, group)| {
+                let (code, reason) = map_code_reason(code);
+                let mut vehicle_details = group
+                    .iter()
+                    .map(|(actor, _)| {
+                        let dimens = &actor.vehicle.dimens;
+                        let vehicle_id = dimens.get_vehicle_id().cloned().unwrap();
+                        let shift_index = dimens.get_shift_index().copied().unwrap();
+                        (vehicle_id, shift_index)
+                    })
+                    .collect::<Vec<_>>();
+                // NOTE sort to have consistent order
+                vehicle_details.sort();
+
+                UnassignedJobReason {
+                    details: Some(
+                        vehicle_details
+                            .into_iter()
+                            .map(|(vehicle_id, shift_index)| UnassignedJobDetail { vehicle_id, shift_index })
+                            .collect(),
+                    ),
+                    code: code.to_string(),
+                    description: reason.to_string(),
+                }
+            })
+            .collect(),
+        _ => create_simple_reasons(ViolationCode(0)),
+    }
+}
+
 fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
     // NOTE at the moment only break violation is mapped
     let violations = solution
@@ -399,7 +628,7 @@ fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_job_type())
 }
 
-fn get_capacity(dimens: &Dimensions) -> Option<Demand<MultiDimLoad>> {
+pub(super) fn get_capacity(dimens: &Dimensions) -> Option<Demand<MultiDimLoad>> {
     // NOTE: try to detect whether dimensions stores multidimensional demand
     let demand: Option<Demand<MultiDimLoad>> = dimens.get_job_demand().cloned();
     if let Some(demand) = demand {
@@ -415,6 +644,16 @@ fn get_capacity(dimens: &Dimensions) -> Option<Demand<MultiDimLoad>> {
     })
 }
 
+pub(super) fn get_vehicle_capacity(dimens: &Dimensions) -> Option<MultiDimLoad> {
+    // NOTE: the same detection as for job demand
+    let capacity: Option<&MultiDimLoad> = dimens.get_vehicle_capacity();
+    if let Some(capacity) = capacity {
+        return Some(*capacity);
+    }
+
+    dimens.get_vehicle_capacity().map(|capacity: &SingleDimLoad| MultiDimLoad::new(vec![capacity.value]))
+}
+
 fn get_parking_time(extras: &DomainExtras) -> Float {
     extras.get_cluster_config().map_or(0., |config| config.serving.get_parking())
 }
@@ -0,0 +1,147 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/alternatives_test.rs"]
+mod alternatives_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+use std::collections::HashSet;
+
+/// Checks that alternative job ids are defined in plan.
+fn check_e1800_job_existence(ctx: &ValidationContext, alternatives: &[Alternative]) -> Result<(), FormatError> {
+    let job_ids = alternatives
+        .iter()
+        .flat_map(|alternative| alternative.jobs.iter())
+        .filter(|&job_id| !ctx.job_index.contains_key(job_id))
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1800".to_string(),
+            "alternative has job id which does not present in the plan".to_string(),
+            format!("remove from alternatives or add jobs to the plan, ids: '{}'", sorted_ids(job_ids)),
+        ))
+    }
+}
+
+/// Checks that alternative has at least two jobs.
+fn check_e1801_too_few_jobs(alternatives: &[Alternative]) -> Result<(), FormatError> {
+    let invalid = alternatives
+        .iter()
+        .filter(|alternative| alternative.jobs.iter().collect::<HashSet<_>>().len() < 2)
+        .collect::<Vec<_>>();
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        let job_ids = invalid.into_iter().flat_map(|alternative| alternative.jobs.iter()).cloned().collect();
+
+        Err(FormatError::new(
+            "E1801".to_string(),
+            "alternative has less than two jobs".to_string(),
+            format!("ensure that each alternative has at least two different jobs, ids: '{}'", sorted_ids(job_ids)),
+        ))
+    }
+}
+
+/// Checks that job is used only in one alternative.
+fn check_e1802_multiple_alternatives(alternatives: &[Alternative]) -> Result<(), FormatError> {
+    let job_ids = alternatives
+        .iter()
+        .flat_map(|alternative| alternative.jobs.iter().collect::<HashSet<_>>())
+        .fold(HashMap::<&String, usize>::default(), |mut acc, job_id| {
+            *acc.entry(job_id).or_default() += 1;
+            acc
+        })
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(job_id, _)| job_id.clone())
+        .collect::<HashSet<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1802".to_string(),
+            "job is used in multiple alternatives".to_string(),
+            format!("ensure that job is used only in one alternative, ids: '{}'", sorted_ids(job_ids)),
+        ))
+    }
+}
+
+/// Checks that alternative refers only jobs which are not expanded into multiple jobs and are not
+/// used in relations or precedences.
+fn check_e1803_invalid_jobs(ctx: &ValidationContext, alternatives: &[Alternative]) -> Result<(), FormatError> {
+    let related_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .flat_map(|relation| relation.jobs.iter())
+        .chain(
+            ctx.problem
+                .plan
+                .precedences
+                .iter()
+                .flatten()
+                .flat_map(|precedence| [&precedence.predecessor, &precedence.successor]),
+        )
+        .collect::<HashSet<_>>();
+
+    let job_ids = alternatives
+        .iter()
+        .flat_map(|alternative| {
+            let is_optional = matches!(alternative.type_field, AlternativeType::AtMostOne);
+            alternative.jobs.iter().map(move |job_id| (job_id, is_optional))
+        })
+        .filter(|&(job_id, is_optional)| {
+            related_ids.contains(job_id)
+                || ctx.job_index.get(job_id).is_some_and(|job| {
+                    job.visits.is_some()
+                        || job.split.is_some()
+                        || job.sync.is_some()
+                        || (is_optional && job.mandatory.unwrap_or(false))
+                })
+        })
+        .map(|(job_id, _)| job_id.clone())
+        .collect::<HashSet<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1803".to_string(),
+            "alternative has job which is not supported".to_string(),
+            format!(
+                "ensure that job used in alternative has no visits, split or sync, is not mandatory in atMostOne \
+                 alternative and is not used in relations or precedences, ids: '{}'",
+                sorted_ids(job_ids)
+            ),
+        ))
+    }
+}
+
+fn sorted_ids(ids: HashSet<String>) -> String {
+    let mut ids = ids.into_iter().collect::<Vec<_>>();
+    ids.sort();
+
+    ids.join(", ")
+}
+
+/// Validates alternatives in the plan.
+pub fn validate_alternatives(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    if let Some(alternatives) = ctx.problem.plan.alternatives.as_ref() {
+        combine_error_results(&[
+            check_e1800_job_existence(ctx, alternatives),
+            check_e1801_too_few_jobs(alternatives),
+            check_e1802_multiple_alternatives(alternatives),
+            check_e1803_invalid_jobs(ctx, alternatives),
+        ])
+        .map_err(From::from)
+    } else {
+        Ok(())
+    }
+}
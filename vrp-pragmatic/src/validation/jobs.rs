@@ -4,6 +4,7 @@ mod jobs_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use std::collections::HashSet;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -179,6 +180,346 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that periodic job has valid visits definition.
+fn check_e1108_invalid_job_visits(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let job_ids = ctx.jobs().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    let relation_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter().flat_map(|relation| relation.jobs.iter()))
+        .map(|job_id| job_id.as_str())
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.visits.as_ref().is_some_and(|visits| {
+                let has_invalid_patterns = visits.patterns.as_ref().is_some_and(|patterns| {
+                    patterns.is_empty()
+                        || patterns.iter().any(|pattern| {
+                            pattern.len() != visits.frequency
+                                || pattern.iter().collect::<HashSet<_>>().len() != pattern.len()
+                        })
+                });
+                let has_id_clash = job.visit_ids().iter().any(|visit_id| job_ids.contains(visit_id.as_str()));
+
+                visits.frequency == 0
+                    || has_invalid_patterns
+                    || has_id_clash
+                    || job.group.is_some()
+                    || relation_ids.contains(job.id.as_str())
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid job visits".to_string(),
+            format!(
+                "ensure that visits have positive frequency, patterns of frequency size with unique days, \
+                 no group and no relations, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that all job tasks have the same product.
+fn check_e1109_mixed_job_products(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.all_tasks_iter().map(|task| task.product.as_ref()).collect::<HashSet<_>>().len() > 1)
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "job has tasks with different products".to_string(),
+            format!("ensure that all job tasks have the same product, job ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that supply jobs are delivery jobs at known satellite locations.
+fn check_e1110_invalid_satellite_jobs(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let satellites = ctx.satellites();
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.satellite.as_ref().is_some_and(|satellite| {
+                let location = satellites.get(satellite).copied().flatten();
+                let has_other_tasks = job.pickups.is_some() || job.replacements.is_some() || job.services.is_some();
+                let has_wrong_location = job
+                    .deliveries
+                    .iter()
+                    .flat_map(|tasks| tasks.iter())
+                    .flat_map(|task| task.places.iter())
+                    .any(|place| ctx.coord_index.get_by_loc(&place.location) != location);
+
+                location.is_none() || has_other_tasks || has_wrong_location
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid satellite job".to_string(),
+            format!(
+                "ensure that satellite is defined in fleet resources and job has only deliveries at satellite \
+                 location, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that soft time windows have at least one penalty rate and no negative values.
+fn check_e1111_invalid_soft_time_windows(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.soft_time_windows.as_ref().is_some_and(|soft| {
+                let values = [soft.earliness, soft.lateness, soft.max_earliness, soft.max_lateness];
+
+                (soft.earliness.is_none() && soft.lateness.is_none())
+                    || values.iter().flatten().any(|value| *value < 0.)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid soft time windows".to_string(),
+            format!(
+                "ensure that earliness or lateness penalty is set and no value is negative, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that split job has one pickup or delivery task with positive one-dimensional demand.
+fn check_e1112_invalid_split_jobs(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let job_ids = ctx.jobs().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    let relation_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter().flat_map(|relation| relation.jobs.iter()))
+        .map(|job_id| job_id.as_str())
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.split.as_ref().is_some_and(|split| {
+                let tasks = job.all_tasks_iter().collect::<Vec<_>>();
+                let has_other_tasks = job.replacements.is_some() || job.services.is_some();
+                let has_valid_demand = tasks
+                    .first()
+                    .and_then(|task| task.demand.as_ref())
+                    .is_some_and(|demand| demand.len() == 1 && demand.iter().all(|quantity| *quantity > 0));
+
+                let has_id_clash = job.visit_ids().iter().any(|part_id| job_ids.contains(part_id.as_str()));
+
+                tasks.len() != 1
+                    || has_other_tasks
+                    || !has_valid_demand
+                    || has_id_clash
+                    || job.visits.is_some()
+                    || job.group.is_some()
+                    || relation_ids.contains(job.id.as_str())
+                    || split.min_quantity.is_some_and(|quantity| quantity < 1)
+                    || split.max_splits.is_some_and(|splits| splits < 1)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid split job".to_string(),
+            format!(
+                "ensure that split job has only one pickup or delivery task with positive one-dimensional demand, \
+                 positive min quantity and max splits, no visits, group or relations, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that max ride time is set for pickup and delivery job with exactly one positive value.
+fn check_e1113_invalid_max_ride_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.max_ride_time.as_ref().is_some_and(|max_ride_time| {
+                let has_pickups_and_deliveries = job.pickups.as_ref().is_some_and(|tasks| !tasks.is_empty())
+                    && job.deliveries.as_ref().is_some_and(|tasks| !tasks.is_empty());
+                let has_other_tasks = job.replacements.is_some() || job.services.is_some();
+                let has_valid_value = match (max_ride_time.duration, max_ride_time.factor) {
+                    (Some(duration), None) => duration > 0.,
+                    (None, Some(factor)) => factor >= 1.,
+                    _ => false,
+                };
+
+                !has_pickups_and_deliveries || has_other_tasks || !has_valid_value
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid max ride time".to_string(),
+            format!(
+                "ensure that max ride time is used only with pickup and delivery job and either positive duration \
+                 or factor not less than one is set, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job items have valid dimensions, unique ids and are loaded only once.
+fn check_e1114_invalid_job_items(ctx: &ValidationContext) -> Result<(), FormatError> {
+    fn get_items(tasks: Option<&Vec<JobTask>>) -> Vec<&JobItem> {
+        tasks.into_iter().flatten().flat_map(|task| task.items.iter().flatten()).collect()
+    }
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            let pickup_items = get_items(job.pickups.as_ref());
+            let delivery_items = get_items(job.deliveries.as_ref());
+            let other_items = get_items(job.replacements.as_ref()).len() + get_items(job.services.as_ref()).len();
+
+            // NOTE items of pickup and delivery job are loaded at pickup, so they are specified only there
+            let has_pickups_and_deliveries = job.pickups.as_ref().is_some_and(|tasks| !tasks.is_empty())
+                && job.deliveries.as_ref().is_some_and(|tasks| !tasks.is_empty());
+            let has_invalid_tasks = other_items > 0 || (has_pickups_and_deliveries && !delivery_items.is_empty());
+
+            let items = pickup_items.into_iter().chain(delivery_items).collect::<Vec<_>>();
+            let has_invalid_items = items.iter().any(|item| {
+                item.length <= 0. || item.width <= 0. || item.height <= 0. || item.weight.is_some_and(|w| w < 0.)
+            });
+            let has_duplicates = items.iter().map(|item| &item.id).collect::<HashSet<_>>().len() != items.len();
+
+            has_invalid_tasks || has_invalid_items || has_duplicates
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1114".to_string(),
+            "invalid job items".to_string(),
+            format!(
+                "ensure that job items have unique ids, positive dimensions, not negative weight and are specified \
+                 only within pickups or deliveries (pickups only for pickup and delivery job), job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that synchronized job has one task and requires at least two vehicles.
+fn check_e1115_invalid_sync_jobs(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let job_ids = ctx.jobs().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    let relation_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter().flat_map(|relation| relation.jobs.iter()))
+        .map(|job_id| job_id.as_str())
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.sync.as_ref().is_some_and(|sync| {
+                let has_id_clash = job.visit_ids().iter().any(|copy_id| job_ids.contains(copy_id.as_str()));
+
+                job.all_tasks_iter().count() != 1
+                    || has_id_clash
+                    || job.visits.is_some()
+                    || job.split.is_some()
+                    || job.group.is_some()
+                    || relation_ids.contains(job.id.as_str())
+                    || sync.vehicles < 2
+                    || sync.tolerance.is_some_and(|tolerance| tolerance < 0.)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1115".to_string(),
+            "invalid sync job".to_string(),
+            format!(
+                "ensure that synchronized job has only one task, requires at least two vehicles, has not negative \
+                 tolerance, no visits, split, group or relations, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that synchronized jobs are not used together with driving breaks of hours-of-service rules.
+fn check_e1116_sync_jobs_with_driving_breaks(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_driving_breaks = ctx.vehicles().any(|vehicle| {
+        vehicle.hours_of_service.as_ref().is_some_and(|hours_of_service| {
+            hours_of_service.driving_break.is_some() || hours_of_service.daily_rest.is_some()
+        })
+    });
+
+    let ids =
+        ctx.jobs().filter(|job| has_driving_breaks && job.sync.is_some()).map(|job| job.id.clone()).collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1116".to_string(),
+            "sync job is used with driving breaks".to_string(),
+            format!(
+                "remove sync from jobs or driving break from vehicles hours-of-service rules, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -190,6 +531,15 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_invalid_job_visits(ctx),
+        check_e1109_mixed_job_products(ctx),
+        check_e1110_invalid_satellite_jobs(ctx),
+        check_e1111_invalid_soft_time_windows(ctx),
+        check_e1112_invalid_split_jobs(ctx),
+        check_e1113_invalid_max_ride_time(ctx),
+        check_e1114_invalid_job_items(ctx),
+        check_e1115_invalid_sync_jobs(ctx),
+        check_e1116_sync_jobs_with_driving_breaks(ctx),
     ])
     .map_err(From::from)
 }
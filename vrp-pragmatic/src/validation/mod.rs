@@ -29,6 +29,15 @@ use self::vehicles::validate_vehicles;
 mod relations;
 use self::relations::validate_relations;
 
+mod precedences;
+use self::precedences::validate_precedences;
+
+mod alternatives;
+use self::alternatives::validate_alternatives;
+
+mod sites;
+use self::sites::validate_sites;
+
 mod routing;
 use self::routing::validate_routing;
 use std::collections::HashMap;
@@ -53,6 +62,9 @@ impl<'a> ValidationContext<'a> {
             .chain(validate_objectives(self).err())
             .chain(validate_routing(self).err())
             .chain(validate_relations(self).err())
+            .chain(validate_precedences(self).err())
+            .chain(validate_alternatives(self).err())
+            .chain(validate_sites(self).err())
             .flatten()
             .collect::<Vec<_>>()
             .into();
@@ -70,6 +82,25 @@ impl<'a> ValidationContext<'a> {
         self.problem.fleet.vehicles.iter()
     }
 
+    /// Gets list of drivers from the problem.
+    fn drivers(&self) -> impl Iterator<Item = &Driver> {
+        self.problem.fleet.drivers.iter().flat_map(|drivers| drivers.iter())
+    }
+
+    /// Gets satellites defined in fleet resources with their location indices.
+    fn satellites(&self) -> HashMap<&String, Option<usize>> {
+        self.problem
+            .fleet
+            .resources
+            .iter()
+            .flat_map(|resources| resources.iter())
+            .filter_map(|resource| match resource {
+                VehicleResource::Satellite { id, location } => Some((id, self.coord_index.get_by_loc(location))),
+                VehicleResource::Reload { .. } | VehicleResource::Recharge { .. } => None,
+            })
+            .collect()
+    }
+
     /// Gets a flat list of job tasks from the job.
     fn tasks(&self, job: &'a Job) -> Vec<&'a JobTask> {
         job.pickups
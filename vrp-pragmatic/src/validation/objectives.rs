@@ -5,7 +5,7 @@ mod objectives_test;
 use super::*;
 use crate::format::problem::Objective::*;
 use crate::utils::combine_error_results;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use vrp_core::utils::Either;
 
 /// Checks that objective is not empty when specified.
@@ -166,6 +166,69 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that minimize deviation objective has valid reference plan and weight.
+fn check_e1608_invalid_deviation_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let shifts = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter().map(move |vehicle_id| (vehicle_id, vehicle.shifts.len())))
+        .collect::<HashMap<_, _>>();
+
+    let invalid = get_objectives_flattened(objectives)
+        .filter_map(|objective| match objective {
+            MinimizeDeviation { reference, weight } => Some((reference, weight)),
+            _ => None,
+        })
+        .flat_map(|(reference, weight)| {
+            let invalid_weight = Some(*weight).filter(|weight| *weight <= 0.).map(|weight| format!("weight {weight}"));
+            let invalid_vehicles = reference
+                .iter()
+                .filter(|tour| {
+                    shifts.get(&tour.vehicle_id).is_none_or(|shifts| tour.shift_index.unwrap_or_default() >= *shifts)
+                })
+                .map(|tour| format!("vehicle '{}'", tour.vehicle_id));
+
+            invalid_weight.into_iter().chain(invalid_vehicles)
+        })
+        .collect::<Vec<_>>();
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "invalid minimize deviation objective".to_string(),
+            format!(
+                "use positive weight and known vehicle ids with shift indices in reference tours: '{}'",
+                invalid.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that emissions objective can be specified only when vehicle with fuel consumption is used.
+fn check_e1609_no_vehicles_with_consumption_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_emissions_objective =
+        get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeEmissions));
+    let has_no_vehicles_with_consumption = !ctx.vehicles().any(|vehicle| vehicle.consumption.is_some());
+
+    if has_emissions_objective && has_no_vehicles_with_consumption {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "redundant emissions objective".to_string(),
+            "specify fuel consumption for at least one vehicle type or delete 'minimize-emissions' objective"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().collect())
 }
@@ -188,6 +251,8 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_invalid_deviation_objective(ctx, &objectives),
+            check_e1609_no_vehicles_with_consumption_objective(ctx, &objectives),
         ])
         .map_err(From::from)
     } else {
@@ -0,0 +1,158 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/precedences_test.rs"]
+mod precedences_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+use std::collections::HashSet;
+
+/// Checks that precedence job ids are defined in plan.
+fn check_e1700_job_existence(ctx: &ValidationContext, precedences: &[Precedence]) -> Result<(), FormatError> {
+    let job_ids = precedences
+        .iter()
+        .flat_map(|precedence| [&precedence.predecessor, &precedence.successor])
+        .filter(|&job_id| !ctx.job_index.contains_key(job_id))
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1700".to_string(),
+            "precedence has job id which does not present in the plan".to_string(),
+            format!("remove from precedences or add jobs to the plan, ids: '{}'", sorted_ids(job_ids)),
+        ))
+    }
+}
+
+/// Checks that precedence refers only jobs with one task which are not expanded into multiple jobs.
+fn check_e1701_invalid_jobs(ctx: &ValidationContext, precedences: &[Precedence]) -> Result<(), FormatError> {
+    let job_ids = precedences
+        .iter()
+        .flat_map(|precedence| [&precedence.predecessor, &precedence.successor])
+        .filter(|&job_id| {
+            ctx.job_index.get(job_id).is_some_and(|job| {
+                job.all_tasks_iter().count() != 1 || job.visits.is_some() || job.split.is_some() || job.sync.is_some()
+            })
+        })
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1701".to_string(),
+            "precedence has job which is not supported".to_string(),
+            format!(
+                "ensure that job used in precedence has only one task, no visits, split or sync, ids: '{}'",
+                sorted_ids(job_ids)
+            ),
+        ))
+    }
+}
+
+/// Checks that precedence has no negative time lag.
+fn check_e1702_negative_lag(precedences: &[Precedence]) -> Result<(), FormatError> {
+    let job_ids = precedences
+        .iter()
+        .filter(|precedence| precedence.lag.is_some_and(|lag| lag < 0.))
+        .map(|precedence| precedence.successor.clone())
+        .collect::<HashSet<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1702".to_string(),
+            "precedence has negative time lag".to_string(),
+            format!("ensure that precedence lag is not negative, successor ids: '{}'", sorted_ids(job_ids)),
+        ))
+    }
+}
+
+/// Checks that precedences have no cycles.
+fn check_e1703_no_cycles(precedences: &[Precedence]) -> Result<(), FormatError> {
+    let mut in_degrees = precedences
+        .iter()
+        .flat_map(|precedence| [(precedence.predecessor.as_str(), 0), (precedence.successor.as_str(), 0)])
+        .collect::<HashMap<_, usize>>();
+    precedences.iter().for_each(|precedence| *in_degrees.get_mut(precedence.successor.as_str()).unwrap() += 1);
+
+    // NOTE remove jobs without predecessors until there is nothing to remove, the rest forms cycles
+    let mut queue = in_degrees.iter().filter(|(_, degree)| **degree == 0).map(|(id, _)| *id).collect::<Vec<_>>();
+    while let Some(job_id) = queue.pop() {
+        in_degrees.remove(job_id);
+        precedences.iter().filter(|precedence| precedence.predecessor == job_id).for_each(|precedence| {
+            if let Some(degree) = in_degrees.get_mut(precedence.successor.as_str()) {
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push(precedence.successor.as_str());
+                }
+            }
+        });
+    }
+
+    if in_degrees.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1703".to_string(),
+            "precedences have a cycle".to_string(),
+            format!(
+                "remove precedences which form a cycle, ids: '{}'",
+                sorted_ids(in_degrees.into_keys().map(|id| id.to_string()).collect())
+            ),
+        ))
+    }
+}
+
+/// Checks that precedences are not used together with driving breaks of hours-of-service rules.
+fn check_e1704_driving_breaks(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.hours_of_service.as_ref().is_some_and(|hours_of_service| {
+                hours_of_service.driving_break.is_some() || hours_of_service.daily_rest.is_some()
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<HashSet<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1704".to_string(),
+            "precedences are used with driving breaks".to_string(),
+            format!(
+                "remove precedences or driving break from vehicles hours-of-service rules, vehicle type ids: '{}'",
+                sorted_ids(type_ids)
+            ),
+        ))
+    }
+}
+
+fn sorted_ids(ids: HashSet<String>) -> String {
+    let mut ids = ids.into_iter().collect::<Vec<_>>();
+    ids.sort();
+
+    ids.join(", ")
+}
+
+/// Validates precedences in the plan.
+pub fn validate_precedences(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    if let Some(precedences) = ctx.problem.plan.precedences.as_ref() {
+        combine_error_results(&[
+            check_e1700_job_existence(ctx, precedences),
+            check_e1701_invalid_jobs(ctx, precedences),
+            check_e1702_negative_lag(precedences),
+            check_e1703_no_cycles(precedences),
+            check_e1704_driving_breaks(ctx),
+        ])
+        .map_err(From::from)
+    } else {
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@
 mod routing_test;
 
 use super::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
 use std::collections::HashSet;
 use vrp_core::prelude::Float;
@@ -115,6 +116,77 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that speed profiles are defined properly.
+fn check_e1506_invalid_speed_profiles(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_valid_profile = |profile: &MatrixSpeedProfile| {
+        let times = profile.points.iter().map(|point| parse_time_safe(&point.time).ok()).collect::<Option<Vec<_>>>();
+
+        !profile.points.is_empty()
+            && profile.points.iter().all(|point| point.factor.is_finite() && point.factor > 0.)
+            && times.is_some_and(|times| times.windows(2).all(|pair| pair[0] < pair[1]))
+    };
+
+    let profile_names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile
+                .speed_profiles
+                .as_ref()
+                .is_some_and(|profiles| profiles.is_empty() || !profiles.iter().all(is_valid_profile))
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if profile_names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid speed profiles".to_string(),
+            format!(
+                "ensure that speed profiles of '{}' have points with valid times in ascending order \
+                 and positive factors",
+                profile_names.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that edge classes are defined properly.
+fn check_e1507_invalid_edge_classes(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let matrices = ctx.matrices.map(|matrices| matrices.as_slice()).unwrap_or(&[]);
+    let has_speed_profiles = ctx.problem.fleet.profiles.iter().any(|profile| profile.speed_profiles.is_some());
+
+    let has_timestamps = has_speed_profiles && matrices.iter().any(|matrix| matrix.timestamp.is_some());
+
+    let has_invalid_classes = matrices.iter().enumerate().any(|(idx, matrix)| {
+        let Some(edge_classes) = matrix.edge_classes.as_ref() else { return false };
+
+        let profile = match matrix.profile.as_ref() {
+            Some(name) => ctx.problem.fleet.profiles.iter().find(|profile| profile.name == *name),
+            None => ctx.problem.fleet.profiles.get(idx),
+        };
+        let classes_size = profile.and_then(|profile| profile.speed_profiles.as_ref()).map_or(0, |p| p.len());
+
+        edge_classes.len() != matrix.travel_times.len() || edge_classes.iter().any(|class| *class >= classes_size)
+    });
+
+    if has_timestamps || has_invalid_classes {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid edge classes".to_string(),
+            "ensure that edge classes have the same size as matrix and refer to speed profiles of the matrix \
+             profile, speed profiles cannot be used with timestamped matrices"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     let location_types = (ctx.coord_index.has_coordinates(), ctx.coord_index.has_indices());
@@ -126,6 +198,8 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_invalid_speed_profiles(ctx),
+        check_e1507_invalid_edge_classes(ctx),
     ])
     .map_err(From::from)
 }
@@ -0,0 +1,226 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/sites_test.rs"]
+mod sites_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+use std::collections::HashSet;
+
+/// Checks that sites have unique locations.
+fn check_e1900_duplicated_locations(ctx: &ValidationContext, sites: &[Site]) -> Result<(), FormatError> {
+    let mut locations = HashSet::new();
+    let indices = sites
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, site)| ctx.coord_index.get_by_loc(&site.location).map(|location| (idx, location)))
+        .filter(|(_, location)| !locations.insert(*location))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if indices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1900".to_string(),
+            "duplicated site locations".to_string(),
+            format!("ensure that each site has unique location, site indices: '{}'", join_indices(indices)),
+        ))
+    }
+}
+
+/// Checks that sites have valid opening hours.
+fn check_e1901_invalid_opening_hours(sites: &[Site]) -> Result<(), FormatError> {
+    let indices = sites
+        .iter()
+        .enumerate()
+        .filter(|(_, site)| site.opening_hours.as_ref().is_some_and(|hours| !check_raw_time_windows(hours, false)))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if indices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1901".to_string(),
+            "invalid site opening hours".to_string(),
+            format!(
+                "change site opening hours so that they are valid and don't intersect, site indices: '{}'",
+                join_indices(indices)
+            ),
+        ))
+    }
+}
+
+/// Checks that sites have positive max vehicles and non-negative setup time.
+fn check_e1902_invalid_site_properties(sites: &[Site]) -> Result<(), FormatError> {
+    let indices = sites
+        .iter()
+        .enumerate()
+        .filter(|(_, site)| site.max_vehicles == Some(0) || site.setup_time.is_some_and(|time| time < 0.))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if indices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1902".to_string(),
+            "invalid site properties".to_string(),
+            format!(
+                "ensure that max vehicles is positive and setup time is not negative, site indices: '{}'",
+                join_indices(indices)
+            ),
+        ))
+    }
+}
+
+/// Checks that jobs at site locations can be served within site opening hours.
+fn check_e1903_jobs_outside_opening_hours(ctx: &ValidationContext, sites: &[Site]) -> Result<(), FormatError> {
+    let site_hours = sites
+        .iter()
+        .filter_map(|site| {
+            let location = ctx.coord_index.get_by_loc(&site.location)?;
+            let hours = site.opening_hours.as_ref()?;
+
+            Some((location, get_time_windows(hours).into_iter().flatten().collect::<Vec<_>>()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    if site_hours.is_empty() {
+        return Ok(());
+    }
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.pickups
+                .iter()
+                .chain(job.deliveries.iter())
+                .chain(job.replacements.iter())
+                .chain(job.services.iter())
+                .flat_map(|tasks| tasks.iter())
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| {
+                    ctx.coord_index.get_by_loc(&place.location).and_then(|location| site_hours.get(&location)).map(
+                        |hours| {
+                            let times = place.times.as_ref().map(|times| get_time_windows(times));
+                            (hours, times)
+                        },
+                    )
+                })
+                .any(|(hours, times)| match times {
+                    Some(times) => !times.iter().flatten().any(|time| hours.iter().any(|hours| time.intersects(hours))),
+                    None => hours.is_empty(),
+                })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1903".to_string(),
+            "job cannot be served within site opening hours".to_string(),
+            format!(
+                "change job time windows or site opening hours so that they intersect, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that sites which shift activity schedule are not used together with synchronized jobs.
+fn check_e1904_sync_jobs(ctx: &ValidationContext, sites: &[Site]) -> Result<(), FormatError> {
+    let indices = get_schedule_sites(sites);
+    let has_sync_jobs = ctx.jobs().any(|job| job.sync.is_some());
+
+    if indices.is_empty() || !has_sync_jobs {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1904".to_string(),
+            "sites are used with sync jobs".to_string(),
+            format!(
+                "remove sync from jobs or max vehicles and setup time from sites, site indices: '{}'",
+                join_indices(indices)
+            ),
+        ))
+    }
+}
+
+/// Checks that sites which shift activity schedule are not used together with precedences.
+fn check_e1905_precedences(ctx: &ValidationContext, sites: &[Site]) -> Result<(), FormatError> {
+    let indices = get_schedule_sites(sites);
+    let has_precedences = ctx.problem.plan.precedences.as_ref().is_some_and(|precedences| !precedences.is_empty());
+
+    if indices.is_empty() || !has_precedences {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1905".to_string(),
+            "sites are used with precedences".to_string(),
+            format!(
+                "remove precedences or max vehicles and setup time from sites, site indices: '{}'",
+                join_indices(indices)
+            ),
+        ))
+    }
+}
+
+/// Checks that sites which shift activity schedule are not used together with driving breaks of
+/// hours-of-service rules.
+fn check_e1906_driving_breaks(ctx: &ValidationContext, sites: &[Site]) -> Result<(), FormatError> {
+    let indices = get_schedule_sites(sites);
+    let has_driving_breaks = ctx.vehicles().any(|vehicle| {
+        vehicle.hours_of_service.as_ref().is_some_and(|hours_of_service| {
+            hours_of_service.driving_break.is_some() || hours_of_service.daily_rest.is_some()
+        })
+    });
+
+    if indices.is_empty() || !has_driving_breaks {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1906".to_string(),
+            "sites are used with driving breaks".to_string(),
+            format!(
+                "remove driving break from vehicles hours-of-service rules or max vehicles and setup time from sites, \
+                 site indices: '{}'",
+                join_indices(indices)
+            ),
+        ))
+    }
+}
+
+/// Returns indices of the sites with max vehicles or setup time: they change activity schedule after insertion.
+fn get_schedule_sites(sites: &[Site]) -> Vec<usize> {
+    sites
+        .iter()
+        .enumerate()
+        .filter(|(_, site)| site.max_vehicles.is_some() || site.setup_time.is_some_and(|time| time > 0.))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn join_indices(indices: Vec<usize>) -> String {
+    indices.iter().map(|idx| idx.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// Validates sites in the plan.
+pub fn validate_sites(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    if let Some(sites) = ctx.problem.plan.sites.as_ref() {
+        combine_error_results(&[
+            check_e1900_duplicated_locations(ctx, sites),
+            check_e1901_invalid_opening_hours(sites),
+            check_e1902_invalid_site_properties(sites),
+            check_e1903_jobs_outside_opening_hours(ctx, sites),
+            check_e1904_sync_jobs(ctx, sites),
+            check_e1905_precedences(ctx, sites),
+            check_e1906_driving_breaks(ctx, sites),
+        ])
+        .map_err(From::from)
+    } else {
+        Ok(())
+    }
+}
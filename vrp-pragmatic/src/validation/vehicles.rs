@@ -216,8 +216,9 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
         .resources
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, .. } => id.to_string(),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, .. } => Some(id.to_string()),
+            VehicleResource::Recharge { .. } | VehicleResource::Satellite { .. } => None,
         })
         .collect::<Vec<_>>();
 
@@ -258,6 +259,445 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that fleet has no drivers with duplicate ids.
+fn check_e1309_no_drivers_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
+    get_duplicates(ctx.drivers().map(|driver| &driver.id)).map_or(Ok(()), |ids| {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "duplicated driver ids".to_string(),
+            format!("remove duplicated driver ids: {}", ids.join(", ")),
+        ))
+    })
+}
+
+/// Checks that driver shift time is correct.
+fn check_e1310_driver_shift_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let driver_ids = ctx
+        .drivers()
+        .filter_map(|driver| {
+            let tws = driver
+                .shifts
+                .iter()
+                .map(|shift| vec![shift.earliest.clone(), shift.latest.clone()])
+                .collect::<Vec<_>>();
+            if !tws.is_empty() && check_raw_time_windows(&tws, false) { None } else { Some(driver.id.to_string()) }
+        })
+        .collect::<Vec<_>>();
+
+    if driver_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid driver shift time".to_string(),
+            format!(
+                "ensure that driver has at least one shift with correct and not intersecting times, driver ids: {}",
+                driver_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle compartments are defined correctly.
+fn check_e1311_vehicle_compartments(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.compartments.as_ref().is_some_and(|compartments| {
+                let has_reloads = vehicle.shifts.iter().any(|shift| shift.reloads.is_some());
+                let has_duplicates = get_duplicates(compartments.iter().map(|c| &c.id)).is_some();
+                let has_invalid_capacity = compartments.iter().any(|c| {
+                    c.capacity.len() != vehicle.capacity.len()
+                        || c.capacity.iter().zip(vehicle.capacity.iter()).any(|(lhs, rhs)| *lhs < 0 || lhs > rhs)
+                });
+
+                compartments.is_empty() || has_reloads || has_duplicates || has_invalid_capacity
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle compartments".to_string(),
+            format!(
+                "ensure that compartments have unique ids, capacity of vehicle's dimension within its limits \
+                 and vehicle has no reloads, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that satellites are defined correctly and used by vehicle shifts.
+fn check_e1312_vehicle_satellites(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let satellite_ids = ctx.problem.fleet.resources.iter().flat_map(|resources| resources.iter()).filter_map(
+        |resource| match resource {
+            VehicleResource::Satellite { id, .. } => Some(id),
+            VehicleResource::Reload { .. } | VehicleResource::Recharge { .. } => None,
+        },
+    );
+
+    if get_duplicates(satellite_ids).is_some() {
+        return Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle satellite".to_string(),
+            "make sure that fleet satellite ids are unique".to_string(),
+        ));
+    }
+
+    let satellites = ctx.satellites();
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.shifts.iter().any(|shift| {
+                shift.start.satellite.as_ref().is_some_and(|satellite| {
+                    let location = satellites.get(satellite).copied().flatten();
+
+                    location.is_none()
+                        || ctx.coord_index.get_by_loc(&shift.start.location) != location
+                        || shift.reloads.is_some()
+                })
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle satellite".to_string(),
+            format!(
+                "make sure that shift satellite is defined in fleet resources, shift starts at its location \
+                 and has no reloads, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle hours-of-service rules are defined correctly.
+fn check_e1313_vehicle_hours_of_service(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.hours_of_service.as_ref().is_some_and(|hos| {
+                let is_invalid_break = hos.driving_break.as_ref().is_some_and(|driving_break| {
+                    driving_break.max_driving <= 0.
+                        || driving_break.parts.is_empty()
+                        || driving_break.parts.iter().any(|part| *part <= 0.)
+                });
+                let is_invalid_rest = hos
+                    .daily_rest
+                    .as_ref()
+                    .is_some_and(|daily_rest| daily_rest.max_driving <= 0. || daily_rest.duration <= 0.);
+                let is_invalid_limit = hos
+                    .max_tour_driving
+                    .into_iter()
+                    .chain(hos.max_vehicle_driving)
+                    .chain(hos.max_weekly_driving)
+                    .any(|l| l <= 0.);
+                let is_empty = hos.driving_break.is_none()
+                    && hos.daily_rest.is_none()
+                    && hos.max_tour_driving.is_none()
+                    && hos.max_vehicle_driving.is_none()
+                    && hos.max_weekly_driving.is_none();
+
+                is_invalid_break || is_invalid_rest || is_invalid_limit || is_empty
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle hours of service".to_string(),
+            format!(
+                "ensure that at least one rule is specified, driving limits are positive, daily rest has positive \
+                 driving limit and duration, and driving break has at least one part with positive duration, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle overtime is defined correctly.
+fn check_e1314_vehicle_overtime(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|vehicle, shift, shift_time| {
+            shift.overtime.as_ref().is_none_or(|overtime| {
+                let is_valid_end = parse_time_safe(&overtime.regular_end).is_ok_and(|regular_end| {
+                    shift_time.as_ref().is_none_or(|shift_time| shift_time.contains(regular_end))
+                });
+                let is_valid_rates = !overtime.rates.is_empty()
+                    && overtime.rates.first().is_some_and(|rate| rate.after >= 0.)
+                    && overtime.rates.windows(2).all(|rates| rates[0].after < rates[1].after)
+                    && overtime.rates.iter().all(|rate| rate.time >= vehicle.costs.time);
+
+                is_valid_end && is_valid_rates
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid vehicle overtime".to_string(),
+            format!(
+                "ensure that regular end is within shift time, overtime rates are sorted by non-negative unique \
+                 offsets and their time costs are not less than vehicle's time cost, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle cargo space has positive dimensions.
+fn check_e1318_vehicle_cargo_space(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.cargo_space.as_ref().is_some_and(|space| {
+                space.length <= 0.
+                    || space.width <= 0.
+                    || space.height <= 0.
+                    || space.max_weight.is_some_and(|max_weight| max_weight < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1318".to_string(),
+            "invalid vehicle cargo space".to_string(),
+            format!(
+                "ensure that cargo space dimensions are positive and max weight is not negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle fuel consumption is defined correctly.
+fn check_e1315_vehicle_consumption(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.consumption.as_ref().is_some_and(|consumption| {
+                consumption.empty < 0.
+                    || consumption.full < consumption.empty
+                    || consumption.drag.is_some_and(|drag| drag < 0.)
+                    || consumption.co2 < 0.
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "invalid vehicle consumption".to_string(),
+            format!(
+                "ensure that consumption values are not negative and full vehicle consumption is not less than \
+                 empty, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle recharges have a valid distance limit or battery.
+fn check_e1316_vehicle_recharges(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.recharges.as_ref().is_none_or(|recharges| {
+                let is_valid_distance = recharges.max_distance.is_none_or(|distance| distance > 0.);
+                let is_valid_battery = match recharges.battery.as_ref() {
+                    Some(battery) => {
+                        let initial = battery.initial.unwrap_or(battery.capacity);
+                        let reserve = battery.reserve.unwrap_or_default();
+
+                        battery.capacity > 0.
+                            && (0. ..=battery.capacity).contains(&initial)
+                            && (0. ..=initial).contains(&reserve)
+                            && battery.consumption.empty >= 0.
+                            && battery.consumption.full.is_none_or(|full| full >= battery.consumption.empty)
+                    }
+                    None => recharges.max_distance.is_some(),
+                };
+                let is_valid_curves = recharges.stations.iter().all(|station| {
+                    station.charging_curve.as_ref().is_none_or(|curve| {
+                        !curve.is_empty()
+                            && curve.iter().all(|point| (0. ..=1.).contains(&point.soc) && point.power > 0.)
+                    })
+                });
+
+                is_valid_distance && is_valid_battery && is_valid_curves
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1316".to_string(),
+            "invalid vehicle recharges".to_string(),
+            format!(
+                "ensure that either positive max distance or battery is specified, battery has positive capacity, \
+                 initial state of charge is within capacity and not less than reserve, consumption is not negative \
+                 and charging curves have state of charge in [0, 1] range and positive power, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that recharge stations refer to defined recharge resources with positive number of chargers.
+fn check_e1317_vehicle_recharge_resources(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let recharge_resources = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Recharge { id, chargers } => Some((id.to_string(), *chargers)),
+            VehicleResource::Reload { .. } | VehicleResource::Satellite { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let unique_resource_ids = recharge_resources.iter().map(|(id, _)| id.clone()).collect::<HashSet<_>>();
+
+    if recharge_resources.len() != unique_resource_ids.len()
+        || recharge_resources.iter().any(|(_, chargers)| *chargers == 0)
+    {
+        return Err(FormatError::new(
+            "E1317".to_string(),
+            "invalid vehicle recharge resource".to_string(),
+            "make sure that fleet recharge resource ids are unique and each resource has at least one charger"
+                .to_string(),
+        ));
+    }
+
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(move |_, shift, _| {
+            shift
+                .recharges
+                .iter()
+                .flat_map(|recharges| recharges.stations.iter())
+                .filter_map(|station| station.resource_id.as_ref())
+                .all(|resource_id| unique_resource_ids.contains(resource_id))
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1317".to_string(),
+            "invalid vehicle recharge resource".to_string(),
+            format!(
+                "make sure that fleet has all recharge resources defined, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Specifies maximum amount of vehicle and driver shift pairs: each pair is a separate actor in the solver.
+const MAX_DRIVER_PAIRS: usize = 10_000;
+
+/// Checks that amount of vehicle and driver shift pairs is within the limit.
+fn check_e1319_vehicle_driver_pairs(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let driver_shifts = ctx
+        .drivers()
+        .flat_map(|driver| driver.shifts.iter())
+        .filter_map(|shift| get_time_window(&shift.earliest, &shift.latest))
+        .collect::<Vec<_>>();
+
+    if driver_shifts.is_empty() {
+        return Ok(());
+    }
+
+    // NOTE only shifts which have time intersection are paired
+    let pairs = ctx
+        .vehicles()
+        .flat_map(|vehicle| {
+            let shifts = vehicle.shifts.iter().filter_map(get_shift_time_window).collect::<Vec<_>>();
+            std::iter::repeat_n(shifts, vehicle.vehicle_ids.len())
+        })
+        .flatten()
+        .map(|vehicle_shift| {
+            driver_shifts.iter().filter(|driver_shift| driver_shift.intersects(&vehicle_shift)).count()
+        })
+        .sum::<usize>();
+
+    if pairs <= MAX_DRIVER_PAIRS {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1319".to_string(),
+            "too many vehicle and driver pairs".to_string(),
+            format!(
+                "reduce amount of vehicles, drivers or their shifts with intersecting time: there are {pairs} \
+                 vehicle and driver shift pairs, but at most {MAX_DRIVER_PAIRS} are supported"
+            ),
+        ))
+    }
+}
+
+/// Checks that driving breaks and daily rests are not used with recharges or shared reload resources:
+/// they reschedule the route after breaks are inserted, so the breaks would be lost.
+fn check_e1320_vehicle_hours_of_service_rests(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|vehicle, shift, _| {
+            let has_rests = vehicle
+                .hours_of_service
+                .as_ref()
+                .is_some_and(|hos| hos.driving_break.is_some() || hos.daily_rest.is_some());
+            let has_rescheduling =
+                shift.recharges.is_some() || shift.reloads.iter().flatten().any(|reload| reload.resource_id.is_some());
+
+            !(has_rests && has_rescheduling)
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1320".to_string(),
+            "driving breaks are used with recharges or reload resources".to_string(),
+            format!(
+                "remove driving break and daily rest from hours of service, or recharges and reload resources from \
+                 vehicle shifts, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -301,6 +741,18 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_offset_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_no_drivers_with_duplicate_ids(ctx),
+        check_e1310_driver_shift_time(ctx),
+        check_e1311_vehicle_compartments(ctx),
+        check_e1312_vehicle_satellites(ctx),
+        check_e1313_vehicle_hours_of_service(ctx),
+        check_e1314_vehicle_overtime(ctx),
+        check_e1315_vehicle_consumption(ctx),
+        check_e1316_vehicle_recharges(ctx),
+        check_e1317_vehicle_recharge_resources(ctx),
+        check_e1318_vehicle_cargo_space(ctx),
+        check_e1319_vehicle_driver_pairs(ctx),
+        check_e1320_vehicle_hours_of_service_rests(ctx),
     ])
     .map_err(From::from)
 }
@@ -0,0 +1,128 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_alternative_problem(jobs: Vec<Job>, type_field: AlternativeType) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs,
+            alternatives: Some(vec![Alternative { type_field, jobs: vec!["job1".to_string(), "job2".to_string()] }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+fn get_served_ids(solution: &Solution) -> Vec<String> {
+    let mut job_ids = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+        .map(|activity| activity.job_id.clone())
+        .filter(|job_id| job_id.starts_with("job"))
+        .collect::<Vec<_>>();
+    job_ids.sort();
+
+    job_ids
+}
+
+#[test]
+fn can_serve_only_one_job_of_alternative() {
+    let problem = create_alternative_problem(
+        vec![
+            create_pickup_job("job1", (1., 0.)),
+            create_delivery_job_with_demand("job2", (5., 0.), vec![2]),
+            create_delivery_job("job3", (3., 0.)),
+        ],
+        AlternativeType::ExactlyOne,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_served_ids(&solution), vec!["job1".to_string(), "job3".to_string()]);
+}
+
+#[test]
+fn can_leave_at_most_one_alternative_unassigned_without_penalty() {
+    let problem = create_alternative_problem(
+        vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (5., 0.))],
+        AlternativeType::AtMostOne,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    let mut unassigned = solution.unassigned.unwrap();
+    unassigned.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+    assert_eq!(unassigned.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1", "job2"]);
+    assert!(unassigned.iter().all(|job| job.reasons.iter().any(|reason| reason.code == "ALTERNATIVE_CONSTRAINT")));
+}
+
+#[test]
+fn can_serve_at_most_one_alternative_with_value() {
+    let problem = create_alternative_problem(
+        vec![create_delivery_job_with_value("job1", (1., 0.), 100.), create_delivery_job("job2", (5., 0.))],
+        AlternativeType::AtMostOne,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_served_ids(&solution), vec!["job1".to_string()]);
+}
+
+#[test]
+fn can_serve_alternative_when_other_job_is_infeasible() {
+    let problem = create_alternative_problem(
+        vec![
+            create_delivery_job_with_skills("job1", (1., 0.), all_of_skills(vec!["unknown_skill".to_string()])),
+            create_delivery_job("job2", (5., 0.)),
+        ],
+        AlternativeType::ExactlyOne,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_served_ids(&solution), vec!["job2".to_string()]);
+}
+
+parameterized_test! {can_explain_unassigned_alternative, type_field, {
+    can_explain_unassigned_alternative_impl(type_field);
+}}
+
+can_explain_unassigned_alternative! {
+    case01_exactly_one: AlternativeType::ExactlyOne,
+    case02_at_most_one: AlternativeType::AtMostOne,
+}
+
+fn can_explain_unassigned_alternative_impl(type_field: AlternativeType) {
+    let skills = all_of_skills(vec!["unknown_skill".to_string()]);
+    let problem = create_alternative_problem(
+        vec![
+            create_delivery_job_with_skills("job1", (1., 0.), skills.clone()),
+            create_delivery_job_with_skills("job2", (5., 0.), skills),
+            create_delivery_job("job3", (3., 0.)),
+        ],
+        type_field,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_served_ids(&solution), vec!["job3".to_string()]);
+    let mut unassigned = solution.unassigned.unwrap();
+    unassigned.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+    assert_eq!(unassigned.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1", "job2"]);
+    unassigned.iter().for_each(|job| {
+        let codes = job.reasons.iter().map(|reason| reason.code.as_str()).collect::<Vec<_>>();
+        assert_eq!(codes, vec!["SKILL_CONSTRAINT", "ALTERNATIVE_CONSTRAINT"]);
+        assert!(job.reasons[1].description.ends_with("ids: 'job1, job2'"));
+    });
+}
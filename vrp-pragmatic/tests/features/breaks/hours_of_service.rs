@@ -0,0 +1,114 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_problem(locations: Vec<Float>, hours_of_service: VehicleHoursOfService) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: locations
+                .into_iter()
+                .enumerate()
+                .map(|(idx, location)| create_delivery_job(&format!("job{}", idx + 1), (location, 0.)))
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { hours_of_service: Some(hours_of_service), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_rest_count(solution: &Solution) -> usize {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "rest")
+        .count()
+}
+
+#[test]
+fn can_insert_driving_break() {
+    let problem = create_problem(
+        vec![10., 20., 30.],
+        VehicleHoursOfService {
+            driving_break: Some(VehicleDrivingBreak { max_driving: 40., parts: vec![5.] }),
+            daily_rest: None,
+            max_tour_driving: None,
+            max_vehicle_driving: None,
+            max_weekly_driving: None,
+        },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_rest_count(&solution), 1);
+    // NOTE continuous driving depends on tour direction, so only its limit is checked
+    let hours_of_service = solution.tours[0].hours_of_service.clone().expect("no hours of service");
+    assert_eq!((hours_of_service.driving, hours_of_service.breaks), (60, 1));
+    assert!(hours_of_service.continuous_driving <= 40);
+    assert_eq!(solution.tours[0].statistic.times.break_time, 5);
+    assert_eq!(solution.tours[0].statistic.duration, 68);
+}
+
+#[test]
+fn can_have_unassigned_job_because_of_tour_driving_limit() {
+    let problem = create_problem(
+        vec![10., 20., 40.],
+        VehicleHoursOfService {
+            driving_break: None,
+            daily_rest: None,
+            max_tour_driving: Some(50.),
+            max_vehicle_driving: None,
+            max_weekly_driving: None,
+        },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job3".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "HOURS_OF_SERVICE_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to driver hours-of-service rules".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+            }],
+        }])
+    );
+    assert_eq!(get_rest_count(&solution), 0);
+}
+
+#[test]
+fn can_insert_daily_rest() {
+    let problem = create_problem(
+        vec![10., 20., 30.],
+        VehicleHoursOfService {
+            driving_break: None,
+            daily_rest: Some(VehicleDailyRest { max_driving: 40., duration: 20. }),
+            max_tour_driving: None,
+            max_vehicle_driving: None,
+            max_weekly_driving: None,
+        },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_rest_count(&solution), 1);
+    let hours_of_service = solution.tours[0].hours_of_service.clone().expect("no hours of service");
+    assert_eq!((hours_of_service.driving, hours_of_service.breaks, hours_of_service.daily_rests), (60, 1, 1));
+    assert_eq!(solution.tours[0].statistic.times.break_time, 20);
+    assert_eq!(solution.tours[0].statistic.duration, 83);
+}
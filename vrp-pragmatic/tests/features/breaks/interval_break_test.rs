@@ -21,6 +21,7 @@ fn can_assign_interval_break_between_jobs() {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
                         location: (0., 0.).to_loc(),
+                        satellite: None,
                     },
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeOffset(vec![5., 10.]),
@@ -103,6 +104,7 @@ fn can_assign_interval_break_with_reload() {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
                         location: (0., 0.).to_loc(),
+                        satellite: None,
                     },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (30., 0.).to_loc() }),
                     breaks: Some(vec![VehicleBreak::Optional {
@@ -117,6 +119,7 @@ fn can_assign_interval_break_with_reload() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -16,6 +16,7 @@ fn can_use_two_breaks() {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
                         location: (0., 0.).to_loc(),
+                        satellite: None,
                     },
                     breaks: Some(vec![
                         VehicleBreak::Optional {
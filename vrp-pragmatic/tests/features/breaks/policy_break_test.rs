@@ -24,7 +24,12 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
             vehicles: vec![
                 VehicleType {
                     shifts: vec![VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: (100., 0.).to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: (100., 0.).to_loc(),
+                            satellite: None,
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(1000.),
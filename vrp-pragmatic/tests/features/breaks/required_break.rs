@@ -4,7 +4,12 @@ use crate::format_time;
 use crate::helpers::*;
 
 fn create_shift_start() -> ShiftStart {
-    ShiftStart { earliest: format_time(0.), latest: Some(format_time(0.)), location: (0., 0.).to_loc() }
+    ShiftStart {
+        earliest: format_time(0.),
+        latest: Some(format_time(0.)),
+        location: (0., 0.).to_loc(),
+        satellite: None,
+    }
 }
 
 fn create_problem(jobs: Vec<Job>, vehicle_break: VehicleBreak, is_open: bool) -> Problem {
@@ -265,6 +270,8 @@ fn can_handle_required_break_with_infeasible_sequence_relation() {
             }],
             demand: None,
             order: None,
+            product: None,
+            items: None,
         }]),
         ..create_job(index.to_string().as_str())
     };
@@ -293,6 +300,7 @@ fn can_handle_required_break_with_infeasible_sequence_relation() {
                         earliest: format_time(86400. + 28800.),
                         latest: Some(format_time(86400. + 28800.)),
                         location: Location::Reference { index: 5 },
+                        satellite: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
@@ -324,6 +332,7 @@ fn can_handle_required_break_with_infeasible_sequence_relation() {
             8710, 0, 8733, 60163, 215, 9103, 23, 8725, 0, 53146, 52996, 56684, 53188, 60477, 53211, 0,
         ],
         error_codes: None,
+        edge_classes: None,
     };
 
     let solution = solve_with_metaheuristic_and_iterations_without_check(problem, Some(vec![matrix]), 200);
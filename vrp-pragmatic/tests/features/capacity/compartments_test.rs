@@ -0,0 +1,169 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_product(id: &str, location: (f64, f64), demand: i32, product: &str) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            demand: Some(vec![demand]),
+            product: Some(product.to_string()),
+            ..create_task(location, None)
+        }]),
+        ..create_job(id)
+    }
+}
+
+fn create_compartment(id: &str, capacity: i32, products: Option<Vec<&str>>) -> VehicleCompartment {
+    VehicleCompartment {
+        id: id.to_string(),
+        capacity: vec![capacity],
+        products: products.map(|products| products.into_iter().map(|p| p.to_string()).collect()),
+        incompatible: None,
+    }
+}
+
+fn get_compartment_loads(stop: &Stop) -> Vec<(String, Vec<i32>)> {
+    stop.compartments().iter().flat_map(|c| c.iter()).map(|c| (c.id.clone(), c.load.clone())).collect()
+}
+
+#[test]
+fn can_load_products_into_separate_compartments() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_product("diesel1", (1., 0.), 3, "diesel"),
+                create_delivery_job_with_product("diesel2", (2., 0.), 2, "diesel"),
+                create_delivery_job_with_product("petrol", (3., 0.), 4, "petrol"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![10],
+                compartments: Some(vec![
+                    create_compartment("c1", 5, Some(vec!["diesel"])),
+                    create_compartment("c2", 5, Some(vec!["petrol"])),
+                ]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(
+        get_compartment_loads(tour.stops.first().unwrap()),
+        vec![("c1".to_string(), vec![5]), ("c2".to_string(), vec![4])]
+    );
+    assert_eq!(
+        get_compartment_loads(tour.stops.last().unwrap()),
+        vec![("c1".to_string(), vec![0]), ("c2".to_string(), vec![0])]
+    );
+    let mut compartments = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| (activity.job_id.clone(), activity.compartment.clone().unwrap()))
+        .collect::<Vec<_>>();
+    compartments.sort();
+    assert_eq!(
+        compartments,
+        vec![
+            ("diesel1".to_string(), "c1".to_string()),
+            ("diesel2".to_string(), "c1".to_string()),
+            ("petrol".to_string(), "c2".to_string())
+        ]
+    );
+}
+
+#[test]
+fn can_unassign_job_due_to_incompatible_products() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_product("meat", (1., 0.), 1, "meat"),
+                create_delivery_job_with_product("fish", (2., 0.), 1, "fish"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![10],
+                compartments: Some(vec![VehicleCompartment {
+                    incompatible: Some(vec![vec!["meat".to_string(), "fish".to_string()]]),
+                    ..create_compartment("c1", 10, None)
+                }]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map_or(0, |u| u.len()), 1);
+    let reasons = solution.unassigned.iter().flatten().flat_map(|u| u.reasons.iter().cloned()).collect::<Vec<_>>();
+    assert_eq!(
+        reasons,
+        vec![UnassignedJobReason {
+            code: "COMPARTMENT_CONSTRAINT".to_string(),
+            description: "cannot be assigned due to vehicle compartments constraint".to_string(),
+            details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
+        }]
+    );
+}
+
+#[test]
+fn can_use_compartment_for_pickup_and_delivery_job() {
+    let pickup_delivery = create_pickup_delivery_job("pd", (1., 0.), (2., 0.));
+    let with_product = |tasks: Option<Vec<JobTask>>| {
+        tasks.map(|tasks| {
+            tasks.into_iter().map(|task| JobTask { product: Some("petrol".to_string()), ..task }).collect()
+        })
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    pickups: with_product(pickup_delivery.pickups.clone()),
+                    deliveries: with_product(pickup_delivery.deliveries.clone()),
+                    ..pickup_delivery
+                },
+                create_delivery_job_with_product("diesel", (3., 0.), 1, "diesel"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![2],
+                compartments: Some(vec![
+                    create_compartment("c1", 1, Some(vec!["diesel"])),
+                    create_compartment("c2", 1, Some(vec!["petrol"])),
+                ]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_compartment_loads(solution.tours[0].stops.first().unwrap()),
+        vec![("c1".to_string(), vec![1]), ("c2".to_string(), vec![0])]
+    );
+}
@@ -1 +1,3 @@
+mod compartments_test;
+mod packing_test;
 mod simple_capacity_test;
@@ -0,0 +1,145 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_item(id: &str, location: (f64, f64), size: (f64, f64, f64), rotatable: bool) -> Job {
+    let task = create_task_with_item(id, location, size, rotatable);
+    Job { deliveries: Some(vec![task]), ..create_job(id) }
+}
+
+fn create_pickup_job_with_item(id: &str, location: (f64, f64), size: (f64, f64, f64)) -> Job {
+    let task = create_task_with_item(id, location, size, false);
+    Job { pickups: Some(vec![task]), ..create_job(id) }
+}
+
+fn create_task_with_item(id: &str, location: (f64, f64), size: (f64, f64, f64), rotatable: bool) -> JobTask {
+    JobTask {
+        items: Some(vec![JobItem {
+            id: format!("{id}_item"),
+            length: size.0,
+            width: size.1,
+            height: size.2,
+            weight: None,
+            stackable: Some(false),
+            rotatable: Some(rotatable),
+        }]),
+        ..create_task(location, None)
+    }
+}
+
+fn create_problem(jobs: Vec<Job>, cargo_space: (f64, f64, f64)) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                cargo_space: Some(VehicleCargoSpace {
+                    length: cargo_space.0,
+                    width: cargo_space.1,
+                    height: cargo_space.2,
+                    max_weight: None,
+                }),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_unassign_job_when_its_item_does_not_fit_cargo_space() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job_with_item("job1", (1., 0.), (2., 2., 2.), true),
+            create_delivery_job_with_item("job2", (2., 0.), (2., 2., 2.), true),
+            create_delivery_job_with_item("job3", (3., 0.), (2., 2., 2.), true),
+        ],
+        (4., 2., 4.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].loading_plan.as_ref().map(|plan| plan.len()), Some(2));
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(
+        unassigned[0].reasons,
+        vec![UnassignedJobReason {
+            code: "PACKING_CONSTRAINT".to_string(),
+            description: "cannot be assigned due to vehicle cargo space constraint".to_string(),
+            details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+        }]
+    );
+}
+
+parameterized_test! {can_rotate_item_to_fit_cargo_space, (rotatable, expected_size), {
+    can_rotate_item_to_fit_cargo_space_impl(rotatable, expected_size);
+}}
+
+can_rotate_item_to_fit_cargo_space! {
+    case01_rotatable: (true, Some(vec![2., 4., 1.])),
+    case02_not_rotatable: (false, None),
+}
+
+fn can_rotate_item_to_fit_cargo_space_impl(rotatable: bool, expected_size: Option<Vec<f64>>) {
+    let problem =
+        create_problem(vec![create_delivery_job_with_item("job1", (1., 0.), (4., 2., 1.), rotatable)], (2., 4., 2.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let size = solution
+        .tours
+        .first()
+        .and_then(|tour| tour.loading_plan.as_ref())
+        .and_then(|plan| plan.first())
+        .map(|placement| placement.size.clone());
+    assert_eq!(size, expected_size);
+    assert_eq!(solution.unassigned.is_some(), expected_size.is_none());
+}
+
+#[test]
+fn can_use_space_of_delivered_item_for_picked_up_item() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job_with_item("job1", (1., 0.), (2., 2., 2.), false),
+            create_pickup_job_with_item("job2", (2., 0.), (2., 2., 2.)),
+        ],
+        (2., 2., 2.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["job1"], vec!["job2"], vec!["arrival"]]
+    );
+    assert_eq!(solution.tours[0].loading_plan.as_ref().map(|plan| plan.len()), Some(2));
+}
+
+#[test]
+fn can_pack_items_per_reload_segment() {
+    let mut problem = create_problem(
+        vec![
+            create_delivery_job_with_item("job1", (1., 0.), (2., 2., 2.), false),
+            create_delivery_job_with_item("job2", (2., 0.), (2., 2., 2.), false),
+        ],
+        (2., 2., 2.),
+    );
+    problem.fleet.vehicles[0].shifts[0].reloads =
+        Some(vec![VehicleReload { location: (0., 0.).to_loc(), duration: 2.0, ..create_default_reload() }]);
+    problem.fleet.vehicles[0].capacity = vec![1];
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.tours[0].stops.iter().any(|stop| stop.activities().iter().any(|a| a.activity_type == "reload")));
+    assert_eq!(solution.tours[0].loading_plan.as_ref().map(|plan| plan.len()), Some(2));
+}
@@ -45,6 +45,9 @@ impl From<ActivityData> for Activity {
                 forward: convert_expected_commute_info(fwd),
                 backward: convert_expected_commute_info(bak),
             }),
+            compartment: None,
+            lateness: None,
+            quantity: None,
         }
     }
 }
@@ -87,6 +90,9 @@ impl From<StopData> for Stop {
             },
             load: vec![stop.load],
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
+            compartments: None,
+            state_of_charge: None,
+            overtime: None,
         })
     }
 }
@@ -103,6 +109,7 @@ fn create_statistic(data: (Float, i64, i64, (i64, i64, i64, i64))) -> Statistic
             parking: data.3.3,
             ..Timing::default()
         },
+        emissions: None,
     }
 }
 
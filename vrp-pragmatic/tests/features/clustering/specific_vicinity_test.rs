@@ -30,6 +30,7 @@ fn can_handle_parking_with_no_clusters_and_job_time_windows() {
                         earliest: "1970-01-01T09:00:00Z".to_string(),
                         latest: None,
                         location: Location::Coordinate { lat: 52.497, lng: 13.547 },
+                        satellite: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
@@ -105,6 +106,7 @@ fn can_handle_waiting_time_with_parking_impl(
                         earliest: "1970-01-01T09:00:00Z".to_string(),
                         latest: None,
                         location: vehicle_location.clone(),
+                        satellite: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
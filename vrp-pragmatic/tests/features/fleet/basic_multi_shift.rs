@@ -16,12 +16,22 @@ fn can_use_multiple_times_from_vehicle_and_job() {
             vehicles: vec![VehicleType {
                 shifts: vec![
                     VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: (0., 0.).to_loc(),
+                            satellite: None,
+                        },
                         end: Some(ShiftEnd { earliest: None, latest: format_time(99.), location: (0., 0.).to_loc() }),
                         ..create_default_vehicle_shift()
                     },
                     VehicleShift {
-                        start: ShiftStart { earliest: format_time(100.), latest: None, location: (0., 0.).to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(100.),
+                            latest: None,
+                            location: (0., 0.).to_loc(),
+                            satellite: None,
+                        },
                         end: Some(ShiftEnd { earliest: None, latest: format_time(200.), location: (0., 0.).to_loc() }),
                         ..create_default_vehicle_shift()
                     },
@@ -114,6 +124,7 @@ fn can_prefer_first_days_with_minimize_arrival_time_objective() {
                             earliest: format_time(*earliest),
                             latest: None,
                             location: (0., 0.).to_loc(),
+                            satellite: None,
                         },
                         end: None,
                         ..create_default_vehicle_shift()
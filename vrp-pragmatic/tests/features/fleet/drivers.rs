@@ -0,0 +1,109 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_limit_tours_by_amount_of_drivers() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_vehicle_with_capacity("my_vehicle", vec![1])
+            }],
+            drivers: Some(vec![create_driver_with_shifts("driver_1", vec![(0., 1000.)])]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].driver_id.as_deref(), Some("driver_1"));
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+}
+
+#[test]
+fn can_use_driver_shift_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            drivers: Some(vec![create_driver_with_shifts("driver_1", vec![(0., 15.)])]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"], vec!["arrival"]]);
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job2"]);
+}
+
+#[test]
+fn can_combine_driver_and_vehicle_skills() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills(
+                "job1",
+                (1., 0.),
+                all_of_skills(vec!["vehicle_skill".to_string(), "driver_skill".to_string()]),
+            )],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                skills: Some(vec!["vehicle_skill".to_string()]),
+                ..create_default_vehicle_type()
+            }],
+            drivers: Some(vec![
+                create_driver_with_shifts("driver_1", vec![(0., 1000.)]),
+                Driver {
+                    skills: Some(vec!["driver_skill".to_string()]),
+                    ..create_driver_with_shifts("driver_2", vec![(0., 1000.)])
+                },
+            ]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].driver_id.as_deref(), Some("driver_2"));
+}
+
+#[test]
+fn can_add_driver_costs_to_tour_statistic() {
+    let create_problem = |drivers: Option<Vec<Driver>>| Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet { drivers, ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let solve = |problem: Problem| {
+        let matrix = create_matrix_from_problem(&problem);
+        solve_with_metaheuristic(problem, Some(vec![matrix])).tours[0].statistic.cost
+    };
+    let driver = Driver {
+        costs: DriverCosts { fixed: Some(5.), time: 2. },
+        ..create_driver_with_shifts("driver_1", vec![(0., 1000.)])
+    };
+
+    let without_driver = solve(create_problem(None));
+    let with_driver = solve(create_problem(Some(vec![driver])));
+
+    // NOTE: tour duration is 11: 10 driving, 1 serving
+    assert_eq!(with_driver - without_driver, 5. + 2. * 11.);
+}
@@ -0,0 +1,71 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_vehicle_with_consumption(id: &str, co2: Float) -> VehicleType {
+    VehicleType {
+        consumption: Some(VehicleConsumption { empty: 1., full: 2., drag: None, co2 }),
+        ..create_default_vehicle(id)
+    }
+}
+
+fn create_objectives() -> Option<Vec<Objective>> {
+    Some(vec![MinimizeUnassigned { breaks: None }, MinimizeTours, MinimizeEmissions, MinimizeCost])
+}
+
+#[test]
+fn can_prefer_vehicle_with_less_emissions() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_consumption("diesel", 3.),
+                create_vehicle_with_consumption("hybrid", 1.),
+            ],
+            ..create_default_fleet()
+        },
+        objectives: create_objectives(),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].type_id, "hybrid");
+    // NOTE one unit of ten is carried to the job, then vehicle returns empty
+    let emissions = solution.tours[0].statistic.emissions.clone().expect("no emissions");
+    assert!((emissions.fuel - (10. * 1.1 + 10.)).abs() < 1E-6);
+    assert!((emissions.co2 - emissions.fuel).abs() < 1E-6);
+    assert_eq!(solution.statistic.emissions, Some(emissions));
+}
+
+#[test]
+fn can_deliver_heavy_job_first() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("light", (0., 10.), vec![1]),
+                create_delivery_job_with_demand("heavy", (10., 0.), vec![8]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_consumption("diesel", 3.)], ..create_default_fleet() },
+        objectives: create_objectives(),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .map(|a| a.job_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["departure", "heavy", "light", "arrival"]
+    );
+}
@@ -1,5 +1,9 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod drivers;
+mod emissions;
 mod multi_dimens;
+mod overtime;
 mod profile_variation;
+mod speed_profiles;
 mod unreachable_jobs;
@@ -0,0 +1,38 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_charge_overtime_after_regular_end() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_duration("job1", (10., 0.), 10.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    overtime: Some(VehicleOvertime {
+                        regular_end: format_time(10.),
+                        rates: vec![VehicleOvertimeRate { after: 0., time: 3. }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let statistic = &solution.tours[0].statistic;
+    assert_eq!(statistic.duration, 30);
+    assert_eq!(statistic.times.overtime, 20);
+    // NOTE fixed + distance + regular time + overtime surcharge
+    assert_eq!(statistic.cost, 10. + 20. + 30. + 20. * 2.);
+    assert_eq!(solution.statistic.times.overtime, 20);
+    let overtimes = solution.tours[0].stops.iter().map(|stop| stop.as_point().and_then(|point| point.overtime));
+    assert_eq!(overtimes.collect::<Vec<_>>(), vec![Some(0), Some(10), Some(20)]);
+}
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_fleet_with_speed_profiles(speed_profiles: Vec<Vec<(&str, Float)>>) -> Fleet {
+    Fleet {
+        profiles: vec![MatrixProfile {
+            name: "car".to_string(),
+            speed: None,
+            speed_profiles: Some(
+                speed_profiles
+                    .into_iter()
+                    .map(|points| MatrixSpeedProfile {
+                        points: points
+                            .into_iter()
+                            .map(|(time, factor)| MatrixSpeedPoint { time: time.to_string(), factor })
+                            .collect(),
+                    })
+                    .collect(),
+            ),
+        }],
+        ..create_default_fleet()
+    }
+}
+
+#[test]
+fn can_use_speed_profile_for_all_edges() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: create_fleet_with_speed_profiles(vec![vec![("1970-01-01T00:00:00Z", 0.5)]]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.statistic.distance, 20);
+    assert_eq!(tour.statistic.duration, 41);
+}
+
+#[test]
+fn can_use_speed_profiles_with_edge_classes() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: create_fleet_with_speed_profiles(vec![
+            vec![("1970-01-01T00:00:00Z", 0.5)],
+            vec![("1970-01-01T00:00:00Z", 1.), ("1970-01-01T00:00:21Z", 1.), ("1970-01-01T00:00:31Z", 2.)],
+        ]),
+        ..create_empty_problem()
+    };
+    // NOTE job location has index 0, depot has index 1: only back to depot edge has class 1
+    let matrix = Matrix { edge_classes: Some(vec![0, 1, 0, 0]), ..create_matrix_from_problem(&problem) };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.statistic.distance, 20);
+    assert_eq!(tour.statistic.duration, 28);
+}
@@ -20,6 +20,7 @@ fn can_use_unknown_location() {
                         earliest: format_time(0.),
                         latest: None,
                         location: Location::Custom { r#type: CustomLocationType::Unknown },
+                        satellite: None,
                     },
                     ..create_default_open_vehicle_shift()
                 }],
@@ -35,6 +36,7 @@ fn can_use_unknown_location() {
         travel_times: vec![0, 5, 5, 0],
         distances: vec![0, 5, 5, 0],
         error_codes: None,
+        edge_classes: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -17,6 +17,7 @@ fn can_use_location_index() {
                         earliest: format_time(0.),
                         latest: None,
                         location: Location::Reference { index: 2 },
+                        satellite: None,
                     },
                     ..create_default_open_vehicle_shift()
                 }],
@@ -32,6 +33,7 @@ fn can_use_location_index() {
         travel_times: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         distances: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         error_codes: None,
+        edge_classes: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -22,6 +22,7 @@ fn can_limit_by_max_distance() {
         travel_times: vec![1, 1, 1, 1],
         distances: vec![1, 100, 100, 1],
         error_codes: None,
+        edge_classes: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -48,7 +49,12 @@ fn can_handle_empty_route() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (10., 0.).to_loc() }),
                     ..create_default_open_vehicle_shift()
                 }],
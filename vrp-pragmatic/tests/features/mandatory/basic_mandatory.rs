@@ -0,0 +1,43 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_prefer_mandatory_job_over_cheaper_one, (is_mandatory, expected_served, expected_unassigned), {
+    can_prefer_mandatory_job_over_cheaper_one_impl(is_mandatory, expected_served, expected_unassigned);
+}}
+
+can_prefer_mandatory_job_over_cheaper_one! {
+    case01_not_mandatory: (None, "job1", "job2"),
+    case02_mandatory: (Some(true), "job2", "job1"),
+}
+
+fn can_prefer_mandatory_job_over_cheaper_one_impl(
+    is_mandatory: Option<bool>,
+    expected_served: &str,
+    expected_unassigned: &str,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                Job { mandatory: is_mandatory, ..create_delivery_job("job2", (5., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![1])], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let served = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(served, vec![expected_served]);
+    let unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(unassigned, vec![expected_unassigned]);
+}
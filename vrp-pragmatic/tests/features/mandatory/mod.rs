@@ -0,0 +1 @@
+mod basic_mandatory;
@@ -1,6 +1,7 @@
 //! This module contains feature tests: minimalistic tests which check features in isolation
 //! and their combination.
 
+mod alternative;
 mod breaks;
 mod capacity;
 mod clustering;
@@ -9,13 +10,20 @@ mod fleet;
 mod format;
 mod group;
 mod limits;
+mod mandatory;
 mod multjob;
+mod periodic;
 mod pickdev;
+mod precedence;
 mod priorities;
 mod recharge;
 mod relations;
 mod reload;
+mod replanning;
+mod site;
 mod skills;
+mod split;
+mod sync;
 mod timing;
 mod tour_shape;
 mod unassigned;
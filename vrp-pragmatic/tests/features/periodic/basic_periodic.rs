@@ -0,0 +1,69 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_periodic_problem(visits: JobVisits, days: usize) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { visits: Some(visits), ..create_delivery_job("job1", (1., 0.)) },
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: (0..days).map(create_vehicle_shift_on_day).collect(),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_assign_visits_on_pattern_days() {
+    let problem =
+        create_periodic_problem(JobVisits { frequency: 2, patterns: Some(vec![vec![0, 2]]), min_spacing: None }, 4);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let mut visit_shifts = solution
+        .tours
+        .iter()
+        .filter(|tour| get_ids_from_tour(tour).iter().flatten().any(|id| id.starts_with("job1_visit_")))
+        .map(|tour| tour.shift_index)
+        .collect::<Vec<_>>();
+    visit_shifts.sort();
+    assert_eq!(visit_shifts, vec![0, 2]);
+}
+
+parameterized_test! {can_unassign_all_visits_when_not_all_fit, (visits, days), {
+    can_unassign_all_visits_when_not_all_fit_impl(visits, days);
+}}
+
+can_unassign_all_visits_when_not_all_fit! {
+    case01_min_spacing: (JobVisits { frequency: 3, patterns: None, min_spacing: Some(2) }, 4),
+    case02_only_one_visit_fits: (JobVisits { frequency: 2, patterns: Some(vec![vec![0, 2]]), min_spacing: None }, 1),
+}
+
+fn can_unassign_all_visits_when_not_all_fit_impl(visits: JobVisits, days: usize) {
+    let frequency = visits.frequency;
+    let problem = create_periodic_problem(visits, days);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(
+        solution
+            .tours
+            .iter()
+            .all(|tour| get_ids_from_tour(tour).iter().flatten().all(|id| !id.starts_with("job1_visit_")))
+    );
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert_eq!(unassigned.len(), frequency);
+    assert!(unassigned.iter().all(|job| job.job_id.starts_with("job1_visit_")));
+    assert!(unassigned.iter().all(|job| job.reasons.iter().all(|reason| reason.code == "PERIODIC_CONSTRAINT")));
+}
@@ -0,0 +1,48 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_follow_vehicle_loading_policy, (policy, job1, job2, expected), {
+    can_follow_vehicle_loading_policy_impl(policy, job1, job2, expected);
+}}
+
+can_follow_vehicle_loading_policy! {
+    case01_lifo: (VehicleLoadingPolicy::Lifo, ((1., 0.), (3., 0.)), ((2., 0.), (4., 0.)), vec!["job1", "job2", "job2", "job1"]),
+    case02_fifo: (VehicleLoadingPolicy::Fifo, ((1., 0.), (4., 0.)), ((2., 0.), (3., 0.)), vec!["job1", "job2", "job1", "job2"]),
+}
+
+type JobLocations = ((f64, f64), (f64, f64));
+
+fn can_follow_vehicle_loading_policy_impl(
+    policy: VehicleLoadingPolicy,
+    job1: JobLocations,
+    job2: JobLocations,
+    expected: Vec<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", job1.0, job1.1),
+                create_pickup_delivery_job("job2", job2.0, job2.1),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { loading_policy: Some(policy), ..create_default_vehicle("my_vehicle") }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.job_id.starts_with("job"))
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids, expected);
+}
@@ -0,0 +1,83 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_job_with_max_ride_time(
+    id: &str,
+    pickup: (f64, f64),
+    delivery: (f64, f64),
+    duration: Option<f64>,
+    factor: Option<f64>,
+) -> Job {
+    Job { max_ride_time: Some(JobMaxRideTime { duration, factor }), ..create_pickup_delivery_job(id, pickup, delivery) }
+}
+
+fn get_job_activity_indices(solution: &Solution, job_id: &str) -> Vec<usize> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .enumerate()
+        .filter(|(_, activity)| activity.job_id == job_id)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+parameterized_test! {can_serve_job_without_detour_within_max_ride_time, (duration, factor), {
+    can_serve_job_without_detour_within_max_ride_time_impl(duration, factor);
+}}
+
+can_serve_job_without_detour_within_max_ride_time! {
+    case01_absolute: (Some(4.), None),
+    case02_relative: (None, Some(1.)),
+}
+
+fn can_serve_job_without_detour_within_max_ride_time_impl(duration: Option<f64>, factor: Option<f64>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_max_ride_time("job1", (1., 0.), (5., 0.), duration, factor),
+                create_pickup_delivery_job("job2", (3., 0.), (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let indices = get_job_activity_indices(&solution, "job1");
+    assert_eq!(indices.len(), 2);
+    assert_eq!(indices[0] + 1, indices[1]);
+}
+
+#[test]
+fn can_unassign_job_when_max_ride_time_is_too_small() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job_with_max_ride_time("job1", (1., 0.), (10., 0.), Some(5.), None)],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "RIDE_TIME_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to max ride time constraint".to_string(),
+                details: None,
+            }]
+        }])
+    );
+}
@@ -1,3 +1,5 @@
 mod basic_pick_dev;
+mod loading_policy;
+mod max_ride_time;
 mod mixed_pick_dev_simple_jobs;
 mod relation_pick_dev;
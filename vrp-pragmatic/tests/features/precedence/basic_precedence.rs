@@ -0,0 +1,120 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+use vrp_core::prelude::Float;
+
+fn create_vehicle_type(type_id: &str, start: (f64, f64)) -> VehicleType {
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vec![format!("{type_id}_1")],
+        shifts: vec![create_default_vehicle_shift_with_locations(start, start)],
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_precedence_problem(jobs: Vec<Job>, vehicles: Vec<VehicleType>, lag: Option<Float>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs,
+            precedences: Some(vec![Precedence { predecessor: "job1".to_string(), successor: "job2".to_string(), lag }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles, ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+/// Returns start and end of the job's service.
+fn get_service_time(solution: &Solution, job_id: &str) -> (Float, Float) {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        })
+        .find(|(_, activity)| activity.job_id == job_id)
+        .map(|(stop, activity)| {
+            // NOTE activity time is omitted when service starts on arrival
+            activity.time.as_ref().map_or_else(
+                || (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)),
+                |time| (parse_time(&time.start), parse_time(&time.end)),
+            )
+        })
+        .expect("cannot find job in solution")
+}
+
+fn get_unassigned_codes(solution: &Solution) -> Vec<(String, String)> {
+    let mut codes = solution
+        .unassigned
+        .iter()
+        .flatten()
+        .map(|job| (job.job_id.clone(), job.reasons.first().map(|reason| reason.code.clone()).unwrap_or_default()))
+        .collect::<Vec<_>>();
+    codes.sort();
+
+    codes
+}
+
+#[test]
+fn can_start_successor_after_predecessor_with_lag() {
+    let problem = create_precedence_problem(
+        vec![create_delivery_job_with_duration("job1", (10., 0.), 10.), create_delivery_job("job2", (95., 0.))],
+        vec![create_vehicle_type("v1", (0., 0.)), create_vehicle_type("v2", (100., 0.))],
+        Some(5.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let ((_, predecessor_end), (successor_start, _)) =
+        (get_service_time(&solution, "job1"), get_service_time(&solution, "job2"));
+    assert!(successor_start >= predecessor_end + 5.);
+}
+
+#[test]
+fn can_unassign_successor_when_lag_cannot_be_respected() {
+    let problem = create_precedence_problem(
+        vec![
+            create_delivery_job_with_duration("job1", (10., 0.), 10.),
+            create_delivery_job_with_times("job2", (12., 0.), vec![(0, 20)], 1.),
+            create_delivery_job("job3", (5., 0.)),
+        ],
+        vec![create_vehicle_type("v1", (0., 0.))],
+        Some(5.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    // NOTE reason depends on insertion order: job2 cannot be served in time after job1 or before it
+    let unassigned = get_unassigned_codes(&solution).into_iter().map(|(job_id, _)| job_id).collect::<Vec<_>>();
+    assert_eq!(unassigned, vec!["job2".to_string()]);
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops.len(), 4);
+}
+
+#[test]
+fn can_unassign_successor_without_predecessor() {
+    let problem = create_precedence_problem(
+        vec![
+            create_delivery_job_with_times("job1", (10., 0.), vec![(0, 1)], 1.),
+            create_delivery_job("job2", (12., 0.)),
+            create_delivery_job("job3", (5., 0.)),
+        ],
+        vec![create_vehicle_type("v1", (0., 0.))],
+        None,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        get_unassigned_codes(&solution),
+        vec![
+            ("job1".to_string(), "TIME_WINDOW_CONSTRAINT".to_string()),
+            ("job2".to_string(), "PRECEDENCE_CONSTRAINT".to_string())
+        ]
+    );
+}
@@ -146,6 +146,8 @@ fn can_handle_order_between_special_activities() {
             places: vec![JobPlace { times: None, location: location.to_loc(), duration: 100., tag: None }],
             demand: Some(vec![1]),
             order: Some(order),
+            product: None,
+            items: None,
         }]),
         ..create_job(id)
     };
@@ -0,0 +1,74 @@
+use crate::format::problem::*;
+use crate::format::solution::Stop;
+use crate::helpers::*;
+use crate::parse_time;
+use vrp_core::prelude::Float;
+
+fn create_battery_problem(initial: Float, charging_curve: Option<Vec<(Float, Float)>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (30., 0.)), create_delivery_job("job2", (70., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: None,
+                        battery: Some(VehicleBattery {
+                            capacity: 60.,
+                            initial: Some(initial),
+                            reserve: Some(5.),
+                            consumption: VehicleEnergyConsumption { empty: 1., full: None },
+                        }),
+                        stations: vec![VehicleRechargeStation {
+                            location: (50., 0.).to_loc(),
+                            duration: 10.,
+                            times: None,
+                            tag: None,
+                            charging_curve: charging_curve.map(|curve| {
+                                curve.into_iter().map(|(soc, power)| VehicleChargingPoint { soc, power }).collect()
+                            }),
+                            resource_id: None,
+                        }],
+                    }),
+                    ..create_default_vehicle_shift_with_locations((0., 0.), (100., 0.))
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_states_of_charge(stops: &[Stop]) -> Vec<Option<Float>> {
+    stops.iter().map(|stop| stop.as_point().and_then(|point| point.state_of_charge)).collect()
+}
+
+parameterized_test! {can_charge_only_required_energy, (charging_curve, expected_duration), {
+    can_charge_only_required_energy_impl(charging_curve, expected_duration);
+}}
+
+can_charge_only_required_energy! {
+    case01_no_curve: (None, 10.),
+    case02_constant_power: (Some(vec![(0., 3600.)]), 60.),
+    case03_half_power: (Some(vec![(0., 1800.), (1., 1800.)]), 110.),
+}
+
+fn can_charge_only_required_energy_impl(charging_curve: Option<Vec<(Float, Float)>>, expected_duration: Float) {
+    let problem = create_battery_problem(55., charging_curve);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    assert_eq!(
+        get_ids_from_tour(tour),
+        vec![vec!["departure"], vec!["job1"], vec!["recharge"], vec!["job2"], vec!["arrival"]]
+    );
+    assert_eq!(get_states_of_charge(&tour.stops), vec![Some(55.), Some(25.), Some(55.), Some(35.), Some(5.)]);
+    let recharge = tour.stops[2].schedule();
+    assert_eq!(parse_time(&recharge.departure) - parse_time(&recharge.arrival), expected_duration);
+}
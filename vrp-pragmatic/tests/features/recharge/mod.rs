@@ -1 +1,3 @@
 mod basic_recharge;
+mod battery_recharge;
+mod shared_chargers;
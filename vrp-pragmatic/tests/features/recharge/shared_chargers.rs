@@ -0,0 +1,58 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+#[test]
+fn can_wait_for_free_charger_at_shared_station() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (30., 0.)),
+                create_delivery_job("job2", (70., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+                create_delivery_job("job4", (70., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: Some(55.),
+                        battery: None,
+                        stations: vec![VehicleRechargeStation {
+                            location: (50., 0.).to_loc(),
+                            duration: 100.,
+                            times: None,
+                            tag: None,
+                            charging_curve: None,
+                            resource_id: Some("station".to_string()),
+                        }],
+                    }),
+                    ..create_default_vehicle_shift_with_locations((0., 0.), (100., 0.))
+                }],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(vec![VehicleResource::Recharge { id: "station".to_string(), chargers: 1 }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let departures = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter(|stop| stop.activities().iter().any(|activity| activity.activity_type == "recharge"))
+        .map(|stop| parse_time(&stop.schedule().departure))
+        .collect::<Vec<_>>();
+    assert_eq!(departures.len(), 2);
+    assert!((departures[0] - departures[1]).abs() >= 100.);
+}
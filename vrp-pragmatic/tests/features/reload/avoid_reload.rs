@@ -27,7 +27,12 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -36,6 +41,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -35,7 +35,12 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -44,6 +49,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -18,7 +18,12 @@ fn can_use_reloads_with_different_locations() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (32., 0.).to_loc() }),
                     breaks: None,
                     reloads: Some(vec![
@@ -36,6 +41,7 @@ fn can_use_reloads_with_different_locations() {
                         },
                     ]),
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -6,3 +6,4 @@ mod multi_job_reload;
 mod multi_vehicle_reload;
 mod picks_devs_reload;
 mod shared_reload;
+mod two_echelon_reload;
@@ -15,7 +15,12 @@ fn can_use_multi_dim_capacity() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
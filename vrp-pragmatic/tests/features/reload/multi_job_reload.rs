@@ -22,7 +22,12 @@ fn can_serve_multi_job_and_delivery_with_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (10., 0.).to_loc() }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -31,6 +36,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -18,7 +18,12 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (6., 0.).to_loc() }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -27,6 +32,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -0,0 +1,118 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+use std::iter::once;
+
+const SATELLITE_CODE: &str = "SATELLITE_CONSTRAINT";
+const SKILL_CODE: &str = "SKILL_CONSTRAINT";
+
+fn create_supply_job(demand: i32) -> Job {
+    Job { satellite: Some("s1".to_string()), ..create_delivery_job_with_demand("supply", (10., 0.), vec![demand]) }
+}
+
+fn create_customer_job(id: &str, location: (f64, f64), demand: i32) -> Job {
+    Job {
+        skills: Some(all_of_skills(vec!["micro".to_string()])),
+        ..create_delivery_job_with_demand(id, location, vec![demand])
+    }
+}
+
+fn create_two_echelon_problem(supply: i32, shift_start: f64, customers: Vec<i32>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: once(create_supply_job(supply))
+                .chain(customers.into_iter().enumerate().map(|(idx, demand)| {
+                    create_customer_job(format!("c{}", idx + 1).as_str(), (12. + idx as f64, 0.), demand)
+                }))
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("hub"),
+                VehicleType {
+                    shifts: vec![VehicleShift {
+                        start: ShiftStart {
+                            earliest: format_time(shift_start),
+                            latest: None,
+                            location: (10., 0.).to_loc(),
+                            satellite: Some("s1".to_string()),
+                        },
+                        ..create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))
+                    }],
+                    skills: Some(vec!["micro".to_string()]),
+                    ..create_default_vehicle("micro")
+                },
+            ],
+            resources: Some(vec![VehicleResource::Satellite { id: "s1".to_string(), location: (10., 0.).to_loc() }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_reasons(solution: &Solution) -> Vec<(String, Vec<String>)> {
+    solution
+        .unassigned
+        .iter()
+        .flat_map(|unassigned| unassigned.iter())
+        .map(|u_job| {
+            let mut codes = u_job.reasons.iter().map(|reason| reason.code.clone()).collect::<Vec<_>>();
+            codes.sort();
+            (u_job.job_id.clone(), codes)
+        })
+        .collect()
+}
+
+fn get_job_ids(solution: &Solution, vehicle_id: &str) -> Vec<String> {
+    let mut job_ids = solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect::<Vec<_>>();
+    job_ids.sort();
+
+    job_ids
+}
+
+parameterized_test! {can_serve_customers_from_satellite, (supply, shift_start, customers, expected_micro, expected_unassigned), {
+    can_serve_customers_from_satellite_impl(supply, shift_start, customers, expected_micro, expected_unassigned);
+}}
+
+can_serve_customers_from_satellite! {
+    case01_enough_supply: (5, 20., vec![2, 3], vec!["c1", "c2"], vec![]),
+    case02_not_enough_supply: (3, 20., vec![2, 3], vec!["c1"], vec![("c2", vec![SATELLITE_CODE, SKILL_CODE])]),
+    // NOTE micro vehicle is not used, so its route is not considered when unassignment reason is detected
+    case03_supply_is_late: (5, 5., vec![2], vec![], vec![("c1", vec![SKILL_CODE])]),
+}
+
+fn can_serve_customers_from_satellite_impl(
+    supply: i32,
+    shift_start: f64,
+    customers: Vec<i32>,
+    expected_micro: Vec<&str>,
+    expected_unassigned: Vec<(&str, Vec<&str>)>,
+) {
+    let problem = create_two_echelon_problem(supply, shift_start, customers);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_job_ids(&solution, "hub_1"), vec!["supply".to_string()]);
+    assert_eq!(
+        get_job_ids(&solution, "micro_1"),
+        expected_micro.into_iter().map(|id| id.to_string()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        get_reasons(&solution),
+        expected_unassigned
+            .into_iter()
+            .map(|(id, codes)| (id.to_string(), codes.into_iter().map(|code| code.to_string()).collect()))
+            .collect::<Vec<(String, Vec<String>)>>()
+    );
+}
@@ -0,0 +1,57 @@
+use crate::format::problem::*;
+use crate::format::replanning::*;
+use crate::format_time;
+use crate::helpers::*;
+use std::sync::Arc;
+
+#[test]
+fn can_replan_in_flight_tour_with_new_and_cancelled_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (8., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    let tour = solution.tours.iter().find(|tour| get_ids_from_tour(tour).concat().contains(&"job2".to_string()));
+    let tour = tour.expect("cannot find tour with job2");
+    let state = ExecutionState {
+        time: format_time(2.),
+        vehicles: vec![VehicleState {
+            vehicle_id: tour.vehicle_id.clone(),
+            shift_index: None,
+            location: (2., 0.).to_loc(),
+            completed: vec![CompletedActivity { job_id: "job2".to_string(), activity_type: "pickup".to_string() }],
+        }],
+        new_jobs: Some(vec![create_delivery_job("job5", (5., 0.))]),
+        cancelled_jobs: Some(vec!["job4".to_string()]),
+    };
+
+    let (problem, init_solution) = create_replanning_problem(&problem, &solution, &state).unwrap();
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap());
+    assert!(to_core_solution(&init_solution, core_problem, create_random()).is_ok());
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let job_ids = solution.tours.iter().flat_map(get_ids_from_tour).flatten().collect::<Vec<_>>();
+    assert!(job_ids.contains(&"job5".to_string()));
+    assert!(!job_ids.contains(&"job4".to_string()));
+    let job2_tour = solution.tours.iter().find(|tour| get_ids_from_tour(tour).concat().contains(&"job2".to_string()));
+    assert_eq!(job2_tour.map(|tour| tour.vehicle_id.as_str()), Some(state.vehicles[0].vehicle_id.as_str()));
+    assert_eq!(job2_tour.unwrap().stops[0].schedule().departure, format_time(2.));
+}
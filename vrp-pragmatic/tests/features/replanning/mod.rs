@@ -0,0 +1,2 @@
+mod basic_replanning;
+mod solution_stability;
@@ -0,0 +1,83 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::Solution;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_reference_tour(vehicle_id: &str, jobs: &[&str]) -> ReferenceTour {
+    ReferenceTour {
+        vehicle_id: vehicle_id.to_string(),
+        shift_index: None,
+        jobs: jobs.iter().map(|job_id| job_id.to_string()).collect(),
+    }
+}
+
+fn get_tour_ids(solution: &Solution, vehicle_id: &str) -> Option<Vec<String>> {
+    solution
+        .tours
+        .iter()
+        .find(|tour| tour.vehicle_id == vehicle_id)
+        .map(|tour| get_ids_from_tour(tour).into_iter().flatten().collect())
+}
+
+fn create_deviation_objective(weight: Float) -> Objective {
+    let reference = vec![
+        create_reference_tour("my_vehicle_1", &["job3", "job1"]),
+        create_reference_tour("my_vehicle_2", &["job2", "job4"]),
+    ];
+
+    MinimizeDeviation { reference, weight }
+}
+
+fn create_test_problem(objectives: Vec<Objective>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+    }
+}
+
+#[test]
+fn can_keep_reference_plan_when_deviation_is_prioritized_over_cost() {
+    let problem =
+        create_test_problem(vec![MinimizeUnassigned { breaks: None }, create_deviation_objective(1.), MinimizeCost]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_tour_ids(&solution, "my_vehicle_1"),
+        Some(vec!["departure".to_string(), "job3".to_string(), "job1".to_string(), "arrival".to_string()])
+    );
+    assert_eq!(
+        get_tour_ids(&solution, "my_vehicle_2"),
+        Some(vec!["departure".to_string(), "job2".to_string(), "job4".to_string(), "arrival".to_string()])
+    );
+}
+
+#[test]
+fn can_prefer_cost_over_reference_plan_when_cost_is_prioritized() {
+    let problem =
+        create_test_problem(vec![MinimizeUnassigned { breaks: None }, MinimizeCost, create_deviation_objective(1.)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+}
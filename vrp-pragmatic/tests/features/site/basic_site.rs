@@ -0,0 +1,105 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+use vrp_core::prelude::Float;
+
+fn create_site_problem(jobs: Vec<Job>, site: Site, vehicle_ids: Vec<&str>, capacity: i32) -> Problem {
+    Problem {
+        plan: Plan { jobs, sites: Some(vec![site]), ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+                shifts: vec![VehicleShift { end: None, ..create_default_vehicle_shift() }],
+                capacity: vec![capacity],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_site_activity_times(solution: &Solution) -> Vec<(Float, Float)> {
+    let mut times = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.job_id.starts_with("job"))
+        .map(|(stop, activity)| {
+            let time = activity.time.clone().unwrap_or_else(|| Interval {
+                start: stop.schedule().arrival.clone(),
+                end: stop.schedule().departure.clone(),
+            });
+            (parse_time(&time.start), parse_time(&time.end))
+        })
+        .collect::<Vec<_>>();
+    times.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    times
+}
+
+#[test]
+fn can_apply_setup_time_once_per_stop() {
+    let problem = create_site_problem(
+        vec![
+            create_delivery_job_with_duration("job1", (10., 0.), 1.),
+            create_delivery_job_with_duration("job2", (10., 0.), 1.),
+        ],
+        Site { location: (10., 0.).to_loc(), opening_hours: None, max_vehicles: None, setup_time: Some(5.) },
+        vec!["v1"],
+        10,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let stop = &solution.tours[0].stops[1];
+    assert_eq!(stop.activities().len(), 2);
+    assert_eq!(parse_time(&stop.schedule().arrival), 10.);
+    assert_eq!(parse_time(&stop.schedule().departure), 17.);
+}
+
+#[test]
+fn can_limit_number_of_vehicles_at_site() {
+    let problem = create_site_problem(
+        vec![
+            create_delivery_job_with_duration("job1", (10., 0.), 10.),
+            create_delivery_job_with_duration("job2", (10., 0.), 10.),
+        ],
+        Site { location: (10., 0.).to_loc(), opening_hours: None, max_vehicles: Some(1), setup_time: None },
+        vec!["v1", "v2"],
+        1,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(get_site_activity_times(&solution), vec![(10., 20.), (20., 30.)]);
+}
+
+#[test]
+fn can_serve_jobs_within_site_opening_hours() {
+    let problem = create_site_problem(
+        vec![create_delivery_job_with_duration("job1", (10., 0.), 1.)],
+        Site {
+            location: (10., 0.).to_loc(),
+            opening_hours: Some(vec![vec![format_time(20.), format_time(100.)]]),
+            max_vehicles: None,
+            setup_time: None,
+        },
+        vec!["v1"],
+        10,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_site_activity_times(&solution), vec![(20., 21.)]);
+}
@@ -0,0 +1,85 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_split_problem(demand: i32, split: JobSplit) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![Job { split: Some(split), ..create_delivery_job_with_demand("job1", (1., 0.), vec![demand]) }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_served_quantities(solution: &Solution) -> Vec<i32> {
+    let mut quantities = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| activity.job_id.starts_with("job1_split_"))
+                .flat_map(|activity| activity.quantity.clone().unwrap_or_default())
+                .sum::<i32>()
+        })
+        .collect::<Vec<_>>();
+    quantities.sort();
+
+    quantities
+}
+
+#[test]
+fn can_split_demand_between_vehicles() {
+    let problem = create_split_problem(15, JobSplit { min_quantity: Some(5), max_splits: None });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_served_quantities(&solution), vec![5, 10]);
+}
+
+#[test]
+fn can_split_demand_which_exceeds_twice_vehicle_capacity() {
+    let mut problem = create_split_problem(25, JobSplit { min_quantity: Some(5), max_splits: None });
+    problem.fleet.vehicles[0].vehicle_ids.push("v3".to_string());
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_served_quantities(&solution), vec![5, 10, 10]);
+}
+
+#[test]
+fn can_serve_split_job_by_one_vehicle_when_it_fits() {
+    let problem = create_split_problem(8, JobSplit { min_quantity: Some(2), max_splits: None });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_served_quantities(&solution), vec![8]);
+}
+
+#[test]
+fn can_unassign_split_parts_due_to_max_splits() {
+    let problem = create_split_problem(15, JobSplit { min_quantity: Some(5), max_splits: Some(1) });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert_eq!(unassigned.len(), 1);
+    assert!(unassigned[0].job_id.starts_with("job1_split_"));
+}
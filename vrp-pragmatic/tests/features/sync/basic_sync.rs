@@ -0,0 +1,108 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_vehicle_type(type_id: &str, start: (f64, f64)) -> VehicleType {
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vec![format!("{type_id}_1")],
+        shifts: vec![create_default_vehicle_shift_with_locations(start, start)],
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_sync_problem(vehicles: Vec<VehicleType>, tolerance: Option<Float>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![Job { sync: Some(JobSync { vehicles: 2, tolerance }), ..create_service_job("job1", (10., 0.)) }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles, ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+fn get_service_starts(solution: &Solution) -> Vec<String> {
+    let mut starts = solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        })
+        .filter(|(_, activity)| activity.job_id.starts_with("job1_sync_"))
+        .map(|(stop, activity)| {
+            // NOTE activity time is omitted when service starts on arrival
+            activity.time.as_ref().map_or_else(|| stop.schedule().arrival.clone(), |time| time.start.clone())
+        })
+        .collect::<Vec<_>>();
+    starts.sort();
+
+    starts
+}
+
+#[test]
+fn can_serve_job_by_two_vehicles_at_the_same_time() {
+    let problem =
+        create_sync_problem(vec![create_vehicle_type("v1", (0., 0.)), create_vehicle_type("v2", (5., 0.))], None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(get_service_starts(&solution), vec![format_time(10.), format_time(10.)]);
+}
+
+#[test]
+fn can_serve_job_by_two_vehicles_within_tolerance() {
+    let problem =
+        create_sync_problem(vec![create_vehicle_type("v1", (0., 0.)), create_vehicle_type("v2", (5., 0.))], Some(3.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_service_starts(&solution), vec![format_time(7.), format_time(10.)]);
+}
+
+#[test]
+fn can_unassign_job_when_not_enough_vehicles() {
+    let problem = create_sync_problem(vec![create_vehicle_type("v1", (0., 0.))], None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert!(solution.tours.is_empty());
+    assert_eq!(unassigned.len(), 2);
+    assert!(unassigned.iter().all(|job| job.reasons.iter().any(|reason| reason.code == "SYNC_CONSTRAINT")));
+}
+
+#[test]
+fn can_serve_multiple_sync_jobs_with_other_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { sync: Some(JobSync { vehicles: 2, tolerance: None }), ..create_service_job("job1", (10., 0.)) },
+                Job { sync: Some(JobSync { vehicles: 2, tolerance: None }), ..create_service_job("job2", (20., 0.)) },
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (7., 0.)),
+                create_delivery_job("job5", (15., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type("v1", (0., 0.)), create_vehicle_type("v2", (5., 0.))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+}
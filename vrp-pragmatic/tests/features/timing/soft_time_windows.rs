@@ -0,0 +1,99 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_problem_with_late_job(max_lateness: Option<Float>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 10)], 0.),
+                create_delivery_job_with_times("job2", (20., 0.), vec![(10, 20)], 0.),
+                create_delivery_job_with_times("job3", (30., 0.), vec![(20, 30)], 0.),
+                create_delivery_job_with_times("job4", (40., 0.), vec![(30, 40)], 0.),
+                Job {
+                    soft_time_windows: Some(JobSoftTimeWindows {
+                        earliness: None,
+                        lateness: Some(1.),
+                        max_earliness: None,
+                        max_lateness,
+                    }),
+                    ..create_delivery_job_with_times("job5", (50., 0.), vec![(0, 10)], 0.)
+                },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+fn get_lateness(solution: &Solution, job_id: &str) -> Option<i64> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .find(|activity| activity.job_id == job_id)
+        .and_then(|activity| activity.lateness)
+}
+
+#[test]
+fn can_serve_job_late_with_soft_time_window() {
+    let problem = create_problem_with_late_job(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_lateness(&solution, "job5"), Some(40));
+    assert!(["job1", "job2", "job3", "job4"].iter().all(|job_id| get_lateness(&solution, job_id).is_none()));
+}
+
+#[test]
+fn can_have_unassigned_job_because_of_max_lateness() {
+    let problem = create_problem_with_late_job(Some(30.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job5"]);
+    assert_eq!(solution.unassigned.iter().flatten().next().unwrap().reasons[0].code, "TIME_WINDOW_CONSTRAINT");
+}
+
+parameterized_test! {can_wait_for_soft_time_window_when_waiting_is_cheaper, (earliness, expected_start), {
+    can_wait_for_soft_time_window_when_waiting_is_cheaper_impl(earliness, expected_start);
+}}
+
+can_wait_for_soft_time_window_when_waiting_is_cheaper! {
+    case01_waiting_is_cheaper: (10., 50.),
+    case02_waiting_is_expensive: (0.5, 10.),
+}
+
+fn can_wait_for_soft_time_window_when_waiting_is_cheaper_impl(earliness: Float, expected_start: Float) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                soft_time_windows: Some(JobSoftTimeWindows {
+                    earliness: Some(earliness),
+                    lateness: None,
+                    max_earliness: Some(50.),
+                    max_lateness: None,
+                }),
+                ..create_delivery_job_with_times("job1", (10., 0.), vec![(50, 60)], 0.)
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let stop = solution.tours[0].stops.get(1).unwrap();
+    assert_eq!(stop.schedule().departure, format_time(expected_start));
+}
@@ -93,7 +93,12 @@ pub fn default_costs_prototype() -> impl Strategy<Value = VehicleCosts> {
 pub fn default_shift_places_prototype() -> impl Strategy<Value = (ShiftStart, Option<ShiftEnd>)> {
     generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
         Just((
-            ShiftStart { earliest: default_time_plus_offset(9), latest: None, location: location.clone() },
+            ShiftStart {
+                earliest: default_time_plus_offset(9),
+                latest: None,
+                location: location.clone(),
+                satellite: None,
+            },
             Some(ShiftEnd { earliest: None, latest: default_time_plus_offset(18), location }),
         ))
     })
@@ -76,7 +76,7 @@ prop_compose! {
                         tag: Some("p1".to_owned()),
                         ..pickup
                     }
-                ], demand: demand.clone(), order }
+                ], demand: demand.clone(), order, product: None, items: None, }
             ]),
             deliveries: Some(vec![
              JobTask { places: vec![
@@ -84,15 +84,16 @@ prop_compose! {
                         tag: Some("d1".to_owned()),
                         ..delivery
                     }
-                ], demand, order: None }
+                ], demand, order: None, product: None, items: None, }
             ]),
             replacements: None,
             services: None,
             skills,
             value,
             group,
-            compatibility
-        }
+            compatibility,
+            visits: None, satellite: None, soft_time_windows: None, split: None, max_ride_time: None, sync: None,
+            mandatory: None, }
     }
 }
 
@@ -138,7 +139,8 @@ prop_compose! {
             value,
             group,
             compatibility,
-        }
+            visits: None, satellite: None, soft_time_windows: None, split: None, max_ride_time: None, sync: None,
+            mandatory: None, }
     }
 }
 
@@ -153,7 +155,7 @@ prop_compose! {
      demand in demand_proto,
      order in order_proto,
     ) -> JobTask {
-       JobTask { places: vec![place], demand, order }
+       JobTask { places: vec![place], demand, order, product: None, items: None, }
     }
 }
 
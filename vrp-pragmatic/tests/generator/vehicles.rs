@@ -33,8 +33,7 @@ prop_compose! {
             shifts,
             capacity,
             skills,
-            limits,
-        }
+            limits, compartments: None, hours_of_service: None, consumption: None, loading_policy: None, cargo_space: None, }
     }
 }
 
@@ -79,6 +78,8 @@ prop_compose! {
           location,
           duration,
           tag,
+          charging_curve: None,
+          resource_id: None,
         }
     }
 }
@@ -109,8 +110,7 @@ prop_compose! {
           end: places.1,
           breaks,
           reloads,
-          recharges,
-        }
+          recharges, overtime: None, }
     }
 }
 
@@ -130,7 +130,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None }
+        Fleet { vehicles, drivers: None, profiles, resources: None }
     }
 }
 
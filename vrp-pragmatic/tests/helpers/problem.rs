@@ -11,7 +11,13 @@ pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
-    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None }
+    JobTask {
+        places: vec![create_job_place(location, tag)],
+        demand: Some(vec![1]),
+        order: None,
+        product: None,
+        items: None,
+    }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -25,6 +31,13 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        visits: None,
+        satellite: None,
+        soft_time_windows: None,
+        split: None,
+        max_ride_time: None,
+        sync: None,
+        mandatory: None,
     }
 }
 
@@ -38,6 +51,8 @@ pub fn create_delivery_job_with_order(id: &str, location: (f64, f64), order: i32
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: Some(order),
+            product: None,
+            items: None,
         }]),
         ..create_job(id)
     }
@@ -49,6 +64,8 @@ pub fn create_delivery_job_with_group(id: &str, location: (f64, f64), group: &st
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            product: None,
+            items: None,
         }]),
         group: Some(group.to_string()),
         ..create_job(id)
@@ -61,6 +78,8 @@ pub fn create_delivery_job_with_compatibility(id: &str, location: (f64, f64), co
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            product: None,
+            items: None,
         }]),
         compatibility: Some(compatibility.to_string()),
         ..create_job(id)
@@ -81,6 +100,8 @@ pub fn create_delivery_job_with_duration(id: &str, location: (f64, f64), duratio
             places: vec![JobPlace { duration, ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            product: None,
+            items: None,
         }]),
         ..create_job(id)
     }
@@ -97,6 +118,8 @@ pub fn create_delivery_job_with_times(
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            product: None,
+            items: None,
         }]),
         ..create_job(id)
     }
@@ -145,6 +168,8 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             order: None,
+            product: None,
+            items: None,
         }]),
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace {
@@ -154,6 +179,8 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand),
             order: None,
+            product: None,
+            items: None,
         }]),
 
         ..create_job(id)
@@ -166,6 +193,8 @@ pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
             places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
             demand: Some(vec![1]),
             order: None,
+            product: None,
+            items: None,
         }]),
         ..create_job(id)
     }
@@ -187,6 +216,8 @@ pub fn create_multi_job(
                 }],
                 demand: Some(demand),
                 order: None,
+                product: None,
+                items: None,
             })
             .collect::<Vec<_>>();
 
@@ -206,21 +237,38 @@ pub fn create_default_vehicle_shift() -> VehicleShift {
 
 pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc(), satellite: None },
         end: None,
         breaks: None,
         reloads: None,
         recharges: None,
+        overtime: None,
     }
 }
 
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: (start.0, start.1).to_loc() },
+        start: ShiftStart {
+            earliest: format_time(0.),
+            latest: None,
+            location: (start.0, start.1).to_loc(),
+            satellite: None,
+        },
         end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (end.0, end.1).to_loc() }),
         breaks: None,
         reloads: None,
         recharges: None,
+        overtime: None,
+    }
+}
+
+pub fn create_vehicle_shift_on_day(day: usize) -> VehicleShift {
+    let start = day as Float * 86400.;
+
+    VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc(), satellite: None },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(start + 1000.), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
     }
 }
 
@@ -254,15 +302,37 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        compartments: None,
+        hours_of_service: None,
+        consumption: None,
+        loading_policy: None,
+        cargo_space: None,
+    }
+}
+
+pub fn create_driver_with_shifts(id: &str, shifts: Vec<(Float, Float)>) -> Driver {
+    Driver {
+        id: id.to_string(),
+        costs: DriverCosts { fixed: None, time: 1. },
+        shifts: shifts
+            .into_iter()
+            .map(|(earliest, latest)| DriverShift { earliest: format_time(earliest), latest: format_time(latest) })
+            .collect(),
+        skills: None,
     }
 }
 
 pub fn create_default_fleet() -> Fleet {
-    Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles(), resources: None }
+    Fleet {
+        vehicles: vec![create_default_vehicle_type()],
+        drivers: None,
+        profiles: create_default_matrix_profiles(),
+        resources: None,
+    }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile { name: "car".to_string(), speed: None, speed_profiles: None }]
 }
 
 pub fn create_min_jobs_cost_objective() -> Option<Vec<Objective>> {
@@ -270,13 +340,13 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Objective>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, precedences: None, alternatives: None, sites: None, clustering: None }
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: create_empty_plan(),
-        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![], drivers: None, profiles: vec![], resources: None },
         objectives: None,
     }
 }
@@ -292,6 +362,7 @@ pub fn create_matrix(data: Vec<i64>) -> Matrix {
         travel_times: data.clone(),
         distances: data,
         error_codes: None,
+        edge_classes: None,
     }
 }
 
@@ -26,6 +26,7 @@ impl StopBuilder {
                 time: Schedule { arrival: format_time(0.), departure: format_time(0.) },
                 load: vec![],
                 activities: vec![],
+                compartments: None,
             }),
         }
     }
@@ -68,6 +69,26 @@ impl StopBuilder {
         self
     }
 
+    pub fn compartments(mut self, compartments: Vec<(&str, Vec<i32>)>) -> Self {
+        let compartments =
+            compartments.into_iter().map(|(id, load)| CompartmentLoad { id: id.to_string(), load }).collect();
+        match &mut self.stop {
+            Stop::Point(point) => point.compartments = Some(compartments),
+            Stop::Transit(transit) => transit.compartments = Some(compartments),
+        }
+
+        self
+    }
+
+    pub fn state_of_charge(mut self, state_of_charge: Float) -> Self {
+        match &mut self.stop {
+            Stop::Point(point) => point.state_of_charge = Some(state_of_charge),
+            Stop::Transit(_) => panic!("transit stop has no state of charge"),
+        }
+
+        self
+    }
+
     pub fn schedule_stamp(mut self, arrival: Timestamp, departure: Timestamp) -> Self {
         *self.stop.schedule_mut() = Schedule { arrival: format_time(arrival), departure: format_time(departure) };
 
@@ -171,6 +192,9 @@ impl Default for StopBuilder {
                 load: vec![],
                 parking: None,
                 activities: vec![],
+                compartments: None,
+                state_of_charge: None,
+                overtime: None,
             }),
         }
     }
@@ -239,6 +263,12 @@ impl ActivityBuilder {
         self
     }
 
+    pub fn compartment(mut self, compartment: &str) -> Self {
+        self.activity.compartment = Some(compartment.to_string());
+
+        self
+    }
+
     pub fn build(self) -> Activity {
         if self.activity.activity_type.is_empty() {
             panic!("missing activity type");
@@ -262,6 +292,9 @@ impl Default for ActivityBuilder {
                 time: None,
                 job_tag: None,
                 commute: None,
+                compartment: None,
+                lateness: None,
+                quantity: None,
             },
         }
     }
@@ -370,8 +403,11 @@ impl Default for TourBuilder {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![],
                 statistic: Default::default(),
+                hours_of_service: None,
+                loading_plan: None,
             },
         }
     }
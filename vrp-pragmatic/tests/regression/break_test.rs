@@ -25,6 +25,8 @@ fn can_handle_properly_invalid_break_removal() {
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            product: None,
+                            items: None,
                         }]),
                         ..create_job("job1")
                     },
@@ -41,6 +43,8 @@ fn can_handle_properly_invalid_break_removal() {
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            product: None,
+                            items: None,
                         }]),
                         ..create_job("job2")
                     },
@@ -57,6 +61,8 @@ fn can_handle_properly_invalid_break_removal() {
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            product: None,
+                            items: None,
                         }]),
                         ..create_job("job3")
                     },
@@ -73,6 +79,8 @@ fn can_handle_properly_invalid_break_removal() {
                             }],
                             demand: Some(vec![2]),
                             order: None,
+                            product: None,
+                            items: None,
                         }]),
                         ..create_job("job4")
                     },
@@ -89,6 +97,8 @@ fn can_handle_properly_invalid_break_removal() {
                             }],
                             demand: Some(vec![3]),
                             order: None,
+                            product: None,
+                            items: None,
                         }]),
                         ..create_job("job5")
                     },
@@ -105,6 +115,8 @@ fn can_handle_properly_invalid_break_removal() {
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            product: None,
+                            items: None,
                         }]),
                         ..create_job("job6")
                     },
@@ -122,6 +134,7 @@ fn can_handle_properly_invalid_break_removal() {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
                             latest: None,
                             location: Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 },
+                            satellite: None,
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
@@ -138,10 +151,16 @@ fn can_handle_properly_invalid_break_removal() {
                         }]),
                         reloads: None,
                         recharges: None,
+                        overtime: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    hours_of_service: None,
+                    consumption: None,
+                    loading_policy: None,
+                    cargo_space: None,
                 }],
                 ..create_default_fleet()
             },
@@ -1,6 +1,7 @@
 use super::*;
 use crate::helpers::*;
 use vrp_core::models::examples::create_example_problem;
+use vrp_core::prelude::Float;
 
 parameterized_test! {check_vehicles, (known_ids, tours, expected_result), {
     check_vehicles_impl(known_ids, tours, expected_result);
@@ -31,8 +32,11 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 vehicle_id: id.to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index,
+                driver_id: None,
                 stops: vec![],
                 statistic: Statistic::default(),
+                hours_of_service: None,
+                loading_plan: None,
             })
             .collect(),
         ..SolutionBuilder::default().build()
@@ -44,6 +48,67 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
     assert_eq!(result.map_err(|_| ()), expected_result);
 }
 
+parameterized_test! {check_drivers, (has_drivers, tours, expected_result), {
+    check_drivers_impl(has_drivers, tours, expected_result);
+}}
+
+check_drivers! {
+    case_01: (false, vec![(None, (0., 10.))], Ok(())),
+    case_02: (false, vec![(Some("driver_1"), (0., 10.))], Err(())),
+    case_03: (true, vec![(Some("driver_1"), (0., 10.))], Ok(())),
+    case_04: (true, vec![(None, (0., 10.))], Err(())),
+    case_05: (true, vec![(Some("driver_3"), (0., 10.))], Err(())),
+    case_06: (true, vec![(Some("driver_1"), (0., 10.)), (Some("driver_1"), (20., 30.))], Ok(())),
+    case_07: (true, vec![(Some("driver_1"), (0., 10.)), (Some("driver_1"), (0., 15.))], Err(())),
+    case_08: (true, vec![(Some("driver_2"), (0., 10.)), (Some("driver_1"), (0., 15.))], Ok(())),
+    case_09: (true, vec![(Some("driver_2"), (0., 20.))], Err(())),
+}
+
+fn check_drivers_impl(has_drivers: bool, tours: Vec<(Option<&str>, (Float, Float))>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            drivers: if has_drivers {
+                Some(vec![
+                    create_driver_with_shifts("driver_1", vec![(0., 15.), (20., 30.)]),
+                    create_driver_with_shifts("driver_2", vec![(0., 15.)]),
+                ])
+            } else {
+                None
+            },
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: tours
+            .into_iter()
+            .map(|(driver_id, (start, end))| Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                driver_id: driver_id.map(|id| id.to_string()),
+                stops: vec![
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(start, start)
+                        .load(vec![0])
+                        .build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(end, end).load(vec![0]).build_arrival(),
+                ],
+                statistic: Statistic::default(),
+                hours_of_service: None,
+                loading_plan: None,
+            })
+            .collect(),
+        ..SolutionBuilder::default().build()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_drivers(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
 parameterized_test! {check_jobs, (jobs, tours, unassigned, expected_result), {
     check_jobs_impl(jobs, tours, unassigned, expected_result);
 }}
@@ -134,6 +199,8 @@ fn check_jobs_impl(
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
+                product: None,
+                items: None,
             })
             .collect()
     };
@@ -164,8 +231,11 @@ fn check_jobs_impl(
                 vehicle_id: id.to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index,
+                driver_id: None,
                 stops: stops.into_iter().map(create_stop).collect(),
                 statistic: Statistic::default(),
+                hours_of_service: None,
+                loading_plan: None,
             })
             .collect(),
         unassigned: Some(
@@ -260,6 +330,77 @@ fn can_detect_job_duration_violation() {
     assert_eq!(result, Err(vec!["cannot match activities to jobs: job1:<no tag>".into()]));
 }
 
+parameterized_test! {can_check_soft_time_windows, (arrival, earliness, lateness, expected), {
+    can_check_soft_time_windows_impl(arrival, earliness, lateness, expected);
+}}
+
+can_check_soft_time_windows! {
+    case01_on_time: (2., None, None, Ok(())),
+    case02_late: (3., None, Some(1), Ok(())),
+    case03_late_not_reported: (3., None, None, Err(())),
+    case04_wrong_lateness: (3., None, Some(2), Err(())),
+    case05_too_late: (8., None, Some(6), Err(())),
+    case06_early_when_waiting_is_cheaper: (0., Some(2.), None, Err(())),
+    case07_early_when_waiting_is_expensive: (0., Some(0.5), None, Ok(())),
+}
+
+fn can_check_soft_time_windows_impl(
+    arrival: Float,
+    earliness: Option<Float>,
+    lateness: Option<i64>,
+    expected: Result<(), ()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                soft_time_windows: Some(JobSoftTimeWindows {
+                    earliness,
+                    lateness: Some(1.),
+                    max_earliness: earliness.map(|_| 1.),
+                    max_lateness: Some(5.),
+                }),
+                ..create_delivery_job_with_times("job1", (1., 0.), vec![(1, 2)], 1.)
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let mut solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(arrival - 1., arrival - 1.)
+                        .load(vec![1])
+                        .build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(arrival, arrival + 1.)
+                        .load(vec![0])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(arrival + 2., arrival + 2.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_arrival(),
+                ])
+                .statistic(StatisticBuilder::default().driving(2).serving(1).build())
+                .build(),
+        )
+        .build();
+    solution.tours[0].stops[1].activities_mut()[0].lateness = lateness;
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_assignment(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected);
+}
+
 #[test]
 fn can_detect_group_violations() {
     let problem = Problem {
@@ -309,3 +450,283 @@ fn can_detect_group_violations() {
 
     assert_eq!(result, Err("job groups are not respected: 'group1'".into()));
 }
+
+parameterized_test! {check_splits, (max_splits, tours, expected_result), {
+    check_splits_impl(max_splits, tours, expected_result);
+}}
+
+check_splits! {
+    case_01: (None, vec![("my_vehicle_1", vec![("job1_split_1", 5), ("job1_split_2", 5), ("job1_split_3", 5)])], Ok(())),
+    case_02: (Some(2), vec![("my_vehicle_1", vec![("job1_split_1", 5), ("job1_split_2", 5)]), ("my_vehicle_2", vec![("job1_split_3", 5)])], Ok(())),
+    case_03: (Some(1), vec![("my_vehicle_1", vec![("job1_split_1", 5), ("job1_split_2", 5)]), ("my_vehicle_2", vec![("job1_split_3", 5)])], Err(())),
+    case_04: (None, vec![("my_vehicle_1", vec![("job1_split_1", 10)])], Err(())),
+}
+
+fn check_splits_impl(max_splits: Option<usize>, tours: Vec<(&str, Vec<(&str, i32)>)>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                split: Some(JobSplit { min_quantity: Some(5), max_splits }),
+                ..create_delivery_job_with_demand("job1", (1., 0.), vec![15])
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: tours
+            .into_iter()
+            .map(|(vehicle_id, parts)| Tour {
+                vehicle_id: vehicle_id.to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                driver_id: None,
+                stops: parts
+                    .into_iter()
+                    .map(|(job_id, quantity)| {
+                        let mut stop =
+                            StopBuilder::default().coordinate((1., 0.)).load(vec![0]).build_single(job_id, "delivery");
+                        stop.activities_mut()[0].quantity = Some(vec![quantity]);
+                        stop
+                    })
+                    .collect(),
+                statistic: Statistic::default(),
+                hours_of_service: None,
+                loading_plan: None,
+            })
+            .collect(),
+        ..SolutionBuilder::default().build()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_splits(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {check_syncs, (tolerance, tours, expected_result), {
+    check_syncs_impl(tolerance, tours, expected_result);
+}}
+
+check_syncs! {
+    case_01: (None, vec![("my_vehicle_1", vec![("job1_sync_1", 10.)]), ("my_vehicle_2", vec![("job1_sync_2", 10.)])], Ok(())),
+    case_02: (None, vec![("my_vehicle_1", vec![("job1_sync_1", 10.)]), ("my_vehicle_2", vec![("job1_sync_2", 12.)])], Err(())),
+    case_03: (Some(2.), vec![("my_vehicle_1", vec![("job1_sync_1", 10.)]), ("my_vehicle_2", vec![("job1_sync_2", 12.)])], Ok(())),
+    case_04: (None, vec![("my_vehicle_1", vec![("job1_sync_1", 10.)])], Err(())),
+    case_05: (None, vec![("my_vehicle_1", vec![("job1_sync_1", 10.), ("job1_sync_2", 10.)])], Err(())),
+    case_06: (None, vec![], Ok(())),
+}
+
+fn check_syncs_impl(tolerance: Option<Float>, tours: Vec<(&str, Vec<(&str, Float)>)>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { sync: Some(JobSync { vehicles: 2, tolerance }), ..create_service_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: tours
+            .into_iter()
+            .map(|(vehicle_id, copies)| {
+                TourBuilder::default()
+                    .vehicle_id(vehicle_id)
+                    .stops(
+                        copies
+                            .into_iter()
+                            .map(|(job_id, start)| {
+                                StopBuilder::default().coordinate((1., 0.)).load(vec![0]).build_single_time(
+                                    job_id,
+                                    "service",
+                                    (start, start + 1.),
+                                )
+                            })
+                            .collect(),
+                    )
+                    .build()
+            })
+            .collect(),
+        ..SolutionBuilder::default().build()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_syncs(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+fn create_alternative_problem_and_solution(
+    type_field: AlternativeType,
+    served: Vec<&str>,
+    unassigned: Vec<(&str, Vec<&str>)>,
+) -> (Problem, Solution) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_service_job("job1", (1., 0.)), create_service_job("job2", (1., 0.))],
+            alternatives: Some(vec![Alternative { type_field, jobs: vec!["job1".to_string(), "job2".to_string()] }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: if served.is_empty() {
+            vec![]
+        } else {
+            vec![
+                TourBuilder::default()
+                    .vehicle_id("my_vehicle_1")
+                    .stops(
+                        served
+                            .into_iter()
+                            .map(|job_id| {
+                                StopBuilder::default().coordinate((1., 0.)).load(vec![0]).build_single_time(
+                                    job_id,
+                                    "service",
+                                    (1., 2.),
+                                )
+                            })
+                            .collect(),
+                    )
+                    .build(),
+            ]
+        },
+        unassigned: if unassigned.is_empty() {
+            None
+        } else {
+            Some(
+                unassigned
+                    .into_iter()
+                    .map(|(job_id, codes)| UnassignedJob {
+                        job_id: job_id.to_string(),
+                        reasons: codes
+                            .into_iter()
+                            .map(|code| UnassignedJobReason {
+                                code: code.to_string(),
+                                description: "".to_string(),
+                                details: None,
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            )
+        },
+        ..SolutionBuilder::default().build()
+    };
+
+    (problem, solution)
+}
+
+parameterized_test! {check_alternatives, (type_field, served, unassigned, expected_result), {
+    check_alternatives_impl(type_field, served, unassigned, expected_result);
+}}
+
+check_alternatives! {
+    case_01_one_served: (AlternativeType::ExactlyOne, vec!["job1"], vec![], Ok(())),
+    case_02_all_unassigned: (AlternativeType::ExactlyOne, vec![], vec![
+        ("job1", vec!["SKILL_CONSTRAINT", "ALTERNATIVE_CONSTRAINT"]),
+        ("job2", vec!["TIME_WINDOW_CONSTRAINT", "ALTERNATIVE_CONSTRAINT"]),
+    ], Ok(())),
+    case_03_many_served: (AlternativeType::ExactlyOne, vec!["job1", "job2"], vec![], Err(())),
+    case_04_some_unassigned: (AlternativeType::ExactlyOne, vec![], vec![
+        ("job1", vec!["SKILL_CONSTRAINT", "ALTERNATIVE_CONSTRAINT"]),
+    ], Err(())),
+    case_05_served_and_unassigned: (AlternativeType::ExactlyOne, vec!["job1"], vec![
+        ("job2", vec!["SKILL_CONSTRAINT", "ALTERNATIVE_CONSTRAINT"]),
+    ], Err(())),
+    case_06_no_alternative_reason: (AlternativeType::ExactlyOne, vec![], vec![
+        ("job1", vec!["SKILL_CONSTRAINT"]),
+        ("job2", vec!["SKILL_CONSTRAINT"]),
+    ], Err(())),
+    case_07_exactly_one_not_explained: (AlternativeType::ExactlyOne, vec![], vec![
+        ("job1", vec!["ALTERNATIVE_CONSTRAINT"]),
+        ("job2", vec!["ALTERNATIVE_CONSTRAINT"]),
+    ], Err(())),
+    case_08_at_most_one_not_served: (AlternativeType::AtMostOne, vec![], vec![
+        ("job1", vec!["ALTERNATIVE_CONSTRAINT"]),
+        ("job2", vec!["ALTERNATIVE_CONSTRAINT"]),
+    ], Ok(())),
+    case_09_at_most_one_many_served: (AlternativeType::AtMostOne, vec!["job1", "job2"], vec![], Err(())),
+}
+
+fn check_alternatives_impl(
+    type_field: AlternativeType,
+    served: Vec<&str>,
+    unassigned: Vec<(&str, Vec<&str>)>,
+    expected_result: Result<(), ()>,
+) {
+    let (problem, solution) = create_alternative_problem_and_solution(type_field, served, unassigned);
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_alternatives(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+#[test]
+fn can_skip_alternative_jobs_when_checking_jobs_presence() {
+    let (problem, solution) =
+        create_alternative_problem_and_solution(AlternativeType::ExactlyOne, vec!["job2"], vec![]);
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_jobs_presence(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), Ok(()));
+}
+
+parameterized_test! {check_mandatory, (unassigned, expected_result), {
+    check_mandatory_impl(unassigned, expected_result);
+}}
+
+check_mandatory! {
+    case_01_optional_unassigned: (vec!["job2"], Ok(())),
+    case_02_mandatory_unassigned: (vec!["job1", "job2"], Err(())),
+}
+
+fn check_mandatory_impl(unassigned: Vec<&str>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { mandatory: Some(true), ..create_delivery_job("job1", (1., 0.)) },
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        unassigned: Some(
+            unassigned
+                .into_iter()
+                .map(|job_id| UnassignedJob { job_id: job_id.to_string(), reasons: vec![] })
+                .collect(),
+        ),
+        ..SolutionBuilder::default().build()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_mandatory(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
@@ -77,7 +77,12 @@ fn can_check_breaks_impl(
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: break_times,
@@ -86,6 +91,7 @@ fn can_check_breaks_impl(
                     }]),
                     reloads: None,
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -102,6 +108,9 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        compartment: None,
+        lateness: None,
+        quantity: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -111,6 +120,9 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            compartment: None,
+            lateness: None,
+            quantity: None,
         });
     }
 
@@ -150,3 +162,92 @@ fn can_check_breaks_impl(
 
     assert_eq!(result, expected_result);
 }
+
+parameterized_test! {can_check_hours_of_service, (max_driving, parts, max_tour_driving, daily_rest, has_rest, expected_result), {
+    can_check_hours_of_service_impl(max_driving, parts, max_tour_driving, daily_rest, has_rest, expected_result);
+}}
+
+can_check_hours_of_service! {
+    case01_no_break_needed: (40., vec![5.], None, None, false, Ok(())),
+    case02_with_rest: (30., vec![5.], None, None, true, Ok(())),
+    case03_no_rest: (30., vec![5.], None, None, false, Err(vec![
+        "continuous driving time '40' exceeds limit '30' for vehicle 'my_vehicle_1', shift index '0'".into()
+    ])),
+    case04_short_rest: (30., vec![2., 5.], None, None, true, Err(vec![
+        "rest duration '5' is less than required '7' for vehicle 'my_vehicle_1', shift index '0'".into()
+    ])),
+    case05_tour_driving: (40., vec![5.], Some(35.), None, false, Err(vec![
+        "tour driving time '40' exceeds limit '35' for vehicle 'my_vehicle_1', shift index '0'".into()
+    ])),
+    case06_with_daily_rest: (40., vec![5.], None, Some((30., 5.)), true, Ok(())),
+    case07_no_daily_rest: (40., vec![5.], None, Some((30., 5.)), false, Err(vec![
+        "daily driving time '40' exceeds limit '30' for vehicle 'my_vehicle_1', shift index '0'".into()
+    ])),
+    case08_break_is_not_daily_rest: (40., vec![5.], None, Some((30., 8.)), true, Err(vec![
+        "daily driving time '40' exceeds limit '30' for vehicle 'my_vehicle_1', shift index '0'".into()
+    ])),
+}
+
+fn can_check_hours_of_service_impl(
+    max_driving: Float,
+    parts: Vec<Float>,
+    max_tour_driving: Option<Float>,
+    daily_rest: Option<(Float, Float)>,
+    has_rest: bool,
+    expected_result: Result<(), Vec<GenericError>>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                hours_of_service: Some(VehicleHoursOfService {
+                    driving_break: Some(VehicleDrivingBreak { max_driving, parts }),
+                    daily_rest: daily_rest.map(|(max_driving, duration)| VehicleDailyRest { max_driving, duration }),
+                    max_tour_driving,
+                    max_vehicle_driving: None,
+                    max_weekly_driving: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let rest = if has_rest { 5. } else { 0. };
+    let mut first_stop = StopBuilder::default().coordinate((10., 0.)).schedule_stamp(10., 11. + rest).load(vec![1]);
+    first_stop = first_stop.activity(ActivityBuilder::delivery().job_id("job1").time_stamp(10., 11.).build());
+    if has_rest {
+        first_stop = first_stop
+            .activity(ActivityBuilder::default().job_id("rest").activity_type("rest").time_stamp(11., 16.).build());
+    }
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    first_stop.distance(10).build(),
+                    StopBuilder::default()
+                        .coordinate((20., 0.))
+                        .schedule_stamp(21. + rest, 22. + rest)
+                        .load(vec![0])
+                        .distance(20)
+                        .build_single("job2", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(42. + rest, 42. + rest)
+                        .load(vec![0])
+                        .distance(40)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_hours_of_service(&ctx).map_err(|err| vec![err]);
+
+    assert_eq!(result, expected_result);
+}
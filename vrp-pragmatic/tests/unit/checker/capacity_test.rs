@@ -1,6 +1,7 @@
 use super::*;
 use crate::format_time;
 use crate::helpers::*;
+use std::iter::once;
 use vrp_core::models::examples::create_example_problem;
 
 parameterized_test! {can_check_load, (stop_loads, expected_result), {
@@ -36,7 +37,12 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Gen
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -45,6 +51,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Gen
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -234,3 +241,413 @@ fn can_check_resource_consumption() {
             .into())
     );
 }
+
+parameterized_test! {can_check_compartment_load, (job1_compartment, stop_loads, expected), {
+    can_check_compartment_load_impl(job1_compartment, stop_loads, expected);
+}}
+
+can_check_compartment_load! {
+    case01_correct: (Some("c1"), vec![(1, 1), (0, 1), (0, 0)], Ok(())),
+    case02_load_mismatch: (
+        Some("c1"),
+        vec![(2, 1), (1, 1), (0, 0)],
+        Err("load mismatch of compartment 'c1' at stop 0 in tour 'my_vehicle_1'".into())
+    ),
+    case03_not_allowed: (
+        Some("c2"),
+        vec![(0, 2), (0, 1), (0, 0)],
+        Err("job 'job1' has product which is not allowed in compartment 'c2' in tour 'my_vehicle_1'".into())
+    ),
+    case04_not_assigned: (
+        None,
+        vec![(0, 1), (0, 1), (0, 0)],
+        Err("job 'job1' has no compartment assigned in tour 'my_vehicle_1'".into())
+    ),
+}
+
+fn can_check_compartment_load_impl(
+    job1_compartment: Option<&str>,
+    stop_loads: Vec<(i32, i32)>,
+    expected: GenericResult<()>,
+) {
+    let create_job_with_product = |id: &str, location: (f64, f64), product: &str| Job {
+        deliveries: Some(vec![JobTask { product: Some(product.to_string()), ..create_task(location, None) }]),
+        ..create_job(id)
+    };
+    let create_compartment = |id: &str, product: &str| VehicleCompartment {
+        id: id.to_string(),
+        capacity: vec![2],
+        products: Some(vec![product.to_string()]),
+        incompatible: None,
+    };
+    let get_compartments = |idx: usize| {
+        let (c1_load, c2_load) = stop_loads[idx];
+        vec![("c1", vec![c1_load]), ("c2", vec![c2_load])]
+    };
+    let job1_activity = ActivityBuilder::delivery().job_id("job1");
+    let job1_activity = match job1_compartment {
+        Some(compartment) => job1_activity.compartment(compartment),
+        None => job1_activity,
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_product("job1", (1., 0.), "diesel"),
+                create_job_with_product("job2", (2., 0.), "petrol"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                compartments: Some(vec![create_compartment("c1", "diesel"), create_compartment("c2", "petrol")]),
+                ..create_vehicle_with_capacity("my_vehicle", vec![4])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(0., 0.)
+                        .load(vec![2])
+                        .compartments(get_compartments(0))
+                        .build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1])
+                        .compartments(get_compartments(1))
+                        .distance(1)
+                        .activity(job1_activity.build())
+                        .build(),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(3., 4.)
+                        .load(vec![0])
+                        .compartments(get_compartments(2))
+                        .distance(2)
+                        .activity(ActivityBuilder::delivery().job_id("job2").compartment("c2").build())
+                        .build(),
+                ])
+                .statistic(StatisticBuilder::default().driving(2).serving(2).build())
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_compartment_load(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_satellite_inventory, (supply, micro_departure, is_supply_on_micro, expected), {
+    can_check_satellite_inventory_impl(supply, micro_departure, is_supply_on_micro, expected);
+}}
+
+can_check_satellite_inventory! {
+    case01_valid: (2, 20., false, Ok(())),
+    case02_not_enough_inventory: (1, 20., false,
+        Err("satellite 's1' has not enough inventory for second-level vehicles".into())),
+    case03_inventory_is_late: (2, 5., false,
+        Err("satellite 's1' inventory is not ready before second-level vehicles departure".into())),
+    case04_supply_on_second_level: (2, 20., true,
+        Err("supply job 'supply' is served by second-level vehicle in tour 'micro_1'".into())),
+}
+
+fn can_check_satellite_inventory_impl(
+    supply: i32,
+    micro_departure: f64,
+    is_supply_on_micro: bool,
+    expected: GenericResult<()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    satellite: Some("s1".to_string()),
+                    ..create_delivery_job_with_demand("supply", (10., 0.), vec![supply])
+                },
+                create_delivery_job_with_demand("job1", (12., 0.), vec![2]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("hub"),
+                VehicleType {
+                    shifts: vec![VehicleShift {
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: (10., 0.).to_loc(),
+                            satellite: Some("s1".to_string()),
+                        },
+                        ..create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))
+                    }],
+                    ..create_default_vehicle("micro")
+                },
+            ],
+            resources: Some(vec![VehicleResource::Satellite { id: "s1".to_string(), location: (10., 0.).to_loc() }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let create_tour = |vehicle: &str, start: (f64, f64), departure: f64, jobs: Vec<(&str, (f64, f64))>| {
+        let stops = once(
+            StopBuilder::default()
+                .coordinate(start)
+                .schedule_stamp(departure, departure)
+                .load(vec![jobs.len() as i32])
+                .build_departure(),
+        )
+        .chain(jobs.iter().enumerate().map(|(idx, (job_id, location))| {
+            let arrival = departure + (location.0 - start.0).abs();
+            StopBuilder::default()
+                .coordinate(*location)
+                .schedule_stamp(arrival, arrival + 1.)
+                .load(vec![(jobs.len() - idx - 1) as i32])
+                .build_single(job_id, "delivery")
+        }))
+        .chain(once(StopBuilder::default().coordinate(start).schedule_stamp(100., 100.).load(vec![0]).build_arrival()))
+        .collect();
+
+        TourBuilder::default().type_id(vehicle).vehicle_id(format!("{vehicle}_1").as_str()).stops(stops).build()
+    };
+    let (hub_jobs, micro_jobs) = if is_supply_on_micro {
+        (vec![], vec![("supply", (10., 0.)), ("job1", (12., 0.))])
+    } else {
+        (vec![("supply", (10., 0.))], vec![("job1", (12., 0.))])
+    };
+    let solution = SolutionBuilder::default()
+        .tour(create_tour("hub", (0., 0.), 0., hub_jobs))
+        .tour(create_tour("micro", (10., 0.), micro_departure, micro_jobs))
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_satellite_inventory(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_loading_policy, (policy, activities, expected), {
+    can_check_loading_policy_impl(policy, activities, expected);
+}}
+
+can_check_loading_policy! {
+    case01_lifo_valid: (Some(VehicleLoadingPolicy::Lifo), vec![("job1", "pickup"), ("job2", "pickup"), ("job2", "delivery"), ("job1", "delivery")], Ok(())),
+    case02_lifo_invalid: (Some(VehicleLoadingPolicy::Lifo), vec![("job1", "pickup"), ("job2", "pickup"), ("job1", "delivery"), ("job2", "delivery")],
+        Err("loading policy violation for job 'job1' in tour 'my_vehicle_1'".into())),
+    case03_fifo_valid: (Some(VehicleLoadingPolicy::Fifo), vec![("job1", "pickup"), ("job2", "pickup"), ("job1", "delivery"), ("job2", "delivery")], Ok(())),
+    case04_fifo_invalid: (Some(VehicleLoadingPolicy::Fifo), vec![("job1", "pickup"), ("job2", "pickup"), ("job2", "delivery"), ("job1", "delivery")],
+        Err("loading policy violation for job 'job2' in tour 'my_vehicle_1'".into())),
+    case05_no_policy: (None, vec![("job1", "pickup"), ("job2", "pickup"), ("job1", "delivery"), ("job2", "delivery")], Ok(())),
+}
+
+fn can_check_loading_policy_impl(
+    policy: Option<VehicleLoadingPolicy>,
+    activities: Vec<(&str, &str)>,
+    expected: GenericResult<()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (4., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { loading_policy: policy, ..create_default_vehicle("my_vehicle") }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let stops =
+        once(StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure())
+            .chain(activities.into_iter().enumerate().map(|(idx, (job_id, activity_type))| {
+                let (location, tag) = match (job_id, activity_type) {
+                    ("job1", "pickup") => ((1., 0.), "p1"),
+                    ("job2", "pickup") => ((2., 0.), "p1"),
+                    ("job2", _) => ((3., 0.), "d1"),
+                    _ => ((4., 0.), "d1"),
+                };
+                let time = (idx + 1) as f64 * 2.;
+
+                StopBuilder::default()
+                    .coordinate(location)
+                    .schedule_stamp(time, time + 1.)
+                    .load(vec![0])
+                    .build_single_tag(job_id, activity_type, tag)
+            }))
+            .collect();
+    let solution = SolutionBuilder::default().tour(TourBuilder::default().stops(stops).build()).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_loading_policy(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_loading_plan, (placements, max_weight, expected), {
+    can_check_loading_plan_impl(placements, max_weight, expected);
+}}
+
+can_check_loading_plan! {
+    case01_side_by_side: (vec![("i1", (0., 0., 0.)), ("i2", (2., 0., 0.))], None, Ok(())),
+    case02_stacked: (vec![("i1", (0., 0., 0.)), ("i2", (0., 0., 2.))], None, Ok(())),
+    case03_missing: (vec![("i1", (0., 0., 0.))], None,
+        Err("missing item 'i2' of job 'job1' in loading plan of tour 'my_vehicle_1'".into())),
+    case04_overlap: (vec![("i1", (0., 0., 0.)), ("i2", (1., 0., 0.))], None,
+        Err("item 'i1' overlaps with other item in loading plan of tour 'my_vehicle_1'".into())),
+    case05_not_supported: (vec![("i1", (0., 0., 0.)), ("i2", (2., 0., 1.))], None,
+        Err("item 'i2' is not supported by stackable items in loading plan of tour 'my_vehicle_1'".into())),
+    case06_outside: (vec![("i1", (0., 0., 0.)), ("i2", (3., 0., 0.))], None,
+        Err("item 'i2' of job 'job1' has invalid size or position in loading plan of tour 'my_vehicle_1'".into())),
+    case07_overweight: (vec![("i1", (0., 0., 0.)), ("i2", (2., 0., 0.))], Some(1.),
+        Err("total weight of items on board exceeds max weight in loading plan of tour 'my_vehicle_1'".into())),
+}
+
+fn can_check_loading_plan_impl(
+    placements: Vec<(&str, (f64, f64, f64))>,
+    max_weight: Option<f64>,
+    expected: GenericResult<()>,
+) {
+    let create_item = |id: &str| JobItem {
+        id: id.to_string(),
+        length: 2.,
+        width: 2.,
+        height: 2.,
+        weight: Some(1.),
+        stackable: None,
+        rotatable: None,
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    items: Some(vec![create_item("i1"), create_item("i2")]),
+                    ..create_task((1., 0.), None)
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                cargo_space: Some(VehicleCargoSpace { length: 4., width: 2., height: 4., max_weight }),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let tour = TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![0])
+                .distance(1)
+                .build_single("job1", "delivery"),
+        ])
+        .build();
+    let loading_plan = placements
+        .into_iter()
+        .map(|(item_id, position)| ItemPlacement {
+            job_id: "job1".to_string(),
+            item_id: item_id.to_string(),
+            position: vec![position.0, position.1, position.2],
+            size: vec![2., 2., 2.],
+        })
+        .collect();
+    let solution = SolutionBuilder::default().tour(Tour { loading_plan: Some(loading_plan), ..tour }).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_loading_plan(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_loading_plan_with_items_on_board, (activities, max_weight, expected), {
+    can_check_loading_plan_with_items_on_board_impl(activities, max_weight, expected);
+}}
+
+can_check_loading_plan_with_items_on_board! {
+    case01_delivery_then_pickup: (vec![("job1", "delivery"), ("job2", "pickup")], None, Ok(())),
+    case02_pickup_then_delivery: (vec![("job2", "pickup"), ("job1", "delivery")], None,
+        Err("item 'i1' overlaps with other item in loading plan of tour 'my_vehicle_1'".into())),
+    case03_weight_delivery_then_pickup: (vec![("job1", "delivery"), ("job2", "pickup")], Some(1.), Ok(())),
+}
+
+fn can_check_loading_plan_with_items_on_board_impl(
+    activities: Vec<(&str, &str)>,
+    max_weight: Option<f64>,
+    expected: GenericResult<()>,
+) {
+    let items = |id: &str| {
+        Some(vec![JobItem {
+            id: id.to_string(),
+            length: 2.,
+            width: 2.,
+            height: 2.,
+            weight: Some(1.),
+            stackable: None,
+            rotatable: None,
+        }])
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    deliveries: Some(vec![JobTask { items: items("i1"), ..create_task((1., 0.), None) }]),
+                    ..create_job("job1")
+                },
+                Job {
+                    pickups: Some(vec![JobTask { items: items("i2"), ..create_task((2., 0.), None) }]),
+                    ..create_job("job2")
+                },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                cargo_space: Some(VehicleCargoSpace { length: 2., width: 2., height: 2., max_weight }),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let stops =
+        once(StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure())
+            .chain(activities.into_iter().enumerate().map(|(idx, (job_id, activity_type))| {
+                let (location, time) = (if job_id == "job1" { 1. } else { 2. }, idx as f64 + 1.);
+                StopBuilder::default()
+                    .coordinate((location, 0.))
+                    .schedule_stamp(time, time)
+                    .load(vec![0])
+                    .build_single(job_id, activity_type)
+            }))
+            .collect();
+    let loading_plan = ["i1", "i2"]
+        .into_iter()
+        .map(|item_id| ItemPlacement {
+            job_id: if item_id == "i1" { "job1" } else { "job2" }.to_string(),
+            item_id: item_id.to_string(),
+            position: vec![0., 0., 0.],
+            size: vec![2., 2., 2.],
+        })
+        .collect();
+    let tour = TourBuilder::default().stops(stops).build();
+    let solution = SolutionBuilder::default().tour(Tour { loading_plan: Some(loading_plan), ..tour }).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_loading_plan(&ctx);
+
+    assert_eq!(result, expected);
+}
@@ -23,8 +23,11 @@ fn create_test_solution(statistic: Statistic, stops: Vec<Stop>) -> Solution {
             vehicle_id: "some_real_vehicle".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops,
             statistic,
+            hours_of_service: None,
+            loading_plan: None,
         })
         .build()
 }
@@ -124,7 +127,12 @@ fn can_check_shift_time() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(5.), location: (0., 0.).to_loc() }),
                     ..create_default_vehicle_shift()
                 }],
@@ -175,15 +183,23 @@ fn can_check_recharge_distance() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
                     end: None,
                     recharges: Some(VehicleRecharges {
-                        max_distance: 8.,
+                        max_distance: Some(8.),
+                        battery: None,
                         stations: vec![VehicleRechargeStation {
                             location: (8., 0.).to_loc(),
                             duration: 0.,
                             times: None,
                             tag: None,
+                            charging_curve: None,
+                            resource_id: None,
                         }],
                     }),
                     ..create_default_vehicle_shift()
@@ -228,3 +244,297 @@ fn can_check_recharge_distance() {
             .into())
     );
 }
+
+parameterized_test! {can_check_battery_state_of_charge, (states, reserve, expected), {
+    can_check_battery_state_of_charge_impl(states, reserve, expected);
+}}
+
+can_check_battery_state_of_charge! {
+    case01_valid: ((10., 9.5, 5.), 1., None),
+    case02_wrong_start: ((8., 7.5, 3.), 1., Some("state of charge violation: expected 10 at start, got 8")),
+    case03_wrong_arrival: ((10., 9., 4.5), 1., Some("state of charge violation: expected 9.5, got 9 at stop")),
+    case04_below_reserve: ((10., 9.5, 5.), 6., Some("state of charge violation: expected at least 6, got 5 at stop")),
+}
+
+fn can_check_battery_state_of_charge_impl(states: (Float, Float, Float), reserve: Float, expected: Option<&str>) {
+    let (start, first, second) = states;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        satellite: None,
+                    },
+                    end: None,
+                    recharges: Some(VehicleRecharges {
+                        max_distance: None,
+                        battery: Some(VehicleBattery {
+                            capacity: 10.,
+                            initial: None,
+                            reserve: Some(reserve),
+                            consumption: VehicleEnergyConsumption { empty: 0.5, full: None },
+                        }),
+                        stations: vec![VehicleRechargeStation {
+                            location: (8., 0.).to_loc(),
+                            duration: 0.,
+                            times: None,
+                            tag: None,
+                            charging_curve: None,
+                            resource_id: None,
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(0., 0.)
+                        .load(vec![2])
+                        .state_of_charge(start)
+                        .build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1])
+                        .distance(1)
+                        .state_of_charge(first)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((10., 0.))
+                        .schedule_stamp(11., 12.)
+                        .load(vec![0])
+                        .distance(10)
+                        .state_of_charge(second)
+                        .build_single("job2", "delivery"),
+                ])
+                .statistic(StatisticBuilder::default().driving(10).serving(2).waiting(0).build())
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_recharge_limits(&ctx);
+
+    match (result, expected) {
+        (Ok(_), None) => {}
+        (Err(err), Some(expected)) => assert!(err.to_string().starts_with(expected), "unexpected error: {err}"),
+        (result, expected) => unreachable!("unexpected result: {result:?}, expected: {expected:?}"),
+    }
+}
+
+parameterized_test! {can_check_recharge_resources, (second_schedule, expected), {
+    can_check_recharge_resources_impl(second_schedule, expected);
+}}
+
+can_check_recharge_resources! {
+    case01_sequential: ((15., 25.), None),
+    case02_waiting: ((6., 25.), None),
+    case03_concurrent: ((6., 16.), Some("recharge resource violation: 'station' is used by 2 vehicles at 6")),
+}
+
+fn can_check_recharge_resources_impl(second_schedule: (Float, Float), expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                shifts: vec![VehicleShift {
+                    end: None,
+                    recharges: Some(VehicleRecharges {
+                        max_distance: Some(8.),
+                        battery: None,
+                        stations: vec![VehicleRechargeStation {
+                            location: (5., 0.).to_loc(),
+                            duration: 10.,
+                            times: None,
+                            tag: None,
+                            charging_curve: None,
+                            resource_id: Some("station".to_string()),
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(vec![VehicleResource::Recharge { id: "station".to_string(), chargers: 1 }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let create_tour = |vehicle_id: &str, (arrival, departure): (Float, Float)| {
+        TourBuilder::default()
+            .vehicle_id(vehicle_id)
+            .stops(vec![
+                StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure(),
+                StopBuilder::default()
+                    .coordinate((5., 0.))
+                    .schedule_stamp(arrival, departure)
+                    .load(vec![0])
+                    .distance(5)
+                    .build_single("recharge", "recharge"),
+            ])
+            .statistic(StatisticBuilder::default().driving(5).serving(10).build())
+            .build()
+    };
+    let solution =
+        SolutionBuilder::default().tour(create_tour("v1", (5., 15.))).tour(create_tour("v2", second_schedule)).build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_recharge_resources(&ctx);
+
+    match (result, expected) {
+        (Ok(_), None) => {}
+        (Err(err), Some(prefix)) => assert!(err.to_string().starts_with(prefix), "unexpected error: {err}"),
+        (result, expected) => unreachable!("unexpected result: {result:?}, expected: {expected:?}"),
+    }
+}
+
+parameterized_test! {can_check_sites, (second_schedule, expected), {
+    can_check_sites_impl(second_schedule, expected);
+}}
+
+can_check_sites! {
+    case01_sequential: ((7., 7., 8.), None),
+    case02_waiting: ((5., 7., 8.), None),
+    case03_concurrent: ((6., 6., 7.), Some("site violation: site with index 0 is used by 2 vehicles at 6")),
+}
+
+fn can_check_sites_impl(second_schedule: (Float, Float, Float), expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (5., 0.))],
+            sites: Some(vec![Site {
+                location: (5., 0.).to_loc(),
+                opening_hours: None,
+                max_vehicles: Some(1),
+                setup_time: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                shifts: vec![VehicleShift { end: None, ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let create_tour = |vehicle_id: &str, job_id: &str, (arrival, start, departure): (Float, Float, Float)| {
+        TourBuilder::default()
+            .vehicle_id(vehicle_id)
+            .stops(vec![
+                StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                StopBuilder::default()
+                    .coordinate((5., 0.))
+                    .schedule_stamp(arrival, departure)
+                    .load(vec![0])
+                    .distance(5)
+                    .build_single_time(job_id, "delivery", (start, departure)),
+            ])
+            .statistic(StatisticBuilder::default().driving(5).serving(1).build())
+            .build()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(create_tour("v1", "job1", (5., 5., 7.)))
+        .tour(create_tour("v2", "job2", second_schedule))
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_sites(&ctx);
+
+    match (result, expected) {
+        (Ok(_), None) => {}
+        (Err(err), Some(prefix)) => assert!(err.to_string().starts_with(prefix), "unexpected error: {err}"),
+        (result, expected) => unreachable!("unexpected result: {result:?}, expected: {expected:?}"),
+    }
+}
+
+parameterized_test! {can_check_ride_times, (duration, factor, delivery_arrival, expected), {
+    can_check_ride_times_impl(duration, factor, delivery_arrival, expected);
+}}
+
+can_check_ride_times! {
+    case01_absolute_fits: (Some(4.), None, 6., None),
+    case02_absolute_exceeds: (Some(4.), None, 7., Some("max ride time violation for job 'job1'")),
+    case03_relative_fits: (None, Some(1.), 6., None),
+    case04_relative_exceeds: (None, Some(1.), 7., Some("max ride time violation for job 'job1'")),
+}
+
+fn can_check_ride_times_impl(
+    duration: Option<Float>,
+    factor: Option<Float>,
+    delivery_arrival: Float,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                max_ride_time: Some(JobMaxRideTime { duration, factor }),
+                ..create_pickup_delivery_job("job1", (1., 0.), (5., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { end: None, ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1])
+                        .distance(1)
+                        .build_single("job1", "pickup"),
+                    StopBuilder::default()
+                        .coordinate((5., 0.))
+                        .schedule_stamp(delivery_arrival, delivery_arrival + 1.)
+                        .load(vec![0])
+                        .distance(5)
+                        .build_single("job1", "delivery"),
+                ])
+                .statistic(StatisticBuilder::default().driving(5).serving(2).build())
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, Some(vec![matrix]), solution).unwrap();
+
+    let result = check_ride_times(&ctx);
+
+    match (result, expected) {
+        (Ok(_), None) => {}
+        (Err(err), Some(prefix)) => assert!(err.to_string().starts_with(prefix), "unexpected error: {err}"),
+        (result, expected) => unreachable!("unexpected result: {result:?}, expected: {expected:?}"),
+    }
+}
@@ -86,7 +86,12 @@ mod single {
                     profile: create_default_vehicle_profile(),
                     costs: create_default_vehicle_costs(),
                     shifts: vec![VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: (0., 0.).to_loc(),
+                            satellite: None,
+                        },
                         end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
                         breaks: Some(vec![VehicleBreak::Optional {
                             time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(0.), format_time(1000.)]),
@@ -99,10 +104,16 @@ mod single {
                             ..create_default_reload()
                         }]),
                         recharges: None,
+                        overtime: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    hours_of_service: None,
+                    consumption: None,
+                    loading_policy: None,
+                    cargo_space: None,
                 }],
                 ..create_default_fleet()
             },
@@ -173,3 +184,147 @@ mod single {
         assert_eq!(result, expected_result);
     }
 }
+
+mod precedence {
+    use super::*;
+    use vrp_core::models::examples::create_example_problem;
+    use vrp_core::prelude::Float;
+
+    parameterized_test! {can_check_precedences, (lag, tours, expected_result), {
+        can_check_precedences_impl(lag, tours, expected_result);
+    }}
+
+    can_check_precedences! {
+        case_01: (None, vec![("my_vehicle_1", vec![("job1", 10.)]), ("my_vehicle_2", vec![("job2", 11.)])], Ok(())),
+        case_02: (None, vec![("my_vehicle_1", vec![("job1", 10.)]), ("my_vehicle_2", vec![("job2", 10.)])], Err(())),
+        case_03: (Some(5.), vec![("my_vehicle_1", vec![("job1", 10.)]), ("my_vehicle_2", vec![("job2", 16.)])], Ok(())),
+        case_04: (Some(5.), vec![("my_vehicle_1", vec![("job1", 10.)]), ("my_vehicle_2", vec![("job2", 15.)])], Err(())),
+        case_05: (None, vec![("my_vehicle_1", vec![("job1", 10.), ("job2", 11.)])], Ok(())),
+        case_06: (None, vec![("my_vehicle_1", vec![("job2", 10.), ("job1", 11.)])], Err(())),
+        case_07: (None, vec![("my_vehicle_1", vec![("job1", 10.)])], Ok(())),
+        case_08: (None, vec![("my_vehicle_2", vec![("job2", 10.)])], Err(())),
+    }
+
+    fn can_check_precedences_impl(
+        lag: Option<Float>,
+        tours: Vec<(&str, Vec<(&str, Float)>)>,
+        expected_result: Result<(), ()>,
+    ) {
+        let problem = Problem {
+            plan: Plan {
+                jobs: vec![create_service_job("job1", (1., 0.)), create_service_job("job2", (1., 0.))],
+                precedences: Some(vec![Precedence {
+                    predecessor: "job1".to_string(),
+                    successor: "job2".to_string(),
+                    lag,
+                }]),
+                ..create_empty_plan()
+            },
+            fleet: Fleet {
+                vehicles: vec![VehicleType {
+                    vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                    ..create_default_vehicle_type()
+                }],
+                ..create_default_fleet()
+            },
+            ..create_empty_problem()
+        };
+        let solution = Solution {
+            tours: tours
+                .into_iter()
+                .map(|(vehicle_id, jobs)| {
+                    TourBuilder::default()
+                        .vehicle_id(vehicle_id)
+                        .stops(
+                            jobs.into_iter()
+                                .map(|(job_id, start)| {
+                                    StopBuilder::default().coordinate((1., 0.)).load(vec![0]).build_single_time(
+                                        job_id,
+                                        "service",
+                                        (start, start + 1.),
+                                    )
+                                })
+                                .collect(),
+                        )
+                        .build()
+                })
+                .collect(),
+            ..SolutionBuilder::default().build()
+        };
+        let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+        let result = check_precedences(&ctx);
+
+        assert_eq!(result.map_err(|_| ()), expected_result);
+    }
+}
+
+mod visits {
+    use super::*;
+    use vrp_core::models::examples::create_example_problem;
+
+    parameterized_test! {check_visits, (patterns, min_spacing, tours, expected_result), {
+        check_visits_impl(patterns, min_spacing, tours, expected_result);
+    }}
+
+    check_visits! {
+        case_01: (None, None, vec![(0, vec!["job1_visit_1"]), (2, vec!["job1_visit_2"])], Ok(())),
+        case_02: (None, None, vec![(0, vec!["job1_visit_1", "job1_visit_2"])], Err(())),
+        case_03: (None, Some(3), vec![(0, vec!["job1_visit_1"]), (2, vec!["job1_visit_2"])], Err(())),
+        case_04: (Some(vec![vec![0, 2]]), None, vec![(0, vec!["job1_visit_1"]), (2, vec!["job1_visit_2"])], Ok(())),
+        case_05: (Some(vec![vec![1, 3]]), None, vec![(0, vec!["job1_visit_1"]), (2, vec!["job1_visit_2"])], Err(())),
+        case_06: (Some(vec![vec![1, 2]]), None, vec![(2, vec!["job1_visit_1"])], Err(())),
+        case_07: (None, None, vec![], Ok(())),
+    }
+
+    fn check_visits_impl(
+        patterns: Option<Vec<Vec<usize>>>,
+        min_spacing: Option<usize>,
+        tours: Vec<(usize, Vec<&str>)>,
+        expected_result: Result<(), ()>,
+    ) {
+        let problem = Problem {
+            plan: Plan {
+                jobs: vec![Job {
+                    visits: Some(JobVisits { frequency: 2, patterns, min_spacing }),
+                    ..create_delivery_job("job1", (1., 0.))
+                }],
+                ..create_empty_plan()
+            },
+            fleet: Fleet {
+                vehicles: vec![VehicleType {
+                    shifts: (0..4).map(create_vehicle_shift_on_day).collect(),
+                    ..create_default_vehicle_type()
+                }],
+                ..create_default_fleet()
+            },
+            ..create_empty_problem()
+        };
+        let solution = Solution {
+            tours: tours
+                .into_iter()
+                .map(|(shift_index, job_ids)| Tour {
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    type_id: "my_vehicle".to_string(),
+                    shift_index,
+                    driver_id: None,
+                    stops: job_ids
+                        .into_iter()
+                        .map(|job_id| {
+                            StopBuilder::default().coordinate((1., 0.)).load(vec![0]).build_single(job_id, "delivery")
+                        })
+                        .collect(),
+                    statistic: Statistic::default(),
+                    hours_of_service: None,
+                    loading_plan: None,
+                })
+                .collect(),
+            ..SolutionBuilder::default().build()
+        };
+        let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+        let result = check_visits(&ctx);
+
+        assert_eq!(result.map_err(|_| ()), expected_result);
+    }
+}
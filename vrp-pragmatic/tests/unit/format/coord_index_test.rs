@@ -20,6 +20,8 @@ fn can_use_index_with_coordinate_an_unknown_location_types() {
                         }],
                         demand: None,
                         order: None,
+                        product: None,
+                        items: None,
                     }]),
                     ..create_job("job3")
                 },
@@ -1,4 +1,4 @@
-use super::create_transport_costs;
+use super::{create_transport_costs, get_profile_matrix};
 use crate::format::problem::*;
 use crate::format_time;
 use crate::helpers::*;
@@ -15,6 +15,7 @@ fn matrix(profile: Option<&str>, timestamp: Option<Float>, fill_value: i64, size
         travel_times: vec![fill_value; size],
         distances: vec![fill_value; size],
         error_codes: None,
+        edge_classes: None,
     }
 }
 
@@ -25,13 +26,17 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
         travel_times: vec![1; 4],
         distances: vec![2; 3],
         error_codes: None,
+        edge_classes: None,
     }
 }
 
 fn create_problem(profiles: &[&str]) -> Problem {
     Problem {
         fleet: Fleet {
-            profiles: profiles.iter().map(|p| MatrixProfile { name: p.to_string(), speed: None }).collect(),
+            profiles: profiles
+                .iter()
+                .map(|p| MatrixProfile { name: p.to_string(), speed: None, speed_profiles: None })
+                .collect(),
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -173,3 +178,20 @@ fn can_create_transport_costs_positive_cases_impl(
         assert_eq!(result, distance);
     });
 }
+
+parameterized_test! {can_get_profile_matrix, (matrices, profile_name, index, expected), {
+    can_get_profile_matrix_impl(matrices, profile_name, index, expected);
+}}
+
+can_get_profile_matrix! {
+    case01_by_name_in_order: (vec![matrix(Some("car1"), None, 1, 4), matrix(Some("car2"), None, 2, 4)], "car2", 1, Some(2)),
+    case02_by_name_out_of_order: (vec![matrix(Some("car2"), None, 2, 4), matrix(Some("car1"), None, 1, 4)], "car1", 0, Some(1)),
+    case03_by_index_without_name: (vec![matrix(None, None, 1, 4), matrix(None, None, 2, 4)], "car2", 1, Some(2)),
+    case04_unknown_name: (vec![matrix(Some("car1"), None, 1, 4)], "car2", 0, None),
+}
+
+fn can_get_profile_matrix_impl(matrices: Vec<Matrix>, profile_name: &str, index: usize, expected: Option<i64>) {
+    let result = get_profile_matrix(matrices.as_slice(), profile_name, index);
+
+    assert_eq!(result.map(|matrix| matrix.distances[0]), expected);
+}
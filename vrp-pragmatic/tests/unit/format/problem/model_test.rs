@@ -64,3 +64,27 @@ fn can_deserialize_matrix() {
     assert_eq!(matrix.distances.len(), 16);
     assert_eq!(matrix.travel_times.len(), 16);
 }
+
+parameterized_test! {can_get_split_quantities, (demand, min_quantity, expected), {
+    can_get_split_quantities_impl(demand, min_quantity, expected);
+}}
+
+can_get_split_quantities! {
+    case01_even: (15, 5, vec![5, 5, 5]),
+    case02_rest_in_last_part: (27, 5, vec![5, 5, 5, 5, 7]),
+    case03_less_than_min: (3, 5, vec![3]),
+    case04_default_min: (3, 1, vec![1, 1, 1]),
+}
+
+fn can_get_split_quantities_impl(demand: i32, min_quantity: i32, expected: Vec<i32>) {
+    use crate::helpers::*;
+    let job = Job {
+        split: Some(JobSplit { min_quantity: Some(min_quantity), max_splits: None }),
+        ..create_delivery_job_with_demand("job1", (1., 0.), vec![demand])
+    };
+
+    let quantities = job.split_quantities();
+
+    assert_eq!(quantities, Some(expected));
+    assert_eq!(job.visit_ids().len(), quantities.unwrap().len());
+}
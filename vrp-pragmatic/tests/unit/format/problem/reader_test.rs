@@ -80,6 +80,8 @@ fn can_read_complex_problem() {
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
+                        product: None,
+                        items: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique".to_string()])),
                     ..create_job("delivery_job")
@@ -97,6 +99,8 @@ fn can_read_complex_problem() {
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        product: None,
+                        items: None,
                     }]),
                     deliveries: Some(vec![JobTask {
                         places: vec![JobPlace {
@@ -110,6 +114,8 @@ fn can_read_complex_problem() {
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        product: None,
+                        items: None,
                     }]),
                     ..create_job("pickup_delivery_job")
                 },
@@ -126,6 +132,8 @@ fn can_read_complex_problem() {
                         }],
                         demand: Some(vec![3]),
                         order: None,
+                        product: None,
+                        items: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique2".to_string()])),
                     ..create_job("pickup_job")
@@ -144,6 +152,7 @@ fn can_read_complex_problem() {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: None,
                         location: (52.4862, 13.45148).to_loc(),
+                        satellite: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
@@ -164,10 +173,16 @@ fn can_read_complex_problem() {
                     }]),
                     reloads: None,
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
                 limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), tour_size: Some(3) }),
+                compartments: None,
+                hours_of_service: None,
+                consumption: None,
+                loading_policy: None,
+                cargo_space: None,
             }],
             ..create_default_fleet()
         },
@@ -179,6 +194,7 @@ fn can_read_complex_problem() {
         travel_times: vec![1; 25],
         distances: vec![2; 25],
         error_codes: None,
+        edge_classes: None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
@@ -292,10 +308,10 @@ fn can_create_approximation_matrices() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile { name: "car1".to_string(), speed: Some(8.), speed_profiles: None },
+                MatrixProfile { name: "car2".to_string(), speed: Some(10.), speed_profiles: None },
+                MatrixProfile { name: "car3".to_string(), speed: Some(5.), speed_profiles: None },
+                MatrixProfile { name: "car4".to_string(), speed: None, speed_profiles: None },
             ],
             ..create_default_fleet()
         },
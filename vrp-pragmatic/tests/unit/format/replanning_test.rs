@@ -0,0 +1,201 @@
+use super::*;
+use crate::format::solution::UnassignedJob;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (3., 0.)),
+                create_delivery_job("job3", (4., 0.)),
+                create_delivery_job("job4", (5., 0.)),
+                create_pickup_job("job5", (6., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution() -> Solution {
+    SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1])
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(3., 4.)
+                        .load(vec![2])
+                        .build_single("job2", "pickup"),
+                    StopBuilder::default()
+                        .coordinate((3., 0.))
+                        .schedule_stamp(5., 6.)
+                        .load(vec![1])
+                        .build_single("job2", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((4., 0.))
+                        .schedule_stamp(7., 8.)
+                        .load(vec![0])
+                        .build_single("job3", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((6., 0.))
+                        .schedule_stamp(10., 11.)
+                        .load(vec![1])
+                        .build_single("job5", "pickup"),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(17., 17.).load(vec![1]).build_arrival(),
+                ])
+                .build(),
+        )
+        .unassigned(Some(vec![UnassignedJob { job_id: "job4".to_string(), reasons: vec![] }]))
+        .build()
+}
+
+fn create_test_state(completed: Vec<(&str, &str)>) -> ExecutionState {
+    ExecutionState {
+        time: format_time(3.),
+        vehicles: vec![VehicleState {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: None,
+            location: (2., 0.).to_loc(),
+            completed: completed
+                .into_iter()
+                .map(|(job_id, activity_type)| CompletedActivity {
+                    job_id: job_id.to_string(),
+                    activity_type: activity_type.to_string(),
+                })
+                .collect(),
+        }],
+        new_jobs: Some(vec![create_delivery_job("job6", (7., 0.))]),
+        cancelled_jobs: Some(vec!["job4".to_string()]),
+    }
+}
+
+#[test]
+fn can_create_replanning_problem() {
+    let state = create_test_state(vec![("job1", "delivery"), ("job2", "pickup"), ("job5", "pickup")]);
+
+    let (problem, solution) =
+        create_replanning_problem(&create_test_problem(), &create_test_solution(), &state).unwrap();
+
+    let job_ids = problem.plan.jobs.iter().map(|job| job.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(job_ids, vec!["job2", "job3", "job6"]);
+    let job2 = problem.plan.jobs.first().unwrap();
+    assert!(job2.pickups.is_none());
+    assert_eq!(job2.deliveries.as_ref().map(|tasks| tasks.len()), Some(1));
+    let relations = problem.plan.relations.unwrap();
+    assert_eq!(relations.len(), 1);
+    assert_eq!(relations[0].jobs, vec!["job2".to_string()]);
+    assert_eq!(relations[0].vehicle_id, "my_vehicle_1");
+    assert_eq!(relations[0].shift_index, Some(0));
+
+    let vehicles = problem.fleet.vehicles;
+    assert_eq!(vehicles.len(), 2);
+    assert_eq!(vehicles[0].type_id, "my_vehicle");
+    assert_eq!(vehicles[0].vehicle_ids, vec!["my_vehicle_2".to_string()]);
+    assert_eq!(vehicles[1].type_id, "my_vehicle_my_vehicle_1");
+    assert_eq!(vehicles[1].vehicle_ids, vec!["my_vehicle_1".to_string()]);
+    assert_eq!(vehicles[1].capacity, vec![9]);
+    let start = &vehicles[1].shifts[0].start;
+    assert_eq!(start.earliest, format_time(3.));
+    assert_eq!(start.latest, Some(format_time(3.)));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.type_id, "my_vehicle_my_vehicle_1");
+    assert_eq!(
+        get_ids_from_tour(tour),
+        vec![vec!["departure"], vec!["job2"], vec!["job3"], vec!["arrival"]]
+            .into_iter()
+            .map(|ids| ids.into_iter().map(|id| id.to_string()).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(tour.stops[0].schedule().departure, format_time(3.));
+}
+
+#[test]
+fn can_keep_precedences_between_remaining_jobs() {
+    let create_precedence = |predecessor: &str, successor: &str| Precedence {
+        predecessor: predecessor.to_string(),
+        successor: successor.to_string(),
+        lag: None,
+    };
+    let mut problem = create_test_problem();
+    problem.plan.precedences = Some(vec![
+        create_precedence("job1", "job3"),
+        create_precedence("job2", "job3"),
+        create_precedence("job3", "job4"),
+    ]);
+    let state = create_test_state(vec![("job1", "delivery"), ("job2", "pickup"), ("job5", "pickup")]);
+
+    let (problem, _) = create_replanning_problem(&problem, &create_test_solution(), &state).unwrap();
+
+    let precedences = problem.plan.precedences.unwrap();
+    assert_eq!(precedences.len(), 1);
+    assert_eq!((precedences[0].predecessor.as_str(), precedences[0].successor.as_str()), ("job2", "job3"));
+}
+
+#[test]
+fn can_remove_alternatives_of_completed_jobs() {
+    let create_alternative = |jobs: &[&str]| Alternative {
+        type_field: AlternativeType::ExactlyOne,
+        jobs: jobs.iter().map(|job_id| job_id.to_string()).collect(),
+    };
+    let mut problem = create_test_problem();
+    problem.plan.alternatives =
+        Some(vec![create_alternative(&["job1", "job3"]), create_alternative(&["job2", "job4", "job5"])]);
+    let state = create_test_state(vec![("job1", "delivery")]);
+
+    let (problem, _) = create_replanning_problem(&problem, &create_test_solution(), &state).unwrap();
+
+    let job_ids = problem.plan.jobs.iter().map(|job| job.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(job_ids, vec!["job2", "job5", "job6"]);
+    let alternatives = problem.plan.alternatives.unwrap();
+    assert_eq!(alternatives.len(), 1);
+    assert_eq!(alternatives[0].jobs, vec!["job2".to_string(), "job5".to_string()]);
+}
+
+parameterized_test! {can_detect_invalid_execution_state, (vehicle_id, completed, cancelled, expected), {
+    can_detect_invalid_execution_state_impl(vehicle_id, completed, cancelled, expected);
+}}
+
+can_detect_invalid_execution_state! {
+    case01_unknown_vehicle: ("my_vehicle_3", vec![], "job4", "unknown vehicle id in execution state: 'my_vehicle_3'"),
+    case02_unknown_cancelled: ("my_vehicle_1", vec![], "job7", "unknown cancelled job id: 'job7'"),
+    case03_unknown_completed: ("my_vehicle_1", vec![("job7", "delivery")], "job4", "unknown completed job id: 'job7'"),
+    case04_too_many_tasks: (
+        "my_vehicle_1", vec![("job1", "delivery"), ("job1", "delivery")], "job4",
+        "job 'job1' has less delivery tasks than completed: 2"
+    ),
+    case05_unknown_type: ("my_vehicle_1", vec![("job1", "reload")], "job4", "unsupported completed activity type: 'reload'"),
+}
+
+fn can_detect_invalid_execution_state_impl(
+    vehicle_id: &str,
+    completed: Vec<(&str, &str)>,
+    cancelled: &str,
+    expected: &str,
+) {
+    let mut state = create_test_state(completed);
+    state.vehicles[0].vehicle_id = vehicle_id.to_string();
+    state.cancelled_jobs = Some(vec![cancelled.to_string()]);
+
+    let result = create_replanning_problem(&create_test_problem(), &create_test_solution(), &state);
+
+    assert_eq!(result.err().map(|err| err.to_string()), Some(expected.to_string()));
+}
@@ -0,0 +1,205 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::UnassignmentInfo;
+use vrp_core::models::common::{Schedule, TimeWindow};
+use vrp_core::models::solution::{Activity, Place, Registry, Route, Tour};
+use vrp_core::utils::DefaultRandom;
+
+fn create_test_solution(unassigned: Vec<(&str, UnassignmentInfo)>) -> (DomainProblem, DomainSolution) {
+    create_test_solution_with_job(create_delivery_job("job1", (1., 0.)), unassigned)
+}
+
+fn create_test_solution_with_job(
+    mandatory_job: Job,
+    unassigned: Vec<(&str, UnassignmentInfo)>,
+) -> (DomainProblem, DomainSolution) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { mandatory: Some(true), ..mandatory_job }, create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string(), "v3".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = (problem, vec![matrix]).read_pragmatic().unwrap();
+
+    let solution = DomainSolution {
+        cost: Default::default(),
+        registry: Registry::new(&problem.fleet, Arc::new(DefaultRandom::default())),
+        routes: vec![],
+        unassigned: unassigned
+            .into_iter()
+            .map(|(job_id, info)| {
+                let job = problem
+                    .jobs
+                    .all()
+                    .iter()
+                    .find(|job| job.dimens().get_job_id().is_some_and(|id| id == job_id))
+                    .cloned()
+                    .expect("cannot find job");
+                (job, info)
+            })
+            .collect(),
+        telemetry: None,
+    };
+
+    (problem, solution)
+}
+
+fn create_detailed_info(solution: &DomainSolution, codes: Vec<(&str, ViolationCode)>) -> UnassignmentInfo {
+    let actors = solution.registry.all().collect::<Vec<_>>();
+
+    UnassignmentInfo::Detailed(
+        codes
+            .into_iter()
+            .map(|(vehicle_id, code)| {
+                let actor = actors
+                    .iter()
+                    .find(|actor| actor.vehicle.dimens.get_vehicle_id().is_some_and(|id| id == vehicle_id))
+                    .cloned()
+                    .expect("cannot find actor");
+                (actor, code)
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn can_skip_diagnosis_when_mandatory_jobs_are_assigned() {
+    let (problem, solution) = create_test_solution(vec![("job2", UnassignmentInfo::Simple(CAPACITY_CONSTRAINT_CODE))]);
+
+    let result = check_mandatory_jobs(&problem, &solution);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn can_diagnose_unassigned_mandatory_job() {
+    let (problem, mut solution) = create_test_solution(vec![("job1", UnassignmentInfo::Unknown)]);
+    solution.unassigned[0].1 = create_detailed_info(
+        &solution,
+        vec![("v1", TIME_CONSTRAINT_CODE), ("v2", CAPACITY_CONSTRAINT_CODE), ("v3", CAPACITY_CONSTRAINT_CODE)],
+    );
+
+    let diagnosis = diagnose_mandatory_jobs(&problem, &solution);
+
+    assert_eq!(diagnosis.len(), 1);
+    assert_eq!(diagnosis[0].job_id, "job1");
+    assert_eq!(diagnosis[0].reasons.len(), 2);
+    let relaxations = diagnosis[0]
+        .relaxations
+        .iter()
+        .map(|relaxation| {
+            let vehicle_ids = relaxation.details.iter().flatten().map(|detail| detail.vehicle_id.as_str());
+            (relaxation.type_field.as_str(), relaxation.code.as_str(), vehicle_ids.collect::<Vec<_>>())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        relaxations,
+        vec![
+            ("addCapacity", "CAPACITY_CONSTRAINT", vec!["v2", "v3"]),
+            ("extendShift", "TIME_WINDOW_CONSTRAINT", vec!["v1"]),
+        ]
+    );
+}
+
+#[test]
+fn can_return_error_with_diagnosis_details() {
+    let (problem, solution) = create_test_solution(vec![
+        ("job1", UnassignmentInfo::Simple(SKILL_CONSTRAINT_CODE)),
+        ("job2", UnassignmentInfo::Simple(SKILL_CONSTRAINT_CODE)),
+    ]);
+
+    let result = check_mandatory_jobs(&problem, &solution).unwrap_err();
+
+    assert_eq!(result.code, "E0005");
+    assert!(result.action.ends_with("ids: 'job1'"));
+    assert!(result.action.contains("delta is provided only for time window and capacity constraints"));
+    let details = serde_json::from_str::<Vec<MandatoryJobDiagnosis>>(result.details.unwrap().as_str()).unwrap();
+    assert_eq!(details.len(), 1);
+    assert_eq!(details[0].relaxations[0].type_field, "addSkills");
+    assert_eq!(details[0].relaxations[0].details, None);
+}
+
+#[test]
+fn can_suggest_area_relaxation_without_delta() {
+    let (problem, solution) = create_test_solution(vec![("job1", UnassignmentInfo::Simple(AREA_CONSTRAINT_CODE))]);
+
+    let diagnosis = diagnose_mandatory_jobs(&problem, &solution);
+
+    assert_eq!(diagnosis.len(), 1);
+    assert_eq!(diagnosis[0].relaxations[0].type_field, "extendArea");
+    assert_eq!(diagnosis[0].relaxations[0].code, "AREA_CONSTRAINT");
+    assert_eq!(diagnosis[0].relaxations[0].details, None);
+}
+
+fn create_delivery_job_with_demand(id: &str, location: (f64, f64), demand: Vec<i32>) -> Job {
+    let job = create_delivery_job(id, location);
+    let task = JobTask { demand: Some(demand), ..job.deliveries.as_ref().unwrap()[0].clone() };
+
+    Job { deliveries: Some(vec![task]), ..job }
+}
+
+parameterized_test! {can_compute_relaxation_delta, (job, code, expected), {
+    can_compute_relaxation_delta_impl(job, code, expected);
+}}
+
+can_compute_relaxation_delta! {
+    case01_job_time_window: (create_delivery_job_with_times("job1", (10., 0.), vec![(0, 5)], 1.), TIME_CONSTRAINT_CODE, Some(vec![5])),
+    case02_shift_time: (create_delivery_job("job1", (600., 0.)), TIME_CONSTRAINT_CODE, Some(vec![201])),
+    case03_capacity: (create_delivery_job_with_demand("job1", (1., 0.), vec![13]), CAPACITY_CONSTRAINT_CODE, Some(vec![3])),
+    case04_capacity_fits: (create_delivery_job("job1", (1., 0.)), CAPACITY_CONSTRAINT_CODE, Some(vec![0])),
+    case05_skills: (create_delivery_job("job1", (1., 0.)), SKILL_CONSTRAINT_CODE, None),
+}
+
+fn can_compute_relaxation_delta_impl(job: Job, code: ViolationCode, expected: Option<Vec<i64>>) {
+    let (problem, mut solution) = create_test_solution_with_job(job, vec![("job1", UnassignmentInfo::Unknown)]);
+    solution.unassigned[0].1 = create_detailed_info(&solution, vec![("v1", code)]);
+
+    let diagnosis = diagnose_mandatory_jobs(&problem, &solution);
+
+    assert_eq!(diagnosis.len(), 1);
+    assert_eq!(diagnosis[0].relaxations.len(), 1);
+    let details = diagnosis[0].relaxations[0].details.clone().expect("no details");
+    assert_eq!(details, vec![RelaxationDetail { vehicle_id: "v1".to_string(), shift_index: 0, delta: expected }]);
+}
+
+#[test]
+fn can_compute_capacity_delta_with_route_load() {
+    let job = create_delivery_job_with_demand("job1", (1., 0.), vec![10]);
+    let (problem, mut solution) = create_test_solution_with_job(job, vec![("job1", UnassignmentInfo::Unknown)]);
+    solution.unassigned[0].1 = create_detailed_info(&solution, vec![("v1", CAPACITY_CONSTRAINT_CODE)]);
+    let actor = solution.registry.all().find(|actor| actor.vehicle.dimens.get_vehicle_id().unwrap() == "v1").unwrap();
+    let single =
+        problem.jobs.all().iter().find(|job| job.dimens().get_job_id().unwrap() == "job2").unwrap().to_single().clone();
+    let mut tour = Tour::new(&actor);
+    tour.insert_at(
+        Activity {
+            schedule: Schedule::new(2., 3.),
+            place: Place {
+                idx: 0,
+                location: single.places[0].location.unwrap(),
+                duration: 1.,
+                time: TimeWindow::max(),
+            },
+            job: Some(single),
+            commute: None,
+        },
+        1,
+    );
+    solution.routes.push(Route { actor, tour });
+
+    let diagnosis = diagnose_mandatory_jobs(&problem, &solution);
+
+    let details = diagnosis[0].relaxations[0].details.clone().expect("no details");
+    assert_eq!(details[0].delta, Some(vec![1]));
+}
@@ -74,6 +74,9 @@ fn can_create_geo_json_for_cluster_geometry() {
                 time: Some(Interval { start: format_time(0.), end: format_time(1.) }),
                 job_tag: None,
                 commute: Some(Commute { forward: None, backward: None }),
+                compartment: None,
+                lateness: None,
+                quantity: None,
             },
             Activity {
                 job_id: "job2".to_string(),
@@ -93,8 +96,14 @@ fn can_create_geo_json_for_cluster_geometry() {
                         time: Interval { start: format_time(3.), end: format_time(4.) },
                     }),
                 }),
+                compartment: None,
+                lateness: None,
+                quantity: None,
             },
         ],
+        compartments: None,
+        state_of_charge: None,
+        overtime: None,
     };
 
     let features = get_cluster_geometry(0, 0, &stop).unwrap();
@@ -148,8 +148,11 @@ fn can_handle_empty_tour_error_in_init_solution() {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: Default::default(),
             statistic: Default::default(),
+            hours_of_service: None,
+            loading_plan: None,
         })
         .build();
 
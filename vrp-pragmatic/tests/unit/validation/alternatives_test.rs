@@ -0,0 +1,99 @@
+use super::*;
+use crate::helpers::*;
+
+fn validate_result(ctx: &ValidationContext) -> Option<FormatError> {
+    let result = validate_alternatives(ctx);
+
+    result.err().map(|result| {
+        assert_eq!(result.errors.len(), 1);
+        result.errors.first().cloned().unwrap()
+    })
+}
+
+parameterized_test! {can_detect_alternative_errors, (alternatives, expected), {
+    can_detect_alternative_errors_impl(alternatives, expected);
+}}
+
+can_detect_alternative_errors! {
+    case01_valid: (vec![vec!["job1", "job2"]], None),
+    case02_valid_multiple: (vec![vec!["job1", "job2"], vec!["job3", "multi"]], None),
+    case03_unknown_job: (vec![vec!["job1", "job6"]], Some(("E1800", "job6"))),
+    case04_single_job: (vec![vec!["job1"]], Some(("E1801", "job1"))),
+    case05_duplicated_job: (vec![vec!["job1", "job1"]], Some(("E1801", "job1"))),
+    case06_multiple_alternatives: (vec![vec!["job1", "job2"], vec!["job2", "job3"]], Some(("E1802", "job2"))),
+    case07_precedence_job: (vec![vec!["job3", "job4"]], Some(("E1803", "job4"))),
+}
+
+fn can_detect_alternative_errors_impl(alternatives: Vec<Vec<&str>>, expected: Option<(&str, &str)>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+                create_delivery_job("job5", (5., 0.)),
+                create_pickup_delivery_job("multi", (1., 0.), (2., 0.)),
+            ],
+            precedences: Some(vec![Precedence {
+                predecessor: "job4".to_string(),
+                successor: "job5".to_string(),
+                lag: None,
+            }]),
+            alternatives: Some(
+                alternatives
+                    .into_iter()
+                    .map(|jobs| Alternative {
+                        type_field: AlternativeType::ExactlyOne,
+                        jobs: jobs.into_iter().map(|job_id| job_id.to_string()).collect(),
+                    })
+                    .collect(),
+            ),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    if let Some((code, action)) = expected {
+        assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
+        assert!(result.map_or("".to_string(), |err| err.action).contains(action));
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_mandatory_job_in_optional_alternative, (type_field, expected), {
+    can_detect_mandatory_job_in_optional_alternative_impl(type_field, expected);
+}}
+
+can_detect_mandatory_job_in_optional_alternative! {
+    case01_exactly_one: (AlternativeType::ExactlyOne, None),
+    case02_at_most_one: (AlternativeType::AtMostOne, Some(("E1803", "job1"))),
+}
+
+fn can_detect_mandatory_job_in_optional_alternative_impl(type_field: AlternativeType, expected: Option<(&str, &str)>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { mandatory: Some(true), ..create_delivery_job("job1", (1., 0.)) },
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            alternatives: Some(vec![Alternative { type_field, jobs: vec!["job1".to_string(), "job2".to_string()] }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    if let Some((code, action)) = expected {
+        assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
+        assert!(result.map_or("".to_string(), |err| err.action).contains(action));
+    } else {
+        assert!(result.is_none());
+    }
+}
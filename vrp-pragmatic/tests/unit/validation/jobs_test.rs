@@ -1,5 +1,6 @@
 use super::*;
 use crate::helpers::*;
+use vrp_core::prelude::Float;
 
 fn assert_result(code: &str, action: &str, result: Option<FormatError>) {
     assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
@@ -73,3 +74,351 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_job_visits, (frequency, patterns, group, relation_job_id, other_job_id, expected), {
+    can_detect_invalid_job_visits_impl(frequency, patterns, group, relation_job_id, other_job_id, expected);
+}}
+
+can_detect_invalid_job_visits! {
+    case01_valid: (2, Some(vec![vec![0, 2], vec![1, 3]]), None, None, "job2", None),
+    case02_zero_frequency: (0, None, None, None, "job2", Some("E1108")),
+    case03_wrong_pattern_size: (2, Some(vec![vec![0, 2, 4]]), None, None, "job2", Some("E1108")),
+    case04_duplicate_pattern_days: (2, Some(vec![vec![1, 1]]), None, None, "job2", Some("E1108")),
+    case05_empty_patterns: (2, Some(vec![]), None, None, "job2", Some("E1108")),
+    case06_with_group: (2, None, Some("group"), None, "job2", Some("E1108")),
+    case07_in_relation: (2, None, None, Some("job1"), "job2", Some("E1108")),
+    case08_visit_id_clash: (2, None, None, None, "job1_visit_2", Some("E1108")),
+}
+
+fn can_detect_invalid_job_visits_impl(
+    frequency: usize,
+    patterns: Option<Vec<Vec<usize>>>,
+    group: Option<&str>,
+    relation_job_id: Option<&str>,
+    other_job_id: &str,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    visits: Some(JobVisits { frequency, patterns, min_spacing: None }),
+                    group: group.map(|group| group.to_string()),
+                    ..create_delivery_job("job1", (1., 0.))
+                },
+                create_delivery_job(other_job_id, (2., 0.)),
+            ],
+            relations: relation_job_id.map(|job_id| {
+                vec![Relation {
+                    type_field: RelationType::Any,
+                    jobs: vec![job_id.to_string()],
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    shift_index: None,
+                }]
+            }),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1108_invalid_job_visits(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_mixed_job_products, (pickup_product, delivery_product, expected), {
+    can_detect_mixed_job_products_impl(pickup_product, delivery_product, expected);
+}}
+
+can_detect_mixed_job_products! {
+    case01_same: (Some("diesel"), Some("diesel"), None),
+    case02_no_products: (None, None, None),
+    case03_different: (Some("diesel"), Some("petrol"), Some("E1109")),
+    case04_partial: (Some("diesel"), None, Some("E1109")),
+}
+
+fn can_detect_mixed_job_products_impl(
+    pickup_product: Option<&str>,
+    delivery_product: Option<&str>,
+    expected: Option<&str>,
+) {
+    let job = create_pickup_delivery_job("job1", (1., 0.), (2., 0.));
+    let set_product = |tasks: Option<Vec<JobTask>>, product: Option<&str>| {
+        tasks.map(|tasks| {
+            tasks.into_iter().map(|task| JobTask { product: product.map(|p| p.to_string()), ..task }).collect()
+        })
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                pickups: set_product(job.pickups.clone(), pickup_product),
+                deliveries: set_product(job.deliveries.clone(), delivery_product),
+                ..job
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1109_mixed_job_products(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_satellite_jobs, (satellite, location, has_pickup, expected), {
+    can_detect_invalid_satellite_jobs_impl(satellite, location, has_pickup, expected);
+}}
+
+can_detect_invalid_satellite_jobs! {
+    case01_valid: ("s1", (10., 0.), false, None),
+    case02_unknown_satellite: ("s2", (10., 0.), false, Some("E1110")),
+    case03_wrong_location: ("s1", (11., 0.), false, Some("E1110")),
+    case04_has_pickup: ("s1", (10., 0.), true, Some("E1110")),
+}
+
+fn can_detect_invalid_satellite_jobs_impl(
+    satellite: &str,
+    location: (f64, f64),
+    has_pickup: bool,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                pickups: if has_pickup { Some(vec![create_task((0., 0.), None)]) } else { None },
+                satellite: Some(satellite.to_string()),
+                ..create_delivery_job("job1", location)
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            resources: Some(vec![VehicleResource::Satellite { id: "s1".to_string(), location: (10., 0.).to_loc() }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1110_invalid_satellite_jobs(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_soft_time_windows, (earliness, lateness, max_lateness, expected), {
+    can_detect_invalid_soft_time_windows_impl(earliness, lateness, max_lateness, expected);
+}}
+
+can_detect_invalid_soft_time_windows! {
+    case01_valid: (Some(1.), Some(2.), Some(3600.), None),
+    case02_only_lateness: (None, Some(2.), None, None),
+    case03_no_rates: (None, None, Some(3600.), Some("E1111")),
+    case04_negative_rate: (Some(-1.), None, None, Some("E1111")),
+    case05_negative_limit: (None, Some(1.), Some(-1.), Some("E1111")),
+}
+
+fn can_detect_invalid_soft_time_windows_impl(
+    earliness: Option<Float>,
+    lateness: Option<Float>,
+    max_lateness: Option<Float>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                soft_time_windows: Some(JobSoftTimeWindows { earliness, lateness, max_earliness: None, max_lateness }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1111_invalid_soft_time_windows(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_split_jobs, (job, min_quantity, max_splits, expected), {
+    can_detect_invalid_split_jobs_impl(job, min_quantity, max_splits, expected);
+}}
+
+can_detect_invalid_split_jobs! {
+    case01_valid: (create_delivery_job_with_demand("job1", (1., 0.), vec![10]), Some(2), Some(3), None),
+    case02_valid_defaults: (create_delivery_job_with_demand("job1", (1., 0.), vec![10]), None, None, None),
+    case03_multi_dim_demand: (create_delivery_job_with_demand("job1", (1., 0.), vec![10, 1]), None, None, Some("E1112")),
+    case04_zero_demand: (create_delivery_job_with_demand("job1", (1., 0.), vec![0]), None, None, Some("E1112")),
+    case05_pickup_delivery: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), None, None, Some("E1112")),
+    case06_service: (create_service_job("job1", (1., 0.)), None, None, Some("E1112")),
+    case07_zero_min: (create_delivery_job_with_demand("job1", (1., 0.), vec![10]), Some(0), None, Some("E1112")),
+    case08_zero_splits: (create_delivery_job_with_demand("job1", (1., 0.), vec![10]), None, Some(0), Some("E1112")),
+}
+
+fn can_detect_invalid_split_jobs_impl(
+    job: Job,
+    min_quantity: Option<i32>,
+    max_splits: Option<usize>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { split: Some(JobSplit { min_quantity, max_splits }), ..job }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1112_invalid_split_jobs(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_max_ride_time, (job, duration, factor, expected), {
+    can_detect_invalid_max_ride_time_impl(job, duration, factor, expected);
+}}
+
+can_detect_invalid_max_ride_time! {
+    case01_valid_duration: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), Some(10.), None, None),
+    case02_valid_factor: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), None, Some(1.5), None),
+    case03_both: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), Some(10.), Some(1.5), Some("E1113")),
+    case04_none: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), None, None, Some("E1113")),
+    case05_zero_duration: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), Some(0.), None, Some("E1113")),
+    case06_small_factor: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), None, Some(0.5), Some("E1113")),
+    case07_delivery: (create_delivery_job("job1", (1., 0.)), Some(10.), None, Some("E1113")),
+}
+
+fn can_detect_invalid_max_ride_time_impl(
+    job: Job,
+    duration: Option<Float>,
+    factor: Option<Float>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { max_ride_time: Some(JobMaxRideTime { duration, factor }), ..job }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1113_invalid_max_ride_time(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+fn create_job_item(id: &str, size: (Float, Float, Float), weight: Option<Float>) -> JobItem {
+    JobItem {
+        id: id.to_string(),
+        length: size.0,
+        width: size.1,
+        height: size.2,
+        weight,
+        stackable: None,
+        rotatable: None,
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_items, (job, pickup_items, delivery_items, expected), {
+    can_detect_invalid_job_items_impl(job, pickup_items, delivery_items, expected);
+}}
+
+can_detect_invalid_job_items! {
+    case01_delivery: (create_delivery_job("job1", (1., 0.)), vec![], vec![create_job_item("i1", (1., 1., 1.), Some(1.))], None),
+    case02_pickup_delivery: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), vec![create_job_item("i1", (1., 1., 1.), None)], vec![], None),
+    case03_pickup_delivery_on_delivery: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), vec![], vec![create_job_item("i1", (1., 1., 1.), None)], Some("E1114")),
+    case04_zero_size: (create_delivery_job("job1", (1., 0.)), vec![], vec![create_job_item("i1", (1., 0., 1.), None)], Some("E1114")),
+    case05_negative_weight: (create_delivery_job("job1", (1., 0.)), vec![], vec![create_job_item("i1", (1., 1., 1.), Some(-1.))], Some("E1114")),
+    case06_duplicate_ids: (create_delivery_job("job1", (1., 0.)), vec![], vec![create_job_item("i1", (1., 1., 1.), None), create_job_item("i1", (1., 1., 1.), None)], Some("E1114")),
+}
+
+fn can_detect_invalid_job_items_impl(
+    job: Job,
+    pickup_items: Vec<JobItem>,
+    delivery_items: Vec<JobItem>,
+    expected: Option<&str>,
+) {
+    let with_items = |tasks: Option<Vec<JobTask>>, items: Vec<JobItem>| {
+        tasks.map(|tasks| {
+            tasks.into_iter().map(|task| JobTask { items: Some(items.clone()), ..task }).collect::<Vec<_>>()
+        })
+    };
+    let job = Job {
+        pickups: with_items(job.pickups.clone(), pickup_items),
+        deliveries: with_items(job.deliveries.clone(), delivery_items),
+        ..job
+    };
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1114_invalid_job_items(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_sync_jobs, (job, vehicles, tolerance, expected), {
+    can_detect_invalid_sync_jobs_impl(job, vehicles, tolerance, expected);
+}}
+
+can_detect_invalid_sync_jobs! {
+    case01_valid: (create_service_job("job1", (1., 0.)), 2, Some(5.), None),
+    case02_valid_delivery: (create_delivery_job("job1", (1., 0.)), 3, None, None),
+    case03_one_vehicle: (create_service_job("job1", (1., 0.)), 1, None, Some("E1115")),
+    case04_negative_tolerance: (create_service_job("job1", (1., 0.)), 2, Some(-1.), Some("E1115")),
+    case05_pickup_delivery: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), 2, None, Some("E1115")),
+    case06_with_group: (Job { group: Some("group1".to_string()), ..create_service_job("job1", (1., 0.)) }, 2, None, Some("E1115")),
+}
+
+fn can_detect_invalid_sync_jobs_impl(job: Job, vehicles: usize, tolerance: Option<Float>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { sync: Some(JobSync { vehicles, tolerance }), ..job }], ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1115_invalid_sync_jobs(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_sync_jobs_with_driving_breaks, (sync, driving_break, expected), {
+    can_detect_sync_jobs_with_driving_breaks_impl(sync, driving_break, expected);
+}}
+
+can_detect_sync_jobs_with_driving_breaks! {
+    case01_sync_only: (true, false, None),
+    case02_driving_break_only: (false, true, None),
+    case03_sync_and_driving_break: (true, true, Some("E1116")),
+}
+
+fn can_detect_sync_jobs_with_driving_breaks_impl(sync: bool, driving_break: bool, expected: Option<&str>) {
+    let sync = sync.then_some(JobSync { vehicles: 2, tolerance: None });
+    let driving_break = driving_break.then(|| VehicleDrivingBreak { max_driving: 100., parts: vec![10.] });
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { sync, ..create_service_job("job1", (1., 0.)) }], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                hours_of_service: Some(VehicleHoursOfService {
+                    driving_break,
+                    daily_rest: None,
+                    max_tour_driving: Some(100.),
+                    max_vehicle_driving: None,
+                    max_weekly_driving: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1116_sync_jobs_with_driving_breaks(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
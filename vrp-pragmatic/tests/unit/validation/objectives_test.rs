@@ -72,6 +72,8 @@ can_detect_missing_cost_objective! {
     case02: (Some(vec![MinimizeDuration]), None),
     case03: (Some(vec![MinimizeDistance]), None),
     case04: (Some(vec![BalanceDistance]), Some(())),
+    case05: (Some(vec![MinimizeDeviation { reference: vec![], weight: 1. }]), Some(())),
+    case06: (Some(vec![MinimizeDeviation { reference: vec![], weight: 1. }, MinimizeCost]), None),
 }
 
 fn can_detect_missing_cost_objective_impl(objectives: Option<Vec<Objective>>, expected: Option<()>) {
@@ -156,6 +158,7 @@ can_detect_multiple_cost_objective! {
     case03: (Some(vec![MinimizeCost, MinimizeDuration]), Some(())),
     case04: (Some(vec![MinimizeCost, MinimizeDistance]), Some(())),
     case05: (Some(vec![MinimizeDuration, MinimizeDistance]), Some(())),
+    case06: (Some(vec![MinimizeCost, MinimizeDeviation { reference: vec![], weight: 1. }]), None),
 }
 
 fn can_detect_multiple_cost_objective_impl(objectives: Option<Vec<Objective>>, expected: Option<()>) {
@@ -207,3 +210,66 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Objective>>, e
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_deviation_objective, (vehicle_id, shift_index, weight, expected), {
+    can_detect_invalid_deviation_objective_impl(vehicle_id, shift_index, weight, expected);
+}}
+
+can_detect_invalid_deviation_objective! {
+    case01_valid: ("my_vehicle_1", None, 2., None),
+    case02_unknown_vehicle: ("my_vehicle_2", None, 1., Some("E1608".to_string())),
+    case03_unknown_shift: ("my_vehicle_1", Some(1), 1., Some("E1608".to_string())),
+    case04_invalid_weight: ("my_vehicle_1", Some(0), 0., Some("E1608".to_string())),
+}
+
+fn can_detect_invalid_deviation_objective_impl(
+    vehicle_id: &str,
+    shift_index: Option<usize>,
+    weight: Float,
+    expected: Option<String>,
+) {
+    let reference =
+        vec![ReferenceTour { vehicle_id: vehicle_id.to_string(), shift_index, jobs: vec!["job1".to_string()] }];
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        objectives: Some(vec![MinimizeUnassigned { breaks: None }, MinimizeDeviation { reference, weight }]),
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1608_invalid_deviation_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_missing_vehicles_with_consumption, (consumption, expected), {
+    can_detect_missing_vehicles_with_consumption_impl(consumption, expected);
+}}
+
+can_detect_missing_vehicles_with_consumption! {
+    case01_with_consumption: (Some(VehicleConsumption { empty: 1., full: 2., drag: None, co2: 2.6 }), None),
+    case02_without_consumption: (None, Some("E1609".to_string())),
+}
+
+fn can_detect_missing_vehicles_with_consumption_impl(
+    consumption: Option<VehicleConsumption>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { consumption, ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![MinimizeUnassigned { breaks: None }, MinimizeEmissions, MinimizeCost]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1609_no_vehicles_with_consumption_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
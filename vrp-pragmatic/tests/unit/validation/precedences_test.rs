@@ -0,0 +1,95 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn validate_result(ctx: &ValidationContext) -> Option<FormatError> {
+    let result = validate_precedences(ctx);
+
+    result.err().map(|result| {
+        assert_eq!(result.errors.len(), 1);
+        result.errors.first().cloned().unwrap()
+    })
+}
+
+parameterized_test! {can_detect_precedence_errors, (precedences, expected), {
+    can_detect_precedence_errors_impl(precedences, expected);
+}}
+
+can_detect_precedence_errors! {
+    case01_valid: (vec![("job1", "job2", None)], None),
+    case02_valid_with_lag: (vec![("job1", "job2", Some(10.)), ("job2", "job3", None)], None),
+    case03_unknown_job: (vec![("job1", "job4", None)], Some(("E1700", "job4"))),
+    case04_multi_job: (vec![("job1", "multi", None)], Some(("E1701", "multi"))),
+    case05_negative_lag: (vec![("job1", "job2", Some(-1.))], Some(("E1702", "job2"))),
+    case06_self_cycle: (vec![("job1", "job1", None)], Some(("E1703", "job1"))),
+    case07_cycle: (vec![("job1", "job2", None), ("job2", "job3", None), ("job3", "job2", None)], Some(("E1703", "job2, job3"))),
+}
+
+fn can_detect_precedence_errors_impl(precedences: Vec<(&str, &str, Option<Float>)>, expected: Option<(&str, &str)>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_pickup_delivery_job("multi", (1., 0.), (2., 0.)),
+            ],
+            precedences: Some(
+                precedences
+                    .into_iter()
+                    .map(|(predecessor, successor, lag)| Precedence {
+                        predecessor: predecessor.to_string(),
+                        successor: successor.to_string(),
+                        lag,
+                    })
+                    .collect(),
+            ),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    if let Some((code, action)) = expected {
+        assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
+        assert!(result.map_or("".to_string(), |err| err.action).contains(action));
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+#[test]
+fn can_detect_precedences_with_driving_breaks() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            precedences: Some(vec![Precedence {
+                predecessor: "job1".to_string(),
+                successor: "job2".to_string(),
+                lag: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                hours_of_service: Some(VehicleHoursOfService {
+                    driving_break: Some(VehicleDrivingBreak { max_driving: 100., parts: vec![10.] }),
+                    daily_rest: None,
+                    max_tour_driving: None,
+                    max_vehicle_driving: None,
+                    max_weekly_driving: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.clone().map(|err| err.code), Some("E1704".to_string()));
+    assert!(result.map_or("".to_string(), |err| err.action).contains("my_vehicle"));
+}
@@ -6,8 +6,8 @@ fn can_detect_duplicates() {
     let problem = Problem {
         fleet: Fleet {
             profiles: vec![
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, speed_profiles: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, speed_profiles: None },
             ],
             ..create_default_fleet()
         },
@@ -85,6 +85,7 @@ fn can_detect_index_mismatch() {
         travel_times: vec![1; 4],
         distances: vec![1; 4],
         error_codes: None,
+        edge_classes: None,
     }];
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
@@ -102,7 +103,7 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle_type() },
                 VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle_type() },
             ],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, speed_profiles: None }],
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -114,3 +115,84 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+fn create_speed_profile(points: Vec<(&str, Float)>) -> MatrixSpeedProfile {
+    MatrixSpeedProfile {
+        points: points.into_iter().map(|(time, factor)| MatrixSpeedPoint { time: time.to_string(), factor }).collect(),
+    }
+}
+
+parameterized_test! {can_detect_invalid_speed_profiles, (speed_profiles, expected), {
+    can_detect_invalid_speed_profiles_impl(speed_profiles, expected);
+}}
+
+can_detect_invalid_speed_profiles! {
+    case01_valid: (vec![create_speed_profile(vec![("1970-01-01T00:00:00Z", 1.), ("1970-01-01T01:00:00Z", 0.5)])], None),
+    case02_empty_profiles: (vec![], Some("E1506")),
+    case03_empty_points: (vec![create_speed_profile(vec![])], Some("E1506")),
+    case04_negative_factor: (vec![create_speed_profile(vec![("1970-01-01T00:00:00Z", -1.)])], Some("E1506")),
+    case05_wrong_order: (vec![create_speed_profile(vec![("1970-01-01T01:00:00Z", 1.), ("1970-01-01T00:00:00Z", 1.)])], Some("E1506")),
+    case06_invalid_time: (vec![create_speed_profile(vec![("not a time", 1.)])], Some("E1506")),
+}
+
+fn can_detect_invalid_speed_profiles_impl(speed_profiles: Vec<MatrixSpeedProfile>, expected: Option<&str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_profiles: Some(speed_profiles),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1506_invalid_speed_profiles(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_edge_classes, (edge_classes, timestamp, expected), {
+    can_detect_invalid_edge_classes_impl(edge_classes, timestamp, expected);
+}}
+
+can_detect_invalid_edge_classes! {
+    case01_valid: (Some(vec![0, 1, 1, 0]), None, None),
+    case02_no_classes: (None, None, None),
+    case03_wrong_size: (Some(vec![0, 1, 1]), None, Some("E1507")),
+    case04_unknown_class: (Some(vec![0, 1, 2, 0]), None, Some("E1507")),
+    case05_timestamp: (None, Some("1970-01-01T00:00:00Z"), Some("E1507")),
+}
+
+fn can_detect_invalid_edge_classes_impl(
+    edge_classes: Option<Vec<usize>>,
+    timestamp: Option<&str>,
+    expected: Option<&str>,
+) {
+    let speed_profile = create_speed_profile(vec![("1970-01-01T00:00:00Z", 1.)]);
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_profiles: Some(vec![speed_profile.clone(), speed_profile]),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix {
+        timestamp: timestamp.map(|timestamp| timestamp.to_string()),
+        edge_classes,
+        ..create_matrix(vec![0, 1, 1, 0])
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1507_invalid_edge_classes(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
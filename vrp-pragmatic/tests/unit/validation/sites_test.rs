@@ -0,0 +1,113 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn validate_result(ctx: &ValidationContext) -> Option<FormatError> {
+    let result = validate_sites(ctx);
+
+    result.err().map(|result| {
+        assert_eq!(result.errors.len(), 1);
+        result.errors.first().cloned().unwrap()
+    })
+}
+
+fn create_site(location: (f64, f64), hours: Option<(f64, f64)>, max_vehicles: Option<usize>, setup_time: f64) -> Site {
+    Site {
+        location: location.to_loc(),
+        opening_hours: hours.map(|(start, end)| vec![vec![format_time(start), format_time(end)]]),
+        max_vehicles,
+        setup_time: Some(setup_time),
+    }
+}
+
+parameterized_test! {can_detect_site_errors, (sites, expected), {
+    can_detect_site_errors_impl(sites, expected);
+}}
+
+can_detect_site_errors! {
+    case01_valid: (vec![create_site((1., 0.), Some((0., 100.)), Some(2), 10.)], None),
+    case02_valid_multiple: (vec![create_site((1., 0.), None, Some(1), 0.), create_site((2., 0.), None, None, 5.)], None),
+    case03_duplicated_location: (
+        vec![create_site((1., 0.), None, Some(1), 0.), create_site((1., 0.), None, None, 5.)],
+        Some(("E1900", "site indices: '1'"))
+    ),
+    case04_invalid_hours: (vec![create_site((1., 0.), Some((100., 0.)), None, 0.)], Some(("E1901", "site indices: '0'"))),
+    case05_zero_vehicles: (vec![create_site((1., 0.), None, Some(0), 0.)], Some(("E1902", "site indices: '0'"))),
+    case06_negative_setup: (vec![create_site((1., 0.), None, None, -1.)], Some(("E1902", "site indices: '0'"))),
+    case07_job_outside_hours: (vec![create_site((2., 0.), Some((0., 5.)), None, 0.)], Some(("E1903", "job ids: 'job2'"))),
+}
+
+fn can_detect_site_errors_impl(sites: Vec<Site>, expected: Option<(&str, &str)>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(10, 20)], 1.),
+            ],
+            sites: Some(sites),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    if let Some((code, action)) = expected {
+        assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
+        assert!(result.map_or("".to_string(), |err| err.action).contains(action));
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_sites_with_schedule_features, (site, sync, precedence, driving_break, expected), {
+    can_detect_sites_with_schedule_features_impl(site, sync, precedence, driving_break, expected);
+}}
+
+can_detect_sites_with_schedule_features! {
+    case01_hours_only: (create_site((1., 0.), Some((0., 100.)), None, 0.), true, true, true, None),
+    case02_no_features: (create_site((1., 0.), None, Some(1), 10.), false, false, false, None),
+    case03_sync: (create_site((1., 0.), None, Some(1), 0.), true, false, false, Some("E1904")),
+    case04_precedence: (create_site((1., 0.), None, None, 10.), false, true, false, Some("E1905")),
+    case05_driving_break: (create_site((1., 0.), None, Some(1), 10.), false, false, true, Some("E1906")),
+}
+
+fn can_detect_sites_with_schedule_features_impl(
+    site: Site,
+    sync: bool,
+    precedence: bool,
+    driving_break: bool,
+    expected: Option<&str>,
+) {
+    let sync = sync.then_some(JobSync { vehicles: 2, tolerance: None });
+    let precedences = precedence
+        .then(|| vec![Precedence { predecessor: "job1".to_string(), successor: "job2".to_string(), lag: None }]);
+    let driving_break = driving_break.then(|| VehicleDrivingBreak { max_driving: 100., parts: vec![10.] });
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { sync, ..create_service_job("job1", (1., 0.)) }, create_delivery_job("job2", (2., 0.))],
+            precedences,
+            sites: Some(vec![site]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                hours_of_service: Some(VehicleHoursOfService {
+                    driving_break,
+                    daily_rest: None,
+                    max_tour_driving: Some(100.),
+                    max_vehicle_driving: None,
+                    max_weekly_driving: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
@@ -78,6 +78,7 @@ fn can_handle_rescheduling_with_required_break_impl(latest: Option<Float>, expec
                         earliest: format_time(0.),
                         latest: latest.map(format_time),
                         location: (0., 0.).to_loc(),
+                        satellite: None,
                     },
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 10. },
@@ -137,3 +138,494 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_drivers, (drivers, expected), {
+    can_detect_invalid_drivers_impl(drivers, expected);
+}}
+
+can_detect_invalid_drivers! {
+    case01_valid: (vec![("d1", vec![(0., 10.)]), ("d2", vec![(0., 10.), (20., 30.)])], None),
+    case02_duplicate_ids: (vec![("d1", vec![(0., 10.)]), ("d1", vec![(0., 10.)])], Some("E1309")),
+    case03_invalid_shift: (vec![("d1", vec![(10., 0.)])], Some("E1310")),
+    case04_intersecting_shifts: (vec![("d1", vec![(0., 10.), (5., 20.)])], Some("E1310")),
+    case05_no_shifts: (vec![("d1", vec![])], Some("E1310")),
+}
+
+fn can_detect_invalid_drivers_impl(drivers: Vec<(&str, Vec<(Float, Float)>)>, expected: Option<&str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            drivers: Some(drivers.into_iter().map(|(id, shifts)| create_driver_with_shifts(id, shifts)).collect()),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1309_no_drivers_with_duplicate_ids(&ctx).and_then(|_| check_e1310_driver_shift_time(&ctx));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_too_many_vehicle_driver_pairs, (vehicles, drivers, driver_shift, expected), {
+    can_detect_too_many_vehicle_driver_pairs_impl(vehicles, drivers, driver_shift, expected);
+}}
+
+can_detect_too_many_vehicle_driver_pairs! {
+    case01_within_limit: (100, 100, (0., 100.), None),
+    case02_above_limit: (101, 100, (0., 100.), Some("E1319")),
+    case03_no_time_intersection: (101, 100, (2000., 3000.), None),
+}
+
+fn can_detect_too_many_vehicle_driver_pairs_impl(
+    vehicles: usize,
+    drivers: usize,
+    driver_shift: (Float, Float),
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: (0..vehicles).map(|idx| format!("v{idx}")).collect(),
+                ..create_default_vehicle_type()
+            }],
+            drivers: Some(
+                (0..drivers).map(|idx| create_driver_with_shifts(&format!("d{idx}"), vec![driver_shift])).collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1319_vehicle_driver_pairs(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_compartments, (compartments, has_reloads, expected), {
+    can_detect_invalid_compartments_impl(compartments, has_reloads, expected);
+}}
+
+can_detect_invalid_compartments! {
+    case01_valid: (vec![("c1", vec![5]), ("c2", vec![5])], false, None),
+    case02_empty: (vec![], false, Some("E1311")),
+    case03_duplicate_ids: (vec![("c1", vec![5]), ("c1", vec![5])], false, Some("E1311")),
+    case04_wrong_dimension: (vec![("c1", vec![5, 1])], false, Some("E1311")),
+    case05_exceeds_vehicle_capacity: (vec![("c1", vec![11])], false, Some("E1311")),
+    case06_with_reloads: (vec![("c1", vec![5])], true, Some("E1311")),
+}
+
+fn can_detect_invalid_compartments_impl(
+    compartments: Vec<(&str, Vec<i32>)>,
+    has_reloads: bool,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: if has_reloads { Some(vec![create_default_reload()]) } else { None },
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![10],
+                compartments: Some(
+                    compartments
+                        .into_iter()
+                        .map(|(id, capacity)| VehicleCompartment {
+                            id: id.to_string(),
+                            capacity,
+                            products: None,
+                            incompatible: None,
+                        })
+                        .collect(),
+                ),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1311_vehicle_compartments(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_satellites, (satellites, shift_satellite, start, has_reloads, expected), {
+    can_detect_invalid_satellites_impl(satellites, shift_satellite, start, has_reloads, expected);
+}}
+
+can_detect_invalid_satellites! {
+    case01_valid: (vec!["s1"], Some("s1"), (10., 0.), false, None),
+    case02_no_satellite_shift: (vec!["s1"], None, (0., 0.), false, None),
+    case03_duplicate_ids: (vec!["s1", "s1"], Some("s1"), (10., 0.), false, Some("E1312")),
+    case04_unknown_satellite: (vec!["s1"], Some("s2"), (10., 0.), false, Some("E1312")),
+    case05_wrong_location: (vec!["s1"], Some("s1"), (0., 0.), false, Some("E1312")),
+    case06_with_reloads: (vec!["s1"], Some("s1"), (10., 0.), true, Some("E1312")),
+}
+
+fn can_detect_invalid_satellites_impl(
+    satellites: Vec<&str>,
+    shift_satellite: Option<&str>,
+    start: (f64, f64),
+    has_reloads: bool,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: start.to_loc(),
+                        satellite: shift_satellite.map(|id| id.to_string()),
+                    },
+                    reloads: if has_reloads { Some(vec![create_default_reload()]) } else { None },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(
+                satellites
+                    .into_iter()
+                    .map(|id| VehicleResource::Satellite { id: id.to_string(), location: (10., 0.).to_loc() })
+                    .collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1312_vehicle_satellites(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_hours_of_service, (driving_break, daily_rest, limits, expected), {
+    can_detect_invalid_hours_of_service_impl(driving_break, daily_rest, limits, expected);
+}}
+
+can_detect_invalid_hours_of_service! {
+    case01_valid: (Some((16200., vec![900., 1800.])), Some((32400., 39600.)), (Some(32400.), Some(201600.), Some(201600.)), None),
+    case02_only_limit: (None, None, (Some(32400.), None, None), None),
+    case03_empty: (None, None, (None, None, None), Some("E1313")),
+    case04_no_parts: (Some((16200., vec![])), None, (None, None, None), Some("E1313")),
+    case05_negative_part: (Some((16200., vec![-1.])), None, (None, None, None), Some("E1313")),
+    case06_zero_max_driving: (Some((0., vec![2700.])), None, (None, None, None), Some("E1313")),
+    case07_negative_limit: (None, None, (None, Some(-1.), None), Some("E1313")),
+    case08_only_daily_rest: (None, Some((32400., 39600.)), (None, None, None), None),
+    case09_zero_daily_rest: (None, Some((32400., 0.)), (None, None, None), Some("E1313")),
+    case10_only_weekly_limit: (None, None, (None, None, Some(201600.)), None),
+    case11_negative_weekly_limit: (None, None, (None, None, Some(-1.)), Some("E1313")),
+}
+
+fn can_detect_invalid_hours_of_service_impl(
+    driving_break: Option<(Float, Vec<Float>)>,
+    daily_rest: Option<(Float, Float)>,
+    limits: (Option<Float>, Option<Float>, Option<Float>),
+    expected: Option<&str>,
+) {
+    let (max_tour_driving, max_vehicle_driving, max_weekly_driving) = limits;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                hours_of_service: Some(VehicleHoursOfService {
+                    driving_break: driving_break.map(|(max_driving, parts)| VehicleDrivingBreak { max_driving, parts }),
+                    daily_rest: daily_rest.map(|(max_driving, duration)| VehicleDailyRest { max_driving, duration }),
+                    max_tour_driving,
+                    max_vehicle_driving,
+                    max_weekly_driving,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1313_vehicle_hours_of_service(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_overtime, (regular_end, rates, expected), {
+    can_detect_invalid_overtime_impl(regular_end, rates, expected);
+}}
+
+can_detect_invalid_overtime! {
+    case01_valid: (format_time(500.), vec![(0., 2.), (100., 3.)], None),
+    case02_end_outside_shift: (format_time(2000.), vec![(0., 2.)], Some("E1314")),
+    case03_invalid_end: ("not a time".to_string(), vec![(0., 2.)], Some("E1314")),
+    case04_no_rates: (format_time(500.), vec![], Some("E1314")),
+    case05_negative_offset: (format_time(500.), vec![(-1., 2.)], Some("E1314")),
+    case06_unsorted_offsets: (format_time(500.), vec![(100., 2.), (100., 3.)], Some("E1314")),
+    case07_cheaper_than_regular: (format_time(500.), vec![(0., 0.5)], Some("E1314")),
+}
+
+fn can_detect_invalid_overtime_impl(regular_end: String, rates: Vec<(Float, Float)>, expected: Option<&str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    overtime: Some(VehicleOvertime {
+                        regular_end,
+                        rates: rates.into_iter().map(|(after, time)| VehicleOvertimeRate { after, time }).collect(),
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1314_vehicle_overtime(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_consumption, (empty, full, drag, co2, expected), {
+    can_detect_invalid_consumption_impl(empty, full, drag, co2, expected);
+}}
+
+can_detect_invalid_consumption! {
+    case01_valid: (0.3, 0.4, Some(0.001), 2.6, None),
+    case02_valid_without_drag: (0.3, 0.3, None, 2.6, None),
+    case03_negative_empty: (-0.3, 0.4, None, 2.6, Some("E1315")),
+    case04_full_less_than_empty: (0.4, 0.3, None, 2.6, Some("E1315")),
+    case05_negative_drag: (0.3, 0.4, Some(-0.001), 2.6, Some("E1315")),
+    case06_negative_co2: (0.3, 0.4, None, -2.6, Some("E1315")),
+}
+
+fn can_detect_invalid_consumption_impl(
+    empty: Float,
+    full: Float,
+    drag: Option<Float>,
+    co2: Float,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                consumption: Some(VehicleConsumption { empty, full, drag, co2 }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1315_vehicle_consumption(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_recharges, (max_distance, battery, curve, expected), {
+    can_detect_invalid_recharges_impl(max_distance, battery, curve, expected);
+}}
+
+can_detect_invalid_recharges! {
+    case01_valid_distance: (Some(100.), None, None, None),
+    case02_valid_battery: (None, Some((60., Some(30.), Some(5.), 0.2, Some(0.3))), Some(vec![(0., 50.), (0.8, 20.)]), None),
+    case03_no_limit: (None, None, None, Some("E1316")),
+    case04_negative_distance: (Some(-1.), None, None, Some("E1316")),
+    case05_zero_capacity: (None, Some((0., None, None, 0.2, None)), None, Some("E1316")),
+    case06_initial_above_capacity: (None, Some((60., Some(70.), None, 0.2, None)), None, Some("E1316")),
+    case07_reserve_above_initial: (None, Some((60., Some(10.), Some(20.), 0.2, None)), None, Some("E1316")),
+    case08_full_less_than_empty: (None, Some((60., None, None, 0.3, Some(0.2))), None, Some("E1316")),
+    case09_empty_curve: (None, Some((60., None, None, 0.2, None)), Some(vec![]), Some("E1316")),
+    case10_invalid_curve_soc: (None, Some((60., None, None, 0.2, None)), Some(vec![(1.2, 50.)]), Some("E1316")),
+    case11_invalid_curve_power: (None, Some((60., None, None, 0.2, None)), Some(vec![(0.5, 0.)]), Some("E1316")),
+}
+
+#[allow(clippy::type_complexity)]
+fn can_detect_invalid_recharges_impl(
+    max_distance: Option<Float>,
+    battery: Option<(Float, Option<Float>, Option<Float>, Float, Option<Float>)>,
+    curve: Option<Vec<(Float, Float)>>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance,
+                        battery: battery.map(|(capacity, initial, reserve, empty, full)| VehicleBattery {
+                            capacity,
+                            initial,
+                            reserve,
+                            consumption: VehicleEnergyConsumption { empty, full },
+                        }),
+                        stations: vec![VehicleRechargeStation {
+                            location: (1., 0.).to_loc(),
+                            duration: 0.,
+                            times: None,
+                            tag: None,
+                            charging_curve: curve.map(|curve| {
+                                curve.into_iter().map(|(soc, power)| VehicleChargingPoint { soc, power }).collect()
+                            }),
+                            resource_id: None,
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1316_vehicle_recharges(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_handle_recharge_resources, (resources, expected), {
+    can_handle_recharge_resources_impl(resources, expected);
+}}
+
+can_handle_recharge_resources! {
+    case01_valid: (vec![("r1", 2)], None),
+    case02_unknown_resource: (vec![("r2", 2)], Some("E1317")),
+    case03_duplicate_ids: (vec![("r1", 2), ("r1", 1)], Some("E1317")),
+    case04_no_chargers: (vec![("r1", 0)], Some("E1317")),
+}
+
+fn can_handle_recharge_resources_impl(resources: Vec<(&str, usize)>, expected: Option<&str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: Some(100.),
+                        battery: None,
+                        stations: vec![VehicleRechargeStation {
+                            location: (1., 0.).to_loc(),
+                            duration: 0.,
+                            times: None,
+                            tag: None,
+                            charging_curve: None,
+                            resource_id: Some("r1".to_string()),
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(
+                resources
+                    .into_iter()
+                    .map(|(id, chargers)| VehicleResource::Recharge { id: id.to_string(), chargers })
+                    .collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1317_vehicle_recharge_resources(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_cargo_space, (size, max_weight, expected), {
+    can_detect_invalid_cargo_space_impl(size, max_weight, expected);
+}}
+
+can_detect_invalid_cargo_space! {
+    case01_valid: ((4., 2., 2.), Some(100.), None),
+    case02_no_weight: ((4., 2., 2.), None, None),
+    case03_zero_length: ((0., 2., 2.), None, Some("E1318")),
+    case04_negative_height: ((4., 2., -1.), None, Some("E1318")),
+    case05_negative_weight: ((4., 2., 2.), Some(-1.), Some("E1318")),
+}
+
+fn can_detect_invalid_cargo_space_impl(size: (Float, Float, Float), max_weight: Option<Float>, expected: Option<&str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                cargo_space: Some(VehicleCargoSpace { length: size.0, width: size.1, height: size.2, max_weight }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1318_vehicle_cargo_space(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_hours_of_service_rests_with_rescheduling, (has_break, has_daily_rest, has_recharges, reload_resource, expected), {
+    can_detect_hours_of_service_rests_with_rescheduling_impl(has_break, has_daily_rest, has_recharges, reload_resource, expected);
+}}
+
+can_detect_hours_of_service_rests_with_rescheduling! {
+    case01_no_rescheduling: (true, true, false, None, None),
+    case02_break_with_recharges: (true, false, true, None, Some("E1320")),
+    case03_daily_rest_with_reload_resource: (false, true, false, Some("resource"), Some("E1320")),
+    case04_reload_without_resource: (true, false, false, None, None),
+    case05_limits_with_recharges: (false, false, true, Some("resource"), None),
+}
+
+fn can_detect_hours_of_service_rests_with_rescheduling_impl(
+    has_break: bool,
+    has_daily_rest: bool,
+    has_recharges: bool,
+    reload_resource: Option<&str>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        location: (1., 0.).to_loc(),
+                        duration: 2.,
+                        times: None,
+                        tag: None,
+                        resource_id: reload_resource.map(|id| id.to_string()),
+                    }]),
+                    recharges: has_recharges.then(|| VehicleRecharges {
+                        max_distance: Some(100.),
+                        battery: None,
+                        stations: vec![],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                hours_of_service: Some(VehicleHoursOfService {
+                    driving_break: has_break.then(|| VehicleDrivingBreak { max_driving: 100., parts: vec![10.] }),
+                    daily_rest: has_daily_rest.then_some(VehicleDailyRest { max_driving: 200., duration: 50. }),
+                    max_tour_driving: Some(300.),
+                    max_vehicle_driving: None,
+                    max_weekly_driving: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1320_vehicle_hours_of_service_rests(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}